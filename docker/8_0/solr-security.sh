@@ -1,3 +1,4 @@
 #!/bin/sh
+mkdir -p /var/solr/backups
 solr zk cp /opt/solr-8.0.0/security.json zk:security.json -z zoo1:2181
 exec /opt/docker-solr/scripts/docker-entrypoint.sh -f "$@"
\ No newline at end of file
@@ -1,14 +1,42 @@
 use crate::error::Error;
+use crate::models::backup::{BackupPoint, BackupResult, DeletedBackup};
+use crate::models::cluster_status::ClusterStatus;
+use crate::models::col_status::ColStatus;
 use crate::models::context::SolrServerContext;
+use crate::models::extract::SolrExtractResponse;
+use crate::models::reindex::SolrReindexStatus;
 use crate::models::response::SolrResponse;
-use crate::queries::alias::{alias_exists, create_alias, delete_alias, get_aliases};
+use crate::queries::alias::{
+    alias_exists, create_alias, create_alias_with_options, delete_alias, delete_alias_with_options,
+    get_aliases, CreateAliasOptions, DeleteAliasOptions,
+};
+use crate::queries::backup::{
+    backup_collection, delete_backup, list_backups, restore_collection, BackupOptions,
+    DeleteBackupOptions, ListBackupsOptions, RestoreOptions,
+};
+use crate::queries::bulk_index::{BulkIndexSummary, BulkIndexer};
 use crate::queries::collection::{
-    collection_exists, create_collection, delete_collection, get_collections,
+    add_replica, add_replica_property, collection_exists, create_collection,
+    create_collection_with_options, create_shard, delete_collection,
+    delete_collection_with_options, delete_node, delete_replica, delete_replica_property,
+    delete_shard, get_cluster_status, get_col_status, get_collections, modify_collection,
+    move_replica, reload_collection, reload_collections_using_config, rename_collection,
+    replace_node, split_shard, AddReplicaOptions, ColStatusOptions, CreateCollectionOptions,
+    CreateShardOptions, DeleteCollectionOptions, DeleteNodeOptions, DeleteReplicaOptions,
+    DeleteShardOptions, ModifyCollectionOptions, MoveReplicaOptions, ReloadCollectionOptions,
+    RenameCollectionOptions, ReplaceNodeOptions, ReplicaPropertyOptions, SplitShardOptions,
 };
-use crate::queries::config::{config_exists, delete_config, get_configs, upload_config};
-use crate::queries::index::{DeleteQuery, UpdateQuery};
+use crate::queries::config::{
+    config_exists, delete_config, get_configs, upload_config, upload_elevation_config,
+};
+use crate::queries::extract::ExtractQuery;
+use crate::queries::file_update::{CsvUpdateQuery, JsonDocsUpdateQuery};
+use crate::queries::index::{CommitQuery, DeleteQuery, OptimizeQuery, RollbackQuery, UpdateQuery};
+use crate::queries::reindex::{ReindexCollectionQuery, ReindexSummary, Reindexer};
 use crate::queries::select::SelectQuery;
+use crate::queries::update_batch::UpdateBatch;
 use crate::SelectDestination;
+use futures::Stream;
 use serde::Serialize;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -61,6 +89,28 @@ impl AsyncSolrCloudClient {
         upload_config(&self.context, name, path).await
     }
 
+    /// Upload a new `elevate.xml` into an existing config, and reload every collection using it
+    /// # Examples
+    /// ```no_run
+    /// # use std::path::Path;
+    /// # use solrstice::{AsyncSolrCloudClient, SolrServerContextBuilder, SolrSingleServerHost};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let reloaded: Vec<String> = client
+    ///     .upload_elevation_config("config_name", Path::new("/path/to/elevate.xml"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_elevation_config<N: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        name: N,
+        path: P,
+    ) -> Result<Vec<String>, Error> {
+        upload_elevation_config(&self.context, name, path).await
+    }
+
     /// Get the configs existing in SolrCloud
     /// # Examples
     /// ```no_run
@@ -138,6 +188,37 @@ impl AsyncSolrCloudClient {
         create_collection(&self.context, name, config, shards, replication_factor).await
     }
 
+    /// Create a collection in SolrCloud, with all options of the `CREATE` action
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::queries::collection::{CollectionRouter, CreateCollectionOptions};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let options = CreateCollectionOptions::new()
+    ///     .config_name("config_name")
+    ///     .router(CollectionRouter::Implicit)
+    ///     .shards(["shard_a", "shard_b"])
+    ///     .nrt_replicas(2)
+    ///     .wait_for_final_state(true);
+    /// client.create_collection_with_options("collection_name", &options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_collection_with_options<
+        S: AsRef<str>,
+        O: AsRef<CreateCollectionOptions>,
+    >(
+        &self,
+        name: S,
+        options: O,
+    ) -> Result<(), Error> {
+        create_collection_with_options(&self.context, name, options).await
+    }
+
     /// Get collections from SolrCloud
     /// # Examples
     /// ```no_run
@@ -172,6 +253,56 @@ impl AsyncSolrCloudClient {
         collection_exists(&self.context, name).await
     }
 
+    /// Get the state of the cluster, optionally limited to one collection and some of its shards
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let status = client.get_cluster_status(Some("collection_name"), &[]).await?;
+    /// for shard in status.get_collection("collection_name").unwrap().get_shards().values() {
+    ///     let leader = shard.get_leader().map(|leader| leader.get_node_name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_cluster_status(
+        &self,
+        collection: Option<&str>,
+        shards: &[&str],
+    ) -> Result<ClusterStatus, Error> {
+        get_cluster_status(&self.context, collection, shards).await
+    }
+
+    /// Get the index status of collections, with the size and segments of each shard leader. Requires Solr 8.1 or later
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::ColStatusOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let options = ColStatusOptions::new().core_info(true).segments(true);
+    /// let status = client.get_col_status(Some("collection_name"), &options).await?;
+    /// for shard in status.get_collection("collection_name").unwrap().get_shards().values() {
+    ///     let size = shard.get_leader().and_then(|leader| leader.get_segments_info()).and_then(|info| info.get_size_in_gb());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_col_status<O: AsRef<ColStatusOptions>>(
+        &self,
+        collection: Option<&str>,
+        options: O,
+    ) -> Result<ColStatus, Error> {
+        get_col_status(&self.context, collection, options).await
+    }
+
     /// Delete a collection from SolrCloud
     /// # Examples
     /// ```no_run
@@ -189,180 +320,981 @@ impl AsyncSolrCloudClient {
         delete_collection(&self.context, name).await
     }
 
-    /// Create an alias in SolrCloud
+    /// Delete a collection from SolrCloud, with the options of the `DELETE` action
     /// # Examples
     /// ```no_run
     /// # use solrstice::AsyncSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::DeleteCollectionOptions;
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = AsyncSolrCloudClient::new(context);
-    /// client.create_alias("alias_name", &["collection1", "collection2"]).await?;
+    /// let options = DeleteCollectionOptions::new().async_id("delete-collection");
+    /// client.delete_collection_with_options("collection_name", &options).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn create_alias<S: AsRef<str>>(
+    pub async fn delete_collection_with_options<
+        S: AsRef<str>,
+        O: AsRef<DeleteCollectionOptions>,
+    >(
         &self,
-        alias: S,
-        collections: &[S],
+        name: S,
+        options: O,
     ) -> Result<(), Error> {
-        create_alias(&self.context, alias, collections).await
+        delete_collection_with_options(&self.context, name, options).await
     }
 
-    /// Get aliases from SolrCloud
+    /// Reload a collection, so that changes to its config set take effect
     /// # Examples
     /// ```no_run
-    /// # use std::collections::HashMap;
     /// # use solrstice::AsyncSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::ReloadCollectionOptions;
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = AsyncSolrCloudClient::new(context);
-    /// let aliases: HashMap<String, Vec<String>> = client.get_aliases().await?;
+    /// client.reload_collection("collection_name", ReloadCollectionOptions::new()).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_aliases(&self) -> Result<HashMap<String, Vec<String>>, Error> {
-        get_aliases(&self.context).await
+    pub async fn reload_collection<S: AsRef<str>, O: AsRef<ReloadCollectionOptions>>(
+        &self,
+        name: S,
+        options: O,
+    ) -> Result<(), Error> {
+        reload_collection(&self.context, name, options).await
     }
 
-    /// Check if an alias exists in SolrCloud
+    /// Reload every collection using a config set, after it has been changed. Returns the names of the reloaded collections
     /// # Examples
     /// ```no_run
     /// # use solrstice::AsyncSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
+    /// # use std::path::Path;
+    /// use solrstice::queries::collection::ReloadCollectionOptions;
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = AsyncSolrCloudClient::new(context);
-    /// let exists: bool = client.alias_exists("alias_name").await?;
+    /// client.upload_config("config_name", Path::new("/path/to/config")).await?;
+    /// let reloaded: Vec<String> = client.reload_collections_using_config("config_name", ReloadCollectionOptions::new()).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn alias_exists<N: AsRef<str>>(&self, name: N) -> Result<bool, Error> {
-        alias_exists(&self.context, name).await
+    pub async fn reload_collections_using_config<
+        S: AsRef<str>,
+        O: AsRef<ReloadCollectionOptions>,
+    >(
+        &self,
+        config: S,
+        options: O,
+    ) -> Result<Vec<String>, Error> {
+        reload_collections_using_config(&self.context, config, options).await
     }
 
-    /// Delete an alias from SolrCloud
+    /// Change the replication factor, config set, properties or read only state of a collection
     /// # Examples
     /// ```no_run
     /// # use solrstice::AsyncSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::ModifyCollectionOptions;
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = AsyncSolrCloudClient::new(context);
-    /// client.delete_alias("alias_name").await?;
+    /// client.modify_collection("collection_name", ModifyCollectionOptions::new().read_only(true)).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete_alias<S: AsRef<str>>(&self, name: S) -> Result<(), Error> {
-        delete_alias(&self.context, name).await
+    pub async fn modify_collection<S: AsRef<str>, O: AsRef<ModifyCollectionOptions>>(
+        &self,
+        collection: S,
+        options: O,
+    ) -> Result<(), Error> {
+        modify_collection(&self.context, collection, options).await
     }
 
-    /// Index some data into SolrCloud
+    /// Rename a collection. Solr keeps the data under the old name, behind an alias with the new name. Requires Solr 8.1 or later
     /// # Examples
     /// ```no_run
     /// # use solrstice::AsyncSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
-    /// # use solrstice::UpdateQuery;
-    /// # use serde::Serialize;
+    /// use solrstice::queries::collection::RenameCollectionOptions;
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
-    /// #[derive(Serialize)]
-    /// struct Data {id: String}
-    ///
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = AsyncSolrCloudClient::new(context);
-    /// let response = client.index(&UpdateQuery::new(), "collection_name", &[Data {id: "test".to_string()}]).await?;
+    /// client.rename_collection("collection_name", "new_collection_name", RenameCollectionOptions::new().async_id("rename")).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn index<T: Serialize, B: AsRef<UpdateQuery>, C: AsRef<str>>(
+    pub async fn rename_collection<S: AsRef<str>, O: AsRef<RenameCollectionOptions>>(
         &self,
-        builder: B,
-        collection: C,
-        data: &[T],
-    ) -> Result<SolrResponse, Error> {
-        builder
-            .as_ref()
-            .execute(&self.context, collection, data)
-            .await
+        name: S,
+        target: S,
+        options: O,
+    ) -> Result<(), Error> {
+        rename_collection(&self.context, name, target, options).await
     }
 
-    /// Select some data from SolrCloud
+    /// Split a shard into sub-shards. The parent shard is kept as `inactive` until it is deleted
     /// # Examples
     /// ```no_run
     /// # use solrstice::AsyncSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
-    /// # use solrstice::SelectQuery;
+    /// use solrstice::queries::collection::{DeleteShardOptions, SplitMethod, SplitShardOptions};
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = AsyncSolrCloudClient::new(context);
-    /// let response = client.select(&SelectQuery::new().fq(["age:[* TO *]"]), "collection_name").await?;
+    /// let options = SplitShardOptions::new().shard("shard1").num_sub_shards(4).split_method(SplitMethod::Link);
+    /// client.split_shard("collection_name", &options).await?;
+    /// client.delete_shard("collection_name", "shard1", DeleteShardOptions::new()).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn select<B: AsRef<SelectQuery>, D: Into<SelectDestination>>(
+    pub async fn split_shard<S: AsRef<str>, O: AsRef<SplitShardOptions>>(
         &self,
-        builder: B,
-        destination: D,
-    ) -> Result<SolrResponse, Error> {
-        builder
-            .as_ref()
-            .execute(&self.context, destination.into())
-            .await
+        collection: S,
+        options: O,
+    ) -> Result<(), Error> {
+        split_shard(&self.context, collection, options).await
     }
 
-    /// Select some data from SolrCloud. Return the response directly
+    /// Add a shard to a collection using the implicit router
     /// # Examples
     /// ```no_run
-    /// # use std::collections::HashMap;
     /// # use solrstice::AsyncSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
-    /// # use solrstice::SelectQuery;
+    /// use solrstice::queries::collection::CreateShardOptions;
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = AsyncSolrCloudClient::new(context);
-    /// let response: HashMap<String, serde_json::Value> = client.select_raw(&SelectQuery::new().fq(["age:[* TO *]"]), "collection_name").await?;
+    /// let options = CreateShardOptions::new().nrt_replicas(1);
+    /// client.create_shard("collection_name", "2025", &options).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn select_raw<B: AsRef<SelectQuery>, D: Into<SelectDestination>>(
+    pub async fn create_shard<S: AsRef<str>, O: AsRef<CreateShardOptions>>(
         &self,
-        builder: B,
-        destination: D,
-    ) -> Result<HashMap<String, Value>, Error> {
-        builder
-            .as_ref()
-            .execute_raw(&self.context, destination.into())
-            .await
+        collection: S,
+        shard: S,
+        options: O,
+    ) -> Result<(), Error> {
+        create_shard(&self.context, collection, shard, options).await
     }
 
-    /// Delete some data from SolrCloud
+    /// Delete an inactive shard, or a shard of a collection using the implicit router
     /// # Examples
     /// ```no_run
     /// # use solrstice::AsyncSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
-    /// # use solrstice::DeleteQuery;
-    /// # use solrstice::SelectQuery;
+    /// use solrstice::queries::collection::DeleteShardOptions;
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = AsyncSolrCloudClient::new(context);
-    /// let response = client.delete(&DeleteQuery::new().ids(["document1"]).queries(["age:[* TO *]"]), "collection_name").await?;
+    /// client.delete_shard("collection_name", "shard1", DeleteShardOptions::new()).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete<B: AsRef<DeleteQuery>, C: AsRef<str>>(
+    pub async fn delete_shard<S: AsRef<str>, O: AsRef<DeleteShardOptions>>(
         &self,
-        builder: B,
-        collection: C,
-    ) -> Result<SolrResponse, Error> {
-        builder.as_ref().execute(&self.context, collection).await
+        collection: S,
+        shard: S,
+        options: O,
+    ) -> Result<(), Error> {
+        delete_shard(&self.context, collection, shard, options).await
+    }
+
+    /// Add replicas to a shard
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::models::ReplicaType;
+    /// use solrstice::queries::collection::AddReplicaOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let options = AddReplicaOptions::new().replica_type(ReplicaType::Pull).node("localhost:7574_solr");
+    /// client.add_replica("collection_name", "shard1", &options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_replica<S: AsRef<str>, O: AsRef<AddReplicaOptions>>(
+        &self,
+        collection: S,
+        shard: S,
+        options: O,
+    ) -> Result<(), Error> {
+        add_replica(&self.context, collection, shard, options).await
+    }
+
+    /// Delete replicas by name, count or node
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::DeleteReplicaOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let options = DeleteReplicaOptions::new().shard("shard1").replica("core_node3");
+    /// client.delete_replica("collection_name", &options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_replica<S: AsRef<str>, O: AsRef<DeleteReplicaOptions>>(
+        &self,
+        collection: S,
+        options: O,
+    ) -> Result<(), Error> {
+        delete_replica(&self.context, collection, options).await
+    }
+
+    /// Move a replica to another node
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::MoveReplicaOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let options = MoveReplicaOptions::new().replica("core_node3");
+    /// client.move_replica("collection_name", "localhost:7574_solr", &options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn move_replica<S: AsRef<str>, O: AsRef<MoveReplicaOptions>>(
+        &self,
+        collection: S,
+        target_node: S,
+        options: O,
+    ) -> Result<(), Error> {
+        move_replica(&self.context, collection, target_node, options).await
+    }
+
+    /// Delete all replicas on a node
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::DeleteNodeOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// client.delete_node("localhost:8983_solr", DeleteNodeOptions::new().async_id("delete-8983")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_node<S: AsRef<str>, O: AsRef<DeleteNodeOptions>>(
+        &self,
+        node: S,
+        options: O,
+    ) -> Result<(), Error> {
+        delete_node(&self.context, node, options).await
+    }
+
+    /// Move all replicas on a node to other nodes
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::{DeleteNodeOptions, ReplaceNodeOptions};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// client.replace_node("localhost:8983_solr", ReplaceNodeOptions::new()).await?;
+    /// client.delete_node("localhost:8983_solr", DeleteNodeOptions::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn replace_node<S: AsRef<str>, O: AsRef<ReplaceNodeOptions>>(
+        &self,
+        source_node: S,
+        options: O,
+    ) -> Result<(), Error> {
+        replace_node(&self.context, source_node, options).await
+    }
+
+    /// Set a property of a replica. With `shard_unique`, it is removed from the other replicas of the shard
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::ReplicaPropertyOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// client.add_replica_property("collection_name", "shard1", "core_node3", "preferredLeader", "true", ReplicaPropertyOptions::new().shard_unique(true)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_replica_property<S: AsRef<str>, O: AsRef<ReplicaPropertyOptions>>(
+        &self,
+        collection: S,
+        shard: S,
+        replica: S,
+        property: S,
+        value: S,
+        options: O,
+    ) -> Result<(), Error> {
+        add_replica_property(
+            &self.context,
+            collection,
+            shard,
+            replica,
+            property,
+            value,
+            options,
+        )
+        .await
+    }
+
+    /// Remove a property of a replica
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::ReplicaPropertyOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// client.delete_replica_property("collection_name", "shard1", "core_node3", "preferredLeader", ReplicaPropertyOptions::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_replica_property<S: AsRef<str>, O: AsRef<ReplicaPropertyOptions>>(
+        &self,
+        collection: S,
+        shard: S,
+        replica: S,
+        property: S,
+        options: O,
+    ) -> Result<(), Error> {
+        delete_replica_property(&self.context, collection, shard, replica, property, options).await
+    }
+
+    /// Back up a collection to a backup repository. Each incremental backup of the same name adds a backup point
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::backup::BackupOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let options = BackupOptions::new().location("/var/solr/backups").max_num_backup_points(7);
+    /// let result = client.backup_collection("collection_name", "nightly", &options).await?;
+    /// let files: Vec<Option<u64>> = result.get_shards().iter().map(|shard| shard.get_index_file_count()).collect();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn backup_collection<S: AsRef<str>, O: AsRef<BackupOptions>>(
+        &self,
+        collection: S,
+        name: S,
+        options: O,
+    ) -> Result<BackupResult, Error> {
+        backup_collection(&self.context, collection, name, options).await
+    }
+
+    /// Restore a backup point into a new collection
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::backup::RestoreOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let options = RestoreOptions::new().location("/var/solr/backups").backup_id(3);
+    /// client.restore_collection("restored_collection", "nightly", options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn restore_collection<S: AsRef<str>, O: AsRef<RestoreOptions>>(
+        &self,
+        collection: S,
+        name: S,
+        options: O,
+    ) -> Result<(), Error> {
+        restore_collection(&self.context, collection, name, options).await
+    }
+
+    /// List the backup points of a backup. Requires Solr 8.9 or later
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::backup::ListBackupsOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let backups = client.list_backups("nightly", ListBackupsOptions::new().location("/var/solr/backups")).await?;
+    /// let latest = backups.iter().map(|backup| backup.get_backup_id()).max();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_backups<S: AsRef<str>, O: AsRef<ListBackupsOptions>>(
+        &self,
+        name: S,
+        options: O,
+    ) -> Result<Vec<BackupPoint>, Error> {
+        list_backups(&self.context, name, options).await
+    }
+
+    /// Delete backup points, or the files no backup point refers to. Requires Solr 8.9 or later
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::backup::DeleteBackupOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let options = DeleteBackupOptions::new().location("/var/solr/backups").max_num_backup_points(3);
+    /// client.delete_backup("nightly", options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_backup<S: AsRef<str>, O: AsRef<DeleteBackupOptions>>(
+        &self,
+        name: S,
+        options: O,
+    ) -> Result<Vec<DeletedBackup>, Error> {
+        delete_backup(&self.context, name, options).await
+    }
+
+    /// Create an alias in SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// client.create_alias("alias_name", &["collection1", "collection2"]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_alias<S: AsRef<str>>(
+        &self,
+        alias: S,
+        collections: &[S],
+    ) -> Result<(), Error> {
+        create_alias(&self.context, alias, collections).await
+    }
+
+    /// Create an alias in SolrCloud, with the options of the `CREATEALIAS` action
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::alias::CreateAliasOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let options = CreateAliasOptions::new().async_id("create-alias");
+    /// client.create_alias_with_options("alias_name", &["collection1", "collection2"], &options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_alias_with_options<S: AsRef<str>, O: AsRef<CreateAliasOptions>>(
+        &self,
+        alias: S,
+        collections: &[S],
+        options: O,
+    ) -> Result<(), Error> {
+        create_alias_with_options(&self.context, alias, collections, options).await
+    }
+
+    /// Get aliases from SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let aliases: HashMap<String, Vec<String>> = client.get_aliases().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_aliases(&self) -> Result<HashMap<String, Vec<String>>, Error> {
+        get_aliases(&self.context).await
+    }
+
+    /// Check if an alias exists in SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let exists: bool = client.alias_exists("alias_name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn alias_exists<N: AsRef<str>>(&self, name: N) -> Result<bool, Error> {
+        alias_exists(&self.context, name).await
+    }
+
+    /// Delete an alias from SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// client.delete_alias("alias_name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_alias<S: AsRef<str>>(&self, name: S) -> Result<(), Error> {
+        delete_alias(&self.context, name).await
+    }
+
+    /// Delete an alias from SolrCloud, with the options of the `DELETEALIAS` action
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::alias::DeleteAliasOptions;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let options = DeleteAliasOptions::new().async_id("delete-alias");
+    /// client.delete_alias_with_options("alias_name", &options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_alias_with_options<S: AsRef<str>, O: AsRef<DeleteAliasOptions>>(
+        &self,
+        name: S,
+        options: O,
+    ) -> Result<(), Error> {
+        delete_alias_with_options(&self.context, name, options).await
+    }
+
+    /// Index some data into SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::UpdateQuery;
+    /// # use serde::Serialize;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #[derive(Serialize)]
+    /// struct Data {id: String}
+    ///
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let response = client.index(&UpdateQuery::new(), "collection_name", &[Data {id: "test".to_string()}]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn index<T: Serialize, B: AsRef<UpdateQuery>, C: AsRef<str>>(
+        &self,
+        builder: B,
+        collection: C,
+        data: &[T],
+    ) -> Result<SolrResponse, Error> {
+        builder
+            .as_ref()
+            .execute(&self.context, collection, data)
+            .await
+    }
+
+    /// Index a stream of documents in concurrent batches, with a single commit at the end. See [BulkIndexer] for the options.
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::{AsyncSolrCloudClient, BulkIndexer};
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use serde::Serialize;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #[derive(Serialize)]
+    /// struct Data {id: String}
+    ///
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let docs = (0..100_000).map(|i| Data {id: i.to_string()});
+    /// let summary = client.bulk_index(&BulkIndexer::new(), "collection_name", futures::stream::iter(docs)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn bulk_index<
+        T: Serialize,
+        B: AsRef<BulkIndexer>,
+        C: AsRef<str>,
+        S: Stream<Item = T>,
+    >(
+        &self,
+        indexer: B,
+        collection: C,
+        docs: S,
+    ) -> Result<BulkIndexSummary, Error> {
+        indexer
+            .as_ref()
+            .execute(&self.context, collection, docs)
+            .await
+    }
+
+    /// Select some data from SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::SelectQuery;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let response = client.select(&SelectQuery::new().fq(["age:[* TO *]"]), "collection_name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn select<B: AsRef<SelectQuery>, D: Into<SelectDestination>>(
+        &self,
+        builder: B,
+        destination: D,
+    ) -> Result<SolrResponse, Error> {
+        builder
+            .as_ref()
+            .execute(&self.context, destination.into())
+            .await
+    }
+
+    /// Select some data from SolrCloud. Return the response directly
+    /// # Examples
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::SelectQuery;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let response: HashMap<String, serde_json::Value> = client.select_raw(&SelectQuery::new().fq(["age:[* TO *]"]), "collection_name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn select_raw<B: AsRef<SelectQuery>, D: Into<SelectDestination>>(
+        &self,
+        builder: B,
+        destination: D,
+    ) -> Result<HashMap<String, Value>, Error> {
+        builder
+            .as_ref()
+            .execute_raw(&self.context, destination.into())
+            .await
+    }
+
+    /// Delete some data from SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::DeleteQuery;
+    /// # use solrstice::SelectQuery;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let response = client.delete(&DeleteQuery::new().ids(["document1"]).queries(["age:[* TO *]"]), "collection_name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete<B: AsRef<DeleteQuery>, C: AsRef<str>>(
+        &self,
+        builder: B,
+        collection: C,
+    ) -> Result<SolrResponse, Error> {
+        builder.as_ref().execute(&self.context, collection).await
+    }
+
+    /// Commit pending changes
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::CommitQuery;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let response = client.commit(&CommitQuery::new().soft_commit(true), "collection_name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn commit<B: AsRef<CommitQuery>, C: AsRef<str>>(
+        &self,
+        builder: B,
+        collection: C,
+    ) -> Result<SolrResponse, Error> {
+        builder.as_ref().execute(&self.context, collection).await
+    }
+
+    /// Optimize a collection, merging its segments
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::OptimizeQuery;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let response = client.optimize(&OptimizeQuery::new().max_segments(1), "collection_name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn optimize<B: AsRef<OptimizeQuery>, C: AsRef<str>>(
+        &self,
+        builder: B,
+        collection: C,
+    ) -> Result<SolrResponse, Error> {
+        builder.as_ref().execute(&self.context, collection).await
+    }
+
+    /// Roll back all changes since the last commit. Only supported on standalone servers, not in SolrCloud mode
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::RollbackQuery;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let response = client.rollback(&RollbackQuery::new(), "core_name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rollback<B: AsRef<RollbackQuery>, C: AsRef<str>>(
+        &self,
+        builder: B,
+        collection: C,
+    ) -> Result<SolrResponse, Error> {
+        builder.as_ref().execute(&self.context, collection).await
+    }
+
+    /// Send several update commands in one request. See [UpdateBatch]
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::{CommitQuery, DeleteCommand, UpdateBatch};
+    /// # use serde_json::json;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let batch = UpdateBatch::new()
+    ///     .delete(DeleteCommand::query("category:outdated"))
+    ///     .add_doc(json!({"id": "document1"}))
+    ///     .commit(CommitQuery::new());
+    /// let response = client.update(&batch, "collection_name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update<B: AsRef<UpdateBatch>, C: AsRef<str>>(
+        &self,
+        batch: B,
+        collection: C,
+    ) -> Result<SolrResponse, Error> {
+        batch.as_ref().execute(&self.context, collection).await
+    }
+
+    /// Stream a CSV file to Solr, without parsing it in the client. See [CsvUpdateQuery]
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::CsvUpdateQuery;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let query = CsvUpdateQuery::new().separator(';').split(["interests"]);
+    /// let response = client.index_csv_file(&query, "collection_name", "export.csv").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn index_csv_file<Q: AsRef<CsvUpdateQuery>, C: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        query: Q,
+        collection: C,
+        path: P,
+    ) -> Result<SolrResponse, Error> {
+        query
+            .as_ref()
+            .execute_file(&self.context, collection, path)
+            .await
+    }
+
+    /// Stream a JSON or JSON lines file to Solr, without parsing it in the client. See [JsonDocsUpdateQuery]
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::JsonDocsUpdateQuery;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let query = JsonDocsUpdateQuery::new().split("/");
+    /// let response = client.index_json_docs_file(&query, "collection_name", "export.ndjson").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn index_json_docs_file<
+        Q: AsRef<JsonDocsUpdateQuery>,
+        C: AsRef<str>,
+        P: AsRef<Path>,
+    >(
+        &self,
+        query: Q,
+        collection: C,
+        path: P,
+    ) -> Result<SolrResponse, Error> {
+        query
+            .as_ref()
+            .execute_file(&self.context, collection, path)
+            .await
+    }
+
+    /// Upload a binary file such as a PDF to Solr Cell, and index the extracted document. See [ExtractQuery]
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::ExtractQuery;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let query = ExtractQuery::new().literals([("id", "report1")]);
+    /// let response = client.extract_file(&query, "collection_name", "report.pdf").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn extract_file<Q: AsRef<ExtractQuery>, C: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        query: Q,
+        collection: C,
+        path: P,
+    ) -> Result<SolrResponse, Error> {
+        query
+            .as_ref()
+            .execute_file(&self.context, collection, path)
+            .await
+    }
+
+    /// Extract the text and metadata of a binary file with Solr Cell, without indexing it. See [ExtractQuery]
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::{ExtractFormat, ExtractQuery};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let query = ExtractQuery::new().extract_format(ExtractFormat::Text);
+    /// let response = client.extract_only_file(&query, "collection_name", "report.pdf").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn extract_only_file<Q: AsRef<ExtractQuery>, C: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        query: Q,
+        collection: C,
+        path: P,
+    ) -> Result<SolrExtractResponse, Error> {
+        query
+            .as_ref()
+            .extract_only_file(&self.context, collection, path)
+            .await
+    }
+
+    /// Copy the documents of one collection into another, on the client. See [Reindexer]
+    ///
+    /// To copy between Solr clusters, use [Reindexer::execute] with two contexts.
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::Reindexer;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let summary = client.reindex(&Reindexer::new(), "old_collection", "new_collection").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reindex<R: AsRef<Reindexer>, S: AsRef<str>, T: AsRef<str>>(
+        &self,
+        reindexer: R,
+        source: S,
+        target: T,
+    ) -> Result<ReindexSummary, Error> {
+        reindexer
+            .as_ref()
+            .execute(&self.context, source, &self.context, target)
+            .await
+    }
+
+    /// Reindex a collection on the server with `REINDEXCOLLECTION`, and wait for it to finish. See [ReindexCollectionQuery]
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::ReindexCollectionQuery;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let query = ReindexCollectionQuery::new().target("new_collection");
+    /// let status = client.reindex_collection(&query, "old_collection").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reindex_collection<Q: AsRef<ReindexCollectionQuery>, C: AsRef<str>>(
+        &self,
+        query: Q,
+        collection: C,
+    ) -> Result<SolrReindexStatus, Error> {
+        query.as_ref().execute(&self.context, collection).await
     }
 }
@@ -1,18 +1,45 @@
 use crate::error::Error;
+use crate::models::backup::{BackupPoint, BackupResult, DeletedBackup};
+use crate::models::cluster_status::ClusterStatus;
+use crate::models::col_status::ColStatus;
 use crate::models::context::SolrServerContext;
+use crate::models::extract::SolrExtractResponse;
+use crate::models::reindex::SolrReindexStatus;
 use crate::models::response::SolrResponse;
 use crate::queries::alias::{
-    alias_exists_blocking, create_alias_blocking, delete_alias_blocking, get_aliases_blocking,
+    alias_exists_blocking, create_alias_blocking, create_alias_with_options_blocking,
+    delete_alias_blocking, delete_alias_with_options_blocking, get_aliases_blocking,
+    CreateAliasOptions, DeleteAliasOptions,
 };
+use crate::queries::backup::{
+    backup_collection_blocking, delete_backup_blocking, list_backups_blocking,
+    restore_collection_blocking, BackupOptions, DeleteBackupOptions, ListBackupsOptions,
+    RestoreOptions,
+};
+use crate::queries::bulk_index::{BulkIndexSummary, BulkIndexer};
 use crate::queries::collection::{
-    collection_exists_blocking, create_collection_blocking, delete_collection_blocking,
-    get_collections_blocking,
+    add_replica_blocking, add_replica_property_blocking, collection_exists_blocking,
+    create_collection_blocking, create_collection_with_options_blocking, create_shard_blocking,
+    delete_collection_blocking, delete_collection_with_options_blocking, delete_node_blocking,
+    delete_replica_blocking, delete_replica_property_blocking, delete_shard_blocking,
+    get_cluster_status_blocking, get_col_status_blocking, get_collections_blocking,
+    modify_collection_blocking, move_replica_blocking, reload_collection_blocking,
+    reload_collections_using_config_blocking, rename_collection_blocking, replace_node_blocking,
+    split_shard_blocking, AddReplicaOptions, ColStatusOptions, CreateCollectionOptions,
+    CreateShardOptions, DeleteCollectionOptions, DeleteNodeOptions, DeleteReplicaOptions,
+    DeleteShardOptions, ModifyCollectionOptions, MoveReplicaOptions, ReloadCollectionOptions,
+    RenameCollectionOptions, ReplaceNodeOptions, ReplicaPropertyOptions, SplitShardOptions,
 };
 use crate::queries::config::{
     config_exists_blocking, delete_config_blocking, get_configs_blocking, upload_config_blocking,
+    upload_elevation_config_blocking,
 };
-use crate::queries::index::{DeleteQuery, UpdateQuery};
+use crate::queries::extract::ExtractQuery;
+use crate::queries::file_update::{CsvUpdateQuery, JsonDocsUpdateQuery};
+use crate::queries::index::{CommitQuery, DeleteQuery, OptimizeQuery, RollbackQuery, UpdateQuery};
+use crate::queries::reindex::{ReindexCollectionQuery, ReindexSummary, Reindexer};
 use crate::queries::select::SelectQuery;
+use crate::queries::update_batch::UpdateBatch;
 use crate::SelectDestination;
 use serde::Serialize;
 use serde_json::Value;
@@ -70,6 +97,27 @@ impl BlockingSolrCloudClient {
         upload_config_blocking(&self.context, name, path)
     }
 
+    /// Upload a new `elevate.xml` into an existing config, and reload every collection using it
+    /// # Examples
+    /// ```no_run
+    /// # use std::path::Path;
+    /// # use solrstice::{BlockingSolrCloudClient, SolrServerContextBuilder, SolrSingleServerHost};
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let reloaded: Vec<String> =
+    ///     client.upload_elevation_config("config_name", Path::new("/path/to/elevate.xml"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upload_elevation_config<S: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        name: S,
+        path: P,
+    ) -> Result<Vec<String>, Error> {
+        upload_elevation_config_blocking(&self.context, name, path)
+    }
+
     /// Get the configs existing in SolrCloud
     /// # Examples
     /// ```no_run
@@ -147,6 +195,34 @@ impl BlockingSolrCloudClient {
         create_collection_blocking(&self.context, name, config, shards, replication_factor)
     }
 
+    /// Create a collection in SolrCloud, with all options of the `CREATE` action
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::queries::collection::{CollectionRouter, CreateCollectionOptions};
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let options = CreateCollectionOptions::new()
+    ///     .config_name("config_name")
+    ///     .router(CollectionRouter::Implicit)
+    ///     .shards(["shard_a", "shard_b"])
+    ///     .nrt_replicas(2)
+    ///     .wait_for_final_state(true);
+    /// client.create_collection_with_options("collection_name", &options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_collection_with_options<S: AsRef<str>, O: AsRef<CreateCollectionOptions>>(
+        &self,
+        name: S,
+        options: O,
+    ) -> Result<(), Error> {
+        create_collection_with_options_blocking(&self.context, name, options)
+    }
+
     /// Get collections from SolrCloud
     /// # Examples
     /// ```no_run
@@ -182,6 +258,56 @@ impl BlockingSolrCloudClient {
         collection_exists_blocking(&self.context, name)
     }
 
+    /// Get the state of the cluster, optionally limited to one collection and some of its shards
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let status = client.get_cluster_status(Some("collection_name"), &[])?;
+    /// for shard in status.get_collection("collection_name").unwrap().get_shards().values() {
+    ///     let leader = shard.get_leader().map(|leader| leader.get_node_name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_cluster_status(
+        &self,
+        collection: Option<&str>,
+        shards: &[&str],
+    ) -> Result<ClusterStatus, Error> {
+        get_cluster_status_blocking(&self.context, collection, shards)
+    }
+
+    /// Get the index status of collections, with the size and segments of each shard leader. Requires Solr 8.1 or later
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::ColStatusOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let options = ColStatusOptions::new().core_info(true).segments(true);
+    /// let status = client.get_col_status(Some("collection_name"), &options)?;
+    /// for shard in status.get_collection("collection_name").unwrap().get_shards().values() {
+    ///     let size = shard.get_leader().and_then(|leader| leader.get_segments_info()).and_then(|info| info.get_size_in_gb());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_col_status<O: AsRef<ColStatusOptions>>(
+        &self,
+        collection: Option<&str>,
+        options: O,
+    ) -> Result<ColStatus, Error> {
+        get_col_status_blocking(&self.context, collection, options)
+    }
+
     /// Delete a collection from SolrCloud
     /// # Examples
     /// ```no_run
@@ -199,173 +325,964 @@ impl BlockingSolrCloudClient {
         delete_collection_blocking(&self.context, name)
     }
 
-    /// Create an alias in SolrCloud
+    /// Delete a collection from SolrCloud, with the options of the `DELETE` action
     /// # Examples
     /// ```no_run
     /// # use solrstice::BlockingSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::DeleteCollectionOptions;
     /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = BlockingSolrCloudClient::new(context);
-    /// client.create_alias("alias_name", &["collection1", "collection2"])?;
+    /// let options = DeleteCollectionOptions::new().async_id("delete-collection");
+    /// client.delete_collection_with_options("collection_name", &options)?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn create_alias<S: AsRef<str>>(&self, alias: S, collections: &[S]) -> Result<(), Error> {
-        create_alias_blocking(&self.context, alias, collections)
+    pub fn delete_collection_with_options<S: AsRef<str>, O: AsRef<DeleteCollectionOptions>>(
+        &self,
+        name: S,
+        options: O,
+    ) -> Result<(), Error> {
+        delete_collection_with_options_blocking(&self.context, name, options)
     }
 
-    /// Get aliases from SolrCloud
+    /// Reload a collection, so that changes to its config set take effect
     /// # Examples
     /// ```no_run
-    /// # use std::collections::HashMap;
     /// # use solrstice::BlockingSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::ReloadCollectionOptions;
     /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = BlockingSolrCloudClient::new(context);
-    /// let aliases: HashMap<String, Vec<String>> = client.get_aliases()?;
+    /// client.reload_collection("collection_name", ReloadCollectionOptions::new())?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get_aliases(&self) -> Result<HashMap<String, Vec<String>>, Error> {
-        get_aliases_blocking(&self.context)
+    pub fn reload_collection<S: AsRef<str>, O: AsRef<ReloadCollectionOptions>>(
+        &self,
+        name: S,
+        options: O,
+    ) -> Result<(), Error> {
+        reload_collection_blocking(&self.context, name, options)
     }
 
-    /// Check if an alias exists in SolrCloud
+    /// Reload every collection using a config set, after it has been changed. Returns the names of the reloaded collections
     /// # Examples
     /// ```no_run
     /// # use solrstice::BlockingSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
+    /// # use std::path::Path;
+    /// use solrstice::queries::collection::ReloadCollectionOptions;
     /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = BlockingSolrCloudClient::new(context);
-    /// let exists: bool = client.alias_exists("alias_name")?;
+    /// client.upload_config("config_name", Path::new("/path/to/config"))?;
+    /// let reloaded: Vec<String> = client.reload_collections_using_config("config_name", ReloadCollectionOptions::new())?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn alias_exists<S: AsRef<str>>(&self, name: S) -> Result<bool, Error> {
-        alias_exists_blocking(&self.context, name)
+    pub fn reload_collections_using_config<S: AsRef<str>, O: AsRef<ReloadCollectionOptions>>(
+        &self,
+        config: S,
+        options: O,
+    ) -> Result<Vec<String>, Error> {
+        reload_collections_using_config_blocking(&self.context, config, options)
     }
 
-    /// Delete an alias from SolrCloud
+    /// Change the replication factor, config set, properties or read only state of a collection
     /// # Examples
     /// ```no_run
-    /// use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::BlockingSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::ModifyCollectionOptions;
     /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = BlockingSolrCloudClient::new(context);
-    /// client.delete_alias("alias_name")?;
+    /// client.modify_collection("collection_name", ModifyCollectionOptions::new().read_only(true))?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn delete_alias<S: AsRef<str>>(&self, name: S) -> Result<(), Error> {
-        delete_alias_blocking(&self.context, name)
+    pub fn modify_collection<S: AsRef<str>, O: AsRef<ModifyCollectionOptions>>(
+        &self,
+        collection: S,
+        options: O,
+    ) -> Result<(), Error> {
+        modify_collection_blocking(&self.context, collection, options)
     }
 
-    /// Index some data into SolrCloud
+    /// Rename a collection. Solr keeps the data under the old name, behind an alias with the new name. Requires Solr 8.1 or later
     /// # Examples
     /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
-    /// # use solrstice::UpdateQuery;
-    /// # use serde::Serialize;
+    /// use solrstice::queries::collection::RenameCollectionOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// client.rename_collection("collection_name", "new_collection_name", RenameCollectionOptions::new().async_id("rename"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rename_collection<S: AsRef<str>, O: AsRef<RenameCollectionOptions>>(
+        &self,
+        name: S,
+        target: S,
+        options: O,
+    ) -> Result<(), Error> {
+        rename_collection_blocking(&self.context, name, target, options)
+    }
+
+    /// Split a shard into sub-shards. The parent shard is kept as `inactive` until it is deleted
+    /// # Examples
+    /// ```no_run
     /// # use solrstice::BlockingSolrCloudClient;
-    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
-    /// #[derive(Serialize)]
-    /// struct Data {id: String}
-    ///
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::{DeleteShardOptions, SplitMethod, SplitShardOptions};
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = BlockingSolrCloudClient::new(context);
-    /// let response = client.index(&UpdateQuery::new(), "collection_name", &[Data {id: "test".to_string()}])?;
+    /// let options = SplitShardOptions::new().shard("shard1").num_sub_shards(4).split_method(SplitMethod::Link);
+    /// client.split_shard("collection_name", &options)?;
+    /// client.delete_shard("collection_name", "shard1", DeleteShardOptions::new())?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn index<T: Serialize, S: AsRef<str>, B: AsRef<UpdateQuery>>(
+    pub fn split_shard<S: AsRef<str>, O: AsRef<SplitShardOptions>>(
         &self,
-        builder: B,
         collection: S,
-        data: &[T],
-    ) -> Result<SolrResponse, Error> {
-        builder
-            .as_ref()
-            .execute_blocking(&self.context, collection, data)
+        options: O,
+    ) -> Result<(), Error> {
+        split_shard_blocking(&self.context, collection, options)
     }
 
-    /// Select some data from SolrCloud
+    /// Add a shard to a collection using the implicit router
     /// # Examples
     /// ```no_run
     /// # use solrstice::BlockingSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
-    /// # use solrstice::SelectQuery;
+    /// use solrstice::queries::collection::CreateShardOptions;
     /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = BlockingSolrCloudClient::new(context);
-    /// let response = client.select(&SelectQuery::new().fq(["age:[* TO *]"]), "collection_name")?;
+    /// let options = CreateShardOptions::new().nrt_replicas(1);
+    /// client.create_shard("collection_name", "2025", &options)?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn select<D: Into<SelectDestination>, B: AsRef<SelectQuery>>(
+    pub fn create_shard<S: AsRef<str>, O: AsRef<CreateShardOptions>>(
         &self,
-        builder: B,
-        destination: D,
-    ) -> Result<SolrResponse, Error> {
-        builder
-            .as_ref()
-            .execute_blocking(&self.context, destination.into())
+        collection: S,
+        shard: S,
+        options: O,
+    ) -> Result<(), Error> {
+        create_shard_blocking(&self.context, collection, shard, options)
     }
 
-    /// Select some data from SolrCloud. Return the response directly
+    /// Delete an inactive shard, or a shard of a collection using the implicit router
     /// # Examples
     /// ```no_run
-    /// # use std::collections::HashMap;
     /// # use solrstice::BlockingSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
-    /// # use solrstice::SelectQuery;
+    /// use solrstice::queries::collection::DeleteShardOptions;
     /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = BlockingSolrCloudClient::new(context);
-    /// let response: HashMap<String, serde_json::Value> = client.select_raw(&SelectQuery::new().fq(["age:[* TO *]"]), "collection_name")?;
+    /// client.delete_shard("collection_name", "shard1", DeleteShardOptions::new())?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn select_raw<D: Into<SelectDestination>, B: AsRef<SelectQuery>>(
+    pub fn delete_shard<S: AsRef<str>, O: AsRef<DeleteShardOptions>>(
         &self,
-        builder: B,
-        destination: D,
-    ) -> Result<HashMap<String, Value>, Error> {
-        builder
-            .as_ref()
-            .execute_blocking_raw(&self.context, destination.into())
+        collection: S,
+        shard: S,
+        options: O,
+    ) -> Result<(), Error> {
+        delete_shard_blocking(&self.context, collection, shard, options)
     }
 
-    /// Delete some data from SolrCloud
+    /// Add replicas to a shard
     /// # Examples
     /// ```no_run
     /// # use solrstice::BlockingSolrCloudClient;
     /// # use solrstice::SolrSingleServerHost;
     /// # use solrstice::SolrServerContextBuilder;
-    /// # use solrstice::DeleteQuery;
-    /// # use solrstice::SelectQuery;
+    /// use solrstice::models::ReplicaType;
+    /// use solrstice::queries::collection::AddReplicaOptions;
     /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
     /// let client = BlockingSolrCloudClient::new(context);
-    /// let response = client.delete(&DeleteQuery::new().ids(["document1"]).queries(["age:[* TO *]"]), "collection_name")?;
+    /// let options = AddReplicaOptions::new().replica_type(ReplicaType::Pull).node("localhost:7574_solr");
+    /// client.add_replica("collection_name", "shard1", &options)?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn delete<S: AsRef<str>, B: AsRef<DeleteQuery>>(
+    pub fn add_replica<S: AsRef<str>, O: AsRef<AddReplicaOptions>>(
         &self,
-        builder: B,
         collection: S,
-    ) -> Result<SolrResponse, Error> {
-        builder.as_ref().execute_blocking(&self.context, collection)
+        shard: S,
+        options: O,
+    ) -> Result<(), Error> {
+        add_replica_blocking(&self.context, collection, shard, options)
+    }
+
+    /// Delete replicas by name, count or node
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::DeleteReplicaOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let options = DeleteReplicaOptions::new().shard("shard1").replica("core_node3");
+    /// client.delete_replica("collection_name", &options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_replica<S: AsRef<str>, O: AsRef<DeleteReplicaOptions>>(
+        &self,
+        collection: S,
+        options: O,
+    ) -> Result<(), Error> {
+        delete_replica_blocking(&self.context, collection, options)
+    }
+
+    /// Move a replica to another node
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::MoveReplicaOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let options = MoveReplicaOptions::new().replica("core_node3");
+    /// client.move_replica("collection_name", "localhost:7574_solr", &options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn move_replica<S: AsRef<str>, O: AsRef<MoveReplicaOptions>>(
+        &self,
+        collection: S,
+        target_node: S,
+        options: O,
+    ) -> Result<(), Error> {
+        move_replica_blocking(&self.context, collection, target_node, options)
+    }
+
+    /// Delete all replicas on a node
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::DeleteNodeOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// client.delete_node("localhost:8983_solr", DeleteNodeOptions::new().async_id("delete-8983"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_node<S: AsRef<str>, O: AsRef<DeleteNodeOptions>>(
+        &self,
+        node: S,
+        options: O,
+    ) -> Result<(), Error> {
+        delete_node_blocking(&self.context, node, options)
+    }
+
+    /// Move all replicas on a node to other nodes
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::{DeleteNodeOptions, ReplaceNodeOptions};
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// client.replace_node("localhost:8983_solr", ReplaceNodeOptions::new())?;
+    /// client.delete_node("localhost:8983_solr", DeleteNodeOptions::new())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn replace_node<S: AsRef<str>, O: AsRef<ReplaceNodeOptions>>(
+        &self,
+        source_node: S,
+        options: O,
+    ) -> Result<(), Error> {
+        replace_node_blocking(&self.context, source_node, options)
+    }
+
+    /// Set a property of a replica. With `shard_unique`, it is removed from the other replicas of the shard
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::ReplicaPropertyOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// client.add_replica_property("collection_name", "shard1", "core_node3", "preferredLeader", "true", ReplicaPropertyOptions::new().shard_unique(true))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_replica_property<S: AsRef<str>, O: AsRef<ReplicaPropertyOptions>>(
+        &self,
+        collection: S,
+        shard: S,
+        replica: S,
+        property: S,
+        value: S,
+        options: O,
+    ) -> Result<(), Error> {
+        add_replica_property_blocking(
+            &self.context,
+            collection,
+            shard,
+            replica,
+            property,
+            value,
+            options,
+        )
+    }
+
+    /// Remove a property of a replica
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::collection::ReplicaPropertyOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// client.delete_replica_property("collection_name", "shard1", "core_node3", "preferredLeader", ReplicaPropertyOptions::new())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_replica_property<S: AsRef<str>, O: AsRef<ReplicaPropertyOptions>>(
+        &self,
+        collection: S,
+        shard: S,
+        replica: S,
+        property: S,
+        options: O,
+    ) -> Result<(), Error> {
+        delete_replica_property_blocking(
+            &self.context,
+            collection,
+            shard,
+            replica,
+            property,
+            options,
+        )
+    }
+
+    /// Back up a collection to a backup repository. Each incremental backup of the same name adds a backup point
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::backup::BackupOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let options = BackupOptions::new().location("/var/solr/backups").max_num_backup_points(7);
+    /// let result = client.backup_collection("collection_name", "nightly", &options)?;
+    /// let files: Vec<Option<u64>> = result.get_shards().iter().map(|shard| shard.get_index_file_count()).collect();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn backup_collection<S: AsRef<str>, O: AsRef<BackupOptions>>(
+        &self,
+        collection: S,
+        name: S,
+        options: O,
+    ) -> Result<BackupResult, Error> {
+        backup_collection_blocking(&self.context, collection, name, options)
+    }
+
+    /// Restore a backup point into a new collection
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::backup::RestoreOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let options = RestoreOptions::new().location("/var/solr/backups").backup_id(3);
+    /// client.restore_collection("restored_collection", "nightly", options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn restore_collection<S: AsRef<str>, O: AsRef<RestoreOptions>>(
+        &self,
+        collection: S,
+        name: S,
+        options: O,
+    ) -> Result<(), Error> {
+        restore_collection_blocking(&self.context, collection, name, options)
+    }
+
+    /// List the backup points of a backup. Requires Solr 8.9 or later
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::backup::ListBackupsOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let backups = client.list_backups("nightly", ListBackupsOptions::new().location("/var/solr/backups"))?;
+    /// let latest = backups.iter().map(|backup| backup.get_backup_id()).max();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_backups<S: AsRef<str>, O: AsRef<ListBackupsOptions>>(
+        &self,
+        name: S,
+        options: O,
+    ) -> Result<Vec<BackupPoint>, Error> {
+        list_backups_blocking(&self.context, name, options)
+    }
+
+    /// Delete backup points, or the files no backup point refers to. Requires Solr 8.9 or later
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::backup::DeleteBackupOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let options = DeleteBackupOptions::new().location("/var/solr/backups").max_num_backup_points(3);
+    /// client.delete_backup("nightly", options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_backup<S: AsRef<str>, O: AsRef<DeleteBackupOptions>>(
+        &self,
+        name: S,
+        options: O,
+    ) -> Result<Vec<DeletedBackup>, Error> {
+        delete_backup_blocking(&self.context, name, options)
+    }
+
+    /// Create an alias in SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// client.create_alias("alias_name", &["collection1", "collection2"])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_alias<S: AsRef<str>>(&self, alias: S, collections: &[S]) -> Result<(), Error> {
+        create_alias_blocking(&self.context, alias, collections)
+    }
+
+    /// Create an alias in SolrCloud, with the options of the `CREATEALIAS` action
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::alias::CreateAliasOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let options = CreateAliasOptions::new().async_id("create-alias");
+    /// client.create_alias_with_options("alias_name", &["collection1", "collection2"], &options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_alias_with_options<S: AsRef<str>, O: AsRef<CreateAliasOptions>>(
+        &self,
+        alias: S,
+        collections: &[S],
+        options: O,
+    ) -> Result<(), Error> {
+        create_alias_with_options_blocking(&self.context, alias, collections, options)
+    }
+
+    /// Get aliases from SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let aliases: HashMap<String, Vec<String>> = client.get_aliases()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_aliases(&self) -> Result<HashMap<String, Vec<String>>, Error> {
+        get_aliases_blocking(&self.context)
+    }
+
+    /// Check if an alias exists in SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let exists: bool = client.alias_exists("alias_name")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn alias_exists<S: AsRef<str>>(&self, name: S) -> Result<bool, Error> {
+        alias_exists_blocking(&self.context, name)
+    }
+
+    /// Delete an alias from SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// client.delete_alias("alias_name")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_alias<S: AsRef<str>>(&self, name: S) -> Result<(), Error> {
+        delete_alias_blocking(&self.context, name)
+    }
+
+    /// Delete an alias from SolrCloud, with the options of the `DELETEALIAS` action
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// use solrstice::queries::alias::DeleteAliasOptions;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let options = DeleteAliasOptions::new().async_id("delete-alias");
+    /// client.delete_alias_with_options("alias_name", &options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_alias_with_options<S: AsRef<str>, O: AsRef<DeleteAliasOptions>>(
+        &self,
+        name: S,
+        options: O,
+    ) -> Result<(), Error> {
+        delete_alias_with_options_blocking(&self.context, name, options)
+    }
+
+    /// Index some data into SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::UpdateQuery;
+    /// # use serde::Serialize;
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #[derive(Serialize)]
+    /// struct Data {id: String}
+    ///
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let response = client.index(&UpdateQuery::new(), "collection_name", &[Data {id: "test".to_string()}])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn index<T: Serialize, S: AsRef<str>, B: AsRef<UpdateQuery>>(
+        &self,
+        builder: B,
+        collection: S,
+        data: &[T],
+    ) -> Result<SolrResponse, Error> {
+        builder
+            .as_ref()
+            .execute_blocking(&self.context, collection, data)
+    }
+
+    /// Index the documents of an iterator in concurrent batches, with a single commit at the end. See [BulkIndexer] for the options.
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::{BlockingSolrCloudClient, BulkIndexer};
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use serde::Serialize;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #[derive(Serialize)]
+    /// struct Data {id: String}
+    ///
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let docs = (0..100_000).map(|i| Data {id: i.to_string()});
+    /// let summary = client.bulk_index(&BulkIndexer::new(), "collection_name", docs)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bulk_index<
+        T: Serialize,
+        B: AsRef<BulkIndexer>,
+        C: AsRef<str>,
+        I: IntoIterator<Item = T>,
+    >(
+        &self,
+        indexer: B,
+        collection: C,
+        docs: I,
+    ) -> Result<BulkIndexSummary, Error> {
+        indexer
+            .as_ref()
+            .execute_blocking(&self.context, collection, docs)
+    }
+
+    /// Select some data from SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::SelectQuery;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let response = client.select(&SelectQuery::new().fq(["age:[* TO *]"]), "collection_name")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn select<D: Into<SelectDestination>, B: AsRef<SelectQuery>>(
+        &self,
+        builder: B,
+        destination: D,
+    ) -> Result<SolrResponse, Error> {
+        builder
+            .as_ref()
+            .execute_blocking(&self.context, destination.into())
+    }
+
+    /// Select some data from SolrCloud. Return the response directly
+    /// # Examples
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::SelectQuery;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let response: HashMap<String, serde_json::Value> = client.select_raw(&SelectQuery::new().fq(["age:[* TO *]"]), "collection_name")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn select_raw<D: Into<SelectDestination>, B: AsRef<SelectQuery>>(
+        &self,
+        builder: B,
+        destination: D,
+    ) -> Result<HashMap<String, Value>, Error> {
+        builder
+            .as_ref()
+            .execute_blocking_raw(&self.context, destination.into())
+    }
+
+    /// Delete some data from SolrCloud
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::DeleteQuery;
+    /// # use solrstice::SelectQuery;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let response = client.delete(&DeleteQuery::new().ids(["document1"]).queries(["age:[* TO *]"]), "collection_name")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete<S: AsRef<str>, B: AsRef<DeleteQuery>>(
+        &self,
+        builder: B,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        builder.as_ref().execute_blocking(&self.context, collection)
+    }
+
+    /// Commit pending changes
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::CommitQuery;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let response = client.commit(&CommitQuery::new().soft_commit(true), "collection_name")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn commit<S: AsRef<str>, B: AsRef<CommitQuery>>(
+        &self,
+        builder: B,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        builder.as_ref().execute_blocking(&self.context, collection)
+    }
+
+    /// Optimize a collection, merging its segments
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::OptimizeQuery;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let response = client.optimize(&OptimizeQuery::new().max_segments(1), "collection_name")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn optimize<S: AsRef<str>, B: AsRef<OptimizeQuery>>(
+        &self,
+        builder: B,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        builder.as_ref().execute_blocking(&self.context, collection)
+    }
+
+    /// Roll back all changes since the last commit. Only supported on standalone servers, not in SolrCloud mode
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::RollbackQuery;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let response = client.rollback(&RollbackQuery::new(), "core_name")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rollback<S: AsRef<str>, B: AsRef<RollbackQuery>>(
+        &self,
+        builder: B,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        builder.as_ref().execute_blocking(&self.context, collection)
+    }
+
+    /// Send several update commands in one request. See [UpdateBatch]
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::{CommitQuery, DeleteCommand, UpdateBatch};
+    /// # use serde_json::json;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let batch = UpdateBatch::new()
+    ///     .delete(DeleteCommand::query("category:outdated"))
+    ///     .add_doc(json!({"id": "document1"}))
+    ///     .commit(CommitQuery::new());
+    /// let response = client.update(&batch, "collection_name")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update<S: AsRef<str>, B: AsRef<UpdateBatch>>(
+        &self,
+        batch: B,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        batch.as_ref().execute_blocking(&self.context, collection)
+    }
+
+    /// Stream a CSV file to Solr, without parsing it in the client. See [CsvUpdateQuery]
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::CsvUpdateQuery;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let query = CsvUpdateQuery::new().separator(';').split(["interests"]);
+    /// let response = client.index_csv_file(&query, "collection_name", "export.csv")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn index_csv_file<Q: AsRef<CsvUpdateQuery>, C: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        query: Q,
+        collection: C,
+        path: P,
+    ) -> Result<SolrResponse, Error> {
+        query
+            .as_ref()
+            .execute_file_blocking(&self.context, collection, path)
+    }
+
+    /// Stream a JSON or JSON lines file to Solr, without parsing it in the client. See [JsonDocsUpdateQuery]
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::JsonDocsUpdateQuery;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let query = JsonDocsUpdateQuery::new().split("/");
+    /// let response = client.index_json_docs_file(&query, "collection_name", "export.ndjson")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn index_json_docs_file<Q: AsRef<JsonDocsUpdateQuery>, C: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        query: Q,
+        collection: C,
+        path: P,
+    ) -> Result<SolrResponse, Error> {
+        query
+            .as_ref()
+            .execute_file_blocking(&self.context, collection, path)
+    }
+
+    /// Upload a binary file such as a PDF to Solr Cell, and index the extracted document. See [ExtractQuery]
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::ExtractQuery;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let query = ExtractQuery::new().literals([("id", "report1")]);
+    /// let response = client.extract_file(&query, "collection_name", "report.pdf")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn extract_file<Q: AsRef<ExtractQuery>, C: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        query: Q,
+        collection: C,
+        path: P,
+    ) -> Result<SolrResponse, Error> {
+        query
+            .as_ref()
+            .execute_file_blocking(&self.context, collection, path)
+    }
+
+    /// Extract the text and metadata of a binary file with Solr Cell, without indexing it. See [ExtractQuery]
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::{ExtractFormat, ExtractQuery};
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let query = ExtractQuery::new().extract_format(ExtractFormat::Text);
+    /// let response = client.extract_only_file(&query, "collection_name", "report.pdf")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn extract_only_file<Q: AsRef<ExtractQuery>, C: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        query: Q,
+        collection: C,
+        path: P,
+    ) -> Result<SolrExtractResponse, Error> {
+        query
+            .as_ref()
+            .extract_only_file_blocking(&self.context, collection, path)
+    }
+
+    /// Copy the documents of one collection into another, on the client. See [Reindexer]
+    ///
+    /// To copy between Solr clusters, use [Reindexer::execute_blocking] with two contexts.
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::Reindexer;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let summary = client.reindex(&Reindexer::new(), "old_collection", "new_collection")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reindex<R: AsRef<Reindexer>, S: AsRef<str>, T: AsRef<str>>(
+        &self,
+        reindexer: R,
+        source: S,
+        target: T,
+    ) -> Result<ReindexSummary, Error> {
+        reindexer
+            .as_ref()
+            .execute_blocking(&self.context, source, &self.context, target)
+    }
+
+    /// Reindex a collection on the server with `REINDEXCOLLECTION`, and wait for it to finish. See [ReindexCollectionQuery]
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::BlockingSolrCloudClient;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # use solrstice::SolrSingleServerHost;
+    /// # use solrstice::ReindexCollectionQuery;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = BlockingSolrCloudClient::new(context);
+    /// let query = ReindexCollectionQuery::new().target("new_collection");
+    /// let status = client.reindex_collection(&query, "old_collection")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reindex_collection<Q: AsRef<ReindexCollectionQuery>, C: AsRef<str>>(
+        &self,
+        query: Q,
+        collection: C,
+    ) -> Result<SolrReindexStatus, Error> {
+        query.as_ref().execute_blocking(&self.context, collection)
     }
 }
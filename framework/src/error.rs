@@ -1,4 +1,5 @@
 use crate::models::SolrResponseError;
+use regex::Regex;
 use thiserror::Error;
 
 /// Main error type for Solrstice
@@ -27,6 +28,27 @@ pub enum Error {
     SolrResponseError { code: u16, url: String, msg: String },
     #[error("Solr auth error: {code:?} - {url:?}\n{msg:?}")]
     SolrAuthError { code: u16, url: String, msg: String },
+    /// A `_version_` constraint was not met. `id` is the document, if it could be read from the message.
+    #[error("Solr version conflict: {id:?} - {url:?}\n{msg:?}")]
+    SolrVersionConflict {
+        id: Option<String>,
+        url: String,
+        msg: String,
+    },
+
+    /// An async Collections API request failed. `code` is the status code of the operation, if Solr reported it.
+    #[error("Solr admin task failed: {request_id:?} - {code:?}\n{msg:?}")]
+    SolrAdminTaskFailed {
+        request_id: String,
+        code: Option<u16>,
+        msg: String,
+    },
+    /// An async Collections API request did not finish within the timeout. `state` is the last state reported.
+    #[error("Solr admin task timed out: {request_id:?} - {state:?}")]
+    SolrAdminTaskTimeout { request_id: String, state: String },
+
+    #[error("Solr returned partial results: {0}")]
+    SolrPartialResultsError(String),
 
     #[error("Unknown error: {0}")]
     Unknown(String),
@@ -45,9 +67,62 @@ pub fn get_solr_error_from_error_response(url: String, err: SolrResponseError) -
     } else if err.trace.is_some() {
         msg = err.trace.clone().unwrap();
     }
+    if err.code == 409 {
+        return Error::SolrVersionConflict {
+            id: version_conflict_id(&msg),
+            url,
+            msg,
+        };
+    }
     Error::SolrResponseError {
         code: err.code,
         url,
         msg,
     }
 }
+
+lazy_static::lazy_static! {
+    /// Eg: `version conflict for doc1 expected=1 actual=2` or `Document not found for update.  id=doc1`
+    static ref VERSION_CONFLICT_ID: Regex =
+        Regex::new(r"(?:version conflict for (\S+) expected=|Document not found for update\.\s+id=(\S+))").unwrap();
+}
+
+fn version_conflict_id(msg: &str) -> Option<String> {
+    let captures = VERSION_CONFLICT_ID.captures(msg)?;
+    captures
+        .get(1)
+        .or_else(|| captures.get(2))
+        .map(|id| id.as_str().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{get_solr_error_from_error_response, Error};
+    use crate::models::SolrResponseError;
+
+    #[test]
+    fn conflict_becomes_version_conflict() {
+        let error = |msg: &str| {
+            get_solr_error_from_error_response(
+                "url".to_string(),
+                SolrResponseError {
+                    msg: Some(msg.to_string()),
+                    trace: None,
+                    code: 409,
+                },
+            )
+        };
+        assert!(matches!(
+            error("version conflict for city_Alta expected=1 actual=1711"),
+            Error::SolrVersionConflict { id: Some(id), .. } if id == "city_Alta"
+        ));
+        assert!(matches!(
+            error("Document not found for update.  id=city_Alta"),
+            Error::SolrVersionConflict { id: Some(id), .. } if id == "city_Alta"
+        ));
+        assert!(matches!(
+            error("Unknown conflict"),
+            Error::SolrVersionConflict { id: None, .. }
+        ));
+    }
+}
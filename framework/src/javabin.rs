@@ -0,0 +1,739 @@
+//! Decoding and encoding of Solr's binary `javabin` format.
+//!
+//! Responses are decoded into the same shape Solr gives with `wt=json`, so they deserialize into
+//! [SolrResponse](crate::models::SolrResponse) and the documents the same way.
+//! Named lists become flat lists of names and values, as with the default `json.nl=flat`,
+//! dates become ISO-8601 strings, and byte arrays become base64 strings.
+//!
+//! Select it with [SolrServerContextBuilder::with_wire_format](crate::SolrServerContextBuilder::with_wire_format)
+//! or per query with [SelectQuery::wire_format](crate::SelectQuery::wire_format) and [UpdateQuery::wire_format](crate::UpdateQuery::wire_format).
+//! Requests to endpoints without javabin support are always sent as JSON.
+//!
+//! This is a compatibility format, for proxies and setups that only accept javabin.
+//! Responses are decoded into a [serde_json::Value] before deserializing, so it uses no less CPU than JSON.
+use crate::error::Error;
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+
+const VERSION: u8 = 2;
+
+const NULL: u8 = 0;
+const BOOL_TRUE: u8 = 1;
+const BOOL_FALSE: u8 = 2;
+const BYTE: u8 = 3;
+const SHORT: u8 = 4;
+const DOUBLE: u8 = 5;
+const INT: u8 = 6;
+const LONG: u8 = 7;
+const FLOAT: u8 = 8;
+const DATE: u8 = 9;
+const MAP: u8 = 10;
+const SOLRDOC: u8 = 11;
+const SOLRDOCLST: u8 = 12;
+const BYTEARR: u8 = 13;
+const ITERATOR: u8 = 14;
+const END: u8 = 15;
+const SOLRINPUTDOC: u8 = 16;
+const MAP_ENTRY_ITER: u8 = 17;
+const ENUM_FIELD_VALUE: u8 = 18;
+const MAP_ENTRY: u8 = 19;
+
+// Tags that carry their size in the lower 5 bits
+const STR: u8 = 1 << 5;
+const SINT: u8 = 2 << 5;
+const SLONG: u8 = 3 << 5;
+const ARR: u8 = 4 << 5;
+const ORDERED_MAP: u8 = 5 << 5;
+const NAMED_LST: u8 = 6 << 5;
+const EXTERN_STRING: u8 = 7 << 5;
+
+// Same limit as serde_json, so malformed data can not overflow the stack
+const MAX_DEPTH: usize = 128;
+
+const CHILD_DOCUMENTS: &str = "_childDocuments_";
+const ATOMIC_OPERATIONS: &[&str] = &["set", "add", "add-distinct", "remove", "removeregex", "inc"];
+
+/// Decode a javabin response into the value Solr would have returned as JSON.
+/// # Examples
+/// ```
+/// use serde_json::json;
+/// // {"status": 0}
+/// let bytes = [2, 193, 38, b's', b't', b'a', b't', b'u', b's', 64];
+/// assert_eq!(solrstice::javabin::decode(&bytes)?, json!({"status": 0}));
+/// # Ok::<(), solrstice::Error>(())
+/// ```
+pub fn decode(bytes: &[u8]) -> Result<Value, Error> {
+    let mut decoder = Decoder::new(bytes);
+    let version = decoder.read_u8()?;
+    if version != VERSION {
+        return Err(Error::Unknown(format!(
+            "Unsupported javabin version {}, expected {}",
+            version, VERSION
+        )));
+    }
+    // The top level named list is the response itself, which JSON always renders as an object.
+    let tag = decoder.read_u8()?;
+    match tag & 0xe0 {
+        NAMED_LST => {
+            let size = decoder.read_size(tag)?;
+            decoder.read_object(size)
+        }
+        _ => decoder.read_tagged(tag),
+    }
+}
+
+/// Decode a javabin response and deserialize it, like [serde_json::from_slice] does for JSON.
+///
+/// The response is decoded with [decode] and then deserialized from the [Value], which is slower than [serde_json::from_slice].
+/// # Examples
+/// ```
+/// use solrstice::models::SolrResponse;
+/// // {"responseHeader": {"status": 0, "QTime": 1}}
+/// let bytes = [
+///     2, 193, 46, b'r', b'e', b's', b'p', b'o', b'n', b's', b'e', b'H', b'e', b'a', b'd', b'e', b'r',
+///     162, 38, b's', b't', b'a', b't', b'u', b's', 64, 37, b'Q', b'T', b'i', b'm', b'e', 65,
+/// ];
+/// let response: SolrResponse = solrstice::javabin::from_slice(&bytes)?;
+/// assert_eq!(response.get_response_header().unwrap().q_time, 1);
+/// # Ok::<(), solrstice::Error>(())
+/// ```
+pub fn from_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error> {
+    Ok(serde_json::from_value(decode(bytes)?)?)
+}
+
+/// Encode documents as a javabin update request, as sent to `/update` with `Content-Type: application/javabin`.
+///
+/// The documents are serialized the same way as for JSON updates. Atomic updates such as `{"set": 1}` are sent as maps,
+/// other objects, and the documents in `_childDocuments_`, are sent as child documents.
+/// The params are request parameters that Solr reads from the body, such as `overwrite` and `commitWithin`.
+/// # Examples
+/// ```
+/// use serde_json::json;
+/// let bytes = solrstice::javabin::encode_documents(&[json!({"id": "1"})], &[("overwrite", "false")])?;
+/// let decoded = solrstice::javabin::decode(&bytes)?;
+/// assert_eq!(decoded["docs"], json!([{"id": "1"}]));
+/// # Ok::<(), solrstice::Error>(())
+/// ```
+pub fn encode_documents<D: Serialize>(
+    docs: &[D],
+    params: &[(&str, &str)],
+) -> Result<Vec<u8>, Error> {
+    let mut encoder = Encoder::default();
+    encoder.buffer.push(VERSION);
+    encoder.write_tag(NAMED_LST, 2);
+    encoder.write_str("params");
+    encoder.write_tag(NAMED_LST, params.len());
+    for (key, value) in params {
+        encoder.write_str(key);
+        encoder.write_str(value);
+    }
+    encoder.write_str("docs");
+    encoder.buffer.push(ITERATOR);
+    for doc in docs {
+        match serde_json::to_value(doc)? {
+            Value::Object(doc) => encoder.write_document(&doc),
+            _ => {
+                return Err(Error::Unknown(
+                    "Documents must serialize to objects".to_string(),
+                ))
+            }
+        }
+    }
+    encoder.buffer.push(END);
+    Ok(encoder.buffer)
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    extern_strings: Vec<String>,
+    depth: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Decoder {
+            bytes,
+            position: 0,
+            extern_strings: Vec::new(),
+            depth: 0,
+        }
+    }
+
+    fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        let end = self.position.saturating_add(length);
+        let bytes = self.bytes.get(self.position..end).ok_or_else(|| {
+            Error::Unknown(format!(
+                "Unexpected end of javabin data at byte {}",
+                self.position
+            ))
+        })?;
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    fn peek_u8(&self) -> Result<u8, Error> {
+        self.bytes
+            .get(self.position)
+            .copied()
+            .ok_or_else(|| Error::Unknown("Unexpected end of javabin data".to_string()))
+    }
+
+    /// Variable length integers are stored in groups of 7 bits, least significant first.
+    fn read_vlong(&mut self) -> Result<u64, Error> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_u8()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(Error::Unknown(
+            "Invalid javabin variable length integer".to_string(),
+        ))
+    }
+
+    fn read_vint(&mut self) -> Result<usize, Error> {
+        Ok(self.read_vlong()? as usize)
+    }
+
+    fn read_size(&mut self, tag: u8) -> Result<usize, Error> {
+        let size = (tag & 0x1f) as usize;
+        match size {
+            0x1f => size
+                .checked_add(self.read_vint()?)
+                .ok_or_else(|| Error::Unknown("Invalid javabin size".to_string())),
+            _ => Ok(size),
+        }
+    }
+
+    fn read_val(&mut self) -> Result<Value, Error> {
+        let tag = self.read_u8()?;
+        if self.depth >= MAX_DEPTH {
+            return Err(Error::Unknown(format!(
+                "Javabin data nested deeper than {} levels",
+                MAX_DEPTH
+            )));
+        }
+        self.depth += 1;
+        let value = self.read_tagged(tag);
+        self.depth -= 1;
+        value
+    }
+
+    fn read_tagged(&mut self, tag: u8) -> Result<Value, Error> {
+        match tag & 0xe0 {
+            STR => return Ok(Value::String(self.read_str(tag)?)),
+            SINT => {
+                let mut value = (tag & 0x0f) as u64;
+                if tag & 0x10 != 0 {
+                    value |= self.read_vlong()? << 4;
+                }
+                return Ok(Value::from(value as u32 as i32));
+            }
+            SLONG => {
+                let mut value = (tag & 0x0f) as u64;
+                if tag & 0x10 != 0 {
+                    value |= self.read_vlong()? << 4;
+                }
+                return Ok(Value::from(value as i64));
+            }
+            ARR => {
+                let size = self.read_size(tag)?;
+                return self.read_list(size).map(Value::Array);
+            }
+            ORDERED_MAP => {
+                let size = self.read_size(tag)?;
+                return self.read_object(size);
+            }
+            NAMED_LST => {
+                let size = self.read_size(tag)?;
+                let size = size
+                    .checked_mul(2)
+                    .ok_or_else(|| Error::Unknown("Invalid javabin size".to_string()))?;
+                return self.read_list(size).map(Value::Array);
+            }
+            EXTERN_STRING => return Ok(Value::String(self.read_extern_str(tag)?)),
+            _ => {}
+        }
+        match tag {
+            NULL => Ok(Value::Null),
+            BOOL_TRUE => Ok(Value::Bool(true)),
+            BOOL_FALSE => Ok(Value::Bool(false)),
+            BYTE => Ok(Value::from(i8::from_be_bytes(self.read_array()?))),
+            SHORT => Ok(Value::from(i16::from_be_bytes(self.read_array()?))),
+            INT => Ok(Value::from(i32::from_be_bytes(self.read_array()?))),
+            LONG => Ok(Value::from(i64::from_be_bytes(self.read_array()?))),
+            DOUBLE => Ok(float_value(f64::from_be_bytes(self.read_array()?))),
+            FLOAT => {
+                // Go through the shortest representation, so 0.1f becomes 0.1 and not 0.10000000149011612
+                let value = f32::from_be_bytes(self.read_array()?);
+                Ok(float_value(value.to_string().parse().unwrap_or(f64::NAN)))
+            }
+            DATE => Ok(Value::String(format_date(i64::from_be_bytes(
+                self.read_array()?,
+            )))),
+            MAP => {
+                let size = self.read_vint()?;
+                self.read_object(size)
+            }
+            SOLRDOC => {
+                let tag = self.read_u8()?;
+                let size = self.read_size(tag)?;
+                self.read_document(size)
+            }
+            SOLRDOCLST => self.read_document_list(),
+            BYTEARR => {
+                let length = self.read_vint()?;
+                let bytes = self.read_bytes(length)?;
+                Ok(Value::String(
+                    base64::engine::general_purpose::STANDARD.encode(bytes),
+                ))
+            }
+            ITERATOR => {
+                let mut values = Vec::new();
+                while self.peek_u8()? != END {
+                    values.push(self.read_val()?);
+                }
+                self.position += 1;
+                Ok(Value::Array(values))
+            }
+            SOLRINPUTDOC => {
+                let size = self.read_vint()?;
+                // The document boost, which is no longer supported by Solr
+                self.read_val()?;
+                self.read_document(size)
+            }
+            MAP_ENTRY_ITER => {
+                let mut map = Map::new();
+                while self.peek_u8()? != END {
+                    let key = key_to_string(self.read_val()?);
+                    map.insert(key, self.read_val()?);
+                }
+                self.position += 1;
+                Ok(Value::Object(map))
+            }
+            ENUM_FIELD_VALUE => {
+                // Enum fields are rendered by name in JSON
+                self.read_val()?;
+                self.read_val()
+            }
+            MAP_ENTRY => {
+                let key = key_to_string(self.read_val()?);
+                let value = self.read_val()?;
+                Ok(Value::Object(Map::from_iter([(key, value)])))
+            }
+            tag => Err(Error::Unknown(format!("Unknown javabin tag {}", tag))),
+        }
+    }
+
+    fn read_str(&mut self, tag: u8) -> Result<String, Error> {
+        let length = self.read_size(tag)?;
+        let bytes = self.read_bytes(length)?;
+        String::from_utf8(bytes.to_vec())
+            .map_err(|e| Error::Unknown(format!("Invalid javabin string: {}", e)))
+    }
+
+    /// Strings that repeat, such as field names, are sent once and referenced by index afterwards.
+    fn read_extern_str(&mut self, tag: u8) -> Result<String, Error> {
+        let index = self.read_size(tag)?;
+        if index != 0 {
+            return self
+                .extern_strings
+                .get(index - 1)
+                .cloned()
+                .ok_or_else(|| Error::Unknown(format!("Unknown javabin extern string {}", index)));
+        }
+        let tag = self.read_u8()?;
+        let value = self.read_str(tag)?;
+        self.extern_strings.push(value.clone());
+        Ok(value)
+    }
+
+    fn read_list(&mut self, size: usize) -> Result<Vec<Value>, Error> {
+        let mut values = Vec::with_capacity(size.min(1024));
+        for _ in 0..size {
+            values.push(self.read_val()?);
+        }
+        Ok(values)
+    }
+
+    fn read_object(&mut self, size: usize) -> Result<Value, Error> {
+        let mut map = Map::new();
+        for _ in 0..size {
+            let key = key_to_string(self.read_val()?);
+            map.insert(key, self.read_val()?);
+        }
+        Ok(Value::Object(map))
+    }
+
+    /// Documents contain fields, and child documents without a field name, which JSON puts in `_childDocuments_`.
+    fn read_document(&mut self, size: usize) -> Result<Value, Error> {
+        let mut map = Map::new();
+        let mut children = Vec::new();
+        for _ in 0..size {
+            match self.peek_u8()? {
+                SOLRDOC | SOLRINPUTDOC => children.push(self.read_val()?),
+                _ => {
+                    let key = key_to_string(self.read_val()?);
+                    map.insert(key, self.read_val()?);
+                }
+            }
+        }
+        if !children.is_empty() {
+            map.insert(CHILD_DOCUMENTS.to_string(), Value::Array(children));
+        }
+        Ok(Value::Object(map))
+    }
+
+    /// A document list starts with `[numFound, start, maxScore, numFoundExact]`, followed by the documents.
+    fn read_document_list(&mut self) -> Result<Value, Error> {
+        let header = match self.read_val()? {
+            Value::Array(header) => header,
+            _ => return Err(Error::Unknown("Invalid javabin document list".to_string())),
+        };
+        let docs = self.read_val()?;
+        let mut header = header.into_iter();
+        let mut map = Map::new();
+        map.insert("numFound".to_string(), header.next().unwrap_or_default());
+        map.insert("start".to_string(), header.next().unwrap_or_default());
+        if let Some(max_score) = header.next().filter(|v| !v.is_null()) {
+            map.insert("maxScore".to_string(), max_score);
+        }
+        if let Some(num_found_exact) = header.next() {
+            map.insert("numFoundExact".to_string(), num_found_exact);
+        }
+        map.insert("docs".to_string(), docs);
+        Ok(Value::Object(map))
+    }
+}
+
+fn key_to_string(key: Value) -> String {
+    match key {
+        Value::String(key) => key,
+        key => key.to_string(),
+    }
+}
+
+fn float_value(value: f64) -> Value {
+    Number::from_f64(value)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+/// Format milliseconds since the epoch the way Solr does, eg: `2020-01-01T12:00:00Z` or `2020-01-01T12:00:00.250Z`
+fn format_date(millis: i64) -> String {
+    let (year, month, day) = civil_from_days(millis.div_euclid(86_400_000));
+    let millis_of_day = millis.rem_euclid(86_400_000);
+    let hours = millis_of_day / 3_600_000;
+    let minutes = millis_of_day / 60_000 % 60;
+    let seconds = millis_of_day / 1000 % 60;
+    let millis = millis_of_day % 1000;
+    let date = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year, month, day, hours, minutes, seconds
+    );
+    match millis {
+        0 => format!("{}Z", date),
+        millis => format!("{}.{:03}Z", date, millis),
+    }
+}
+
+/// The proleptic Gregorian date of a number of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = match month_index < 10 {
+        true => month_index + 3,
+        false => month_index - 9,
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[derive(Default)]
+struct Encoder {
+    buffer: Vec<u8>,
+}
+
+impl Encoder {
+    fn write_vint(&mut self, mut value: usize) {
+        while value > 0x7f {
+            self.buffer.push((value & 0x7f) as u8 | 0x80);
+            value >>= 7;
+        }
+        self.buffer.push(value as u8);
+    }
+
+    fn write_tag(&mut self, tag: u8, size: usize) {
+        if tag & 0xe0 == 0 {
+            self.buffer.push(tag);
+            self.write_vint(size);
+        } else if size < 0x1f {
+            self.buffer.push(tag | size as u8);
+        } else {
+            self.buffer.push(tag | 0x1f);
+            self.write_vint(size - 0x1f);
+        }
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_tag(STR, value.len());
+        self.buffer.extend_from_slice(value.as_bytes());
+    }
+
+    fn write_value(&mut self, value: &Value) {
+        match value {
+            Value::Null => self.buffer.push(NULL),
+            Value::Bool(true) => self.buffer.push(BOOL_TRUE),
+            Value::Bool(false) => self.buffer.push(BOOL_FALSE),
+            Value::Number(number) => self.write_number(number),
+            Value::String(value) => self.write_str(value),
+            Value::Array(values) => {
+                self.write_tag(ARR, values.len());
+                for value in values {
+                    self.write_value(value);
+                }
+            }
+            Value::Object(map) if is_atomic_update(map) => {
+                self.write_tag(MAP, map.len());
+                for (key, value) in map {
+                    self.write_str(key);
+                    self.write_value(value);
+                }
+            }
+            Value::Object(map) => self.write_document(map),
+        }
+    }
+
+    fn write_number(&mut self, number: &Number) {
+        match (number.as_i64(), number.as_f64()) {
+            (Some(value), _) => match i32::try_from(value) {
+                Ok(value) => {
+                    self.buffer.push(INT);
+                    self.buffer.extend_from_slice(&value.to_be_bytes());
+                }
+                Err(_) => {
+                    self.buffer.push(LONG);
+                    self.buffer.extend_from_slice(&value.to_be_bytes());
+                }
+            },
+            (None, value) => {
+                self.buffer.push(DOUBLE);
+                self.buffer
+                    .extend_from_slice(&value.unwrap_or(f64::NAN).to_be_bytes());
+            }
+        }
+    }
+
+    /// Write an input document. Documents in `_childDocuments_` are written as anonymous children.
+    fn write_document(&mut self, document: &Map<String, Value>) {
+        let children = match document.get(CHILD_DOCUMENTS) {
+            Some(Value::Array(children)) => children.as_slice(),
+            _ => &[],
+        };
+        let fields = document
+            .iter()
+            .filter(|(key, _)| key.as_str() != CHILD_DOCUMENTS)
+            .collect::<Vec<_>>();
+        self.write_tag(SOLRINPUTDOC, fields.len() + children.len());
+        // Document boost, ignored by Solr
+        self.buffer.push(FLOAT);
+        self.buffer.extend_from_slice(&1f32.to_be_bytes());
+        for (key, value) in fields {
+            self.write_str(key);
+            self.write_value(value);
+        }
+        for child in children {
+            match child {
+                Value::Object(child) => self.write_document(child),
+                child => self.write_value(child),
+            }
+        }
+    }
+}
+
+fn is_atomic_update(map: &Map<String, Value>) -> bool {
+    !map.is_empty()
+        && map
+            .keys()
+            .all(|key| ATOMIC_OPERATIONS.contains(&key.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::javabin::{decode, encode_documents, format_date, from_slice};
+    use crate::models::SolrResponse;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    fn str_bytes(value: &str) -> Vec<u8> {
+        let mut bytes = vec![0x20 | value.len() as u8];
+        bytes.extend_from_slice(value.as_bytes());
+        bytes
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Doc {
+        id: String,
+        age: i64,
+    }
+
+    #[test]
+    fn decode_select_response() {
+        let mut bytes = vec![2, 0xc2];
+        bytes.extend(str_bytes("responseHeader"));
+        bytes.push(0xa2);
+        bytes.extend(str_bytes("status"));
+        bytes.push(0x40);
+        bytes.extend(str_bytes("QTime"));
+        bytes.push(0x45);
+        bytes.extend(str_bytes("response"));
+        // numFound 2, start 0, no max score, exact
+        bytes.extend([12, 0x84, 0x62, 0x60, 0, 1]);
+        bytes.push(0x82);
+        for (index, (id, age)) in [("1", 20i32), ("2", 300)].into_iter().enumerate() {
+            bytes.extend([11, 0xa2]);
+            // Field names are extern strings, sent in full the first time
+            match index {
+                0 => bytes.extend([0xe0].into_iter().chain(str_bytes("id"))),
+                _ => bytes.push(0xe1),
+            }
+            bytes.extend(str_bytes(id));
+            match index {
+                0 => bytes.extend([0xe0].into_iter().chain(str_bytes("age"))),
+                _ => bytes.push(0xe2),
+            }
+            bytes.push(6);
+            bytes.extend(age.to_be_bytes());
+        }
+
+        let value = decode(&bytes).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "responseHeader": {"status": 0, "QTime": 5},
+                "response": {
+                    "numFound": 2,
+                    "start": 0,
+                    "numFoundExact": true,
+                    "docs": [{"id": "1", "age": 20}, {"id": "2", "age": 300}]
+                }
+            })
+        );
+        let response: SolrResponse = from_slice(&bytes).unwrap();
+        let docs = response.get_docs_response().unwrap();
+        assert_eq!(docs.get_num_found(), 2);
+        assert_eq!(
+            docs.get_docs::<Doc>().unwrap(),
+            vec![
+                Doc {
+                    id: "1".to_string(),
+                    age: 20
+                },
+                Doc {
+                    id: "2".to_string(),
+                    age: 300
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_nested_named_lists_as_flat_lists() {
+        let mut bytes = vec![2, 0xc1];
+        bytes.extend(str_bytes("facet_fields"));
+        bytes.push(0xc2);
+        bytes.extend(str_bytes("a"));
+        bytes.push(0x43);
+        bytes.extend(str_bytes("b"));
+        // 100 as a small int with a continuation: 100 = 4 | (6 << 4)
+        bytes.extend([0x54, 6]);
+        assert_eq!(
+            decode(&bytes).unwrap(),
+            json!({"facet_fields": ["a", 3, "b", 100]})
+        );
+    }
+
+    #[test]
+    fn decode_rejects_unknown_version() {
+        assert!(decode(&[1, 0]).is_err());
+        assert!(decode(&[2, 0x25, b'a']).is_err());
+    }
+
+    #[test]
+    fn decode_rejects_overflowing_sizes() {
+        // A string with an extended size of 0x1f + u64::MAX
+        let mut bytes = vec![2, 0x3f];
+        bytes.extend([0xff; 9]);
+        bytes.push(0x01);
+        assert!(decode(&bytes).is_err());
+        // A named list with an extended size that overflows when doubled
+        let mut bytes = vec![2, 0x81, 0xdf];
+        bytes.extend([0xff; 8]);
+        bytes.push(0x7f);
+        assert!(decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn decode_rejects_deep_nesting() {
+        let mut bytes = vec![2];
+        bytes.extend([0x81; 100_000]);
+        bytes.push(0);
+        assert!(decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn format_dates_like_solr() {
+        assert_eq!(format_date(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_date(1_709_251_200_250), "2024-03-01T00:00:00.250Z");
+        assert_eq!(format_date(-86_400_000), "1969-12-31T00:00:00Z");
+    }
+
+    #[test]
+    fn encode_documents_round_trips() {
+        let docs = [
+            json!({"id": "1", "count": 5_000_000_000i64, "score": 1.5, "tags": ["a", "b"]}),
+            json!({"id": "2", "count": {"inc": 1}, "_childDocuments_": [{"id": "2-1"}]}),
+        ];
+        let bytes = encode_documents(&docs, &[("overwrite", "false")]).unwrap();
+        assert_eq!(
+            decode(&bytes).unwrap(),
+            json!({
+                "params": ["overwrite", "false"],
+                "docs": docs
+            })
+        );
+    }
+
+    #[test]
+    fn encode_long_strings_with_extended_size() {
+        let id = "x".repeat(200);
+        let bytes = encode_documents(&[json!({ "id": id })], &[]).unwrap();
+        assert_eq!(decode(&bytes).unwrap()["docs"][0]["id"], json!(id));
+    }
+
+    #[test]
+    fn encode_rejects_non_object_documents() {
+        assert!(encode_documents(&[1], &[]).is_err());
+    }
+}
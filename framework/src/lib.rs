@@ -82,19 +82,31 @@ pub use crate::hosts::solr_server_host::*;
 pub use crate::hosts::zookeeper_host::*;
 /// Model structs
 pub mod models;
+pub use models::atomic_update::*;
 pub use models::auth::*;
 pub use models::commit_type::*;
 pub use models::context::*;
+pub use models::version::*;
 /// Query types
 pub mod queries;
+pub use queries::admin_task::*;
+pub use queries::bulk_index::*;
+pub use queries::components::elevation::*;
 pub use queries::components::facet_set::*;
 pub use queries::components::grouping::*;
 pub use queries::components::json_facet::*;
 pub use queries::components::stats::*;
 pub use queries::def_type::*;
+pub use queries::extract::*;
+pub use queries::file_update::*;
 pub use queries::index::*;
+pub use queries::reindex::*;
 pub use queries::request_builder::*;
 pub use queries::select::*;
+pub use queries::update_batch::*;
+/// Solr's binary javabin format
+#[cfg(feature = "javabin")]
+pub mod javabin;
 #[cfg(feature = "blocking")]
 /// Tokio Runtime for blocking usage
 mod runtime;
@@ -0,0 +1,196 @@
+use crate::error::Error;
+use crate::models::response::SolrResponseHeader;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The state of an async Collections API request, as reported by `REQUESTSTATUS`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum AdminTaskState {
+    Submitted,
+    Running,
+    Completed,
+    Failed,
+    /// Solr has no request with the id, or its status was deleted.
+    NotFound,
+}
+
+impl AdminTaskState {
+    /// Whether the request has finished, successfully or not.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, AdminTaskState::Completed | AdminTaskState::Failed)
+    }
+}
+
+#[derive(Deserialize)]
+struct RequestStatus {
+    state: AdminTaskState,
+    msg: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestException {
+    msg: Option<String>,
+    rsp_code: Option<u16>,
+}
+
+/// The status of an async Collections API request, returned by [AsyncAdminTask](crate::AsyncAdminTask).
+#[derive(Clone, PartialEq, Debug)]
+pub struct SolrRequestStatus {
+    response_header: Option<SolrResponseHeader>,
+    state: AdminTaskState,
+    msg: Option<String>,
+    response: HashMap<String, Value>,
+}
+
+impl SolrRequestStatus {
+    /// The response header returned by Solr.
+    pub fn get_response_header(&self) -> Option<&SolrResponseHeader> {
+        self.response_header.as_ref()
+    }
+
+    /// The state of the request.
+    pub fn get_state(&self) -> AdminTaskState {
+        self.state
+    }
+
+    /// The message of the status. Eg: `found [create-1] in completed tasks`
+    pub fn get_msg(&self) -> Option<&str> {
+        self.msg.as_deref()
+    }
+
+    /// The rest of the response, with the result of the operation. Eg: `success` with the response of every node.
+    pub fn get_response(&self) -> &HashMap<String, Value> {
+        &self.response
+    }
+
+    pub(crate) fn from_response(mut response: HashMap<String, Value>) -> Result<Self, Error> {
+        let status: RequestStatus = match response.remove("status") {
+            Some(status) => serde_json::from_value(status)?,
+            None => {
+                return Err(Error::Unknown(
+                    "Could not find status in response".to_string(),
+                ))
+            }
+        };
+        let response_header = response
+            .remove("responseHeader")
+            .map(serde_json::from_value)
+            .transpose()?;
+        Ok(SolrRequestStatus {
+            response_header,
+            state: status.state,
+            msg: status.msg,
+            response,
+        })
+    }
+
+    /// The error of a failed request.
+    ///
+    /// Solr reports failures as `exception`, `Operation x caused exception:` or per node under `failure`, depending on the operation.
+    pub(crate) fn to_error(&self, request_id: &str) -> Option<Error> {
+        if self.state != AdminTaskState::Failed {
+            return None;
+        }
+        let exception = self
+            .response
+            .get("exception")
+            .cloned()
+            .and_then(|e| serde_json::from_value::<RequestException>(e).ok());
+        let code = exception.as_ref().and_then(|e| e.rsp_code);
+        let caused = self
+            .response
+            .iter()
+            .find(|(key, _)| key.ends_with("caused exception:"))
+            .and_then(|(_, value)| value.as_str().map(|s| s.to_string()));
+        let failure = self
+            .response
+            .get("failure")
+            .and_then(|failure| failure.as_object())
+            .map(|failure| {
+                failure
+                    .iter()
+                    .map(|(node, error)| match error {
+                        Value::String(error) => format!("{}: {}", node, error),
+                        error => format!("{}: {}", node, error),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            });
+        let msg = exception
+            .and_then(|e| e.msg)
+            .or(caused)
+            .or(failure)
+            .or_else(|| self.msg.clone())
+            .unwrap_or_else(|| "Unknown Solr Error".to_string());
+        Some(Error::SolrAdminTaskFailed {
+            request_id: request_id.to_string(),
+            code,
+            msg,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::admin_task::{AdminTaskState, SolrRequestStatus};
+    use crate::Error;
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+
+    fn status(value: Value) -> SolrRequestStatus {
+        let response: HashMap<String, Value> = serde_json::from_value(value).unwrap();
+        SolrRequestStatus::from_response(response).unwrap()
+    }
+
+    #[test]
+    fn completed_status_has_no_error() {
+        let status = status(json!({
+            "responseHeader": {"status": 0, "QTime": 2},
+            "success": {"localhost:8983_solr": {"responseHeader": {"status": 0, "QTime": 500}}},
+            "status": {"state": "completed", "msg": "found [create-1] in completed tasks"}
+        }));
+        assert_eq!(status.get_state(), AdminTaskState::Completed);
+        assert!(status.get_response().contains_key("success"));
+        assert!(status.to_error("create-1").is_none());
+    }
+
+    #[test]
+    fn failed_status_reads_exception() {
+        let status = status(json!({
+            "responseHeader": {"status": 0, "QTime": 3},
+            "Operation splitshard caused exception:": "org.apache.solr.common.SolrException: No shard with the specified name exists: shard4",
+            "exception": {"msg": "No shard with the specified name exists: shard4", "rspCode": 400},
+            "status": {"state": "failed", "msg": "found [split-1] in failed tasks"}
+        }));
+        assert!(matches!(
+            status.to_error("split-1"),
+            Some(Error::SolrAdminTaskFailed { request_id, code: Some(400), msg })
+                if request_id == "split-1" && msg == "No shard with the specified name exists: shard4"
+        ));
+    }
+
+    #[test]
+    fn failed_status_reads_node_failures() {
+        let status = status(json!({
+            "failure": {"localhost:8983_solr": "org.apache.solr.client.solrj.SolrServerException: Core exists"},
+            "status": {"state": "failed", "msg": "found [create-1] in failed tasks"}
+        }));
+        assert!(matches!(
+            status.to_error("create-1"),
+            Some(Error::SolrAdminTaskFailed { code: None, msg, .. })
+                if msg == "localhost:8983_solr: org.apache.solr.client.solrj.SolrServerException: Core exists"
+        ));
+    }
+
+    #[test]
+    fn not_found_state_is_parsed() {
+        let status = status(json!({
+            "status": {"state": "notfound", "msg": "Did not find [missing] in any tasks queue"}
+        }));
+        assert_eq!(status.get_state(), AdminTaskState::NotFound);
+        assert!(!status.get_state().is_finished());
+    }
+}
@@ -0,0 +1,277 @@
+use crate::models::version::VersionConstraint;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A single atomic update operation on a field.
+///
+/// Can be used directly as a field type, to build atomic updates from structs.
+/// Operations on fields that are non-indexed, non-stored docValues fields, are done in place by Solr.
+/// # Examples
+/// ```
+/// use serde::Serialize;
+/// use solrstice::AtomicOperation;
+///
+/// #[derive(Serialize)]
+/// struct CityUpdate {
+///     id: String,
+///     #[serde(skip_serializing_if = "Option::is_none")]
+///     population: Option<AtomicOperation<i64>>,
+///     interests: AtomicOperation<Vec<String>>,
+/// }
+///
+/// let update = CityUpdate {
+///     id: "city_Alta".to_string(),
+///     population: Some(AtomicOperation::Inc(100)),
+///     interests: AtomicOperation::AddDistinct(vec!["fishing".to_string()]),
+/// };
+/// assert_eq!(
+///     serde_json::to_value(&update)?,
+///     serde_json::json!({"id": "city_Alta", "population": {"inc": 100}, "interests": {"add-distinct": ["fishing"]}})
+/// );
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum AtomicOperation<T> {
+    /// Set or replace the value. `null` removes the field.
+    #[serde(rename = "set")]
+    Set(T),
+    /// Add values to a multivalued field, or add child documents.
+    #[serde(rename = "add")]
+    Add(T),
+    /// Add values to a multivalued field, if they are not already present.
+    #[serde(rename = "add-distinct")]
+    AddDistinct(T),
+    /// Remove all occurrences of the values from a multivalued field, or remove child documents.
+    #[serde(rename = "remove")]
+    Remove(T),
+    /// Remove all occurrences of values matching the regular expressions from a multivalued field.
+    #[serde(rename = "removeregex")]
+    RemoveRegex(T),
+    /// Increment a numeric field by the value.
+    #[serde(rename = "inc")]
+    Inc(T),
+}
+
+impl<T> AtomicOperation<T> {
+    /// The name of the operation as used by Solr.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AtomicOperation::Set(_) => "set",
+            AtomicOperation::Add(_) => "add",
+            AtomicOperation::AddDistinct(_) => "add-distinct",
+            AtomicOperation::Remove(_) => "remove",
+            AtomicOperation::RemoveRegex(_) => "removeregex",
+            AtomicOperation::Inc(_) => "inc",
+        }
+    }
+
+    fn into_value(self) -> T {
+        match self {
+            AtomicOperation::Set(v)
+            | AtomicOperation::Add(v)
+            | AtomicOperation::AddDistinct(v)
+            | AtomicOperation::Remove(v)
+            | AtomicOperation::RemoveRegex(v)
+            | AtomicOperation::Inc(v) => v,
+        }
+    }
+}
+
+/// A document with atomic update operations, only changing the given fields of an existing document.
+///
+/// Can be passed to [UpdateQuery::execute](crate::queries::index::UpdateQuery::execute) like any other document.
+/// Atomic updates can also be used as child documents, or sent for a child document directly by setting [AtomicUpdate::root].
+/// # Examples
+/// ```no_run
+/// use solrstice::{AsyncSolrCloudClient, AtomicUpdate, SolrServerContextBuilder, SolrSingleServerHost, UpdateQuery};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let update = AtomicUpdate::new("city_Alta")
+///     .set("city_name", "Alta")
+///     .add("population", AtomicUpdate::new("city_Alta_3").field("age", 30).field("count", 100));
+/// client.index(&UpdateQuery::new(), "collection_name", &[update]).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Debug)]
+#[serde(transparent)]
+pub struct AtomicUpdate(Map<String, Value>);
+
+impl AtomicUpdate {
+    /// Create an atomic update for the document with the given `id`.
+    ///
+    /// Use [AtomicUpdate::with_unique_key] if the unique key of the collection is not `id`.
+    /// # Examples
+    /// ```
+    /// use solrstice::AtomicUpdate;
+    /// let update = AtomicUpdate::new("document1").inc("count", 1);
+    /// assert_eq!(serde_json::to_value(&update)?, serde_json::json!({"id": "document1", "count": {"inc": 1}}));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn new<S: Into<String>>(id: S) -> Self {
+        Self::with_unique_key("id", id)
+    }
+
+    /// Create an atomic update for the document where the unique key `field` is `value`.
+    pub fn with_unique_key<K: Into<String>, S: Into<String>>(field: K, value: S) -> Self {
+        AtomicUpdate(Map::new()).field(field, value.into())
+    }
+
+    /// Set a plain field, without an operation. Used for the unique key, and for full child documents.
+    pub fn field<K: Into<String>, V: Into<Value>>(mut self, field: K, value: V) -> Self {
+        self.0.insert(field.into(), value.into());
+        self
+    }
+
+    /// Set the `_root_` of the document. Needed when updating a child document directly, as it is stored with its root document.
+    pub fn root<S: Into<String>>(self, root: S) -> Self {
+        self.field("_root_", root.into())
+    }
+
+    /// Only apply the update if the `_version_` of the document matches the constraint.
+    /// # Examples
+    /// ```
+    /// use solrstice::{AtomicUpdate, VersionConstraint};
+    /// let update = AtomicUpdate::new("document1").version(VersionConstraint::MustExist).set("name", "New name");
+    /// assert_eq!(serde_json::to_value(&update)?["_version_"], 1);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn version<V: Into<VersionConstraint>>(self, version: V) -> Self {
+        self.field("_version_", version.into().as_i64())
+    }
+
+    /// Add an operation on a field. Several operations on the same field are sent together.
+    /// # Examples
+    /// ```
+    /// use solrstice::{AtomicOperation, AtomicUpdate};
+    /// let update = AtomicUpdate::new("document1")
+    ///     .operation("interests", AtomicOperation::Add("cars"))
+    ///     .operation("interests", AtomicOperation::Remove("boats"));
+    /// assert_eq!(
+    ///     serde_json::to_value(&update)?,
+    ///     serde_json::json!({"id": "document1", "interests": {"add": "cars", "remove": "boats"}})
+    /// );
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn operation<K: Into<String>, V: Into<Value>>(
+        mut self,
+        field: K,
+        operation: AtomicOperation<V>,
+    ) -> Self {
+        let name = operation.name();
+        let value = operation.into_value().into();
+        let entry = self
+            .0
+            .entry(field.into())
+            .or_insert_with(|| Value::Object(Map::new()));
+        if !entry.is_object() {
+            *entry = Value::Object(Map::new());
+        }
+        if let Value::Object(operations) = entry {
+            operations.insert(name.to_string(), value);
+        }
+        self
+    }
+
+    /// Set or replace the value of a field. Use `Value::Null` to remove the field.
+    pub fn set<K: Into<String>, V: Into<Value>>(self, field: K, value: V) -> Self {
+        self.operation(field, AtomicOperation::Set(value))
+    }
+
+    /// Add values to a multivalued field, or add child documents.
+    pub fn add<K: Into<String>, V: Into<Value>>(self, field: K, value: V) -> Self {
+        self.operation(field, AtomicOperation::Add(value))
+    }
+
+    /// Add values to a multivalued field, if they are not already present.
+    pub fn add_distinct<K: Into<String>, V: Into<Value>>(self, field: K, value: V) -> Self {
+        self.operation(field, AtomicOperation::AddDistinct(value))
+    }
+
+    /// Remove values from a multivalued field, or remove child documents by id.
+    pub fn remove<K: Into<String>, V: Into<Value>>(self, field: K, value: V) -> Self {
+        self.operation(field, AtomicOperation::Remove(value))
+    }
+
+    /// Remove values matching the regular expressions from a multivalued field.
+    pub fn remove_regex<K: Into<String>, V: Into<Value>>(self, field: K, value: V) -> Self {
+        self.operation(field, AtomicOperation::RemoveRegex(value))
+    }
+
+    /// Increment a numeric field.
+    pub fn inc<K: Into<String>, V: Into<Value>>(self, field: K, value: V) -> Self {
+        self.operation(field, AtomicOperation::Inc(value))
+    }
+}
+
+impl AsRef<AtomicUpdate> for AtomicUpdate {
+    fn as_ref(&self) -> &AtomicUpdate {
+        self
+    }
+}
+
+impl From<&AtomicUpdate> for AtomicUpdate {
+    fn from(update: &AtomicUpdate) -> Self {
+        update.clone()
+    }
+}
+
+impl From<AtomicUpdate> for Value {
+    fn from(update: AtomicUpdate) -> Self {
+        Value::Object(update.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::atomic_update::AtomicUpdate;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn atomic_update_serializes_operations() {
+        let update = AtomicUpdate::with_unique_key("key", "document1")
+            .set("name", "New name")
+            .set("removed", Value::Null)
+            .add("tags", vec!["a", "b"])
+            .add_distinct("tags", "c")
+            .remove("categories", "old")
+            .remove_regex("categories", "^tmp_.*")
+            .inc("count", -2);
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({
+                "key": "document1",
+                "name": {"set": "New name"},
+                "removed": {"set": null},
+                "tags": {"add": ["a", "b"], "add-distinct": "c"},
+                "categories": {"remove": "old", "removeregex": "^tmp_.*"},
+                "count": {"inc": -2}
+            })
+        );
+    }
+
+    #[test]
+    fn atomic_update_works_on_child_documents() {
+        let update = AtomicUpdate::new("parent")
+            .add(
+                "children",
+                vec![AtomicUpdate::new("child1").field("age", 3)],
+            )
+            .remove("children", AtomicUpdate::new("child2"));
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({
+                "id": "parent",
+                "children": {"add": [{"id": "child1", "age": 3}], "remove": {"id": "child2"}}
+            })
+        );
+
+        let child = AtomicUpdate::new("child1").root("parent").inc("age", 1);
+        assert_eq!(
+            serde_json::to_value(&child).unwrap(),
+            json!({"id": "child1", "_root_": "parent", "age": {"inc": 1}})
+        );
+    }
+}
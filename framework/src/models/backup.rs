@@ -0,0 +1,434 @@
+use crate::error::{get_solr_error_from_error_response, Error};
+use crate::models::SolrResponseError;
+use crate::queries::request_builder::SolrResponseType;
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Formatter;
+
+/// The result of a backup, returned by [backup_collection](crate::queries::backup::backup_collection).
+///
+/// Only incremental backups report the backup id, files and sizes. Backups sent with an `async` id report nothing.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct BackupResult {
+    #[serde(default, rename = "response")]
+    summary: BackupSummary,
+    #[serde(
+        default,
+        rename = "success",
+        deserialize_with = "deserialize_shard_backups"
+    )]
+    shards: Vec<ShardBackup>,
+    #[serde(default, skip_serializing)]
+    error: Option<SolrResponseError>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct BackupSummary {
+    collection: Option<String>,
+    num_shards: Option<usize>,
+    backup_id: Option<u64>,
+    index_version: Option<String>,
+    start_time: Option<String>,
+    end_time: Option<String>,
+    index_file_count: Option<u64>,
+    uploaded_index_file_count: Option<u64>,
+    #[serde(rename = "indexSizeMB")]
+    index_size_mb: Option<f64>,
+    #[serde(rename = "uploadedIndexFileMB")]
+    uploaded_index_file_mb: Option<f64>,
+}
+
+impl BackupResult {
+    /// The collection that was backed up.
+    pub fn get_collection(&self) -> Option<&str> {
+        self.summary.collection.as_deref()
+    }
+
+    /// The number of shards that were backed up.
+    pub fn get_num_shards(&self) -> Option<usize> {
+        self.summary.num_shards
+    }
+
+    /// The id of the backup point, used to restore or delete it. The first backup of a name is `0`.
+    pub fn get_backup_id(&self) -> Option<u64> {
+        self.summary.backup_id
+    }
+
+    /// The Lucene version of the index. Eg: `9.8.0`
+    pub fn get_index_version(&self) -> Option<&str> {
+        self.summary.index_version.as_deref()
+    }
+
+    /// When the backup started. Eg: `2024-01-01T12:00:00.000Z`
+    pub fn get_start_time(&self) -> Option<&str> {
+        self.summary.start_time.as_deref()
+    }
+
+    /// When the backup ended. Eg: `2024-01-01T12:00:05.000Z`
+    pub fn get_end_time(&self) -> Option<&str> {
+        self.summary.end_time.as_deref()
+    }
+
+    /// The number of index files in the backup point, over all shards.
+    pub fn get_index_file_count(&self) -> Option<u64> {
+        self.summary.index_file_count
+    }
+
+    /// The number of index files that were not in an earlier backup point, and had to be copied.
+    pub fn get_uploaded_index_file_count(&self) -> Option<u64> {
+        self.summary.uploaded_index_file_count
+    }
+
+    /// The size of the index in the backup point, in megabytes.
+    pub fn get_index_size_mb(&self) -> Option<f64> {
+        self.summary.index_size_mb
+    }
+
+    /// The size of the index files that had to be copied, in megabytes.
+    pub fn get_uploaded_index_file_mb(&self) -> Option<f64> {
+        self.summary.uploaded_index_file_mb
+    }
+
+    /// The backups of each shard.
+    pub fn get_shards(&self) -> &[ShardBackup] {
+        &self.shards
+    }
+}
+
+impl SolrResponseType for BackupResult {
+    fn check_for_error(&self, url: String) -> Result<(), Error> {
+        match &self.error {
+            None => Ok(()),
+            Some(e) => Err(get_solr_error_from_error_response(url, e.clone())),
+        }
+    }
+}
+
+/// The backup of one shard in a [BackupResult].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ShardBackup {
+    shard: Option<String>,
+    shard_backup_id: Option<String>,
+    start_time: Option<String>,
+    end_time: Option<String>,
+    #[serde(alias = "fileCount")]
+    index_file_count: Option<u64>,
+    uploaded_index_file_count: Option<u64>,
+    #[serde(rename = "indexSizeMB")]
+    index_size_mb: Option<f64>,
+    #[serde(rename = "uploadedIndexFileMB")]
+    uploaded_index_file_mb: Option<f64>,
+}
+
+impl ShardBackup {
+    /// The name of the shard. Eg: `shard1`
+    pub fn get_shard(&self) -> Option<&str> {
+        self.shard.as_deref()
+    }
+
+    /// The metadata file of the shard in the backup point. Eg: `md_shard1_0.json`
+    pub fn get_shard_backup_id(&self) -> Option<&str> {
+        self.shard_backup_id.as_deref()
+    }
+
+    /// When the backup of the shard started.
+    pub fn get_start_time(&self) -> Option<&str> {
+        self.start_time.as_deref()
+    }
+
+    /// When the backup of the shard ended.
+    pub fn get_end_time(&self) -> Option<&str> {
+        self.end_time.as_deref()
+    }
+
+    /// The number of index files of the shard in the backup point.
+    pub fn get_index_file_count(&self) -> Option<u64> {
+        self.index_file_count
+    }
+
+    /// The number of index files of the shard that had to be copied.
+    pub fn get_uploaded_index_file_count(&self) -> Option<u64> {
+        self.uploaded_index_file_count
+    }
+
+    /// The size of the index of the shard, in megabytes.
+    pub fn get_index_size_mb(&self) -> Option<f64> {
+        self.index_size_mb
+    }
+
+    /// The size of the index files of the shard that had to be copied, in megabytes.
+    pub fn get_uploaded_index_file_mb(&self) -> Option<f64> {
+        self.uploaded_index_file_mb
+    }
+}
+
+/// A backup point of a backup, returned by [list_backups](crate::queries::backup::list_backups).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupPoint {
+    backup_id: u64,
+    collection: Option<String>,
+    #[serde(rename = "collection.configName")]
+    config_name: Option<String>,
+    start_time: Option<String>,
+    end_time: Option<String>,
+    index_version: Option<String>,
+    index_file_count: Option<u64>,
+    #[serde(rename = "indexSizeMB")]
+    index_size_mb: Option<f64>,
+    #[serde(default)]
+    shard_backup_ids: HashMap<String, String>,
+}
+
+impl BackupPoint {
+    /// The id of the backup point.
+    pub fn get_backup_id(&self) -> u64 {
+        self.backup_id
+    }
+
+    /// The collection that was backed up.
+    pub fn get_collection(&self) -> Option<&str> {
+        self.collection.as_deref()
+    }
+
+    /// The config set of the collection when it was backed up.
+    pub fn get_config_name(&self) -> Option<&str> {
+        self.config_name.as_deref()
+    }
+
+    /// When the backup started. Eg: `2024-01-01T12:00:00.000Z`
+    pub fn get_start_time(&self) -> Option<&str> {
+        self.start_time.as_deref()
+    }
+
+    /// When the backup ended.
+    pub fn get_end_time(&self) -> Option<&str> {
+        self.end_time.as_deref()
+    }
+
+    /// The Lucene version of the index. Eg: `9.8.0`
+    pub fn get_index_version(&self) -> Option<&str> {
+        self.index_version.as_deref()
+    }
+
+    /// The number of index files in the backup point, over all shards.
+    pub fn get_index_file_count(&self) -> Option<u64> {
+        self.index_file_count
+    }
+
+    /// The size of the index in the backup point, in megabytes.
+    pub fn get_index_size_mb(&self) -> Option<f64> {
+        self.index_size_mb
+    }
+
+    /// The metadata file of each shard, by shard name. Eg: `shard1` to `md_shard1_0.json`
+    pub fn get_shard_backup_ids(&self) -> &HashMap<String, String> {
+        &self.shard_backup_ids
+    }
+
+    pub(crate) fn from_response(mut response: HashMap<String, Value>) -> Result<Vec<Self>, Error> {
+        match response.remove("backups") {
+            Some(backups) => Ok(serde_json::from_value(backups)?),
+            None => Err(Error::Unknown(
+                "Could not find backups in response".to_string(),
+            )),
+        }
+    }
+}
+
+/// What was deleted from a backup, returned by [delete_backup](crate::queries::backup::delete_backup).
+///
+/// Deleting by id reports each deleted backup point. Purging reports the number of unused files that were deleted.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletedBackup {
+    backup_id: Option<u64>,
+    start_time: Option<String>,
+    size: Option<u64>,
+    num_files: Option<u64>,
+    num_backup_ids: Option<u64>,
+    num_shard_backup_ids: Option<u64>,
+    num_index_files: Option<u64>,
+}
+
+impl DeletedBackup {
+    /// The id of the deleted backup point.
+    pub fn get_backup_id(&self) -> Option<u64> {
+        self.backup_id
+    }
+
+    /// When the deleted backup point was started.
+    pub fn get_start_time(&self) -> Option<&str> {
+        self.start_time.as_deref()
+    }
+
+    /// The size of the deleted backup point, in bytes.
+    pub fn get_size(&self) -> Option<u64> {
+        self.size
+    }
+
+    /// The number of files in the deleted backup point.
+    pub fn get_num_files(&self) -> Option<u64> {
+        self.num_files
+    }
+
+    /// The number of unused backup point files that were purged.
+    pub fn get_num_backup_ids(&self) -> Option<u64> {
+        self.num_backup_ids
+    }
+
+    /// The number of unused shard metadata files that were purged.
+    pub fn get_num_shard_backup_ids(&self) -> Option<u64> {
+        self.num_shard_backup_ids
+    }
+
+    /// The number of unused index files that were purged.
+    pub fn get_num_index_files(&self) -> Option<u64> {
+        self.num_index_files
+    }
+
+    pub(crate) fn from_response(mut response: HashMap<String, Value>) -> Result<Vec<Self>, Error> {
+        match response.remove("deleted") {
+            Some(deleted) => Ok(serde_json::from_value(deleted)?),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct NodeResponse {
+    response: Option<ShardBackup>,
+}
+
+/// The shard responses are keyed by node, and a node with several shards is repeated. A map would only keep the last shard.
+fn deserialize_shard_backups<'de, D>(deserializer: D) -> Result<Vec<ShardBackup>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct ShardBackupsVisitor;
+
+    impl<'de> Visitor<'de> for ShardBackupsVisitor {
+        type Value = Vec<ShardBackup>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            formatter.write_str("a map of node responses")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut shards = Vec::new();
+            while let Some((_, node)) = map.next_entry::<String, NodeResponse>()? {
+                shards.extend(node.response);
+            }
+            Ok(shards)
+        }
+    }
+
+    deserializer.deserialize_map(ShardBackupsVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::backup::{BackupPoint, BackupResult, DeletedBackup};
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+
+    #[test]
+    fn backup_result_keeps_shards_of_the_same_node() {
+        let body = r#"{
+            "responseHeader": {"status": 0, "QTime": 1200},
+            "success": {
+                "localhost:8983_solr": {
+                    "responseHeader": {"status": 0, "QTime": 500},
+                    "response": {
+                        "startTime": "2024-01-01T12:00:00.000Z",
+                        "indexFileCount": 10,
+                        "uploadedIndexFileCount": 10,
+                        "indexSizeMB": 0.5,
+                        "uploadedIndexFileMB": 0.5,
+                        "shard": "shard1",
+                        "endTime": "2024-01-01T12:00:01.000Z",
+                        "shardBackupId": "md_shard1_0.json"
+                    }
+                },
+                "localhost:8983_solr": {
+                    "responseHeader": {"status": 0, "QTime": 500},
+                    "response": {
+                        "indexFileCount": 4,
+                        "uploadedIndexFileCount": 2,
+                        "shard": "shard2",
+                        "shardBackupId": "md_shard2_0.json"
+                    }
+                }
+            },
+            "response": {
+                "collection": "books",
+                "numShards": 2,
+                "backupId": 0,
+                "indexVersion": "9.8.0",
+                "startTime": "2024-01-01T12:00:00.000Z",
+                "indexFileCount": 14,
+                "uploadedIndexFileCount": 12,
+                "indexSizeMB": 0.7,
+                "uploadedIndexFileMB": 0.6,
+                "shardBackupIds": ["md_shard1_0.json", "md_shard2_0.json"],
+                "endTime": "2024-01-01T12:00:02.000Z"
+            }
+        }"#;
+        let result: BackupResult = serde_json::from_str(body).unwrap();
+        assert_eq!(result.get_collection(), Some("books"));
+        assert_eq!(result.get_num_shards(), Some(2));
+        assert_eq!(result.get_backup_id(), Some(0));
+        assert_eq!(result.get_index_file_count(), Some(14));
+        assert_eq!(result.get_uploaded_index_file_count(), Some(12));
+        assert_eq!(result.get_shards().len(), 2);
+        assert_eq!(result.get_shards()[0].get_shard(), Some("shard1"));
+        assert_eq!(result.get_shards()[0].get_index_size_mb(), Some(0.5));
+        assert_eq!(result.get_shards()[1].get_shard(), Some("shard2"));
+        assert_eq!(result.get_shards()[1].get_index_file_count(), Some(4));
+        assert_eq!(
+            result.get_shards()[1].get_shard_backup_id(),
+            Some("md_shard2_0.json")
+        );
+    }
+
+    #[test]
+    fn backup_points_and_deleted_backups_are_parsed() {
+        let response: HashMap<String, Value> = serde_json::from_value(json!({
+            "responseHeader": {"status": 0, "QTime": 5},
+            "collection": "books",
+            "backups": [{
+                "indexFileCount": 14,
+                "indexSizeMB": 0.7,
+                "shardBackupIds": {"shard1": "md_shard1_0.json", "shard2": "md_shard2_0.json"},
+                "collection.configName": "books_config",
+                "backupId": 0,
+                "collection": "books",
+                "startTime": "2024-01-01T12:00:00.000Z",
+                "indexVersion": "9.8.0"
+            }]
+        }))
+        .unwrap();
+        let backups = BackupPoint::from_response(response).unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(backups[0].get_backup_id(), 0);
+        assert_eq!(backups[0].get_config_name(), Some("books_config"));
+        assert_eq!(
+            backups[0].get_shard_backup_ids().get("shard2"),
+            Some(&"md_shard2_0.json".to_string())
+        );
+
+        let response: HashMap<String, Value> = serde_json::from_value(json!({
+            "responseHeader": {"status": 0, "QTime": 5},
+            "deleted": [{"numBackupIds": 1, "numShardBackupIds": 2, "numIndexFiles": 6}]
+        }))
+        .unwrap();
+        let deleted = DeletedBackup::from_response(response).unwrap();
+        assert_eq!(deleted[0].get_num_backup_ids(), Some(1));
+        assert_eq!(deleted[0].get_num_index_files(), Some(6));
+        assert_eq!(deleted[0].get_backup_id(), None);
+    }
+}
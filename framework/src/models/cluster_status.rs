@@ -0,0 +1,470 @@
+use crate::error::Error;
+use crate::queries::collection::CollectionRouter;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The state of a SolrCloud cluster, returned by [get_cluster_status](crate::queries::collection::get_cluster_status).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ClusterStatus {
+    #[serde(default)]
+    collections: HashMap<String, CollectionStatus>,
+    #[serde(default, deserialize_with = "deserialize_aliases")]
+    aliases: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    live_nodes: Vec<String>,
+    #[serde(default)]
+    roles: HashMap<String, Vec<String>>,
+}
+
+impl ClusterStatus {
+    /// The collections, by name.
+    pub fn get_collections(&self) -> &HashMap<String, CollectionStatus> {
+        &self.collections
+    }
+
+    /// A collection by name.
+    pub fn get_collection<S: AsRef<str>>(&self, name: S) -> Option<&CollectionStatus> {
+        self.collections.get(name.as_ref())
+    }
+
+    /// The aliases, with the collections they point to.
+    pub fn get_aliases(&self) -> &HashMap<String, Vec<String>> {
+        &self.aliases
+    }
+
+    /// The nodes that are connected to ZooKeeper. Eg: `localhost:8983_solr`
+    pub fn get_live_nodes(&self) -> &[String] {
+        &self.live_nodes
+    }
+
+    /// The nodes with a role. Eg: `overseer`
+    pub fn get_roles(&self) -> &HashMap<String, Vec<String>> {
+        &self.roles
+    }
+
+    pub(crate) fn from_response(mut response: HashMap<String, Value>) -> Result<Self, Error> {
+        let mut status: ClusterStatus = match response.remove("cluster") {
+            Some(cluster) => serde_json::from_value(cluster)?,
+            None => {
+                return Err(Error::Unknown(
+                    "Could not find cluster in response".to_string(),
+                ))
+            }
+        };
+        for (collection_name, collection) in status.collections.iter_mut() {
+            collection.name.clone_from(collection_name);
+            for (shard_name, shard) in collection.shards.iter_mut() {
+                shard.name.clone_from(shard_name);
+                for (replica_name, replica) in shard.replicas.iter_mut() {
+                    replica.name.clone_from(replica_name);
+                }
+            }
+        }
+        Ok(status)
+    }
+}
+
+/// The health of a collection or shard, from the share of its replicas that are active.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Health {
+    /// All replicas are active.
+    Green,
+    /// Some replicas are not active, but the leader is.
+    Yellow,
+    /// Most replicas are not active.
+    Orange,
+    /// No replica is active.
+    Red,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+struct RouterStatus {
+    name: CollectionRouter,
+    field: Option<String>,
+}
+
+/// A collection in the [ClusterStatus].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionStatus {
+    #[serde(skip)]
+    name: String,
+    #[serde(default)]
+    shards: HashMap<String, ShardStatus>,
+    config_name: Option<String>,
+    router: Option<RouterStatus>,
+    #[serde(default, deserialize_with = "deserialize_stringified")]
+    replication_factor: Option<usize>,
+    #[serde(default, deserialize_with = "deserialize_stringified")]
+    nrt_replicas: Option<usize>,
+    #[serde(default, deserialize_with = "deserialize_stringified")]
+    tlog_replicas: Option<usize>,
+    #[serde(default, deserialize_with = "deserialize_stringified")]
+    pull_replicas: Option<usize>,
+    #[serde(default, deserialize_with = "deserialize_stringified")]
+    per_replica_state: Option<bool>,
+    znode_version: Option<u64>,
+    creation_time_millis: Option<u64>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    health: Option<Health>,
+}
+
+impl CollectionStatus {
+    /// The name of the collection.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// The shards, by name.
+    pub fn get_shards(&self) -> &HashMap<String, ShardStatus> {
+        &self.shards
+    }
+
+    /// A shard by name.
+    pub fn get_shard<S: AsRef<str>>(&self, name: S) -> Option<&ShardStatus> {
+        self.shards.get(name.as_ref())
+    }
+
+    /// The config set of the collection.
+    pub fn get_config_name(&self) -> Option<&str> {
+        self.config_name.as_deref()
+    }
+
+    /// The router of the collection.
+    pub fn get_router(&self) -> Option<CollectionRouter> {
+        self.router.as_ref().map(|router| router.name)
+    }
+
+    /// The field routed on, if not the unique key.
+    pub fn get_router_field(&self) -> Option<&str> {
+        self.router
+            .as_ref()
+            .and_then(|router| router.field.as_deref())
+    }
+
+    /// The replication factor the collection was created with.
+    pub fn get_replication_factor(&self) -> Option<usize> {
+        self.replication_factor
+    }
+
+    /// The number of NRT replicas per shard the collection was created with.
+    pub fn get_nrt_replicas(&self) -> Option<usize> {
+        self.nrt_replicas
+    }
+
+    /// The number of TLOG replicas per shard the collection was created with.
+    pub fn get_tlog_replicas(&self) -> Option<usize> {
+        self.tlog_replicas
+    }
+
+    /// The number of PULL replicas per shard the collection was created with.
+    pub fn get_pull_replicas(&self) -> Option<usize> {
+        self.pull_replicas
+    }
+
+    /// Whether the state of each replica is kept in its own ZooKeeper node.
+    pub fn get_per_replica_state(&self) -> Option<bool> {
+        self.per_replica_state
+    }
+
+    /// The version of the state of the collection in ZooKeeper.
+    pub fn get_znode_version(&self) -> Option<u64> {
+        self.znode_version
+    }
+
+    /// When the collection was created, in milliseconds since the epoch.
+    pub fn get_creation_time_millis(&self) -> Option<u64> {
+        self.creation_time_millis
+    }
+
+    /// The aliases pointing to the collection.
+    pub fn get_aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    /// The health of the collection, from the worst of its shards.
+    pub fn get_health(&self) -> Option<Health> {
+        self.health
+    }
+}
+
+/// The state of a shard. Shards are `Construction` while being created by a split, and `Inactive` after they have been split.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ShardState {
+    Active,
+    Inactive,
+    Construction,
+    Recovery,
+    #[serde(rename = "recovery_failed")]
+    RecoveryFailed,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A shard of a [CollectionStatus].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ShardStatus {
+    #[serde(skip)]
+    name: String,
+    range: Option<String>,
+    state: ShardState,
+    #[serde(default)]
+    replicas: HashMap<String, ReplicaStatus>,
+    health: Option<Health>,
+}
+
+impl ShardStatus {
+    /// The name of the shard.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// The hash range of the shard, for the `compositeId` router. Eg: `80000000-ffffffff`
+    pub fn get_range(&self) -> Option<&str> {
+        self.range.as_deref()
+    }
+
+    /// The state of the shard.
+    pub fn get_state(&self) -> ShardState {
+        self.state
+    }
+
+    /// The replicas, by name. Eg: `core_node3`
+    pub fn get_replicas(&self) -> &HashMap<String, ReplicaStatus> {
+        &self.replicas
+    }
+
+    /// The leader of the shard, if it has one.
+    pub fn get_leader(&self) -> Option<&ReplicaStatus> {
+        self.replicas.values().find(|replica| replica.is_leader())
+    }
+
+    /// The health of the shard.
+    pub fn get_health(&self) -> Option<Health> {
+        self.health
+    }
+}
+
+/// The state of a replica. Replicas on nodes that are not live may still be reported as `Active`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ReplicaState {
+    Active,
+    Down,
+    Recovering,
+    #[serde(rename = "recovery_failed")]
+    RecoveryFailed,
+    #[serde(other)]
+    Unknown,
+}
+
+/// How a replica keeps its index.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ReplicaType {
+    /// Indexes every document, and can become leader.
+    Nrt,
+    /// Copies the index from the leader and keeps a transaction log, so it can become leader.
+    Tlog,
+    /// Only copies the index from the leader.
+    Pull,
+}
+
+impl ReplicaType {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ReplicaType::Nrt => "NRT",
+            ReplicaType::Tlog => "TLOG",
+            ReplicaType::Pull => "PULL",
+        }
+    }
+}
+
+/// A replica of a [ShardStatus].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ReplicaStatus {
+    #[serde(skip)]
+    name: String,
+    core: String,
+    base_url: Option<String>,
+    node_name: String,
+    state: ReplicaState,
+    #[serde(rename = "type")]
+    replica_type: ReplicaType,
+    #[serde(default, deserialize_with = "deserialize_stringified")]
+    leader: Option<bool>,
+}
+
+impl ReplicaStatus {
+    /// The name of the replica. Eg: `core_node3`
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// The name of the core of the replica. Eg: `collection_shard1_replica_n1`
+    pub fn get_core(&self) -> &str {
+        &self.core
+    }
+
+    /// The URL of the node the replica is on. Eg: `http://localhost:8983/solr`
+    pub fn get_base_url(&self) -> Option<&str> {
+        self.base_url.as_deref()
+    }
+
+    /// The node the replica is on. Eg: `localhost:8983_solr`
+    pub fn get_node_name(&self) -> &str {
+        &self.node_name
+    }
+
+    /// The state of the replica.
+    pub fn get_state(&self) -> ReplicaState {
+        self.state
+    }
+
+    /// The type of the replica.
+    pub fn get_type(&self) -> ReplicaType {
+        self.replica_type
+    }
+
+    /// Whether the replica is the leader of its shard.
+    pub fn is_leader(&self) -> bool {
+        self.leader.unwrap_or(false)
+    }
+}
+
+/// Solr returns some numbers and booleans as strings, depending on the version.
+fn deserialize_stringified<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + FromStr,
+    T::Err: Display,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(value)) => value.parse().map(Some).map_err(serde::de::Error::custom),
+        Some(value) => serde_json::from_value(value)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+    }
+}
+
+/// Aliases are returned as comma separated collections.
+fn deserialize_aliases<'de, D>(deserializer: D) -> Result<HashMap<String, Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let aliases: HashMap<String, String> = Deserialize::deserialize(deserializer)?;
+    Ok(aliases
+        .into_iter()
+        .map(|(alias, collections)| {
+            let collections = collections
+                .split(',')
+                .filter(|x| !x.is_empty())
+                .map(|x| x.to_string())
+                .collect();
+            (alias, collections)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::cluster_status::{
+        ClusterStatus, Health, ReplicaState, ReplicaType, ShardState,
+    };
+    use crate::queries::collection::CollectionRouter;
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+
+    #[test]
+    fn cluster_status_is_parsed() {
+        let response: HashMap<String, Value> = serde_json::from_value(json!({
+            "responseHeader": {"status": 0, "QTime": 5},
+            "cluster": {
+                "collections": {
+                    "books": {
+                        "pullReplicas": "0",
+                        "configName": "books_config",
+                        "replicationFactor": 2,
+                        "router": {"name": "compositeId"},
+                        "nrtReplicas": "2",
+                        "tlogReplicas": 0,
+                        "shards": {
+                            "shard1": {
+                                "range": "80000000-ffffffff",
+                                "state": "active",
+                                "replicas": {
+                                    "core_node3": {
+                                        "core": "books_shard1_replica_n1",
+                                        "node_name": "localhost:8983_solr",
+                                        "base_url": "http://localhost:8983/solr",
+                                        "state": "active",
+                                        "type": "NRT",
+                                        "force_set_state": "false",
+                                        "leader": "true"
+                                    },
+                                    "core_node5": {
+                                        "core": "books_shard1_replica_p2",
+                                        "node_name": "localhost:7574_solr",
+                                        "state": "recovering",
+                                        "type": "PULL"
+                                    }
+                                },
+                                "health": "YELLOW"
+                            },
+                            "shard2": {"range": "0-7fffffff", "state": "inactive", "replicas": {}}
+                        },
+                        "znodeVersion": 11,
+                        "aliases": ["library"],
+                        "health": "YELLOW"
+                    }
+                },
+                "aliases": {"library": "books", "everything": "books,films"},
+                "live_nodes": ["localhost:8983_solr", "localhost:7574_solr"]
+            }
+        }))
+        .unwrap();
+        let status = ClusterStatus::from_response(response).unwrap();
+        assert_eq!(status.get_live_nodes().len(), 2);
+        assert_eq!(
+            status.get_aliases().get("everything"),
+            Some(&vec!["books".to_string(), "films".to_string()])
+        );
+
+        let books = status.get_collection("books").unwrap();
+        assert_eq!(books.get_name(), "books");
+        assert_eq!(books.get_config_name(), Some("books_config"));
+        assert_eq!(books.get_router(), Some(CollectionRouter::CompositeId));
+        assert_eq!(books.get_replication_factor(), Some(2));
+        assert_eq!(books.get_nrt_replicas(), Some(2));
+        assert_eq!(books.get_pull_replicas(), Some(0));
+        assert_eq!(books.get_aliases(), ["library"]);
+        assert_eq!(books.get_health(), Some(Health::Yellow));
+
+        let shard = books.get_shard("shard1").unwrap();
+        assert_eq!(shard.get_name(), "shard1");
+        assert_eq!(shard.get_state(), ShardState::Active);
+        assert_eq!(shard.get_range(), Some("80000000-ffffffff"));
+        let leader = shard.get_leader().unwrap();
+        assert_eq!(leader.get_name(), "core_node3");
+        assert_eq!(leader.get_node_name(), "localhost:8983_solr");
+        assert_eq!(leader.get_type(), ReplicaType::Nrt);
+        let pull = shard.get_replicas().get("core_node5").unwrap();
+        assert!(!pull.is_leader());
+        assert_eq!(pull.get_state(), ReplicaState::Recovering);
+        assert_eq!(pull.get_type(), ReplicaType::Pull);
+        assert_eq!(
+            books.get_shard("shard2").unwrap().get_state(),
+            ShardState::Inactive
+        );
+    }
+}
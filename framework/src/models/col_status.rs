@@ -0,0 +1,545 @@
+use crate::error::Error;
+use crate::models::cluster_status::{ReplicaState, ReplicaType, ShardState};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The index status of collections, returned by [get_col_status](crate::queries::collection::get_col_status).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ColStatus {
+    collections: HashMap<String, CollectionIndexStatus>,
+}
+
+impl ColStatus {
+    /// The collections, by name.
+    pub fn get_collections(&self) -> &HashMap<String, CollectionIndexStatus> {
+        &self.collections
+    }
+
+    /// A collection by name.
+    pub fn get_collection<S: AsRef<str>>(&self, name: S) -> Option<&CollectionIndexStatus> {
+        self.collections.get(name.as_ref())
+    }
+
+    pub(crate) fn from_response(response: HashMap<String, Value>) -> Result<Self, Error> {
+        let mut collections = HashMap::new();
+        for (collection_name, collection) in response {
+            if collection_name == "responseHeader" || !collection.is_object() {
+                continue;
+            }
+            let mut collection: CollectionIndexStatus = serde_json::from_value(collection)?;
+            collection.name.clone_from(&collection_name);
+            for (shard_name, shard) in collection.shards.iter_mut() {
+                shard.name.clone_from(shard_name);
+            }
+            collections.insert(collection_name, collection);
+        }
+        Ok(ColStatus { collections })
+    }
+}
+
+/// A collection in the [ColStatus].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionIndexStatus {
+    #[serde(skip)]
+    name: String,
+    znode_version: Option<u64>,
+    #[serde(default)]
+    properties: HashMap<String, Value>,
+    #[serde(default)]
+    active_shards: usize,
+    #[serde(default)]
+    inactive_shards: usize,
+    #[serde(default, deserialize_with = "deserialize_non_compliant")]
+    schema_non_compliant: Vec<String>,
+    #[serde(default)]
+    shards: HashMap<String, ShardIndexStatus>,
+}
+
+impl CollectionIndexStatus {
+    /// The name of the collection.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// The version of the state of the collection in ZooKeeper.
+    pub fn get_znode_version(&self) -> Option<u64> {
+        self.znode_version
+    }
+
+    /// The properties of the collection. Eg: `configName`, `replicationFactor`
+    pub fn get_properties(&self) -> &HashMap<String, Value> {
+        &self.properties
+    }
+
+    /// The number of active shards.
+    pub fn get_active_shards(&self) -> usize {
+        self.active_shards
+    }
+
+    /// The number of inactive shards, such as shards that have been split.
+    pub fn get_inactive_shards(&self) -> usize {
+        self.inactive_shards
+    }
+
+    /// Fields whose indexed data does not match the current schema. Only reported with [ColStatusOptions::field_info](crate::queries::collection::ColStatusOptions::field_info).
+    pub fn get_schema_non_compliant(&self) -> &[String] {
+        &self.schema_non_compliant
+    }
+
+    /// The shards, by name.
+    pub fn get_shards(&self) -> &HashMap<String, ShardIndexStatus> {
+        &self.shards
+    }
+
+    /// A shard by name.
+    pub fn get_shard<S: AsRef<str>>(&self, name: S) -> Option<&ShardIndexStatus> {
+        self.shards.get(name.as_ref())
+    }
+}
+
+/// A shard of a [CollectionIndexStatus].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ShardIndexStatus {
+    #[serde(skip)]
+    name: String,
+    state: ShardState,
+    range: Option<String>,
+    replicas: Option<ReplicaCounts>,
+    leader: Option<LeaderIndexStatus>,
+}
+
+impl ShardIndexStatus {
+    /// The name of the shard.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// The state of the shard.
+    pub fn get_state(&self) -> ShardState {
+        self.state
+    }
+
+    /// The hash range of the shard, for the `compositeId` router. Eg: `80000000-ffffffff`
+    pub fn get_range(&self) -> Option<&str> {
+        self.range.as_deref()
+    }
+
+    /// The number of replicas of the shard, by state.
+    pub fn get_replicas(&self) -> Option<&ReplicaCounts> {
+        self.replicas.as_ref()
+    }
+
+    /// The leader of the shard, with the status of its index.
+    pub fn get_leader(&self) -> Option<&LeaderIndexStatus> {
+        self.leader.as_ref()
+    }
+}
+
+/// The number of replicas of a [ShardIndexStatus], by state.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ReplicaCounts {
+    #[serde(default)]
+    total: usize,
+    #[serde(default)]
+    active: usize,
+    #[serde(default)]
+    down: usize,
+    #[serde(default)]
+    recovering: usize,
+    #[serde(default)]
+    recovery_failed: usize,
+}
+
+impl ReplicaCounts {
+    /// The number of replicas.
+    pub fn get_total(&self) -> usize {
+        self.total
+    }
+
+    /// The number of active replicas.
+    pub fn get_active(&self) -> usize {
+        self.active
+    }
+
+    /// The number of down replicas.
+    pub fn get_down(&self) -> usize {
+        self.down
+    }
+
+    /// The number of recovering replicas.
+    pub fn get_recovering(&self) -> usize {
+        self.recovering
+    }
+
+    /// The number of replicas that failed to recover.
+    pub fn get_recovery_failed(&self) -> usize {
+        self.recovery_failed
+    }
+}
+
+/// The leader replica of a [ShardIndexStatus], with the status of its index.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct LeaderIndexStatus {
+    #[serde(rename = "coreNode")]
+    core_node: Option<String>,
+    core: String,
+    base_url: Option<String>,
+    node_name: String,
+    state: ReplicaState,
+    #[serde(rename = "type")]
+    replica_type: ReplicaType,
+    #[serde(rename = "segInfos")]
+    segments_info: Option<SegmentsInfo>,
+    error: Option<String>,
+}
+
+impl LeaderIndexStatus {
+    /// The name of the replica. Eg: `core_node3`
+    pub fn get_name(&self) -> Option<&str> {
+        self.core_node.as_deref()
+    }
+
+    /// The name of the core of the replica. Eg: `collection_shard1_replica_n1`
+    pub fn get_core(&self) -> &str {
+        &self.core
+    }
+
+    /// The URL of the node the replica is on. Eg: `http://localhost:8983/solr`
+    pub fn get_base_url(&self) -> Option<&str> {
+        self.base_url.as_deref()
+    }
+
+    /// The node the replica is on. Eg: `localhost:8983_solr`
+    pub fn get_node_name(&self) -> &str {
+        &self.node_name
+    }
+
+    /// The state of the replica.
+    pub fn get_state(&self) -> ReplicaState {
+        self.state
+    }
+
+    /// The type of the replica.
+    pub fn get_type(&self) -> ReplicaType {
+        self.replica_type
+    }
+
+    /// The segments of the index. Missing if the index could not be read, see [LeaderIndexStatus::get_error].
+    pub fn get_segments_info(&self) -> Option<&SegmentsInfo> {
+        self.segments_info.as_ref()
+    }
+
+    /// Why the index of the leader could not be read.
+    pub fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct CommitInfo {
+    min_segment_lucene_version: Option<String>,
+    commit_lucene_version: Option<String>,
+    #[serde(default)]
+    num_segments: usize,
+    segments_file_name: Option<String>,
+    #[serde(default)]
+    total_max_doc: u64,
+    #[serde(default)]
+    user_data: HashMap<String, String>,
+    core: Option<CoreInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+struct CoreInfo {
+    data_dir: Option<String>,
+    index_dir: Option<String>,
+    #[serde(rename = "sizeInGB")]
+    size_in_gb: Option<f64>,
+}
+
+/// The segments of the index of a [LeaderIndexStatus].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentsInfo {
+    #[serde(default)]
+    info: CommitInfo,
+    #[serde(default)]
+    segments: HashMap<String, SegmentInfo>,
+    raw_size: Option<Value>,
+}
+
+impl SegmentsInfo {
+    /// The number of segments in the last commit.
+    pub fn get_num_segments(&self) -> usize {
+        self.info.num_segments
+    }
+
+    /// The number of documents in the index, including deleted documents.
+    pub fn get_total_max_doc(&self) -> u64 {
+        self.info.total_max_doc
+    }
+
+    /// The name of the segments file of the last commit. Eg: `segments_w`
+    pub fn get_segments_file_name(&self) -> Option<&str> {
+        self.info.segments_file_name.as_deref()
+    }
+
+    /// The Lucene version of the last commit. Eg: `9.8.0`
+    pub fn get_commit_lucene_version(&self) -> Option<&str> {
+        self.info.commit_lucene_version.as_deref()
+    }
+
+    /// The oldest Lucene version of any segment. Eg: `8.11.2`
+    pub fn get_min_segment_lucene_version(&self) -> Option<&str> {
+        self.info.min_segment_lucene_version.as_deref()
+    }
+
+    /// The user data of the last commit. Eg: `commitTimeMSec`
+    pub fn get_user_data(&self) -> &HashMap<String, String> {
+        &self.info.user_data
+    }
+
+    /// The size of the index in gigabytes. Only reported with [ColStatusOptions::core_info](crate::queries::collection::ColStatusOptions::core_info).
+    pub fn get_size_in_gb(&self) -> Option<f64> {
+        self.info.core.as_ref().and_then(|core| core.size_in_gb)
+    }
+
+    /// The index directory. Only reported with [ColStatusOptions::core_info](crate::queries::collection::ColStatusOptions::core_info).
+    pub fn get_index_dir(&self) -> Option<&str> {
+        self.info
+            .core
+            .as_ref()
+            .and_then(|core| core.index_dir.as_deref())
+    }
+
+    /// The data directory. Only reported with [ColStatusOptions::core_info](crate::queries::collection::ColStatusOptions::core_info).
+    pub fn get_data_dir(&self) -> Option<&str> {
+        self.info
+            .core
+            .as_ref()
+            .and_then(|core| core.data_dir.as_deref())
+    }
+
+    /// The segments, by name. Only reported with [ColStatusOptions::segments](crate::queries::collection::ColStatusOptions::segments).
+    pub fn get_segments(&self) -> &HashMap<String, SegmentInfo> {
+        &self.segments
+    }
+
+    /// The estimated size of the index by field and type. Only reported with [ColStatusOptions::raw_size](crate::queries::collection::ColStatusOptions::raw_size).
+    pub fn get_raw_size(&self) -> Option<&Value> {
+        self.raw_size.as_ref()
+    }
+}
+
+/// A segment of the index in [SegmentsInfo].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentInfo {
+    name: String,
+    #[serde(rename = "size")]
+    max_doc: u64,
+    #[serde(default)]
+    del_count: u64,
+    #[serde(default)]
+    soft_del_count: u64,
+    #[serde(default)]
+    has_field_updates: bool,
+    #[serde(default)]
+    size_in_bytes: u64,
+    age: Option<String>,
+    source: Option<String>,
+    version: Option<String>,
+    merge_candidate: Option<Value>,
+    #[serde(default)]
+    fields: HashMap<String, Value>,
+}
+
+impl SegmentInfo {
+    /// The name of the segment. Eg: `_i`
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// The number of documents in the segment, including deleted documents.
+    pub fn get_max_doc(&self) -> u64 {
+        self.max_doc
+    }
+
+    /// The number of deleted documents in the segment.
+    pub fn get_del_count(&self) -> u64 {
+        self.del_count
+    }
+
+    /// The number of soft deleted documents in the segment.
+    pub fn get_soft_del_count(&self) -> u64 {
+        self.soft_del_count
+    }
+
+    /// Whether the segment has doc values updates.
+    pub fn has_field_updates(&self) -> bool {
+        self.has_field_updates
+    }
+
+    /// The size of the segment on disk.
+    pub fn get_size_in_bytes(&self) -> u64 {
+        self.size_in_bytes
+    }
+
+    /// When the segment was written. Eg: `2024-01-01T12:00:00.000Z`
+    pub fn get_age(&self) -> Option<&str> {
+        self.age.as_deref()
+    }
+
+    /// What wrote the segment. Eg: `flush` or `merge`
+    pub fn get_source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// The Lucene version that wrote the segment. Eg: `9.8.0`
+    pub fn get_version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Whether the segment would be merged by the next merge, with the reason when it is.
+    pub fn get_merge_candidate(&self) -> Option<&Value> {
+        self.merge_candidate.as_ref()
+    }
+
+    /// The fields in the segment, with their index options. Only reported with [ColStatusOptions::field_info](crate::queries::collection::ColStatusOptions::field_info).
+    pub fn get_fields(&self) -> &HashMap<String, Value> {
+        &self.fields
+    }
+}
+
+/// Solr reports `(NONE)` when every field complies with the schema.
+fn deserialize_non_compliant<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let fields: Vec<String> = Deserialize::deserialize(deserializer)?;
+    Ok(fields
+        .into_iter()
+        .filter(|field| field != "(NONE)")
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::cluster_status::{ReplicaState, ReplicaType, ShardState};
+    use crate::models::col_status::ColStatus;
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+
+    #[test]
+    fn col_status_is_parsed() {
+        let response: HashMap<String, Value> = serde_json::from_value(json!({
+            "responseHeader": {"status": 0, "QTime": 50},
+            "books": {
+                "znodeVersion": 14,
+                "properties": {"configName": "books_config", "replicationFactor": 1},
+                "activeShards": 1,
+                "inactiveShards": 1,
+                "schemaNonCompliant": ["title"],
+                "shards": {
+                    "shard1": {
+                        "state": "active",
+                        "range": "80000000-7fffffff",
+                        "replicas": {"total": 2, "active": 1, "down": 0, "recovering": 1, "recovery_failed": 0},
+                        "leader": {
+                            "coreNode": "core_node2",
+                            "core": "books_shard1_replica_n1",
+                            "base_url": "http://localhost:8983/solr",
+                            "node_name": "localhost:8983_solr",
+                            "state": "active",
+                            "type": "NRT",
+                            "force_set_state": "false",
+                            "leader": "true",
+                            "segInfos": {
+                                "info": {
+                                    "minSegmentLuceneVersion": "9.8.0",
+                                    "commitLuceneVersion": "9.8.0",
+                                    "numSegments": 1,
+                                    "segmentsFileName": "segments_3",
+                                    "totalMaxDoc": 120,
+                                    "userData": {"commitTimeMSec": "1700000000000"},
+                                    "core": {
+                                        "startTime": "2024-01-01T12:00:00.000Z",
+                                        "dataDir": "/var/solr/data/books_shard1_replica_n1/data/",
+                                        "indexDir": "/var/solr/data/books_shard1_replica_n1/data/index/",
+                                        "sizeInGB": 0.5
+                                    }
+                                },
+                                "segments": {
+                                    "_0": {
+                                        "name": "_0",
+                                        "delCount": 20,
+                                        "softDelCount": 0,
+                                        "hasFieldUpdates": false,
+                                        "sizeInBytes": 4096,
+                                        "size": 120,
+                                        "age": "2024-01-01T12:00:00.000Z",
+                                        "source": "flush",
+                                        "version": "9.8.0"
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    "shard2": {"state": "inactive", "range": "0-7fffffff"}
+                }
+            },
+            "films": {
+                "activeShards": 1,
+                "inactiveShards": 0,
+                "schemaNonCompliant": ["(NONE)"],
+                "shards": {}
+            }
+        }))
+        .unwrap();
+        let status = ColStatus::from_response(response).unwrap();
+        assert_eq!(status.get_collections().len(), 2);
+        assert!(status
+            .get_collection("films")
+            .unwrap()
+            .get_schema_non_compliant()
+            .is_empty());
+
+        let books = status.get_collection("books").unwrap();
+        assert_eq!(books.get_name(), "books");
+        assert_eq!(books.get_znode_version(), Some(14));
+        assert_eq!(
+            books.get_properties().get("configName"),
+            Some(&json!("books_config"))
+        );
+        assert_eq!(books.get_active_shards(), 1);
+        assert_eq!(books.get_inactive_shards(), 1);
+        assert_eq!(books.get_schema_non_compliant(), ["title"]);
+        assert_eq!(
+            books.get_shard("shard2").unwrap().get_state(),
+            ShardState::Inactive
+        );
+
+        let shard = books.get_shard("shard1").unwrap();
+        assert_eq!(shard.get_name(), "shard1");
+        assert_eq!(shard.get_replicas().unwrap().get_total(), 2);
+        assert_eq!(shard.get_replicas().unwrap().get_recovering(), 1);
+        let leader = shard.get_leader().unwrap();
+        assert_eq!(leader.get_name(), Some("core_node2"));
+        assert_eq!(leader.get_state(), ReplicaState::Active);
+        assert_eq!(leader.get_type(), ReplicaType::Nrt);
+        let segments_info = leader.get_segments_info().unwrap();
+        assert_eq!(segments_info.get_num_segments(), 1);
+        assert_eq!(segments_info.get_total_max_doc(), 120);
+        assert_eq!(segments_info.get_size_in_gb(), Some(0.5));
+        assert_eq!(segments_info.get_commit_lucene_version(), Some("9.8.0"));
+        let segment = segments_info.get_segments().get("_0").unwrap();
+        assert_eq!(segment.get_max_doc(), 120);
+        assert_eq!(segment.get_del_count(), 20);
+        assert_eq!(segment.get_size_in_bytes(), 4096);
+        assert_eq!(segment.get_source(), Some("flush"));
+    }
+}
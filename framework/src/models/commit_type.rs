@@ -1,22 +1,65 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Copy, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
 /// This struct encapsulates the commit types for Solr's update and delete queries.
 /// By default, a `Hard` commit is performed, equating to `commit=true`.
 /// Conversely, a `Soft` commit corresponds to `softCommit=true`.
+///
+/// Committing on every request is expensive. For high throughput, use `CommitWithin` or `NoCommit`,
+/// and rely on Solr's autocommit or an explicit [CommitQuery](crate::CommitQuery).
 /// # Examples
 /// ```
 /// use solrstice::{CommitType, DeleteQuery, UpdateQuery};
 ///
 /// let update_query = UpdateQuery::new().commit_type(CommitType::Soft);
-/// let delete_query = DeleteQuery::new().commit_type(CommitType::Soft);
+/// let delete_query = DeleteQuery::new().commit_type(CommitType::CommitWithin(10_000));
+/// ```
 pub enum CommitType {
-    Hard,
+    /// Do not commit. The changes become visible on the next commit, e.g. from autocommit.
+    NoCommit,
+    /// Commit within the given number of milliseconds, equating to `commitWithin`.
+    CommitWithin(u64),
+    /// Open a new searcher without flushing to stable storage, equating to `softCommit=true`.
     Soft,
+    /// Flush to stable storage and open a new searcher, equating to `commit=true`.
+    #[default]
+    Hard,
+}
+
+impl CommitType {
+    pub(crate) fn to_query_params(self) -> Vec<(&'static str, String)> {
+        match self {
+            CommitType::NoCommit => vec![],
+            CommitType::CommitWithin(ms) => vec![("commitWithin", ms.to_string())],
+            CommitType::Soft => vec![("softCommit", "true".to_string())],
+            CommitType::Hard => vec![("commit", "true".to_string())],
+        }
+    }
+
+    /// Whether this commit type makes Solr commit as part of the request.
+    pub(crate) fn commits(&self) -> bool {
+        matches!(self, CommitType::Soft | CommitType::Hard)
+    }
 }
 
-impl Default for CommitType {
-    fn default() -> Self {
-        Self::Hard
+#[cfg(test)]
+mod tests {
+    use crate::models::commit_type::CommitType;
+
+    #[test]
+    fn commit_type_query_params() {
+        assert!(CommitType::NoCommit.to_query_params().is_empty());
+        assert_eq!(
+            CommitType::CommitWithin(5000).to_query_params(),
+            vec![("commitWithin", "5000".to_string())]
+        );
+        assert_eq!(
+            CommitType::Soft.to_query_params(),
+            vec![("softCommit", "true".to_string())]
+        );
+        assert_eq!(
+            CommitType::Hard.to_query_params(),
+            vec![("commit", "true".to_string())]
+        );
     }
 }
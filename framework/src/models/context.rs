@@ -1,6 +1,6 @@
 use crate::hosts::solr_host::SolrHost;
 use crate::models::auth::SolrAuth;
-use crate::queries::request_builder::LoggingPolicy;
+use crate::queries::request_builder::{LoggingPolicy, WireFormat};
 use std::sync::Arc;
 
 /// A SolrServerContext specifies how to connect to a solr server, and how to authenticate.
@@ -18,6 +18,9 @@ pub struct SolrServerContextBuilder {
     pub(crate) auth: Option<Arc<dyn SolrAuth + Send + Sync>>,
     pub(crate) client: Option<reqwest::Client>,
     pub(crate) logging_policy: LoggingPolicy,
+    pub(crate) wire_format: WireFormat,
+    pub(crate) request_compression: Option<usize>,
+    pub(crate) response_compression: bool,
 }
 
 impl SolrServerContextBuilder {
@@ -34,6 +37,9 @@ impl SolrServerContextBuilder {
             auth: None,
             client: None,
             logging_policy: LoggingPolicy::Fast(512),
+            wire_format: WireFormat::Json,
+            request_compression: None,
+            response_compression: false,
         }
     }
 
@@ -79,6 +85,55 @@ impl SolrServerContextBuilder {
         self
     }
 
+    /// Set the format of requests and responses, for the queries that support it. Default is `WireFormat::Json`.
+    ///
+    /// Queries can override it, eg: [SelectQuery::wire_format](crate::SelectQuery::wire_format).
+    /// Requests to endpoints without javabin support are always sent as JSON.
+    /// # Examples
+    /// ```
+    /// use solrstice::{SolrServerContextBuilder, SolrSingleServerHost, WireFormat};
+    ///
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983"))
+    ///   .with_wire_format(WireFormat::Javabin)
+    ///   .build();
+    /// ```
+    #[cfg(feature = "javabin")]
+    pub fn with_wire_format(mut self, wire_format: WireFormat) -> Self {
+        self.wire_format = wire_format;
+        self
+    }
+
+    /// Gzip request bodies of at least `min_size` bytes, and send them with `Content-Encoding: gzip`. Default is off.
+    ///
+    /// Jetty only inflates request bodies when Solr is started with `-Djetty.gzip.inflateBufferSize` set.
+    /// Streamed bodies, such as files, are sent as is. Logging shows the uncompressed body.
+    /// # Examples
+    /// ```
+    /// use solrstice::{SolrServerContextBuilder, SolrSingleServerHost};
+    ///
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983"))
+    ///   .with_request_compression(64 * 1024)
+    ///   .build();
+    /// ```
+    pub fn with_request_compression<O: Into<Option<usize>>>(mut self, min_size: O) -> Self {
+        self.request_compression = min_size.into();
+        self
+    }
+
+    /// Ask Solr for gzip or deflate compressed responses with `Accept-Encoding`, and decompress them. Default is false.
+    /// # Examples
+    /// ```
+    /// use solrstice::{SolrServerContextBuilder, SolrSingleServerHost};
+    ///
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983"))
+    ///   .with_response_compression(true)
+    ///   .build();
+    /// ```
+    pub fn with_response_compression(mut self, response_compression: bool) -> Self {
+        self.response_compression = response_compression;
+        self
+    }
+
     /// Build a SolrServerContext
     /// # Examples
     /// ```no_run
@@ -109,6 +164,9 @@ pub struct SolrServerContext {
     pub(crate) auth: Option<Arc<dyn SolrAuth + Send + Sync>>,
     pub(crate) client: reqwest::Client,
     pub(crate) logging_policy: LoggingPolicy,
+    pub(crate) wire_format: WireFormat,
+    pub(crate) request_compression: Option<usize>,
+    pub(crate) response_compression: bool,
 }
 
 impl From<SolrServerContextBuilder> for SolrServerContext {
@@ -118,6 +176,9 @@ impl From<SolrServerContextBuilder> for SolrServerContext {
             auth: builder.auth,
             client: builder.client.unwrap_or_default(),
             logging_policy: builder.logging_policy,
+            wire_format: builder.wire_format,
+            request_compression: builder.request_compression,
+            response_compression: builder.response_compression,
         }
     }
 }
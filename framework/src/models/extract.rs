@@ -0,0 +1,131 @@
+use crate::error::Error;
+use crate::models::response::SolrResponseHeader;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The text and metadata Solr Cell extracted from a file, without indexing it.
+///
+/// Returned by [ExtractQuery::extract_only_file](crate::ExtractQuery::extract_only_file).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct SolrExtractResponse {
+    response_header: Option<SolrResponseHeader>,
+    name: String,
+    content: String,
+    metadata: HashMap<String, Vec<String>>,
+}
+
+impl SolrExtractResponse {
+    /// The response header returned by Solr.
+    pub fn get_response_header(&self) -> Option<&SolrResponseHeader> {
+        self.response_header.as_ref()
+    }
+
+    /// The name Solr gave the file, usually the file name.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// The extracted content. XHTML, unless the extract format is [ExtractFormat::Text](crate::ExtractFormat::Text).
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+
+    /// The extracted metadata. Eg: `Content-Type`, `dc:title` or `stream_size`
+    pub fn get_metadata(&self) -> &HashMap<String, Vec<String>> {
+        &self.metadata
+    }
+
+    /// Solr returns the content under the name of the file, and the metadata under `<name>_metadata`.
+    pub(crate) fn from_response(mut response: HashMap<String, Value>) -> Result<Self, Error> {
+        let name = response
+            .keys()
+            .filter_map(|key| key.strip_suffix("_metadata"))
+            .find(|name| response.contains_key(*name))
+            .map(|name| name.to_string())
+            .ok_or(Error::Unknown(
+                "Solr did not return any extracted content".to_string(),
+            ))?;
+        let content = match response.remove(&name) {
+            Some(Value::String(content)) => content,
+            _ => String::new(),
+        };
+        let metadata = response
+            .remove(&format!("{}_metadata", name))
+            .map(metadata_from_value)
+            .unwrap_or_default();
+        let response_header = response
+            .remove("responseHeader")
+            .map(serde_json::from_value)
+            .transpose()?;
+        Ok(SolrExtractResponse {
+            response_header,
+            name,
+            content,
+            metadata,
+        })
+    }
+}
+
+/// Metadata is a map with `json.nl=map`, and a flat list of names and values otherwise.
+fn metadata_from_value(metadata: Value) -> HashMap<String, Vec<String>> {
+    let pairs: Vec<(String, Value)> = match metadata {
+        Value::Object(map) => map.into_iter().collect(),
+        Value::Array(list) => list
+            .chunks(2)
+            .filter_map(|pair| match pair {
+                [Value::String(key), value] => Some((key.clone(), value.clone())),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+    pairs
+        .into_iter()
+        .map(|(key, value)| {
+            let values = match value {
+                Value::Array(values) => values.into_iter().map(value_to_string).collect(),
+                value => vec![value_to_string(value)],
+            };
+            (key, values)
+        })
+        .collect()
+}
+
+fn value_to_string(value: Value) -> String {
+    match value {
+        Value::String(value) => value,
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::extract::SolrExtractResponse;
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+
+    #[test]
+    fn extract_response_reads_content_and_metadata() {
+        let response: HashMap<String, Value> = serde_json::from_value(json!({
+            "responseHeader": {"status": 0, "QTime": 12},
+            "report.pdf": "Quarterly report",
+            "report.pdf_metadata": ["stream_size", ["1234"], "Content-Type", ["application/pdf"]]
+        }))
+        .unwrap();
+        let response = SolrExtractResponse::from_response(response).unwrap();
+        assert_eq!(response.get_name(), "report.pdf");
+        assert_eq!(response.get_content(), "Quarterly report");
+        assert_eq!(
+            response.get_metadata(),
+            &HashMap::from([
+                ("stream_size".to_string(), vec!["1234".to_string()]),
+                (
+                    "Content-Type".to_string(),
+                    vec!["application/pdf".to_string()]
+                ),
+            ])
+        );
+        assert_eq!(response.get_response_header().unwrap().q_time, 12);
+    }
+}
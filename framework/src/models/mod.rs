@@ -1,11 +1,28 @@
 //! Models used by the Solr Client.
 
+/// Status of async Collections API requests.
+pub(crate) mod admin_task;
+pub use admin_task::*;
+/// Results of collection backups.
+pub(crate) mod backup;
+pub use backup::*;
+/// Typed atomic updates for partial document updates.
+pub(crate) mod atomic_update;
 /// All authentication types supported by the library.
 pub(crate) mod auth;
+/// The state of a SolrCloud cluster.
+pub(crate) mod cluster_status;
+pub use cluster_status::*;
+/// The index status of collections.
+pub(crate) mod col_status;
+pub use col_status::*;
 /// Commit types for Solr's update and delete queries.
 pub(crate) mod commit_type;
 /// Context for the solr Client. Specifying how to connect.
 pub(crate) mod context;
+/// Text and metadata extracted by Solr Cell.
+pub(crate) mod extract;
+pub use extract::*;
 /// Facet
 pub(crate) mod facet_set;
 pub use facet_set::*;
@@ -16,9 +33,14 @@ pub use group::*;
 /// Facets returned by JSON faceting.
 pub(crate) mod json_facet;
 pub use json_facet::*;
+/// Status of server side reindexes.
+pub(crate) mod reindex;
+pub use reindex::*;
 /// Models used to get responses from Solr
 pub(crate) mod response;
 pub(crate) mod stats;
 pub use stats::*;
 
 pub use response::*;
+/// Optimistic concurrency with `_version_`.
+pub(crate) mod version;
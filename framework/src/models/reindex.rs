@@ -0,0 +1,108 @@
+use crate::error::Error;
+use crate::models::response::SolrResponseHeader;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The status of a server side reindex, returned by [ReindexCollectionQuery](crate::ReindexCollectionQuery).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SolrReindexStatus {
+    #[serde(skip)]
+    response_header: Option<SolrResponseHeader>,
+    state: Option<String>,
+    phase: Option<String>,
+    input_docs: Option<u64>,
+    processed_docs: Option<u64>,
+    actual_source_collection: Option<String>,
+    actual_target_collection: Option<String>,
+    checkpoint_collection: Option<String>,
+}
+
+impl SolrReindexStatus {
+    /// The response header returned by Solr.
+    pub fn get_response_header(&self) -> Option<&SolrResponseHeader> {
+        self.response_header.as_ref()
+    }
+
+    /// The state of the reindex. Eg: `running`, `finished` or `aborted`
+    pub fn get_state(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+
+    /// The phase of a running reindex. Eg: `copying` or `done`
+    pub fn get_phase(&self) -> Option<&str> {
+        self.phase.as_deref()
+    }
+
+    /// The number of documents in the source collection matching the query.
+    pub fn get_input_docs(&self) -> Option<u64> {
+        self.input_docs
+    }
+
+    /// The number of documents reindexed so far.
+    pub fn get_processed_docs(&self) -> Option<u64> {
+        self.processed_docs
+    }
+
+    /// The collection that was read, with any alias resolved.
+    pub fn get_actual_source_collection(&self) -> Option<&str> {
+        self.actual_source_collection.as_deref()
+    }
+
+    /// The collection that was written to, with any alias resolved.
+    pub fn get_actual_target_collection(&self) -> Option<&str> {
+        self.actual_target_collection.as_deref()
+    }
+
+    /// The collection Solr keeps the progress of the reindex in.
+    pub fn get_checkpoint_collection(&self) -> Option<&str> {
+        self.checkpoint_collection.as_deref()
+    }
+
+    /// Solr returns the status under `reindexStatus` when starting or aborting, and under `status` when asked for it.
+    pub(crate) fn from_response(mut response: HashMap<String, Value>) -> Result<Self, Error> {
+        let status = response
+            .remove("reindexStatus")
+            .or_else(|| response.remove("status"))
+            .unwrap_or_default();
+        let mut status: SolrReindexStatus = match status {
+            Value::Object(_) => serde_json::from_value(status)?,
+            _ => SolrReindexStatus::default(),
+        };
+        status.response_header = response
+            .remove("responseHeader")
+            .map(serde_json::from_value)
+            .transpose()?;
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::reindex::SolrReindexStatus;
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+
+    #[test]
+    fn reindex_status_reads_status() {
+        let response: HashMap<String, Value> = serde_json::from_value(json!({
+            "responseHeader": {"status": 0, "QTime": 1200},
+            "reindexStatus": {
+                "phase": "done",
+                "inputDocs": 12,
+                "processedDocs": 12,
+                "actualSourceCollection": "old",
+                "state": "finished",
+                "actualTargetCollection": "new",
+                "checkpointCollection": ".reindex_ck_old"
+            }
+        }))
+        .unwrap();
+        let status = SolrReindexStatus::from_response(response).unwrap();
+        assert_eq!(status.get_state(), Some("finished"));
+        assert_eq!(status.get_processed_docs(), Some(12));
+        assert_eq!(status.get_actual_target_collection(), Some("new"));
+        assert_eq!(status.get_response_header().unwrap().q_time, 1200);
+    }
+}
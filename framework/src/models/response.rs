@@ -6,9 +6,10 @@ use crate::models::stats::SolrStatsResult;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::value::RawValue;
+use serde_json::Value;
 use std::collections::HashMap;
 
-/// Response header given by solr, if not `responseHeader=false` is passed.
+/// Response header given by solr, if not `omitHeader=true` is passed.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct SolrResponseHeader {
     #[serde(rename = "zkConnected")]
@@ -19,6 +20,116 @@ pub struct SolrResponseHeader {
     #[serde(rename = "QTime")]
     /// The time in milliseconds that the request took to process.
     pub q_time: usize,
+    #[serde(
+        rename = "partialResults",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    /// Set by Solr if some shards did not respond, and `shards.tolerant=true` was passed,
+    /// or if a limit such as `timeAllowed`, `cpuAllowed` or `memAllowed` was exceeded.
+    pub partial_results: Option<bool>,
+    #[serde(
+        rename = "segmentTerminatedEarly",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    /// Set by Solr if the search was terminated early because of `segmentTerminateEarly=true`.
+    pub segment_terminated_early: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The achieved replication factor of an update. The number of replicas, including the leader, that received the update.
+    pub rf: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The request parameters echoed by Solr, if `echoParams` is `explicit` or `all`.
+    pub params: Option<HashMap<String, Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The documents and deletes that failed, if the update went through `TolerantUpdateProcessorFactory`.
+    pub errors: Option<Vec<SolrUpdateError>>,
+    #[serde(rename = "maxErrors", default, skip_serializing_if = "Option::is_none")]
+    /// The number of failures allowed by `TolerantUpdateProcessorFactory`. `-1` allows any number.
+    pub max_errors: Option<i64>,
+}
+
+/// The kind of update command that failed in a tolerant update.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SolrUpdateErrorType {
+    #[serde(rename = "ADD")]
+    Add,
+    #[serde(rename = "DELID")]
+    DeleteById,
+    #[serde(rename = "DELQ")]
+    DeleteByQuery,
+}
+
+/// A single failed update, returned by [SolrResponse::get_update_errors].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct SolrUpdateError {
+    #[serde(rename = "type")]
+    error_type: SolrUpdateErrorType,
+    id: String,
+    message: String,
+}
+
+impl SolrUpdateError {
+    /// Whether an add, a delete by id or a delete by query failed.
+    pub fn get_error_type(&self) -> SolrUpdateErrorType {
+        self.error_type
+    }
+
+    /// The id of the document, or the query for deletes by query.
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    /// The error message from Solr.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Information about a single shard, returned if `shards.info=true` is passed.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct SolrShardInfo {
+    #[serde(rename = "numFound")]
+    num_found: Option<usize>,
+    #[serde(rename = "maxScore")]
+    max_score: Option<f64>,
+    #[serde(rename = "shardAddress")]
+    shard_address: Option<String>,
+    time: Option<usize>,
+    error: Option<String>,
+    trace: Option<String>,
+}
+
+impl SolrShardInfo {
+    /// The number of documents found on the shard.
+    pub fn get_num_found(&self) -> Option<usize> {
+        self.num_found
+    }
+
+    /// The highest score on the shard, if scores were requested.
+    pub fn get_max_score(&self) -> Option<f64> {
+        self.max_score
+    }
+
+    /// The address of the replica that answered.
+    pub fn get_shard_address(&self) -> Option<&str> {
+        self.shard_address.as_deref()
+    }
+
+    /// The time in milliseconds the shard took to answer.
+    pub fn get_time(&self) -> Option<usize> {
+        self.time
+    }
+
+    /// The error given by the shard, if it failed.
+    pub fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// The stack trace given by the shard, if it failed.
+    pub fn get_trace(&self) -> Option<&str> {
+        self.trace.as_deref()
+    }
 }
 
 /// If the request was not successful, this will be populated.
@@ -87,7 +198,7 @@ impl SolrDocsResponse {
 /// Represents any response Solr can give. This is the top level response.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct SolrResponse {
-    /// The response header given by Solr if not `responseHeader=false` is passed.
+    /// The response header given by Solr if not `omitHeader=true` is passed.
     #[serde(rename = "responseHeader")]
     pub(crate) response_header: Option<SolrResponseHeader>,
     /// The error given by Solr if the request was not successful.
@@ -117,9 +228,35 @@ pub struct SolrResponse {
     #[serde(rename = "facets")]
     pub(crate) json_facet: Option<SolrJsonFacetResponse>,
     pub(crate) stats: Option<SolrStatsResult>,
+    /// Per shard information returned by Solr if `shards.info=true` is passed.
+    #[serde(rename = "shards.info")]
+    pub(crate) shards_info: Option<HashMap<String, SolrShardInfo>>,
+    /// Alternating ids and versions of added documents, returned if `versions=true` is passed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) adds: Option<Vec<Value>>,
+    /// Alternating ids and versions of deleted documents, returned if `versions=true` is passed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) deletes: Option<Vec<Value>>,
 }
 
 impl SolrResponse {
+    /// Get the response header. This is `None` if `omitHeader=true` was passed.
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::{AsyncSolrCloudClient, SelectQuery, SolrServerContextBuilder, SolrSingleServerHost};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let response = client.select(&SelectQuery::new(), "collection").await?;
+    /// let header = response.get_response_header().ok_or("No response header")?;
+    /// println!("Took {}ms", header.q_time);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_response_header(&self) -> Option<&SolrResponseHeader> {
+        self.response_header.as_ref()
+    }
+
     /// Get the docs returned by a select request.
     /// # Examples
     /// ```no_run
@@ -169,6 +306,102 @@ impl SolrResponse {
     pub fn get_stats(&self) -> Option<&SolrStatsResult> {
         self.stats.as_ref()
     }
+
+    /// Whether the results are partial. This happens when some shards did not respond with [SelectDestination::shards_tolerant](crate::SelectDestination::shards_tolerant),
+    /// or when the search hit the limit of [SelectQuery::time_allowed](crate::SelectQuery::time_allowed), [SelectQuery::cpu_allowed](crate::SelectQuery::cpu_allowed) or [SelectQuery::mem_allowed](crate::SelectQuery::mem_allowed).
+    /// Use [SelectQuery::fail_on_partial_results](crate::SelectQuery::fail_on_partial_results) to get an error instead.
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::{AsyncSolrCloudClient, SelectDestination, SelectQuery, SolrServerContextBuilder, SolrSingleServerHost};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let response = client
+    ///     .select(&SelectQuery::new(), SelectDestination::new("collection").shards_tolerant(true))
+    ///     .await?;
+    /// if response.get_partial_results() {
+    ///     println!("Some shards did not respond");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_partial_results(&self) -> bool {
+        self.response_header
+            .as_ref()
+            .and_then(|header| header.partial_results)
+            .unwrap_or(false)
+    }
+
+    /// Get the per shard information, keyed by the shard addresses.
+    /// Only returned with [SelectDestination::shards_info](crate::SelectDestination::shards_info).
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::{AsyncSolrCloudClient, SelectDestination, SelectQuery, SolrServerContextBuilder, SolrSingleServerHost};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let response = client
+    ///     .select(&SelectQuery::new(), SelectDestination::new("collection").shards_info(true))
+    ///     .await?;
+    /// let shards_info = response.get_shards_info().ok_or("No shards info")?;
+    /// for (shard, info) in shards_info {
+    ///     println!("{}: {:?}", shard, info.get_num_found());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_shards_info(&self) -> Option<&HashMap<String, SolrShardInfo>> {
+        self.shards_info.as_ref()
+    }
+
+    /// Get the new `_version_` of each added document, by id.
+    /// Returned if [UpdateQuery::return_versions](crate::queries::index::UpdateQuery::return_versions) is set.
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::{AsyncSolrCloudClient, SolrServerContextBuilder, SolrSingleServerHost, UpdateQuery};
+    /// # use serde_json::json;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let response = client
+    ///     .index(&UpdateQuery::new().return_versions(true), "collection", &[json!({"id": "document1"})])
+    ///     .await?;
+    /// let versions = response.get_added_versions().ok_or("No versions")?;
+    /// println!("document1 is now at version {}", versions["document1"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_added_versions(&self) -> Option<HashMap<String, i64>> {
+        self.adds.as_deref().map(versions_from_pairs)
+    }
+
+    /// Get the `_version_` of each delete, by id.
+    /// Returned if [DeleteQuery::return_versions](crate::queries::index::DeleteQuery::return_versions) is set.
+    pub fn get_deleted_versions(&self) -> Option<HashMap<String, i64>> {
+        self.deletes.as_deref().map(versions_from_pairs)
+    }
+
+    /// Get the updates that failed without failing the request.
+    /// Empty unless [UpdateQuery::max_errors](crate::queries::index::UpdateQuery::max_errors) is used with a tolerant update chain.
+    pub fn get_update_errors(&self) -> &[SolrUpdateError] {
+        self.response_header
+            .as_ref()
+            .and_then(|header| header.errors.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+fn versions_from_pairs(pairs: &[Value]) -> HashMap<String, i64> {
+    pairs
+        .chunks_exact(2)
+        .filter_map(|pair| {
+            let id = match &pair[0] {
+                Value::String(id) => id.clone(),
+                id => id.to_string(),
+            };
+            Some((id, pair[1].as_i64()?))
+        })
+        .collect()
 }
 
 fn from_alias<'de, D>(deserializer: D) -> Result<Option<HashMap<String, Vec<String>>>, D::Error>
@@ -191,3 +424,108 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::models::response::{SolrResponse, SolrUpdateErrorType};
+
+    #[test]
+    fn deserialize_response_header() {
+        let response = serde_json::from_str::<SolrResponse>(
+            r#"{
+                "responseHeader": {
+                    "zkConnected": true, "status": 0, "QTime": 5, "rf": 2, "segmentTerminatedEarly": false,
+                    "params": {"q": "*:*", "fq": ["a:1", "b:2"], "wt": "json"}
+                }
+            }"#,
+        )
+        .unwrap();
+        let header = response.get_response_header().unwrap();
+        assert_eq!(header.q_time, 5);
+        assert_eq!(header.rf, Some(2));
+        assert_eq!(header.segment_terminated_early, Some(false));
+        let params = header.params.as_ref().unwrap();
+        assert_eq!(params["fq"], serde_json::json!(["a:1", "b:2"]));
+        assert!(!response.get_partial_results());
+
+        let response = serde_json::from_str::<SolrResponse>(
+            r#"{"response": {"numFound": 0, "start": 0, "docs": []}}"#,
+        )
+        .unwrap();
+        assert!(response.get_response_header().is_none());
+    }
+
+    #[test]
+    fn deserialize_partial_results_and_shards_info() {
+        let response = serde_json::from_str::<SolrResponse>(
+            r#"{
+                "responseHeader": {"zkConnected": true, "status": 0, "QTime": 3, "partialResults": true},
+                "shards.info": {
+                    "http://localhost:8983/solr/collection_shard1_replica_n1/": {
+                        "numFound": 4, "maxScore": 1.0, "shardAddress": "http://localhost:8983/solr/collection_shard1_replica_n1/", "time": 2
+                    },
+                    "shard2": {"error": "no servers hosting shard: shard2", "trace": "", "time": 0}
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(response.get_partial_results());
+        let shards_info = response.get_shards_info().unwrap();
+        let shard1 = &shards_info["http://localhost:8983/solr/collection_shard1_replica_n1/"];
+        assert_eq!(shard1.get_num_found(), Some(4));
+        assert_eq!(shard1.get_time(), Some(2));
+        assert_eq!(
+            shards_info["shard2"].get_error(),
+            Some("no servers hosting shard: shard2")
+        );
+    }
+
+    #[test]
+    fn deserialize_versions() {
+        let response = serde_json::from_str::<SolrResponse>(
+            r#"{
+                "responseHeader": {"status": 0, "QTime": 4},
+                "adds": ["document1", 1712345678901234567, "document2", 1712345678901234568],
+                "deletes": ["document3", -1712345678901234569]
+            }"#,
+        )
+        .unwrap();
+        let added = response.get_added_versions().unwrap();
+        assert_eq!(added["document1"], 1712345678901234567);
+        assert_eq!(added["document2"], 1712345678901234568);
+        let deleted = response.get_deleted_versions().unwrap();
+        assert_eq!(deleted["document3"], -1712345678901234569);
+    }
+
+    #[test]
+    fn deserialize_tolerant_update_errors() {
+        let response = serde_json::from_str::<SolrResponse>(
+            r#"{
+                "responseHeader": {
+                    "errors": [
+                        {"type": "ADD", "id": "doc2", "message": "ERROR: [doc=doc2] Error adding field 'age'='old'"},
+                        {"type": "DELQ", "id": "age:[* TO", "message": "org.apache.solr.search.SyntaxError"}
+                    ],
+                    "maxErrors": -1, "status": 0, "QTime": 8
+                }
+            }"#,
+        )
+        .unwrap();
+        let errors = response.get_update_errors();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].get_error_type(), SolrUpdateErrorType::Add);
+        assert_eq!(errors[0].get_id(), "doc2");
+        assert!(errors[0].get_message().contains("Error adding field"));
+        assert_eq!(
+            errors[1].get_error_type(),
+            SolrUpdateErrorType::DeleteByQuery
+        );
+        assert_eq!(response.get_response_header().unwrap().max_errors, Some(-1));
+
+        let response = serde_json::from_str::<SolrResponse>(
+            r#"{"responseHeader": {"status": 0, "QTime": 1}}"#,
+        )
+        .unwrap();
+        assert!(response.get_update_errors().is_empty());
+    }
+}
@@ -0,0 +1,133 @@
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A `_version_` constraint for optimistic concurrency.
+///
+/// If the constraint is not met, Solr rejects the update with
+/// [Error::SolrVersionConflict](crate::Error::SolrVersionConflict).
+/// # Examples
+/// ```
+/// use solrstice::VersionConstraint;
+/// assert_eq!(serde_json::to_value(VersionConstraint::MustNotExist)?, serde_json::json!(-1));
+/// assert_eq!(VersionConstraint::from_version(1), Some(VersionConstraint::MustExist));
+/// assert_eq!(VersionConstraint::from_version(0), None);
+/// assert_eq!(VersionConstraint::exact(1), None);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum VersionConstraint {
+    /// The document must exist with exactly this version. Created with [VersionConstraint::exact].
+    Exact(ExactVersion),
+    /// The document must exist, with any version.
+    MustExist,
+    /// The document must not exist.
+    MustNotExist,
+}
+
+/// A `_version_` assigned by Solr, which is always above `1`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ExactVersion(i64);
+
+impl ExactVersion {
+    pub fn get(&self) -> i64 {
+        self.0
+    }
+}
+
+impl VersionConstraint {
+    /// The document must exist with exactly this version.
+    ///
+    /// Returns `None` for versions of `1` or below, as Solr reads those as other constraints. Use [VersionConstraint::from_version] for those.
+    /// # Examples
+    /// ```
+    /// use solrstice::VersionConstraint;
+    /// let constraint = VersionConstraint::exact(1712345678901234567).ok_or("Not a Solr version")?;
+    /// assert_eq!(constraint.as_i64(), 1712345678901234567);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn exact(version: i64) -> Option<Self> {
+        match version > 1 {
+            true => Some(VersionConstraint::Exact(ExactVersion(version))),
+            false => None,
+        }
+    }
+
+    /// Interpret a `_version_` value the way Solr does. `1` means the document must exist, and negative values mean it must not exist.
+    /// `0` means no constraint, and gives `None`.
+    pub fn from_version(version: i64) -> Option<Self> {
+        match version {
+            0 => None,
+            1 => Some(VersionConstraint::MustExist),
+            v if v < 0 => Some(VersionConstraint::MustNotExist),
+            v => VersionConstraint::exact(v),
+        }
+    }
+
+    /// The value of `_version_` as understood by Solr.
+    pub fn as_i64(&self) -> i64 {
+        match self {
+            VersionConstraint::Exact(version) => version.get(),
+            VersionConstraint::MustExist => 1,
+            VersionConstraint::MustNotExist => -1,
+        }
+    }
+}
+
+impl Serialize for VersionConstraint {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.as_i64())
+    }
+}
+
+impl<'de> Deserialize<'de> for VersionConstraint {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        VersionConstraint::from_version(i64::deserialize(deserializer)?)
+            .ok_or_else(|| D::Error::custom("a `_version_` of 0 is no constraint"))
+    }
+}
+
+/// A document sent with a `_version_` constraint.
+/// # Examples
+/// ```no_run
+/// use serde::Serialize;
+/// use solrstice::{AsyncSolrCloudClient, Error, SolrServerContextBuilder, SolrSingleServerHost, UpdateQuery, VersionConstraint, VersionedDocument};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// #[derive(Serialize)]
+/// struct Data {id: String}
+///
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let doc = VersionedDocument::new(Data {id: "document1".to_string()}, VersionConstraint::MustNotExist);
+/// match client.index(&UpdateQuery::new(), "collection_name", &[doc]).await {
+///     Err(Error::SolrVersionConflict { id, .. }) => println!("{:?} already exists", id),
+///     result => { result?; }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct VersionedDocument<D> {
+    #[serde(flatten)]
+    doc: D,
+    #[serde(rename = "_version_")]
+    version: VersionConstraint,
+}
+
+impl<D> VersionedDocument<D> {
+    /// Wrap a document, which must serialize to a map, with a version constraint.
+    pub fn new<V: Into<VersionConstraint>>(doc: D, version: V) -> Self {
+        VersionedDocument {
+            doc,
+            version: version.into(),
+        }
+    }
+
+    pub fn get_doc(&self) -> &D {
+        &self.doc
+    }
+
+    pub fn get_version(&self) -> VersionConstraint {
+        self.version
+    }
+}
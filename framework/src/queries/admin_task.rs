@@ -0,0 +1,202 @@
+use crate::error::Error;
+use crate::models::admin_task::{AdminTaskState, SolrRequestStatus};
+use crate::models::context::SolrServerContext;
+use crate::models::SolrResponse;
+use crate::queries::request_builder::SolrRequestBuilder;
+#[cfg(feature = "blocking")]
+use crate::runtime::RUNTIME;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// A handle to a Collections API request sent with an `async` request id.
+///
+/// Long running operations like creating collections or splitting shards can take longer than an HTTP request should stay open.
+/// Sent with an `async` id, Solr returns at once and runs the operation in the background.
+/// This handle polls `REQUESTSTATUS` until the operation is completed or failed.
+/// Solr keeps the status of finished requests, and rejects a new request with an id that is still in use,
+/// so remove it with [AsyncAdminTask::delete_status] once the result has been read.
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::{create_collection_with_options, CreateCollectionOptions};
+/// use solrstice::{AsyncAdminTask, SolrServerContextBuilder, SolrSingleServerHost};
+/// use std::time::Duration;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let options = CreateCollectionOptions::new().config_name("config_name").num_shards(8).async_id("create-1");
+/// create_collection_with_options(&context, "collection_name", &options).await?;
+///
+/// let task = AsyncAdminTask::new("create-1").poll_interval(Duration::from_secs(2)).timeout(Duration::from_secs(600));
+/// let status = task.wait(&context).await?;
+/// task.delete_status(&context).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+pub struct AsyncAdminTask {
+    request_id: String,
+    poll_interval: Duration,
+    timeout: Option<Duration>,
+}
+
+impl AsRef<AsyncAdminTask> for AsyncAdminTask {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&AsyncAdminTask> for AsyncAdminTask {
+    fn from(task: &AsyncAdminTask) -> Self {
+        task.clone()
+    }
+}
+
+impl AsyncAdminTask {
+    /// Create a handle to the request with the given `async` id. Polls every second, without a timeout.
+    pub fn new<S: Into<String>>(request_id: S) -> Self {
+        AsyncAdminTask {
+            request_id: request_id.into(),
+            poll_interval: Duration::from_secs(1),
+            timeout: None,
+        }
+    }
+
+    /// The `async` id of the request.
+    pub fn get_request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    /// How long to wait between polls. Default is 1 second.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Give up waiting after this long, with [Error::SolrAdminTaskTimeout]. The operation keeps running on the server.
+    /// Default is no timeout.
+    pub fn timeout<O: Into<Option<Duration>>>(mut self, timeout: O) -> Self {
+        self.timeout = timeout.into();
+        self
+    }
+
+    /// Get the current status of the request.
+    pub async fn status<C: AsRef<SolrServerContext>>(
+        &self,
+        context: C,
+    ) -> Result<SolrRequestStatus, Error> {
+        let query_params = [
+            ("action", "REQUESTSTATUS"),
+            ("requestid", self.request_id.as_str()),
+        ];
+        let response = SolrRequestBuilder::new(context.as_ref(), "/solr/admin/collections")
+            .with_query_params(query_params.as_ref())
+            .send_get::<HashMap<String, Value>>()
+            .await?;
+        SolrRequestStatus::from_response(response)
+    }
+
+    /// Poll the status until the request is completed or failed.
+    ///
+    /// A failed request is returned as [Error::SolrAdminTaskFailed], as is a request Solr does not know about.
+    pub async fn wait<C: AsRef<SolrServerContext>>(
+        &self,
+        context: C,
+    ) -> Result<SolrRequestStatus, Error> {
+        let context = context.as_ref();
+        let start = Instant::now();
+        loop {
+            let status = self.status(context).await?;
+            if let Some(error) = status.to_error(&self.request_id) {
+                return Err(error);
+            }
+            match status.get_state() {
+                AdminTaskState::Completed => return Ok(status),
+                AdminTaskState::NotFound => {
+                    return Err(Error::SolrAdminTaskFailed {
+                        request_id: self.request_id.clone(),
+                        code: None,
+                        msg: status
+                            .get_msg()
+                            .unwrap_or("Request id not found")
+                            .to_string(),
+                    })
+                }
+                state => {
+                    if let Some(timeout) = self.timeout {
+                        if start.elapsed() + self.poll_interval > timeout {
+                            return Err(Error::SolrAdminTaskTimeout {
+                                request_id: self.request_id.clone(),
+                                state: format!("{:?}", state),
+                            });
+                        }
+                    }
+                }
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Delete the stored status of the finished request, so its id can be used again.
+    pub async fn delete_status<C: AsRef<SolrServerContext>>(
+        &self,
+        context: C,
+    ) -> Result<(), Error> {
+        let query_params = [
+            ("action", "DELETESTATUS"),
+            ("requestid", self.request_id.as_str()),
+        ];
+        SolrRequestBuilder::new(context.as_ref(), "/solr/admin/collections")
+            .with_query_params(query_params.as_ref())
+            .send_get::<SolrResponse>()
+            .await?;
+        Ok(())
+    }
+
+    /// Delete the stored statuses of all finished requests.
+    pub async fn delete_all_statuses<C: AsRef<SolrServerContext>>(context: C) -> Result<(), Error> {
+        let query_params = [("action", "DELETESTATUS"), ("flush", "true")];
+        SolrRequestBuilder::new(context.as_ref(), "/solr/admin/collections")
+            .with_query_params(query_params.as_ref())
+            .send_get::<SolrResponse>()
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl AsyncAdminTask {
+    /// Get the current status of the request.
+    pub fn status_blocking<C: AsRef<SolrServerContext>>(
+        &self,
+        context: C,
+    ) -> Result<SolrRequestStatus, Error> {
+        RUNTIME.handle().block_on(self.status(context))
+    }
+
+    /// Poll the status until the request is completed or failed.
+    pub fn wait_blocking<C: AsRef<SolrServerContext>>(
+        &self,
+        context: C,
+    ) -> Result<SolrRequestStatus, Error> {
+        RUNTIME.handle().block_on(self.wait(context))
+    }
+
+    /// Delete the stored status of the finished request, so its id can be used again.
+    pub fn delete_status_blocking<C: AsRef<SolrServerContext>>(
+        &self,
+        context: C,
+    ) -> Result<(), Error> {
+        RUNTIME.handle().block_on(self.delete_status(context))
+    }
+
+    /// Delete the stored statuses of all finished requests.
+    pub fn delete_all_statuses_blocking<C: AsRef<SolrServerContext>>(
+        context: C,
+    ) -> Result<(), Error> {
+        RUNTIME
+            .handle()
+            .block_on(Self::delete_all_statuses(context))
+    }
+}
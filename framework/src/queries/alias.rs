@@ -1,9 +1,91 @@
 use crate::error::Error;
 use crate::models::context::SolrServerContext;
 use crate::models::SolrResponse;
+use crate::queries::collection::{send_collection_action, to_query_params};
 use crate::queries::request_builder::SolrRequestBuilder;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Options for the Collections API `CREATEALIAS` action, used by [create_alias_with_options].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::alias::CreateAliasOptions;
+/// let options = CreateAliasOptions::new().async_id("create-alias");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct CreateAliasOptions {
+    async_id: Option<String>,
+}
+
+impl AsRef<CreateAliasOptions> for CreateAliasOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&CreateAliasOptions> for CreateAliasOptions {
+    fn from(options: &CreateAliasOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl CreateAliasOptions {
+    /// Create a new instance of CreateAliasOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([("async", self.async_id.clone())])
+    }
+}
+
+/// Options for the Collections API `DELETEALIAS` action, used by [delete_alias_with_options].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::alias::DeleteAliasOptions;
+/// let options = DeleteAliasOptions::new().async_id("delete-alias");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct DeleteAliasOptions {
+    async_id: Option<String>,
+}
+
+impl AsRef<DeleteAliasOptions> for DeleteAliasOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&DeleteAliasOptions> for DeleteAliasOptions {
+    fn from(options: &DeleteAliasOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl DeleteAliasOptions {
+    /// Create a new instance of DeleteAliasOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([("async", self.async_id.clone())])
+    }
+}
+
 /// Get aliases from the Solr server.
 ///
 /// This is not meant to be used directly, but rather as part of a client.
@@ -32,21 +114,36 @@ pub async fn create_alias<C: AsRef<SolrServerContext>, S: AsRef<str>>(
     context: C,
     name: S,
     collections: &[S],
+) -> Result<(), Error> {
+    create_alias_with_options(context, name, collections, CreateAliasOptions::new()).await
+}
+
+/// Create an alias with the given name pointing to a list of collections, by the Collections API `CREATEALIAS` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::create_alias_with_options](crate::clients::async_cloud_client::AsyncSolrCloudClient::create_alias_with_options)
+pub async fn create_alias_with_options<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<CreateAliasOptions>,
+>(
+    context: C,
+    name: S,
+    collections: &[S],
+    options: O,
 ) -> Result<(), Error> {
     let collections = collections
         .iter()
         .map(|x| x.as_ref())
         .collect::<Vec<&str>>()
         .join(",");
-    let query_params = [
-        ("action", "CREATEALIAS"),
-        ("name", name.as_ref()),
-        ("collections", collections.as_str()),
+    let mut query_params = vec![
+        ("action".to_string(), "CREATEALIAS".to_string()),
+        ("name".to_string(), name.as_ref().to_string()),
+        ("collections".to_string(), collections),
     ];
-    SolrRequestBuilder::new(context.as_ref(), "/solr/admin/collections")
-        .with_query_params(query_params.as_ref())
-        .send_get::<SolrResponse>()
-        .await?;
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
     Ok(())
 }
 
@@ -70,11 +167,28 @@ pub async fn delete_alias<C: AsRef<SolrServerContext>, S: AsRef<str>>(
     context: C,
     name: S,
 ) -> Result<(), Error> {
-    let query_params = [("action", "DELETEALIAS"), ("name", name.as_ref())];
-    SolrRequestBuilder::new(context.as_ref(), "/solr/admin/collections")
-        .with_query_params(query_params.as_ref())
-        .send_get::<SolrResponse>()
-        .await?;
+    delete_alias_with_options(context, name, DeleteAliasOptions::new()).await
+}
+
+/// Delete an alias with the given name, by the Collections API `DELETEALIAS` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::delete_alias_with_options](crate::clients::async_cloud_client::AsyncSolrCloudClient::delete_alias_with_options)
+pub async fn delete_alias_with_options<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<DeleteAliasOptions>,
+>(
+    context: C,
+    name: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "DELETEALIAS".to_string()),
+        ("name".to_string(), name.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
     Ok(())
 }
 
@@ -106,6 +220,29 @@ pub fn create_alias_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
         .block_on(create_alias(context, name, collections))
 }
 
+#[cfg(feature = "blocking")]
+/// Create an alias with the given name pointing to a list of collections, by the Collections API `CREATEALIAS` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [BlockingSolrCloudClient::create_alias_with_options](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::create_alias_with_options)
+pub fn create_alias_with_options_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<CreateAliasOptions>,
+>(
+    context: C,
+    name: S,
+    collections: &[S],
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME.handle().block_on(create_alias_with_options(
+        context,
+        name,
+        collections,
+        options,
+    ))
+}
+
 #[cfg(feature = "blocking")]
 /// Check if an alias with the given name exists.
 ///
@@ -129,3 +266,22 @@ pub fn delete_alias_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
 ) -> Result<(), Error> {
     RUNTIME.handle().block_on(delete_alias(context, name))
 }
+
+#[cfg(feature = "blocking")]
+/// Delete an alias with the given name, by the Collections API `DELETEALIAS` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [BlockingSolrCloudClient::delete_alias_with_options](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::delete_alias_with_options)
+pub fn delete_alias_with_options_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<DeleteAliasOptions>,
+>(
+    context: C,
+    name: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(delete_alias_with_options(context, name, options))
+}
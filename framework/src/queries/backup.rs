@@ -0,0 +1,540 @@
+use crate::error::Error;
+use crate::models::backup::{BackupPoint, BackupResult, DeletedBackup};
+use crate::models::context::SolrServerContext;
+use crate::queries::collection::{send_collection_action, to_query_params};
+use crate::queries::request_builder::{SolrRequestBuilder, SolrResponseType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Options for the Collections API `BACKUP` action, used by [backup_collection].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::backup::BackupOptions;
+/// let options = BackupOptions::new()
+///     .location("/var/solr/backups")
+///     .incremental(true)
+///     .max_num_backup_points(7);
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct BackupOptions {
+    location: Option<String>,
+    repository: Option<String>,
+    incremental: Option<bool>,
+    max_num_backup_points: Option<usize>,
+    async_id: Option<String>,
+}
+
+impl AsRef<BackupOptions> for BackupOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&BackupOptions> for BackupOptions {
+    fn from(options: &BackupOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl BackupOptions {
+    /// Create a new instance of BackupOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The directory in the repository to write the backup to. Default is the `location` of the repository, or the cluster property.
+    pub fn location<S: Into<String>, O: Into<Option<S>>>(mut self, location: O) -> Self {
+        self.location = location.into().map(|x| x.into());
+        self
+    }
+
+    /// The backup repository from `solr.xml` to use. Default is the local filesystem.
+    pub fn repository<S: Into<String>, O: Into<Option<S>>>(mut self, repository: O) -> Self {
+        self.repository = repository.into().map(|x| x.into());
+        self
+    }
+
+    /// Only copy the index files that are not in an earlier backup point of the same name. Requires Solr 8.9 or later, and is the default since Solr 9.
+    pub fn incremental<O: Into<Option<bool>>>(mut self, incremental: O) -> Self {
+        self.incremental = incremental.into();
+        self
+    }
+
+    /// Delete the oldest backup points, so that at most this many are kept. Only for incremental backups.
+    pub fn max_num_backup_points<O: Into<Option<usize>>>(
+        mut self,
+        max_num_backup_points: O,
+    ) -> Self {
+        self.max_num_backup_points = max_num_backup_points.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([
+            ("location", self.location.clone()),
+            ("repository", self.repository.clone()),
+            ("incremental", self.incremental.map(|x| x.to_string())),
+            (
+                "maxNumBackupPoints",
+                self.max_num_backup_points.map(|x| x.to_string()),
+            ),
+            ("async", self.async_id.clone()),
+        ])
+    }
+}
+
+/// Options for the Collections API `RESTORE` action, used by [restore_collection].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::backup::RestoreOptions;
+/// let options = RestoreOptions::new()
+///     .location("/var/solr/backups")
+///     .backup_id(3)
+///     .replication_factor(2);
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct RestoreOptions {
+    location: Option<String>,
+    repository: Option<String>,
+    backup_id: Option<u64>,
+    config_name: Option<String>,
+    replication_factor: Option<usize>,
+    async_id: Option<String>,
+}
+
+impl AsRef<RestoreOptions> for RestoreOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&RestoreOptions> for RestoreOptions {
+    fn from(options: &RestoreOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl RestoreOptions {
+    /// Create a new instance of RestoreOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The directory in the repository the backup was written to.
+    pub fn location<S: Into<String>, O: Into<Option<S>>>(mut self, location: O) -> Self {
+        self.location = location.into().map(|x| x.into());
+        self
+    }
+
+    /// The backup repository from `solr.xml` to use. Default is the local filesystem.
+    pub fn repository<S: Into<String>, O: Into<Option<S>>>(mut self, repository: O) -> Self {
+        self.repository = repository.into().map(|x| x.into());
+        self
+    }
+
+    /// The backup point to restore. Default is the latest.
+    pub fn backup_id<O: Into<Option<u64>>>(mut self, backup_id: O) -> Self {
+        self.backup_id = backup_id.into();
+        self
+    }
+
+    /// The config set of the restored collection. Default is the config set in the backup, which is uploaded if missing.
+    pub fn config_name<S: Into<String>, O: Into<Option<S>>>(mut self, config_name: O) -> Self {
+        self.config_name = config_name.into().map(|x| x.into());
+        self
+    }
+
+    /// The number of replicas of each shard of the restored collection.
+    pub fn replication_factor<O: Into<Option<usize>>>(mut self, replication_factor: O) -> Self {
+        self.replication_factor = replication_factor.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([
+            ("location", self.location.clone()),
+            ("repository", self.repository.clone()),
+            ("backupId", self.backup_id.map(|x| x.to_string())),
+            ("collection.configName", self.config_name.clone()),
+            (
+                "replicationFactor",
+                self.replication_factor.map(|x| x.to_string()),
+            ),
+            ("async", self.async_id.clone()),
+        ])
+    }
+}
+
+/// Options for the Collections API `LISTBACKUP` action, used by [list_backups].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::backup::ListBackupsOptions;
+/// let options = ListBackupsOptions::new().location("/var/solr/backups");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct ListBackupsOptions {
+    location: Option<String>,
+    repository: Option<String>,
+}
+
+impl AsRef<ListBackupsOptions> for ListBackupsOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&ListBackupsOptions> for ListBackupsOptions {
+    fn from(options: &ListBackupsOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl ListBackupsOptions {
+    /// Create a new instance of ListBackupsOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The directory in the repository the backup was written to.
+    pub fn location<S: Into<String>, O: Into<Option<S>>>(mut self, location: O) -> Self {
+        self.location = location.into().map(|x| x.into());
+        self
+    }
+
+    /// The backup repository from `solr.xml` to use. Default is the local filesystem.
+    pub fn repository<S: Into<String>, O: Into<Option<S>>>(mut self, repository: O) -> Self {
+        self.repository = repository.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([
+            ("location", self.location.clone()),
+            ("repository", self.repository.clone()),
+        ])
+    }
+}
+
+/// Options for the Collections API `DELETEBACKUP` action, used by [delete_backup].
+///
+/// One of [backup_id](DeleteBackupOptions::backup_id), [max_num_backup_points](DeleteBackupOptions::max_num_backup_points)
+/// or [purge_unused](DeleteBackupOptions::purge_unused) has to be set.
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::backup::DeleteBackupOptions;
+/// let options = DeleteBackupOptions::new()
+///     .location("/var/solr/backups")
+///     .max_num_backup_points(3);
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct DeleteBackupOptions {
+    location: Option<String>,
+    repository: Option<String>,
+    backup_id: Option<u64>,
+    max_num_backup_points: Option<usize>,
+    purge_unused: Option<bool>,
+    async_id: Option<String>,
+}
+
+impl AsRef<DeleteBackupOptions> for DeleteBackupOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&DeleteBackupOptions> for DeleteBackupOptions {
+    fn from(options: &DeleteBackupOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl DeleteBackupOptions {
+    /// Create a new instance of DeleteBackupOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The directory in the repository the backup was written to.
+    pub fn location<S: Into<String>, O: Into<Option<S>>>(mut self, location: O) -> Self {
+        self.location = location.into().map(|x| x.into());
+        self
+    }
+
+    /// The backup repository from `solr.xml` to use. Default is the local filesystem.
+    pub fn repository<S: Into<String>, O: Into<Option<S>>>(mut self, repository: O) -> Self {
+        self.repository = repository.into().map(|x| x.into());
+        self
+    }
+
+    /// Delete this backup point.
+    pub fn backup_id<O: Into<Option<u64>>>(mut self, backup_id: O) -> Self {
+        self.backup_id = backup_id.into();
+        self
+    }
+
+    /// Delete the oldest backup points, so that this many are kept.
+    pub fn max_num_backup_points<O: Into<Option<usize>>>(
+        mut self,
+        max_num_backup_points: O,
+    ) -> Self {
+        self.max_num_backup_points = max_num_backup_points.into();
+        self
+    }
+
+    /// Delete the files that no backup point refers to, eg. left by a failed backup.
+    pub fn purge_unused<O: Into<Option<bool>>>(mut self, purge_unused: O) -> Self {
+        self.purge_unused = purge_unused.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([
+            ("location", self.location.clone()),
+            ("repository", self.repository.clone()),
+            ("backupId", self.backup_id.map(|x| x.to_string())),
+            (
+                "maxNumBackupPoints",
+                self.max_num_backup_points.map(|x| x.to_string()),
+            ),
+            ("purgeUnused", self.purge_unused.map(|x| x.to_string())),
+            ("async", self.async_id.clone()),
+        ])
+    }
+}
+
+/// Send a backup action, and parse the response as `R`.
+async fn send_backup_action<R: SolrResponseType>(
+    context: &SolrServerContext,
+    query_params: Vec<(String, String)>,
+) -> Result<R, Error> {
+    let query_params = query_params
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect::<Vec<_>>();
+    SolrRequestBuilder::new(context, "/solr/admin/collections")
+        .with_query_params(query_params.as_ref())
+        .send_get::<R>()
+        .await
+}
+
+/// Back up a collection under a backup name, by the Collections API `BACKUP` action.
+///
+/// Each incremental backup of the same name and location adds a backup point, which can be restored on its own.
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::backup_collection](crate::clients::async_cloud_client::AsyncSolrCloudClient::backup_collection)
+pub async fn backup_collection<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<BackupOptions>,
+>(
+    context: C,
+    collection: S,
+    name: S,
+    options: O,
+) -> Result<BackupResult, Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "BACKUP".to_string()),
+        ("collection".to_string(), collection.as_ref().to_string()),
+        ("name".to_string(), name.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_backup_action(context.as_ref(), query_params).await
+}
+
+/// Restore a backup into a new collection, by the Collections API `RESTORE` action.
+///
+/// The collection must not exist. Restoring into an existing collection name is done by restoring under a new name, and pointing an alias at it.
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::restore_collection](crate::clients::async_cloud_client::AsyncSolrCloudClient::restore_collection)
+pub async fn restore_collection<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<RestoreOptions>,
+>(
+    context: C,
+    collection: S,
+    name: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "RESTORE".to_string()),
+        ("collection".to_string(), collection.as_ref().to_string()),
+        ("name".to_string(), name.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// List the backup points of a backup, by the Collections API `LISTBACKUP` action.
+///
+/// Requires Solr 8.9 or later.
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::list_backups](crate::clients::async_cloud_client::AsyncSolrCloudClient::list_backups)
+pub async fn list_backups<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ListBackupsOptions>,
+>(
+    context: C,
+    name: S,
+    options: O,
+) -> Result<Vec<BackupPoint>, Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "LISTBACKUP".to_string()),
+        ("name".to_string(), name.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    let response =
+        send_backup_action::<HashMap<String, Value>>(context.as_ref(), query_params).await?;
+    BackupPoint::from_response(response)
+}
+
+/// Delete backup points or unused files of a backup, by the Collections API `DELETEBACKUP` action.
+///
+/// Requires Solr 8.9 or later.
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::delete_backup](crate::clients::async_cloud_client::AsyncSolrCloudClient::delete_backup)
+pub async fn delete_backup<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<DeleteBackupOptions>,
+>(
+    context: C,
+    name: S,
+    options: O,
+) -> Result<Vec<DeletedBackup>, Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "DELETEBACKUP".to_string()),
+        ("name".to_string(), name.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    let response =
+        send_backup_action::<HashMap<String, Value>>(context.as_ref(), query_params).await?;
+    DeletedBackup::from_response(response)
+}
+
+#[cfg(feature = "blocking")]
+use crate::runtime::RUNTIME;
+
+#[cfg(feature = "blocking")]
+pub fn backup_collection_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<BackupOptions>,
+>(
+    context: C,
+    collection: S,
+    name: S,
+    options: O,
+) -> Result<BackupResult, Error> {
+    RUNTIME
+        .handle()
+        .block_on(backup_collection(context, collection, name, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn restore_collection_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<RestoreOptions>,
+>(
+    context: C,
+    collection: S,
+    name: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(restore_collection(context, collection, name, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn list_backups_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ListBackupsOptions>,
+>(
+    context: C,
+    name: S,
+    options: O,
+) -> Result<Vec<BackupPoint>, Error> {
+    RUNTIME
+        .handle()
+        .block_on(list_backups(context, name, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn delete_backup_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<DeleteBackupOptions>,
+>(
+    context: C,
+    name: S,
+    options: O,
+) -> Result<Vec<DeletedBackup>, Error> {
+    RUNTIME
+        .handle()
+        .block_on(delete_backup(context, name, options))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::queries::backup::{BackupOptions, DeleteBackupOptions, RestoreOptions};
+
+    #[test]
+    fn backup_options_to_query_params() {
+        let options = BackupOptions::new()
+            .location("/var/solr/backups")
+            .incremental(true)
+            .max_num_backup_points(7)
+            .async_id("backup-1");
+        let expected = [
+            ("location", "/var/solr/backups"),
+            ("incremental", "true"),
+            ("maxNumBackupPoints", "7"),
+            ("async", "backup-1"),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+
+        let options = RestoreOptions::new()
+            .repository("s3")
+            .backup_id(3)
+            .config_name("books_config");
+        let expected = [
+            ("repository", "s3"),
+            ("backupId", "3"),
+            ("collection.configName", "books_config"),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+
+        let options = DeleteBackupOptions::new()
+            .location("/var/solr/backups")
+            .purge_unused(true);
+        let expected = [("location", "/var/solr/backups"), ("purgeUnused", "true")]
+            .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+    }
+}
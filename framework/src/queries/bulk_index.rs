@@ -0,0 +1,707 @@
+use crate::error::Error;
+use crate::models::commit_type::CommitType;
+use crate::models::context::SolrServerContext;
+use crate::models::response::{SolrResponse, SolrUpdateError};
+use crate::queries::index::UpdateQuery;
+use crate::queries::request_builder::SolrRequestBuilder;
+#[cfg(feature = "blocking")]
+use crate::runtime::RUNTIME;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::{Debug, Formatter};
+use std::pin::{pin, Pin};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::Receiver;
+
+type ProgressCallback = Arc<dyn Fn(&BulkIndexProgress) + Send + Sync>;
+
+/// Index large amounts of documents, by splitting them into batches that are sent concurrently.
+///
+/// Documents are only pulled from the input when there is room for another batch, so memory use is bounded by
+/// `concurrency` batches. Batches failing with a transient error, such as a connection error or a `5xx` response, are retried
+/// with exponential backoff. A single commit is issued after all batches have been sent.
+///
+/// The batches are sent with the options of an [UpdateQuery], such as [UpdateQuery::max_errors] for a tolerant update chain.
+/// # Examples
+/// ```no_run
+/// use serde::Serialize;
+/// use solrstice::{AsyncSolrCloudClient, BulkIndexer, CommitType, SolrServerContextBuilder, SolrSingleServerHost, UpdateQuery};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// #[derive(Serialize)]
+/// struct Data {id: String}
+///
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let indexer = BulkIndexer::new()
+///     .update_query(UpdateQuery::new().commit_type(CommitType::Soft))
+///     .batch_size(5000)
+///     .concurrency(8)
+///     .on_progress(|progress| println!("{} docs indexed", progress.get_docs_indexed()));
+/// let docs = (0..1_000_000).map(|i| Data {id: i.to_string()});
+/// let summary = client.bulk_index(&indexer, "collection_name", futures::stream::iter(docs)).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct BulkIndexer {
+    update_query: UpdateQuery,
+    batch_size: usize,
+    batch_bytes: usize,
+    concurrency: usize,
+    max_retries: usize,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl Debug for BulkIndexer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BulkIndexer")
+            .field("update_query", &self.update_query)
+            .field("batch_size", &self.batch_size)
+            .field("batch_bytes", &self.batch_bytes)
+            .field("concurrency", &self.concurrency)
+            .field("max_retries", &self.max_retries)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for BulkIndexer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsRef<BulkIndexer> for BulkIndexer {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&BulkIndexer> for BulkIndexer {
+    fn from(indexer: &BulkIndexer) -> Self {
+        indexer.clone()
+    }
+}
+
+impl BulkIndexer {
+    /// Create a new bulk indexer, sending batches of 1000 documents or 10MB, with 4 concurrent requests.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::BulkIndexer;
+    /// let indexer = BulkIndexer::new();
+    /// ```
+    pub fn new() -> Self {
+        BulkIndexer {
+            update_query: UpdateQuery::new(),
+            batch_size: 1000,
+            batch_bytes: 10 * 1024 * 1024,
+            concurrency: 4,
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            on_progress: None,
+        }
+    }
+
+    /// Set the update query the batches are sent with, for the handler, commit type and update options. Default is [UpdateQuery::new].
+    ///
+    /// The commit type is used for the final commit. `CommitType::CommitWithin` is sent with every batch instead, and `CommitType::NoCommit` skips the commit.
+    /// The wire format of the query is not used, as the batches are serialized as JSON.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{BulkIndexer, UpdateQuery};
+    /// let indexer = BulkIndexer::new().update_query(UpdateQuery::new().update_chain("tolerant").max_errors(-1));
+    /// ```
+    pub fn update_query<Q: Into<UpdateQuery>>(mut self, update_query: Q) -> Self {
+        self.update_query = update_query.into();
+        self
+    }
+
+    /// The maximum number of documents in a batch. Default is 1000.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// The maximum size of a batch in bytes, as serialized JSON. Default is 10MB.
+    ///
+    /// A single document larger than this is sent in a batch of its own.
+    pub fn batch_bytes(mut self, batch_bytes: usize) -> Self {
+        self.batch_bytes = batch_bytes;
+        self
+    }
+
+    /// The maximum number of batches sent at the same time. Default is 4.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// How many times a batch failing with a transient error is retried. Default is 3.
+    pub fn max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// The wait before the first retry, doubled for every following retry. Default is 500ms.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// The maximum wait between retries. Default is 30s.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Called after every batch, whether it succeeded or not.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::BulkIndexer;
+    /// let indexer = BulkIndexer::new().on_progress(|progress| {
+    ///     if let Some(error) = progress.get_error() {
+    ///         eprintln!("Batch {} failed: {}", progress.get_batch(), error);
+    ///     }
+    ///     for error in progress.get_update_errors() {
+    ///         eprintln!("{} failed: {}", error.get_id(), error.get_message());
+    ///     }
+    /// });
+    /// ```
+    pub fn on_progress<F: Fn(&BulkIndexProgress) + Send + Sync + 'static>(
+        mut self,
+        on_progress: F,
+    ) -> Self {
+        self.on_progress = Some(Arc::new(on_progress));
+        self
+    }
+
+    /// Index the documents of a stream.
+    ///
+    /// Failed batches, and documents rejected by a tolerant update chain, are reported through [BulkIndexer::on_progress]
+    /// and counted in the summary, without stopping the indexing.
+    /// An error is only returned if a document can not be serialized, or if the final commit fails.
+    ///
+    /// This is not meant to be used directly. Use [AsyncSolrCloudClient::bulk_index](crate::clients::async_cloud_client::AsyncSolrCloudClient::bulk_index) instead.
+    pub async fn execute<C, S, D, St>(
+        &self,
+        context: C,
+        collection: S,
+        docs: St,
+    ) -> Result<BulkIndexSummary, Error>
+    where
+        C: AsRef<SolrServerContext>,
+        S: AsRef<str>,
+        D: Serialize,
+        St: Stream<Item = D>,
+    {
+        let context = context.as_ref();
+        let collection = collection.as_ref();
+        let mut summary = BulkIndexSummary::default();
+        let query_params = self.batch_query().to_query_params();
+        let query_params = &query_params;
+        let outcomes = into_batches(docs, self.batch_size, self.batch_bytes)
+            .enumerate()
+            .map(|(number, batch)| async move {
+                let batch = batch?;
+                Ok::<_, Error>(
+                    self.send_batch(context, collection, query_params, number, batch)
+                        .await,
+                )
+            })
+            .buffer_unordered(self.concurrency);
+        let mut outcomes = pin!(outcomes);
+        while let Some(outcome) = outcomes.try_next().await? {
+            summary.add(&outcome);
+            if let Some(on_progress) = &self.on_progress {
+                on_progress(&BulkIndexProgress {
+                    batch: outcome.number,
+                    batch_docs: outcome.docs,
+                    attempts: outcome.attempts,
+                    docs_indexed: summary.docs_indexed,
+                    docs_failed: summary.docs_failed,
+                    error: outcome.error.as_ref(),
+                    update_errors: &outcome.update_errors,
+                });
+            }
+        }
+        self.commit(context, collection).await?;
+        Ok(summary)
+    }
+
+    /// The query every batch is sent with. Only `commitWithin` is sent with the batches, other commits are left to [BulkIndexer::commit].
+    fn batch_query(&self) -> UpdateQuery {
+        let commit_type = match self.update_query.commit_options.commit_type {
+            CommitType::CommitWithin(ms) => CommitType::CommitWithin(ms),
+            _ => CommitType::NoCommit,
+        };
+        self.update_query.clone().commit_type(commit_type)
+    }
+
+    /// A copy of the indexer that leaves the final commit to the caller, through [BulkIndexer::commit].
+    pub(crate) fn without_commit(&self) -> BulkIndexer {
+        self.clone().update_query(self.batch_query())
+    }
+
+    /// Issue the final commit with the commit options of the update query, unless the commit type does not commit.
+    pub(crate) async fn commit(
+        &self,
+        context: &SolrServerContext,
+        collection: &str,
+    ) -> Result<(), Error> {
+        if self.update_query.commit_options.commit_type.commits() {
+            UpdateQuery::new()
+                .handler(self.update_query.handler.as_str())
+                .commit_options(&self.update_query.commit_options)
+                .execute(context, collection, &[] as &[Value])
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Index the documents of an iterator.
+    ///
+    /// The iterator is read from the async task, so it should not block.
+    pub async fn execute_iter<C, S, D, I>(
+        &self,
+        context: C,
+        collection: S,
+        docs: I,
+    ) -> Result<BulkIndexSummary, Error>
+    where
+        C: AsRef<SolrServerContext>,
+        S: AsRef<str>,
+        D: Serialize,
+        I: IntoIterator<Item = D>,
+    {
+        self.execute(context, collection, stream::iter(docs)).await
+    }
+
+    /// Index the documents received on a channel, until all senders are dropped.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{BulkIndexer, SolrServerContextBuilder, SolrSingleServerHost};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let (sender, receiver) = tokio::sync::mpsc::channel(10_000);
+    /// tokio::spawn(async move {
+    ///     for i in 0..1_000_000 {
+    ///         sender.send(serde_json::json!({"id": i.to_string()})).await.unwrap();
+    ///     }
+    /// });
+    /// let summary = BulkIndexer::new().execute_channel(&context, "collection_name", receiver).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_channel<C, S, D>(
+        &self,
+        context: C,
+        collection: S,
+        docs: Receiver<D>,
+    ) -> Result<BulkIndexSummary, Error>
+    where
+        C: AsRef<SolrServerContext>,
+        S: AsRef<str>,
+        D: Serialize,
+    {
+        let docs = stream::unfold(docs, |mut docs| async move {
+            docs.recv().await.map(|doc| (doc, docs))
+        });
+        self.execute(context, collection, docs).await
+    }
+
+    async fn send_batch(
+        &self,
+        context: &SolrServerContext,
+        collection: &str,
+        query_params: &[(&str, String)],
+        number: usize,
+        batch: DocBatch,
+    ) -> BatchOutcome {
+        let url = format!("/solr/{}/{}", collection, self.update_query.handler);
+        let query_params = query_params
+            .iter()
+            .map(|(key, value)| (*key, value.as_str()))
+            .collect::<Vec<_>>();
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let result = SolrRequestBuilder::new(context, url.as_str())
+                .with_query_params(&query_params)
+                .with_headers([("Content-Type", "application/json")])
+                .send_post_with_body::<_, SolrResponse>(batch.body.clone())
+                .await;
+            match result {
+                Err(e) if attempts <= self.max_retries && is_transient(&e) => {
+                    tokio::time::sleep(self.backoff(attempts)).await;
+                }
+                result => {
+                    let (update_errors, error) = match result {
+                        Ok(response) => (response.get_update_errors().to_vec(), None),
+                        Err(e) => (Vec::new(), Some(e)),
+                    };
+                    return BatchOutcome {
+                        number,
+                        docs: batch.docs,
+                        attempts,
+                        error,
+                        update_errors,
+                    };
+                }
+            }
+        }
+    }
+
+    /// Exponential backoff, with jitter so concurrent batches do not retry at the same time.
+    fn backoff(&self, attempt: usize) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1) as u32);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        backoff / 2 + backoff.mul_f64(fastrand::f64() / 2.0)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BulkIndexer {
+    /// Index the documents of an iterator.
+    ///
+    /// This is not meant to be used directly. Use [BlockingSolrCloudClient::bulk_index](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::bulk_index) instead.
+    pub fn execute_blocking<C, S, D, I>(
+        &self,
+        context: C,
+        collection: S,
+        docs: I,
+    ) -> Result<BulkIndexSummary, Error>
+    where
+        C: AsRef<SolrServerContext>,
+        S: AsRef<str>,
+        D: Serialize,
+        I: IntoIterator<Item = D>,
+    {
+        RUNTIME
+            .handle()
+            .block_on(self.execute_iter(context, collection, docs))
+    }
+}
+
+/// Progress of a [BulkIndexer], reported after every batch.
+#[derive(Debug)]
+pub struct BulkIndexProgress<'a> {
+    batch: usize,
+    batch_docs: usize,
+    attempts: usize,
+    docs_indexed: usize,
+    docs_failed: usize,
+    error: Option<&'a Error>,
+    update_errors: &'a [SolrUpdateError],
+}
+
+impl BulkIndexProgress<'_> {
+    /// The number of the batch, counting from 0 in the order the documents were read.
+    pub fn get_batch(&self) -> usize {
+        self.batch
+    }
+
+    /// The number of documents in the batch.
+    pub fn get_batch_docs(&self) -> usize {
+        self.batch_docs
+    }
+
+    /// The number of times the batch was sent, including retries.
+    pub fn get_attempts(&self) -> usize {
+        self.attempts
+    }
+
+    /// The total number of documents indexed so far.
+    pub fn get_docs_indexed(&self) -> usize {
+        self.docs_indexed
+    }
+
+    /// The total number of documents in failed batches, or rejected by a tolerant update chain, so far.
+    pub fn get_docs_failed(&self) -> usize {
+        self.docs_failed
+    }
+
+    /// The error of the batch, if it failed after all retries.
+    pub fn get_error(&self) -> Option<&Error> {
+        self.error
+    }
+
+    /// The documents of the batch rejected by a tolerant update chain, see [UpdateQuery::max_errors].
+    pub fn get_update_errors(&self) -> &[SolrUpdateError] {
+        self.update_errors
+    }
+}
+
+/// The result of a [BulkIndexer] run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BulkIndexSummary {
+    batches: usize,
+    failed_batches: usize,
+    docs_indexed: usize,
+    docs_failed: usize,
+    retries: usize,
+}
+
+impl BulkIndexSummary {
+    /// The number of batches sent.
+    pub fn get_batches(&self) -> usize {
+        self.batches
+    }
+
+    /// The number of batches that failed after all retries.
+    pub fn get_failed_batches(&self) -> usize {
+        self.failed_batches
+    }
+
+    /// The number of documents indexed.
+    pub fn get_docs_indexed(&self) -> usize {
+        self.docs_indexed
+    }
+
+    /// The number of documents in failed batches, or rejected by a tolerant update chain.
+    pub fn get_docs_failed(&self) -> usize {
+        self.docs_failed
+    }
+
+    /// The number of retries across all batches.
+    pub fn get_retries(&self) -> usize {
+        self.retries
+    }
+
+    fn add(&mut self, outcome: &BatchOutcome) {
+        self.batches += 1;
+        self.retries += outcome.attempts - 1;
+        match outcome.error {
+            None => {
+                self.docs_indexed += outcome.docs.saturating_sub(outcome.update_errors.len());
+                self.docs_failed += outcome.update_errors.len();
+            }
+            Some(_) => {
+                self.failed_batches += 1;
+                self.docs_failed += outcome.docs;
+            }
+        }
+    }
+}
+
+struct BatchOutcome {
+    number: usize,
+    docs: usize,
+    attempts: usize,
+    error: Option<Error>,
+    update_errors: Vec<SolrUpdateError>,
+}
+
+/// Documents serialized into a JSON array.
+struct DocBatch {
+    body: Vec<u8>,
+    docs: usize,
+}
+
+impl DocBatch {
+    fn new() -> Self {
+        DocBatch {
+            body: vec![b'['],
+            docs: 0,
+        }
+    }
+
+    /// The size of the finished batch if `doc` is added.
+    fn size_with(&self, doc: &[u8]) -> usize {
+        self.body.len() + doc.len() + 2
+    }
+
+    fn push(&mut self, doc: Vec<u8>) {
+        if self.docs > 0 {
+            self.body.push(b',');
+        }
+        self.body.extend(doc);
+        self.docs += 1;
+    }
+
+    fn finish(mut self) -> Self {
+        self.body.push(b']');
+        self
+    }
+}
+
+/// Serialize the documents into batches, limited by both count and size.
+fn into_batches<D: Serialize, St: Stream<Item = D>>(
+    docs: St,
+    max_docs: usize,
+    max_bytes: usize,
+) -> impl Stream<Item = Result<DocBatch, Error>> {
+    let docs: Pin<Box<St>> = Box::pin(docs);
+    stream::unfold((docs, None), move |(mut docs, carry)| async move {
+        let mut batch = DocBatch::new();
+        if let Some(doc) = carry {
+            batch.push(doc);
+        }
+        let mut carry = None;
+        while batch.docs < max_docs {
+            let Some(doc) = docs.next().await else {
+                break;
+            };
+            let doc = match serde_json::to_vec(&doc) {
+                Ok(doc) => doc,
+                Err(e) => return Some((Err(e.into()), (docs, None))),
+            };
+            if batch.docs > 0 && batch.size_with(&doc) > max_bytes {
+                carry = Some(doc);
+                break;
+            }
+            batch.push(doc);
+        }
+        match batch.docs {
+            0 => None,
+            _ => Some((Ok(batch.finish()), (docs, carry))),
+        }
+    })
+}
+
+/// Errors where sending the same batch again might succeed.
+fn is_transient(error: &Error) -> bool {
+    match error {
+        Error::ReqwestError(e) => e.is_connect() || e.is_timeout(),
+        Error::SolrConnectionError { code, .. } | Error::SolrResponseError { code, .. } => {
+            *code == 429 || *code >= 500
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::models::commit_type::CommitType;
+    use crate::models::response::SolrUpdateError;
+    use crate::queries::bulk_index::{
+        into_batches, is_transient, BatchOutcome, BulkIndexSummary, BulkIndexer,
+    };
+    use crate::queries::index::{UpdateCommitOptions, UpdateQuery};
+    use futures::{stream, StreamExt};
+    use serde_json::{json, Value};
+    use std::time::Duration;
+
+    async fn batch_bodies(docs: Vec<Value>, max_docs: usize, max_bytes: usize) -> Vec<Value> {
+        into_batches(stream::iter(docs), max_docs, max_bytes)
+            .map(|batch| serde_json::from_slice::<Value>(&batch.unwrap().body).unwrap())
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn batches_are_limited_by_count() {
+        let docs = (0..5).map(|i| json!({"id": i})).collect::<Vec<_>>();
+        let batches = batch_bodies(docs, 2, usize::MAX).await;
+        assert_eq!(
+            batches,
+            vec![
+                json!([{"id": 0}, {"id": 1}]),
+                json!([{"id": 2}, {"id": 3}]),
+                json!([{"id": 4}])
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn batches_are_limited_by_bytes() {
+        let docs = vec![
+            json!({"id": "a"}),
+            json!({"id": "b"}),
+            json!({"id": "large_document"}),
+        ];
+        // `[{"id":"a"},{"id":"b"}]` is 23 bytes
+        let batches = batch_bodies(docs, 100, 23).await;
+        assert_eq!(
+            batches,
+            vec![
+                json!([{"id": "a"}, {"id": "b"}]),
+                json!([{"id": "large_document"}])
+            ]
+        );
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let indexer = BulkIndexer::new()
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(4));
+        let first = indexer.backoff(1);
+        assert!(first >= Duration::from_millis(500) && first <= Duration::from_secs(1));
+        let capped = indexer.backoff(10);
+        assert!(capped >= Duration::from_secs(2) && capped <= Duration::from_secs(4));
+    }
+
+    #[test]
+    fn batches_use_the_update_query_without_committing() {
+        let query = UpdateQuery::new()
+            .commit_options(
+                UpdateCommitOptions::new()
+                    .commit_type(CommitType::Soft)
+                    .overwrite(false),
+            )
+            .max_errors(-1)
+            .update_chain("tolerant");
+        let indexer = BulkIndexer::new().update_query(&query);
+        assert_eq!(
+            indexer.batch_query().to_query_params(),
+            vec![
+                ("overwrite", "false".to_string()),
+                ("maxErrors", "-1".to_string()),
+                ("update.chain", "tolerant".to_string())
+            ]
+        );
+        let indexer =
+            BulkIndexer::new().update_query(query.commit_type(CommitType::CommitWithin(500)));
+        assert_eq!(
+            indexer.batch_query().to_query_params()[0],
+            ("commitWithin", "500".to_string())
+        );
+    }
+
+    #[test]
+    fn only_unavailable_servers_are_transient() {
+        let response_error = |code| Error::SolrResponseError {
+            code,
+            url: "http://localhost:8983/solr/collection/update".to_string(),
+            msg: String::new(),
+        };
+        assert!(is_transient(&response_error(503)));
+        assert!(is_transient(&response_error(429)));
+        assert!(!is_transient(&response_error(400)));
+        let builder_error = reqwest::Client::new().get("not a url").build().unwrap_err();
+        assert!(!is_transient(&Error::from(builder_error)));
+    }
+
+    #[test]
+    fn update_errors_are_counted_as_failed() {
+        let error: SolrUpdateError = serde_json::from_value(json!({
+            "type": "ADD",
+            "id": "document1",
+            "message": "Document is missing mandatory uniqueKey field: id"
+        }))
+        .unwrap();
+        let mut summary = BulkIndexSummary::default();
+        summary.add(&BatchOutcome {
+            number: 0,
+            docs: 10,
+            attempts: 1,
+            error: None,
+            update_errors: vec![error],
+        });
+        assert_eq!(summary.get_docs_indexed(), 9);
+        assert_eq!(summary.get_docs_failed(), 1);
+        assert_eq!(summary.get_failed_batches(), 0);
+    }
+}
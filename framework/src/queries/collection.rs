@@ -1,8 +1,1353 @@
 use crate::error::Error;
+use crate::models::cluster_status::{ClusterStatus, ReplicaType};
+use crate::models::col_status::ColStatus;
 use crate::models::context::SolrServerContext;
 use crate::models::SolrResponse;
 use crate::queries::request_builder::SolrRequestBuilder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::Duration;
 
+/// How documents are assigned to the shards of a collection.
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum CollectionRouter {
+    /// Hash the unique key, or a `shard_key!` prefix of it, into a range of a shard. The default.
+    CompositeId,
+    /// Route each document to the shard named in its `_route_` parameter or router field.
+    /// Shards are created by name with [CreateCollectionOptions::shards].
+    Implicit,
+}
+
+impl CollectionRouter {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CollectionRouter::CompositeId => "compositeId",
+            CollectionRouter::Implicit => "implicit",
+        }
+    }
+}
+
+/// Options for the Collections API `CREATE` action, used by [create_collection_with_options].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::{CollectionRouter, CreateCollectionOptions};
+/// let options = CreateCollectionOptions::new()
+///     .config_name("config_name")
+///     .router(CollectionRouter::Implicit)
+///     .shards(["2023", "2024"])
+///     .nrt_replicas(1)
+///     .pull_replicas(1)
+///     .properties([("solr.autoSoftCommit.maxTime", "5000")]);
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct CreateCollectionOptions {
+    config_name: Option<String>,
+    num_shards: Option<usize>,
+    replication_factor: Option<usize>,
+    router: Option<CollectionRouter>,
+    router_field: Option<String>,
+    shards: Option<Vec<String>>,
+    nrt_replicas: Option<usize>,
+    tlog_replicas: Option<usize>,
+    pull_replicas: Option<usize>,
+    create_node_set: Option<Vec<String>>,
+    per_replica_state: Option<bool>,
+    properties: Option<Vec<(String, String)>>,
+    wait_for_final_state: Option<bool>,
+    async_id: Option<String>,
+}
+
+impl AsRef<CreateCollectionOptions> for CreateCollectionOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&CreateCollectionOptions> for CreateCollectionOptions {
+    fn from(options: &CreateCollectionOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl CreateCollectionOptions {
+    /// Create options with everything left to Solr's defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The config set of the collection. Default is `_default`.
+    pub fn config_name<S: Into<String>, O: Into<Option<S>>>(mut self, config_name: O) -> Self {
+        self.config_name = config_name.into().map(|x| x.into());
+        self
+    }
+
+    /// The number of shards, for the `compositeId` router.
+    pub fn num_shards<O: Into<Option<usize>>>(mut self, num_shards: O) -> Self {
+        self.num_shards = num_shards.into();
+        self
+    }
+
+    /// The number of NRT replicas per shard. Same as [CreateCollectionOptions::nrt_replicas].
+    pub fn replication_factor<O: Into<Option<usize>>>(mut self, replication_factor: O) -> Self {
+        self.replication_factor = replication_factor.into();
+        self
+    }
+
+    /// The router of the collection. Default is [CollectionRouter::CompositeId].
+    pub fn router<O: Into<Option<CollectionRouter>>>(mut self, router: O) -> Self {
+        self.router = router.into();
+        self
+    }
+
+    /// Route on this field instead of the unique key. With the implicit router it holds the name of the shard.
+    pub fn router_field<S: Into<String>, O: Into<Option<S>>>(mut self, router_field: O) -> Self {
+        self.router_field = router_field.into().map(|x| x.into());
+        self
+    }
+
+    /// The names of the shards, required for the implicit router.
+    pub fn shards<S: Into<String>, V: IntoIterator<Item = S>, O: Into<Option<V>>>(
+        mut self,
+        shards: O,
+    ) -> Self {
+        self.shards = shards
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// The number of NRT replicas per shard, which index and search.
+    pub fn nrt_replicas<O: Into<Option<usize>>>(mut self, nrt_replicas: O) -> Self {
+        self.nrt_replicas = nrt_replicas.into();
+        self
+    }
+
+    /// The number of TLOG replicas per shard, which copy the index from the leader but can become leader.
+    pub fn tlog_replicas<O: Into<Option<usize>>>(mut self, tlog_replicas: O) -> Self {
+        self.tlog_replicas = tlog_replicas.into();
+        self
+    }
+
+    /// The number of PULL replicas per shard, which only copy the index from the leader.
+    pub fn pull_replicas<O: Into<Option<usize>>>(mut self, pull_replicas: O) -> Self {
+        self.pull_replicas = pull_replicas.into();
+        self
+    }
+
+    /// Only place replicas on these nodes. Eg: `localhost:8983_solr`. Pass `EMPTY` to create no replicas.
+    pub fn create_node_set<S: Into<String>, V: IntoIterator<Item = S>, O: Into<Option<V>>>(
+        mut self,
+        create_node_set: O,
+    ) -> Self {
+        self.create_node_set = create_node_set
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// Keep the state of each replica in its own ZooKeeper node, which scales better for large collections.
+    pub fn per_replica_state<O: Into<Option<bool>>>(mut self, per_replica_state: O) -> Self {
+        self.per_replica_state = per_replica_state.into();
+        self
+    }
+
+    /// Core properties, sent as `property.name=value`. They can be used as variables in the config set.
+    pub fn properties<K: Into<String>, V: Into<String>, I: IntoIterator<Item = (K, V)>>(
+        mut self,
+        properties: I,
+    ) -> Self {
+        self.properties = Some(
+            properties
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Wait until all replicas are active before returning.
+    pub fn wait_for_final_state<O: Into<Option<bool>>>(mut self, wait_for_final_state: O) -> Self {
+        self.wait_for_final_state = wait_for_final_state.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        let options = [
+            ("collection.configName", self.config_name.clone()),
+            ("numShards", self.num_shards.map(|x| x.to_string())),
+            (
+                "replicationFactor",
+                self.replication_factor.map(|x| x.to_string()),
+            ),
+            ("router.name", self.router.map(|x| x.as_str().to_string())),
+            ("router.field", self.router_field.clone()),
+            ("shards", self.shards.as_ref().map(|x| x.join(","))),
+            ("nrtReplicas", self.nrt_replicas.map(|x| x.to_string())),
+            ("tlogReplicas", self.tlog_replicas.map(|x| x.to_string())),
+            ("pullReplicas", self.pull_replicas.map(|x| x.to_string())),
+            (
+                "createNodeSet",
+                self.create_node_set.as_ref().map(|x| x.join(",")),
+            ),
+            (
+                "perReplicaState",
+                self.per_replica_state.map(|x| x.to_string()),
+            ),
+            (
+                "waitForFinalState",
+                self.wait_for_final_state.map(|x| x.to_string()),
+            ),
+            ("async", self.async_id.clone()),
+        ];
+        let mut query_params = to_query_params(options);
+        query_params.extend(property_params(&self.properties));
+        query_params
+    }
+}
+
+/// How [split_shard] divides the index of the parent shard.
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum SplitMethod {
+    /// Write new indexes for the sub-shards. Slower, but the sub-shards are compact. The default.
+    Rewrite,
+    /// Hard link the index files into the sub-shards, and delete the documents outside their ranges.
+    /// Faster and uses less disk, but the sub-shards are as large as the parent until merged.
+    Link,
+}
+
+impl SplitMethod {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SplitMethod::Rewrite => "rewrite",
+            SplitMethod::Link => "link",
+        }
+    }
+}
+
+/// Options for the Collections API `SPLITSHARD` action, used by [split_shard].
+///
+/// Either the shard or a split key has to be given.
+/// The parent shard stays, as `inactive`, until it is deleted with [delete_shard].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::{SplitMethod, SplitShardOptions};
+/// let options = SplitShardOptions::new()
+///     .shard("shard1")
+///     .num_sub_shards(4)
+///     .split_method(SplitMethod::Link)
+///     .async_id("split-shard1");
+/// let options = SplitShardOptions::new().split_key("customer_a!");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct SplitShardOptions {
+    shard: Option<String>,
+    split_key: Option<String>,
+    ranges: Option<Vec<String>>,
+    num_sub_shards: Option<usize>,
+    split_method: Option<SplitMethod>,
+    wait_for_final_state: Option<bool>,
+    async_id: Option<String>,
+}
+
+impl AsRef<SplitShardOptions> for SplitShardOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&SplitShardOptions> for SplitShardOptions {
+    fn from(options: &SplitShardOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl SplitShardOptions {
+    /// Create a new instance of SplitShardOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The shard to split. Eg: `shard1`
+    pub fn shard<S: Into<String>, O: Into<Option<S>>>(mut self, shard: O) -> Self {
+        self.shard = shard.into().map(|x| x.into());
+        self
+    }
+
+    /// Split the shard holding this route key, so that the documents of the key get a sub-shard of their own. Eg: `customer_a!`
+    pub fn split_key<S: Into<String>, O: Into<Option<S>>>(mut self, split_key: O) -> Self {
+        self.split_key = split_key.into().map(|x| x.into());
+        self
+    }
+
+    /// The hash ranges of the sub-shards, in hexadecimal. They have to cover the range of the shard. Eg: `["0-1f4", "1f5-3e8"]`
+    pub fn ranges<S: Into<String>, V: IntoIterator<Item = S>, O: Into<Option<V>>>(
+        mut self,
+        ranges: O,
+    ) -> Self {
+        self.ranges = ranges
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// Split into this many sub-shards of equal ranges. Default is 2.
+    pub fn num_sub_shards<O: Into<Option<usize>>>(mut self, num_sub_shards: O) -> Self {
+        self.num_sub_shards = num_sub_shards.into();
+        self
+    }
+
+    /// How to divide the index. Default is [SplitMethod::Rewrite].
+    pub fn split_method<O: Into<Option<SplitMethod>>>(mut self, split_method: O) -> Self {
+        self.split_method = split_method.into();
+        self
+    }
+
+    /// Wait until the replicas of the sub-shards are active before returning.
+    pub fn wait_for_final_state<O: Into<Option<bool>>>(mut self, wait_for_final_state: O) -> Self {
+        self.wait_for_final_state = wait_for_final_state.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([
+            ("shard", self.shard.clone()),
+            ("split.key", self.split_key.clone()),
+            ("ranges", self.ranges.as_ref().map(|x| x.join(","))),
+            ("numSubShards", self.num_sub_shards.map(|x| x.to_string())),
+            (
+                "splitMethod",
+                self.split_method.map(|x| x.as_str().to_string()),
+            ),
+            (
+                "waitForFinalState",
+                self.wait_for_final_state.map(|x| x.to_string()),
+            ),
+            ("async", self.async_id.clone()),
+        ])
+    }
+}
+
+/// Options for the Collections API `CREATESHARD` action, used by [create_shard].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::CreateShardOptions;
+/// let options = CreateShardOptions::new()
+///     .nrt_replicas(1)
+///     .pull_replicas(1)
+///     .create_node_set(["localhost:8983_solr"]);
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct CreateShardOptions {
+    nrt_replicas: Option<usize>,
+    tlog_replicas: Option<usize>,
+    pull_replicas: Option<usize>,
+    create_node_set: Option<Vec<String>>,
+    properties: Option<Vec<(String, String)>>,
+    wait_for_final_state: Option<bool>,
+    async_id: Option<String>,
+}
+
+impl AsRef<CreateShardOptions> for CreateShardOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&CreateShardOptions> for CreateShardOptions {
+    fn from(options: &CreateShardOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl CreateShardOptions {
+    /// Create a new instance of CreateShardOptions. Replicas default to the replica counts of the collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of NRT replicas of the shard.
+    pub fn nrt_replicas<O: Into<Option<usize>>>(mut self, nrt_replicas: O) -> Self {
+        self.nrt_replicas = nrt_replicas.into();
+        self
+    }
+
+    /// The number of TLOG replicas of the shard.
+    pub fn tlog_replicas<O: Into<Option<usize>>>(mut self, tlog_replicas: O) -> Self {
+        self.tlog_replicas = tlog_replicas.into();
+        self
+    }
+
+    /// The number of PULL replicas of the shard.
+    pub fn pull_replicas<O: Into<Option<usize>>>(mut self, pull_replicas: O) -> Self {
+        self.pull_replicas = pull_replicas.into();
+        self
+    }
+
+    /// Only place replicas on these nodes. Eg: `localhost:8983_solr`
+    pub fn create_node_set<S: Into<String>, V: IntoIterator<Item = S>, O: Into<Option<V>>>(
+        mut self,
+        create_node_set: O,
+    ) -> Self {
+        self.create_node_set = create_node_set
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// Core properties, sent as `property.name=value`.
+    pub fn properties<K: Into<String>, V: Into<String>, I: IntoIterator<Item = (K, V)>>(
+        mut self,
+        properties: I,
+    ) -> Self {
+        self.properties = Some(
+            properties
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Wait until all replicas are active before returning.
+    pub fn wait_for_final_state<O: Into<Option<bool>>>(mut self, wait_for_final_state: O) -> Self {
+        self.wait_for_final_state = wait_for_final_state.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        let mut query_params = to_query_params([
+            ("nrtReplicas", self.nrt_replicas.map(|x| x.to_string())),
+            ("tlogReplicas", self.tlog_replicas.map(|x| x.to_string())),
+            ("pullReplicas", self.pull_replicas.map(|x| x.to_string())),
+            (
+                "createNodeSet",
+                self.create_node_set.as_ref().map(|x| x.join(",")),
+            ),
+            (
+                "waitForFinalState",
+                self.wait_for_final_state.map(|x| x.to_string()),
+            ),
+            ("async", self.async_id.clone()),
+        ]);
+        query_params.extend(property_params(&self.properties));
+        query_params
+    }
+}
+
+/// Options for the Collections API `DELETESHARD` action, used by [delete_shard].
+///
+/// By default the index, data and instance directories of the replicas are deleted.
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::DeleteShardOptions;
+/// let options = DeleteShardOptions::new().delete_instance_dir(false).async_id("delete-shard1");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct DeleteShardOptions {
+    delete_instance_dir: Option<bool>,
+    delete_data_dir: Option<bool>,
+    delete_index: Option<bool>,
+    async_id: Option<String>,
+}
+
+impl AsRef<DeleteShardOptions> for DeleteShardOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&DeleteShardOptions> for DeleteShardOptions {
+    fn from(options: &DeleteShardOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl DeleteShardOptions {
+    /// Create a new instance of DeleteShardOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Delete the instance directories of the cores. Default is true.
+    pub fn delete_instance_dir<O: Into<Option<bool>>>(mut self, delete_instance_dir: O) -> Self {
+        self.delete_instance_dir = delete_instance_dir.into();
+        self
+    }
+
+    /// Delete the data directories of the cores. Default is true.
+    pub fn delete_data_dir<O: Into<Option<bool>>>(mut self, delete_data_dir: O) -> Self {
+        self.delete_data_dir = delete_data_dir.into();
+        self
+    }
+
+    /// Delete the indexes of the cores. Default is true.
+    pub fn delete_index<O: Into<Option<bool>>>(mut self, delete_index: O) -> Self {
+        self.delete_index = delete_index.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([
+            (
+                "deleteInstanceDir",
+                self.delete_instance_dir.map(|x| x.to_string()),
+            ),
+            ("deleteDataDir", self.delete_data_dir.map(|x| x.to_string())),
+            ("deleteIndex", self.delete_index.map(|x| x.to_string())),
+            ("async", self.async_id.clone()),
+        ])
+    }
+}
+
+/// Options for the Collections API `ADDREPLICA` action, used by [add_replica].
+/// # Examples
+/// ```no_run
+/// use solrstice::models::ReplicaType;
+/// use solrstice::queries::collection::AddReplicaOptions;
+/// let options = AddReplicaOptions::new()
+///     .replica_type(ReplicaType::Tlog)
+///     .node("localhost:8983_solr");
+/// let options = AddReplicaOptions::new().pull_replicas(2);
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct AddReplicaOptions {
+    replica_type: Option<ReplicaType>,
+    node: Option<String>,
+    create_node_set: Option<Vec<String>>,
+    nrt_replicas: Option<usize>,
+    tlog_replicas: Option<usize>,
+    pull_replicas: Option<usize>,
+    properties: Option<Vec<(String, String)>>,
+    wait_for_final_state: Option<bool>,
+    async_id: Option<String>,
+}
+
+impl AsRef<AddReplicaOptions> for AddReplicaOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&AddReplicaOptions> for AddReplicaOptions {
+    fn from(options: &AddReplicaOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl AddReplicaOptions {
+    /// Create a new instance of AddReplicaOptions. Adds one NRT replica on the node Solr picks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The type of the replica. Default is [ReplicaType::Nrt].
+    pub fn replica_type<O: Into<Option<ReplicaType>>>(mut self, replica_type: O) -> Self {
+        self.replica_type = replica_type.into();
+        self
+    }
+
+    /// Place the replica on this node. Eg: `localhost:8983_solr`
+    pub fn node<S: Into<String>, O: Into<Option<S>>>(mut self, node: O) -> Self {
+        self.node = node.into().map(|x| x.into());
+        self
+    }
+
+    /// Place the replicas on these nodes.
+    pub fn create_node_set<S: Into<String>, V: IntoIterator<Item = S>, O: Into<Option<V>>>(
+        mut self,
+        create_node_set: O,
+    ) -> Self {
+        self.create_node_set = create_node_set
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// Add this many NRT replicas, instead of a single replica of [AddReplicaOptions::replica_type].
+    pub fn nrt_replicas<O: Into<Option<usize>>>(mut self, nrt_replicas: O) -> Self {
+        self.nrt_replicas = nrt_replicas.into();
+        self
+    }
+
+    /// Add this many TLOG replicas.
+    pub fn tlog_replicas<O: Into<Option<usize>>>(mut self, tlog_replicas: O) -> Self {
+        self.tlog_replicas = tlog_replicas.into();
+        self
+    }
+
+    /// Add this many PULL replicas.
+    pub fn pull_replicas<O: Into<Option<usize>>>(mut self, pull_replicas: O) -> Self {
+        self.pull_replicas = pull_replicas.into();
+        self
+    }
+
+    /// Core properties, sent as `property.name=value`.
+    pub fn properties<K: Into<String>, V: Into<String>, I: IntoIterator<Item = (K, V)>>(
+        mut self,
+        properties: I,
+    ) -> Self {
+        self.properties = Some(
+            properties
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Wait until the replicas are active before returning.
+    pub fn wait_for_final_state<O: Into<Option<bool>>>(mut self, wait_for_final_state: O) -> Self {
+        self.wait_for_final_state = wait_for_final_state.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        let mut query_params = to_query_params([
+            ("type", self.replica_type.map(|x| x.as_str().to_string())),
+            ("node", self.node.clone()),
+            (
+                "createNodeSet",
+                self.create_node_set.as_ref().map(|x| x.join(",")),
+            ),
+            ("nrtReplicas", self.nrt_replicas.map(|x| x.to_string())),
+            ("tlogReplicas", self.tlog_replicas.map(|x| x.to_string())),
+            ("pullReplicas", self.pull_replicas.map(|x| x.to_string())),
+            (
+                "waitForFinalState",
+                self.wait_for_final_state.map(|x| x.to_string()),
+            ),
+            ("async", self.async_id.clone()),
+        ]);
+        query_params.extend(property_params(&self.properties));
+        query_params
+    }
+}
+
+/// Options for the Collections API `DELETEREPLICA` action, used by [delete_replica].
+///
+/// Replicas are deleted either by name, with the shard and replica, by count, keeping at least one replica per shard,
+/// or by node, deleting all replicas of the collection on it.
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::DeleteReplicaOptions;
+/// let by_name = DeleteReplicaOptions::new().shard("shard1").replica("core_node3");
+/// let by_count = DeleteReplicaOptions::new().shard("shard1").count(2);
+/// let by_node = DeleteReplicaOptions::new().node("localhost:8983_solr");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct DeleteReplicaOptions {
+    shard: Option<String>,
+    replica: Option<String>,
+    count: Option<usize>,
+    replica_type: Option<ReplicaType>,
+    node: Option<String>,
+    only_if_down: Option<bool>,
+    delete_instance_dir: Option<bool>,
+    delete_data_dir: Option<bool>,
+    delete_index: Option<bool>,
+    async_id: Option<String>,
+}
+
+impl AsRef<DeleteReplicaOptions> for DeleteReplicaOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&DeleteReplicaOptions> for DeleteReplicaOptions {
+    fn from(options: &DeleteReplicaOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl DeleteReplicaOptions {
+    /// Create a new instance of DeleteReplicaOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The shard of the replica. Without it, replicas are deleted by count from every shard.
+    pub fn shard<S: Into<String>, O: Into<Option<S>>>(mut self, shard: O) -> Self {
+        self.shard = shard.into().map(|x| x.into());
+        self
+    }
+
+    /// The name of the replica to delete. Eg: `core_node3`
+    pub fn replica<S: Into<String>, O: Into<Option<S>>>(mut self, replica: O) -> Self {
+        self.replica = replica.into().map(|x| x.into());
+        self
+    }
+
+    /// Delete this many replicas per shard. Nothing is deleted if it would leave a shard without replicas.
+    pub fn count<O: Into<Option<usize>>>(mut self, count: O) -> Self {
+        self.count = count.into();
+        self
+    }
+
+    /// Only delete replicas of this type, when deleting by count.
+    pub fn replica_type<O: Into<Option<ReplicaType>>>(mut self, replica_type: O) -> Self {
+        self.replica_type = replica_type.into();
+        self
+    }
+
+    /// Delete the replicas of the collection on this node. Eg: `localhost:8983_solr`
+    pub fn node<S: Into<String>, O: Into<Option<S>>>(mut self, node: O) -> Self {
+        self.node = node.into().map(|x| x.into());
+        self
+    }
+
+    /// Only delete the replica if it is not active.
+    pub fn only_if_down<O: Into<Option<bool>>>(mut self, only_if_down: O) -> Self {
+        self.only_if_down = only_if_down.into();
+        self
+    }
+
+    /// Delete the instance directory of the core. Default is true.
+    pub fn delete_instance_dir<O: Into<Option<bool>>>(mut self, delete_instance_dir: O) -> Self {
+        self.delete_instance_dir = delete_instance_dir.into();
+        self
+    }
+
+    /// Delete the data directory of the core. Default is true.
+    pub fn delete_data_dir<O: Into<Option<bool>>>(mut self, delete_data_dir: O) -> Self {
+        self.delete_data_dir = delete_data_dir.into();
+        self
+    }
+
+    /// Delete the index of the core. Default is true.
+    pub fn delete_index<O: Into<Option<bool>>>(mut self, delete_index: O) -> Self {
+        self.delete_index = delete_index.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([
+            ("shard", self.shard.clone()),
+            ("replica", self.replica.clone()),
+            ("count", self.count.map(|x| x.to_string())),
+            ("type", self.replica_type.map(|x| x.as_str().to_string())),
+            ("node", self.node.clone()),
+            ("onlyIfDown", self.only_if_down.map(|x| x.to_string())),
+            (
+                "deleteInstanceDir",
+                self.delete_instance_dir.map(|x| x.to_string()),
+            ),
+            ("deleteDataDir", self.delete_data_dir.map(|x| x.to_string())),
+            ("deleteIndex", self.delete_index.map(|x| x.to_string())),
+            ("async", self.async_id.clone()),
+        ])
+    }
+}
+
+/// Options for the Collections API `MOVEREPLICA` action, used by [move_replica].
+///
+/// The replica is given either by name, or by its shard and the node it is on.
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::MoveReplicaOptions;
+/// use std::time::Duration;
+/// let options = MoveReplicaOptions::new().replica("core_node3").timeout(Duration::from_secs(300));
+/// let options = MoveReplicaOptions::new().shard("shard1").source_node("localhost:8983_solr");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct MoveReplicaOptions {
+    replica: Option<String>,
+    shard: Option<String>,
+    source_node: Option<String>,
+    in_place_move: Option<bool>,
+    timeout: Option<Duration>,
+    async_id: Option<String>,
+}
+
+impl AsRef<MoveReplicaOptions> for MoveReplicaOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&MoveReplicaOptions> for MoveReplicaOptions {
+    fn from(options: &MoveReplicaOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl MoveReplicaOptions {
+    /// Create a new instance of MoveReplicaOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The name of the replica to move. Eg: `core_node3`
+    pub fn replica<S: Into<String>, O: Into<Option<S>>>(mut self, replica: O) -> Self {
+        self.replica = replica.into().map(|x| x.into());
+        self
+    }
+
+    /// The shard of the replica, used with [MoveReplicaOptions::source_node].
+    pub fn shard<S: Into<String>, O: Into<Option<S>>>(mut self, shard: O) -> Self {
+        self.shard = shard.into().map(|x| x.into());
+        self
+    }
+
+    /// The node the replica is on. Eg: `localhost:8983_solr`
+    pub fn source_node<S: Into<String>, O: Into<Option<S>>>(mut self, source_node: O) -> Self {
+        self.source_node = source_node.into().map(|x| x.into());
+        self
+    }
+
+    /// Move the replica without copying the index, for indexes on a shared file system.
+    pub fn in_place_move<O: Into<Option<bool>>>(mut self, in_place_move: O) -> Self {
+        self.in_place_move = in_place_move.into();
+        self
+    }
+
+    /// How long Solr waits for the new replica to become active. Sent in seconds. Default is 10 minutes.
+    pub fn timeout<O: Into<Option<Duration>>>(mut self, timeout: O) -> Self {
+        self.timeout = timeout.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([
+            ("replica", self.replica.clone()),
+            ("shard", self.shard.clone()),
+            ("sourceNode", self.source_node.clone()),
+            ("inPlaceMove", self.in_place_move.map(|x| x.to_string())),
+            ("timeout", self.timeout.map(|x| x.as_secs().to_string())),
+            ("async", self.async_id.clone()),
+        ])
+    }
+}
+
+/// Options for the Collections API `DELETENODE` action, used by [delete_node].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::DeleteNodeOptions;
+/// let options = DeleteNodeOptions::new().async_id("delete-8983");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct DeleteNodeOptions {
+    async_id: Option<String>,
+}
+
+impl AsRef<DeleteNodeOptions> for DeleteNodeOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&DeleteNodeOptions> for DeleteNodeOptions {
+    fn from(options: &DeleteNodeOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl DeleteNodeOptions {
+    /// Create a new instance of DeleteNodeOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([("async", self.async_id.clone())])
+    }
+}
+
+/// Options for the Collections API `REPLACENODE` action, used by [replace_node].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::ReplaceNodeOptions;
+/// let options = ReplaceNodeOptions::new().target_node("localhost:7574_solr").async_id("replace-8983");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct ReplaceNodeOptions {
+    target_node: Option<String>,
+    timeout: Option<Duration>,
+    async_id: Option<String>,
+}
+
+impl AsRef<ReplaceNodeOptions> for ReplaceNodeOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&ReplaceNodeOptions> for ReplaceNodeOptions {
+    fn from(options: &ReplaceNodeOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl ReplaceNodeOptions {
+    /// Create a new instance of ReplaceNodeOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the replicas to this node. Default is spreading them over the other live nodes.
+    pub fn target_node<S: Into<String>, O: Into<Option<S>>>(mut self, target_node: O) -> Self {
+        self.target_node = target_node.into().map(|x| x.into());
+        self
+    }
+
+    /// How long Solr waits for each new replica to become active. Sent in seconds. Default is 5 minutes.
+    pub fn timeout<O: Into<Option<Duration>>>(mut self, timeout: O) -> Self {
+        self.timeout = timeout.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([
+            ("targetNode", self.target_node.clone()),
+            ("timeout", self.timeout.map(|x| x.as_secs().to_string())),
+            ("async", self.async_id.clone()),
+        ])
+    }
+}
+
+/// Options for the Collections API `RELOAD` action, used by [reload_collection] and [reload_collections_using_config].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::ReloadCollectionOptions;
+/// let options = ReloadCollectionOptions::new().async_id("reload-collection");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct ReloadCollectionOptions {
+    async_id: Option<String>,
+}
+
+impl AsRef<ReloadCollectionOptions> for ReloadCollectionOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&ReloadCollectionOptions> for ReloadCollectionOptions {
+    fn from(options: &ReloadCollectionOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl ReloadCollectionOptions {
+    /// Create a new instance of ReloadCollectionOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask). [reload_collections_using_config] uses `<async_id>-<collection>`.
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([("async", self.async_id.clone())])
+    }
+}
+
+/// Options for the Collections API `MODIFYCOLLECTION` action, used by [modify_collection].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::ModifyCollectionOptions;
+/// let options = ModifyCollectionOptions::new()
+///     .replication_factor(2)
+///     .config_name("new_config")
+///     .properties([("team", "search")]);
+/// let options = ModifyCollectionOptions::new().read_only(true);
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct ModifyCollectionOptions {
+    replication_factor: Option<usize>,
+    config_name: Option<String>,
+    read_only: Option<bool>,
+    properties: Option<Vec<(String, String)>>,
+    async_id: Option<String>,
+}
+
+impl AsRef<ModifyCollectionOptions> for ModifyCollectionOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&ModifyCollectionOptions> for ModifyCollectionOptions {
+    fn from(options: &ModifyCollectionOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl ModifyCollectionOptions {
+    /// Create a new instance of ModifyCollectionOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of NRT replicas new shards get. Existing shards keep their replicas.
+    pub fn replication_factor<O: Into<Option<usize>>>(mut self, replication_factor: O) -> Self {
+        self.replication_factor = replication_factor.into();
+        self
+    }
+
+    /// Use another config set. The collection must be reloaded for it to take effect.
+    pub fn config_name<S: Into<String>, O: Into<Option<S>>>(mut self, config_name: O) -> Self {
+        self.config_name = config_name.into().map(|x| x.into());
+        self
+    }
+
+    /// Reject updates to the collection while true. Queries are still served.
+    pub fn read_only<O: Into<Option<bool>>>(mut self, read_only: O) -> Self {
+        self.read_only = read_only.into();
+        self
+    }
+
+    /// Collection properties, sent as `property.name=value`. An empty value removes the property.
+    pub fn properties<K: Into<String>, V: Into<String>, I: IntoIterator<Item = (K, V)>>(
+        mut self,
+        properties: I,
+    ) -> Self {
+        self.properties = Some(
+            properties
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        let mut query_params = to_query_params([
+            (
+                "replicationFactor",
+                self.replication_factor.map(|x| x.to_string()),
+            ),
+            ("collection.configName", self.config_name.clone()),
+            ("readOnly", self.read_only.map(|x| x.to_string())),
+            ("async", self.async_id.clone()),
+        ]);
+        query_params.extend(property_params(&self.properties));
+        query_params
+    }
+}
+
+/// Options for the Collections API `RENAME` action, used by [rename_collection].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::RenameCollectionOptions;
+/// let options = RenameCollectionOptions::new().async_id("rename-collection");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct RenameCollectionOptions {
+    async_id: Option<String>,
+}
+
+impl AsRef<RenameCollectionOptions> for RenameCollectionOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&RenameCollectionOptions> for RenameCollectionOptions {
+    fn from(options: &RenameCollectionOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl RenameCollectionOptions {
+    /// Create a new instance of RenameCollectionOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([("async", self.async_id.clone())])
+    }
+}
+
+/// Options for the Collections API `COLSTATUS` action, used by [get_col_status].
+///
+/// By default only the number of segments and documents of each leader is reported.
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::ColStatusOptions;
+/// let options = ColStatusOptions::new().core_info(true).segments(true).field_info(true);
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct ColStatusOptions {
+    core_info: Option<bool>,
+    segments: Option<bool>,
+    field_info: Option<bool>,
+    size_info: Option<bool>,
+    raw_size: Option<bool>,
+    raw_size_sampling_percent: Option<f64>,
+}
+
+impl AsRef<ColStatusOptions> for ColStatusOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&ColStatusOptions> for ColStatusOptions {
+    fn from(options: &ColStatusOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl ColStatusOptions {
+    /// Create a new instance of ColStatusOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report the index size and directories of each leader.
+    pub fn core_info<O: Into<Option<bool>>>(mut self, core_info: O) -> Self {
+        self.core_info = core_info.into();
+        self
+    }
+
+    /// Report the segments of each leader.
+    pub fn segments<O: Into<Option<bool>>>(mut self, segments: O) -> Self {
+        self.segments = segments.into();
+        self
+    }
+
+    /// Report the fields of each segment, and the fields that do not comply with the schema. Implies `segments`.
+    pub fn field_info<O: Into<Option<bool>>>(mut self, field_info: O) -> Self {
+        self.field_info = field_info.into();
+        self
+    }
+
+    /// Report the largest files of each segment. Implies `segments`.
+    pub fn size_info<O: Into<Option<bool>>>(mut self, size_info: O) -> Self {
+        self.size_info = size_info.into();
+        self
+    }
+
+    /// Estimate the size of the index by field and type. This reads the index, and can be slow.
+    pub fn raw_size<O: Into<Option<bool>>>(mut self, raw_size: O) -> Self {
+        self.raw_size = raw_size.into();
+        self
+    }
+
+    /// The percentage of documents read to estimate the raw size. Default is 5.
+    pub fn raw_size_sampling_percent<O: Into<Option<f64>>>(
+        mut self,
+        raw_size_sampling_percent: O,
+    ) -> Self {
+        self.raw_size_sampling_percent = raw_size_sampling_percent.into();
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([
+            ("coreInfo", self.core_info.map(|x| x.to_string())),
+            ("segments", self.segments.map(|x| x.to_string())),
+            ("fieldInfo", self.field_info.map(|x| x.to_string())),
+            ("sizeInfo", self.size_info.map(|x| x.to_string())),
+            ("rawSize", self.raw_size.map(|x| x.to_string())),
+            (
+                "rawSizeSamplingPercent",
+                self.raw_size_sampling_percent.map(|x| x.to_string()),
+            ),
+        ])
+    }
+}
+
+/// Options for the Collections API `ADDREPLICAPROP` and `DELETEREPLICAPROP` actions, used by [add_replica_property] and [delete_replica_property].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::ReplicaPropertyOptions;
+/// let options = ReplicaPropertyOptions::new().shard_unique(true);
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct ReplicaPropertyOptions {
+    shard_unique: Option<bool>,
+    async_id: Option<String>,
+}
+
+impl AsRef<ReplicaPropertyOptions> for ReplicaPropertyOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&ReplicaPropertyOptions> for ReplicaPropertyOptions {
+    fn from(options: &ReplicaPropertyOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl ReplicaPropertyOptions {
+    /// Create a new instance of ReplicaPropertyOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove the property from the other replicas of the shard, as Solr always does for `preferredLeader`. Only used by [add_replica_property].
+    pub fn shard_unique<O: Into<Option<bool>>>(mut self, shard_unique: O) -> Self {
+        self.shard_unique = shard_unique.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([
+            ("shardUnique", self.shard_unique.map(|x| x.to_string())),
+            ("async", self.async_id.clone()),
+        ])
+    }
+}
+
+/// Options for the Collections API `DELETE` action, used by [delete_collection_with_options].
+/// # Examples
+/// ```no_run
+/// use solrstice::queries::collection::DeleteCollectionOptions;
+/// let options = DeleteCollectionOptions::new().async_id("delete-collection");
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct DeleteCollectionOptions {
+    async_id: Option<String>,
+}
+
+impl AsRef<DeleteCollectionOptions> for DeleteCollectionOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&DeleteCollectionOptions> for DeleteCollectionOptions {
+    fn from(options: &DeleteCollectionOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl DeleteCollectionOptions {
+    /// Create a new instance of DeleteCollectionOptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        to_query_params([("async", self.async_id.clone())])
+    }
+}
+
+/// The options that are set, as query parameters.
+pub(crate) fn to_query_params<const N: usize>(
+    options: [(&str, Option<String>); N],
+) -> Vec<(String, String)> {
+    options
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (name.to_string(), value)))
+        .collect()
+}
+
+fn property_params(properties: &Option<Vec<(String, String)>>) -> Vec<(String, String)> {
+    properties
+        .iter()
+        .flatten()
+        .map(|(key, value)| (format!("property.{}", key), value.clone()))
+        .collect()
+}
+
+/// Send a Collections API action, with its parameters.
+pub(crate) async fn send_collection_action(
+    context: &SolrServerContext,
+    query_params: Vec<(String, String)>,
+) -> Result<SolrResponse, Error> {
+    let query_params = query_params
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect::<Vec<_>>();
+    SolrRequestBuilder::new(context, "/solr/admin/collections")
+        .with_query_params(query_params.as_ref())
+        .send_get::<SolrResponse>()
+        .await
+}
+
+/// Create a collection with the number of shards and replicas, and the config set.
+///
+/// This is a shorthand for [create_collection_with_options].
 pub async fn create_collection<C: AsRef<SolrServerContext>, S: AsRef<str>>(
     context: C,
     name: S,
@@ -10,17 +1355,32 @@ pub async fn create_collection<C: AsRef<SolrServerContext>, S: AsRef<str>>(
     shards: usize,
     replication_factor: usize,
 ) -> Result<(), Error> {
-    let query_params = [
-        ("action", "CREATE"),
-        ("name", name.as_ref()),
-        ("numShards", &shards.to_string()),
-        ("replicationFactor", &replication_factor.to_string()),
-        ("collection.configName", config.as_ref()),
+    let options = CreateCollectionOptions::new()
+        .config_name(config.as_ref())
+        .num_shards(shards)
+        .replication_factor(replication_factor);
+    create_collection_with_options(context, name, options).await
+}
+
+/// Create a collection with all options of the Collections API `CREATE` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::create_collection_with_options](crate::clients::async_cloud_client::AsyncSolrCloudClient::create_collection_with_options)
+pub async fn create_collection_with_options<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<CreateCollectionOptions>,
+>(
+    context: C,
+    name: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "CREATE".to_string()),
+        ("name".to_string(), name.as_ref().to_string()),
     ];
-    SolrRequestBuilder::new(context.as_ref(), "/solr/admin/collections")
-        .with_query_params(query_params.as_ref())
-        .send_get::<SolrResponse>()
-        .await?;
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
     Ok(())
 }
 
@@ -46,15 +1406,448 @@ pub async fn collection_exists<C: AsRef<SolrServerContext>, S: AsRef<str>>(
     Ok(collections.contains(&name.as_ref().to_string()))
 }
 
+/// Delete a collection.
+///
+/// This is a shorthand for [delete_collection_with_options].
 pub async fn delete_collection<C: AsRef<SolrServerContext>, S: AsRef<str>>(
     context: C,
     name: S,
 ) -> Result<(), Error> {
-    let query_params = [("action", "DELETE"), ("name", name.as_ref())];
-    SolrRequestBuilder::new(context.as_ref(), "/solr/admin/collections")
+    delete_collection_with_options(context, name, DeleteCollectionOptions::new()).await
+}
+
+/// Delete a collection, by the Collections API `DELETE` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::delete_collection_with_options](crate::clients::async_cloud_client::AsyncSolrCloudClient::delete_collection_with_options)
+pub async fn delete_collection_with_options<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<DeleteCollectionOptions>,
+>(
+    context: C,
+    name: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "DELETE".to_string()),
+        ("name".to_string(), name.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Reload a collection, so that changes to its config set take effect.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::reload_collection](crate::clients::async_cloud_client::AsyncSolrCloudClient::reload_collection)
+pub async fn reload_collection<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ReloadCollectionOptions>,
+>(
+    context: C,
+    name: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "RELOAD".to_string()),
+        ("name".to_string(), name.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Reload every collection using the config set, after it has been changed with [upload_config](crate::queries::config::upload_config).
+///
+/// Returns the names of the reloaded collections. With an async id, each collection is reloaded under `<async_id>-<collection>`.
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::reload_collections_using_config](crate::clients::async_cloud_client::AsyncSolrCloudClient::reload_collections_using_config)
+pub async fn reload_collections_using_config<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ReloadCollectionOptions>,
+>(
+    context: C,
+    config: S,
+    options: O,
+) -> Result<Vec<String>, Error> {
+    let collections = get_collections_using_config(context.as_ref(), config).await?;
+    for collection in collections.iter() {
+        let options = ReloadCollectionOptions {
+            async_id: options
+                .as_ref()
+                .async_id
+                .as_ref()
+                .map(|async_id| format!("{}-{}", async_id, collection)),
+        };
+        reload_collection(context.as_ref(), collection, &options).await?;
+    }
+    Ok(collections)
+}
+
+/// Change the attributes of a collection, by the Collections API `MODIFYCOLLECTION` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::modify_collection](crate::clients::async_cloud_client::AsyncSolrCloudClient::modify_collection)
+pub async fn modify_collection<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ModifyCollectionOptions>,
+>(
+    context: C,
+    collection: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "MODIFYCOLLECTION".to_string()),
+        ("collection".to_string(), collection.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Rename a collection, by the Collections API `RENAME` action.
+///
+/// Solr keeps the data under the old name, and points an alias with the new name to it.
+/// Requires Solr 8.1 or later.
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::rename_collection](crate::clients::async_cloud_client::AsyncSolrCloudClient::rename_collection)
+pub async fn rename_collection<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<RenameCollectionOptions>,
+>(
+    context: C,
+    name: S,
+    target: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "RENAME".to_string()),
+        ("name".to_string(), name.as_ref().to_string()),
+        ("target".to_string(), target.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Get the state of the cluster: its collections with their shards and replicas, aliases and live nodes.
+///
+/// `collection` limits the status to one collection, or the collections of an alias, and `shards` to some of its shards.
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::get_cluster_status](crate::clients::async_cloud_client::AsyncSolrCloudClient::get_cluster_status)
+pub async fn get_cluster_status<C: AsRef<SolrServerContext>>(
+    context: C,
+    collection: Option<&str>,
+    shards: &[&str],
+) -> Result<ClusterStatus, Error> {
+    let shards = shards.join(",");
+    let mut query_params = vec![("action", "CLUSTERSTATUS")];
+    if let Some(collection) = collection {
+        query_params.push(("collection", collection));
+    }
+    if !shards.is_empty() {
+        query_params.push(("shard", shards.as_str()));
+    }
+    let response = SolrRequestBuilder::new(context.as_ref(), "/solr/admin/collections")
         .with_query_params(query_params.as_ref())
-        .send_get::<SolrResponse>()
+        .send_get::<HashMap<String, Value>>()
+        .await?;
+    ClusterStatus::from_response(response)
+}
+
+/// Get the names of the collections that use the given config set.
+pub(crate) async fn get_collections_using_config<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+    context: C,
+    config: S,
+) -> Result<Vec<String>, Error> {
+    let status = get_cluster_status(context, None, &[]).await?;
+    Ok(status
+        .get_collections()
+        .values()
+        .filter(|collection| collection.get_config_name() == Some(config.as_ref()))
+        .map(|collection| collection.get_name().to_string())
+        .collect())
+}
+
+/// Get the index status of collections, by the Collections API `COLSTATUS` action.
+///
+/// `collection` limits the status to one collection. The options choose what is reported about the index of each shard leader.
+/// Requires Solr 8.1 or later.
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::get_col_status](crate::clients::async_cloud_client::AsyncSolrCloudClient::get_col_status)
+pub async fn get_col_status<C: AsRef<SolrServerContext>, O: AsRef<ColStatusOptions>>(
+    context: C,
+    collection: Option<&str>,
+    options: O,
+) -> Result<ColStatus, Error> {
+    let mut query_params = vec![("action".to_string(), "COLSTATUS".to_string())];
+    if let Some(collection) = collection {
+        query_params.push(("collection".to_string(), collection.to_string()));
+    }
+    query_params.extend(options.as_ref().to_query_params());
+    let query_params = query_params
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect::<Vec<_>>();
+    let response = SolrRequestBuilder::new(context.as_ref(), "/solr/admin/collections")
+        .with_query_params(query_params.as_ref())
+        .send_get::<HashMap<String, Value>>()
         .await?;
+    ColStatus::from_response(response)
+}
+
+/// Split a shard into sub-shards, by the Collections API `SPLITSHARD` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::split_shard](crate::clients::async_cloud_client::AsyncSolrCloudClient::split_shard)
+pub async fn split_shard<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<SplitShardOptions>,
+>(
+    context: C,
+    collection: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "SPLITSHARD".to_string()),
+        ("collection".to_string(), collection.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Add a shard to a collection using the implicit router, by the Collections API `CREATESHARD` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::create_shard](crate::clients::async_cloud_client::AsyncSolrCloudClient::create_shard)
+pub async fn create_shard<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<CreateShardOptions>,
+>(
+    context: C,
+    collection: S,
+    shard: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "CREATESHARD".to_string()),
+        ("collection".to_string(), collection.as_ref().to_string()),
+        ("shard".to_string(), shard.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Delete a shard, by the Collections API `DELETESHARD` action.
+/// Only shards that are `inactive`, like the parent of a split, or that have no range, as with the implicit router, can be deleted.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::delete_shard](crate::clients::async_cloud_client::AsyncSolrCloudClient::delete_shard)
+pub async fn delete_shard<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<DeleteShardOptions>,
+>(
+    context: C,
+    collection: S,
+    shard: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "DELETESHARD".to_string()),
+        ("collection".to_string(), collection.as_ref().to_string()),
+        ("shard".to_string(), shard.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Add replicas to a shard, by the Collections API `ADDREPLICA` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::add_replica](crate::clients::async_cloud_client::AsyncSolrCloudClient::add_replica)
+pub async fn add_replica<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<AddReplicaOptions>,
+>(
+    context: C,
+    collection: S,
+    shard: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "ADDREPLICA".to_string()),
+        ("collection".to_string(), collection.as_ref().to_string()),
+        ("shard".to_string(), shard.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Delete replicas by name, count or node, by the Collections API `DELETEREPLICA` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::delete_replica](crate::clients::async_cloud_client::AsyncSolrCloudClient::delete_replica)
+pub async fn delete_replica<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<DeleteReplicaOptions>,
+>(
+    context: C,
+    collection: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "DELETEREPLICA".to_string()),
+        ("collection".to_string(), collection.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Move a replica to another node, by the Collections API `MOVEREPLICA` action.
+/// A new replica is created on the target node, and the old one deleted once the new one is active.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::move_replica](crate::clients::async_cloud_client::AsyncSolrCloudClient::move_replica)
+pub async fn move_replica<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<MoveReplicaOptions>,
+>(
+    context: C,
+    collection: S,
+    target_node: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "MOVEREPLICA".to_string()),
+        ("collection".to_string(), collection.as_ref().to_string()),
+        ("targetNode".to_string(), target_node.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Delete all replicas on a node, by the Collections API `DELETENODE` action.
+/// Shards only on that node lose their data, so move their replicas first with [replace_node].
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::delete_node](crate::clients::async_cloud_client::AsyncSolrCloudClient::delete_node)
+pub async fn delete_node<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<DeleteNodeOptions>,
+>(
+    context: C,
+    node: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "DELETENODE".to_string()),
+        ("node".to_string(), node.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Move all replicas on a node to other nodes, by the Collections API `REPLACENODE` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::replace_node](crate::clients::async_cloud_client::AsyncSolrCloudClient::replace_node)
+pub async fn replace_node<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ReplaceNodeOptions>,
+>(
+    context: C,
+    source_node: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "REPLACENODE".to_string()),
+        ("sourceNode".to_string(), source_node.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Set a property of a replica, by the Collections API `ADDREPLICAPROP` action. Eg: `preferredLeader`
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::add_replica_property](crate::clients::async_cloud_client::AsyncSolrCloudClient::add_replica_property)
+pub async fn add_replica_property<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ReplicaPropertyOptions>,
+>(
+    context: C,
+    collection: S,
+    shard: S,
+    replica: S,
+    property: S,
+    value: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "ADDREPLICAPROP".to_string()),
+        ("collection".to_string(), collection.as_ref().to_string()),
+        ("shard".to_string(), shard.as_ref().to_string()),
+        ("replica".to_string(), replica.as_ref().to_string()),
+        ("property".to_string(), property.as_ref().to_string()),
+        ("property.value".to_string(), value.as_ref().to_string()),
+    ];
+    query_params.extend(options.as_ref().to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
+    Ok(())
+}
+
+/// Remove a property of a replica, by the Collections API `DELETEREPLICAPROP` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [AsyncSolrCloudClient::delete_replica_property](crate::clients::async_cloud_client::AsyncSolrCloudClient::delete_replica_property)
+pub async fn delete_replica_property<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ReplicaPropertyOptions>,
+>(
+    context: C,
+    collection: S,
+    shard: S,
+    replica: S,
+    property: S,
+    options: O,
+) -> Result<(), Error> {
+    let mut query_params = vec![
+        ("action".to_string(), "DELETEREPLICAPROP".to_string()),
+        ("collection".to_string(), collection.as_ref().to_string()),
+        ("shard".to_string(), shard.as_ref().to_string()),
+        ("replica".to_string(), replica.as_ref().to_string()),
+        ("property".to_string(), property.as_ref().to_string()),
+    ];
+    let options = ReplicaPropertyOptions {
+        shard_unique: None,
+        ..options.as_ref().clone()
+    };
+    query_params.extend(options.to_query_params());
+    send_collection_action(context.as_ref(), query_params).await?;
     Ok(())
 }
 
@@ -77,6 +1870,21 @@ pub fn create_collection_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
     ))
 }
 
+#[cfg(feature = "blocking")]
+pub fn create_collection_with_options_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<CreateCollectionOptions>,
+>(
+    context: C,
+    name: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(create_collection_with_options(context, name, options))
+}
+
 #[cfg(feature = "blocking")]
 pub fn get_collections_blocking<C: AsRef<SolrServerContext>>(
     context: C,
@@ -84,6 +1892,17 @@ pub fn get_collections_blocking<C: AsRef<SolrServerContext>>(
     RUNTIME.handle().block_on(get_collections(context))
 }
 
+#[cfg(feature = "blocking")]
+pub fn get_cluster_status_blocking<C: AsRef<SolrServerContext>>(
+    context: C,
+    collection: Option<&str>,
+    shards: &[&str],
+) -> Result<ClusterStatus, Error> {
+    RUNTIME
+        .handle()
+        .block_on(get_cluster_status(context, collection, shards))
+}
+
 #[cfg(feature = "blocking")]
 pub fn collection_exists_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
     context: C,
@@ -99,3 +1918,424 @@ pub fn delete_collection_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
 ) -> Result<(), Error> {
     RUNTIME.handle().block_on(delete_collection(context, name))
 }
+
+#[cfg(feature = "blocking")]
+/// Delete a collection, by the Collections API `DELETE` action.
+///
+/// This is not meant to be used directly, but rather as part of a client.
+/// Example usage can be found at [BlockingSolrCloudClient::delete_collection_with_options](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::delete_collection_with_options)
+pub fn delete_collection_with_options_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<DeleteCollectionOptions>,
+>(
+    context: C,
+    name: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(delete_collection_with_options(context, name, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn reload_collection_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ReloadCollectionOptions>,
+>(
+    context: C,
+    name: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(reload_collection(context, name, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn reload_collections_using_config_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ReloadCollectionOptions>,
+>(
+    context: C,
+    config: S,
+    options: O,
+) -> Result<Vec<String>, Error> {
+    RUNTIME
+        .handle()
+        .block_on(reload_collections_using_config(context, config, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn modify_collection_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ModifyCollectionOptions>,
+>(
+    context: C,
+    collection: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(modify_collection(context, collection, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn rename_collection_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<RenameCollectionOptions>,
+>(
+    context: C,
+    name: S,
+    target: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(rename_collection(context, name, target, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn get_col_status_blocking<C: AsRef<SolrServerContext>, O: AsRef<ColStatusOptions>>(
+    context: C,
+    collection: Option<&str>,
+    options: O,
+) -> Result<ColStatus, Error> {
+    RUNTIME
+        .handle()
+        .block_on(get_col_status(context, collection, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn split_shard_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<SplitShardOptions>,
+>(
+    context: C,
+    collection: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(split_shard(context, collection, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn create_shard_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<CreateShardOptions>,
+>(
+    context: C,
+    collection: S,
+    shard: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(create_shard(context, collection, shard, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn delete_shard_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<DeleteShardOptions>,
+>(
+    context: C,
+    collection: S,
+    shard: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(delete_shard(context, collection, shard, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn add_replica_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<AddReplicaOptions>,
+>(
+    context: C,
+    collection: S,
+    shard: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(add_replica(context, collection, shard, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn delete_replica_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<DeleteReplicaOptions>,
+>(
+    context: C,
+    collection: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(delete_replica(context, collection, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn move_replica_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<MoveReplicaOptions>,
+>(
+    context: C,
+    collection: S,
+    target_node: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(move_replica(context, collection, target_node, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn delete_node_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<DeleteNodeOptions>,
+>(
+    context: C,
+    node: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(delete_node(context, node, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn replace_node_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ReplaceNodeOptions>,
+>(
+    context: C,
+    source_node: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME
+        .handle()
+        .block_on(replace_node(context, source_node, options))
+}
+
+#[cfg(feature = "blocking")]
+pub fn add_replica_property_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ReplicaPropertyOptions>,
+>(
+    context: C,
+    collection: S,
+    shard: S,
+    replica: S,
+    property: S,
+    value: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME.handle().block_on(add_replica_property(
+        context, collection, shard, replica, property, value, options,
+    ))
+}
+
+#[cfg(feature = "blocking")]
+pub fn delete_replica_property_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    O: AsRef<ReplicaPropertyOptions>,
+>(
+    context: C,
+    collection: S,
+    shard: S,
+    replica: S,
+    property: S,
+    options: O,
+) -> Result<(), Error> {
+    RUNTIME.handle().block_on(delete_replica_property(
+        context, collection, shard, replica, property, options,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::ReplicaType;
+    use crate::queries::collection::{
+        AddReplicaOptions, ColStatusOptions, CollectionRouter, CreateCollectionOptions,
+        CreateShardOptions, DeleteReplicaOptions, DeleteShardOptions, ModifyCollectionOptions,
+        MoveReplicaOptions, ReplaceNodeOptions, ReplicaPropertyOptions, SplitMethod,
+        SplitShardOptions,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn create_collection_options_to_query_params() {
+        let options = CreateCollectionOptions::new()
+            .config_name("config")
+            .router(CollectionRouter::Implicit)
+            .router_field("shard_s")
+            .shards(["a", "b"])
+            .tlog_replicas(2)
+            .create_node_set(["node1:8983_solr", "node2:8983_solr"])
+            .per_replica_state(true)
+            .properties([("solr.autoCommit.maxTime", "1000")])
+            .async_id("create-1");
+        let query_params = options.to_query_params();
+        let expected = [
+            ("collection.configName", "config"),
+            ("router.name", "implicit"),
+            ("router.field", "shard_s"),
+            ("shards", "a,b"),
+            ("tlogReplicas", "2"),
+            ("createNodeSet", "node1:8983_solr,node2:8983_solr"),
+            ("perReplicaState", "true"),
+            ("async", "create-1"),
+            ("property.solr.autoCommit.maxTime", "1000"),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(query_params, expected);
+    }
+
+    #[test]
+    fn split_shard_options_to_query_params() {
+        let options = SplitShardOptions::new()
+            .split_key("customer_a!")
+            .ranges(["0-7fff", "8000-ffff"])
+            .split_method(SplitMethod::Link)
+            .async_id("split-1");
+        let expected = [
+            ("split.key", "customer_a!"),
+            ("ranges", "0-7fff,8000-ffff"),
+            ("splitMethod", "link"),
+            ("async", "split-1"),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+    }
+
+    #[test]
+    fn shard_options_to_query_params() {
+        let options = CreateShardOptions::new()
+            .pull_replicas(1)
+            .create_node_set(["node1:8983_solr"])
+            .properties([("dataDir", "/data")]);
+        let expected = [
+            ("pullReplicas", "1"),
+            ("createNodeSet", "node1:8983_solr"),
+            ("property.dataDir", "/data"),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+
+        let options = DeleteShardOptions::new()
+            .delete_instance_dir(false)
+            .delete_index(true);
+        let expected = [("deleteInstanceDir", "false"), ("deleteIndex", "true")]
+            .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+    }
+
+    #[test]
+    fn replica_options_to_query_params() {
+        let options = AddReplicaOptions::new()
+            .replica_type(ReplicaType::Tlog)
+            .node("node1:8983_solr")
+            .wait_for_final_state(true);
+        let expected = [
+            ("type", "TLOG"),
+            ("node", "node1:8983_solr"),
+            ("waitForFinalState", "true"),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+
+        let options = DeleteReplicaOptions::new()
+            .shard("shard1")
+            .count(2)
+            .replica_type(ReplicaType::Pull)
+            .only_if_down(true);
+        let expected = [
+            ("shard", "shard1"),
+            ("count", "2"),
+            ("type", "PULL"),
+            ("onlyIfDown", "true"),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+    }
+
+    #[test]
+    fn node_options_to_query_params() {
+        let options = MoveReplicaOptions::new()
+            .shard("shard1")
+            .source_node("node1:8983_solr")
+            .timeout(Duration::from_secs(120))
+            .async_id("move-1");
+        let expected = [
+            ("shard", "shard1"),
+            ("sourceNode", "node1:8983_solr"),
+            ("timeout", "120"),
+            ("async", "move-1"),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+
+        let options = ReplaceNodeOptions::new().target_node("node2:8983_solr");
+        let expected = [("targetNode", "node2:8983_solr")]
+            .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+    }
+
+    #[test]
+    fn replica_property_options_to_query_params() {
+        let options = ReplicaPropertyOptions::new()
+            .shard_unique(false)
+            .async_id("property");
+        let expected = [("shardUnique", "false"), ("async", "property")]
+            .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+    }
+
+    #[test]
+    fn collection_options_to_query_params() {
+        let options = ModifyCollectionOptions::new()
+            .replication_factor(2)
+            .config_name("new_config")
+            .read_only(false)
+            .properties([("team", "search")]);
+        let expected = [
+            ("replicationFactor", "2"),
+            ("collection.configName", "new_config"),
+            ("readOnly", "false"),
+            ("property.team", "search"),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+
+        let options = ColStatusOptions::new()
+            .core_info(true)
+            .field_info(true)
+            .raw_size_sampling_percent(10.0);
+        let expected = [
+            ("coreInfo", "true"),
+            ("fieldInfo", "true"),
+            ("rawSizeSamplingPercent", "10"),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(options.to_query_params(), expected);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::error::Error;
+use crate::queries::solr_params::{ParamKind, SolrParams};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// Pin or exclude documents for a query using Solr's `QueryElevationComponent`.
+///
+/// The collection needs the `elevator` search component configured for the handler.
+/// Documentation can be found at [SolrDocs](https://solr.apache.org/guide/solr/latest/query-guide/query-elevation-component.html)
+/// # Examples
+/// ```no_run
+/// use solrstice::{AsyncSolrCloudClient, ElevationComponent, SelectQuery, SolrSingleServerHost};
+/// # use solrstice::SolrServerContextBuilder;
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let query = SelectQuery::new()
+///     .q("city_name:Alta")
+///     .elevation(
+///         ElevationComponent::new()
+///             .elevate_ids(["city_Tromsø"])
+///             .exclude_ids(["city_Alta_20"])
+///             .elevated_flag(true),
+///     );
+/// let response = client.select(&query, "collection_name").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct ElevationComponent {
+    #[serde(rename = "enableElevation", skip_serializing_if = "Option::is_none")]
+    enable: Option<bool>,
+    #[serde(rename = "forceElevation", skip_serializing_if = "Option::is_none")]
+    force: Option<bool>,
+    #[serde(
+        rename = "elevateIds",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_ids",
+        deserialize_with = "deserialize_ids",
+        default
+    )]
+    elevate_ids: Option<Vec<String>>,
+    #[serde(
+        rename = "excludeIds",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_ids",
+        deserialize_with = "deserialize_ids",
+        default
+    )]
+    exclude_ids: Option<Vec<String>>,
+    #[serde(rename = "markExcludes", skip_serializing_if = "Option::is_none")]
+    mark_excludes: Option<bool>,
+    /// Not a Solr parameter. Moved into `fl` as `[elevated]` before the request is sent.
+    #[serde(rename = "elevatedFlag", skip_serializing_if = "is_false", default)]
+    elevated_flag: bool,
+    /// Not a Solr parameter. Moved into `fl` as `[excluded]` before the request is sent.
+    #[serde(rename = "excludedFlag", skip_serializing_if = "is_false", default)]
+    excluded_flag: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+/// Solr only reads the first value of `elevateIds` and `excludeIds`, so they are sent comma separated.
+/// Backslashes and commas in ids are escaped with a backslash, which Solr removes when splitting.
+fn serialize_ids<S>(ids: &Option<Vec<String>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match ids {
+        None => serializer.serialize_none(),
+        Some(ids) => serializer.serialize_str(
+            &ids.iter()
+                .map(|id| id.replace('\\', "\\\\").replace(',', "\\,"))
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+    }
+}
+
+fn deserialize_ids<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let ids: Option<String> = Option::deserialize(deserializer)?;
+    Ok(ids.map(|ids| split_ids(&ids)))
+}
+
+/// Split comma separated ids, where a backslash escapes the next character.
+fn split_ids(ids: &str) -> Vec<String> {
+    let mut split = Vec::new();
+    let mut current = String::new();
+    let mut chars = ids.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => current.extend(chars.next()),
+            ',' => split.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    split.push(current);
+    split.into_iter().filter(|id| !id.is_empty()).collect()
+}
+
+impl ElevationComponent {
+    /// Create a new elevation component. Without any options set, Solr uses the rules in `elevate.xml`.
+    /// # Examples
+    /// ```rust
+    /// use solrstice::{ElevationComponent, SelectQuery};
+    /// SelectQuery::new().elevation(ElevationComponent::new().elevate_ids(["doc1", "doc2"]));
+    /// ```
+    pub fn new() -> Self {
+        ElevationComponent {
+            enable: None,
+            force: None,
+            elevate_ids: None,
+            exclude_ids: None,
+            mark_excludes: None,
+            elevated_flag: false,
+            excluded_flag: false,
+        }
+    }
+
+    /// Turn elevation on or off for this request. Default in Solr is `true`.
+    /// # Examples
+    /// ```rust
+    /// use solrstice::ElevationComponent;
+    /// ElevationComponent::new().enable(false);
+    /// ```
+    pub fn enable<O: Into<Option<bool>>>(mut self, enable: O) -> Self {
+        self.enable = enable.into();
+        self
+    }
+
+    /// Keep elevated documents on top even when sorting on something other than score.
+    /// # Examples
+    /// ```rust
+    /// use solrstice::ElevationComponent;
+    /// ElevationComponent::new().force(true);
+    /// ```
+    pub fn force<O: Into<Option<bool>>>(mut self, force: O) -> Self {
+        self.force = force.into();
+        self
+    }
+
+    /// Ids of documents to put on top of the result, in the given order.
+    /// # Examples
+    /// ```rust
+    /// use solrstice::ElevationComponent;
+    /// ElevationComponent::new().elevate_ids(["doc1", "doc2"]);
+    /// ```
+    pub fn elevate_ids<S: Into<String>, I: IntoIterator<Item = S>, O: Into<Option<I>>>(
+        mut self,
+        ids: O,
+    ) -> Self {
+        self.elevate_ids = ids
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// Ids of documents to remove from the result.
+    /// # Examples
+    /// ```rust
+    /// use solrstice::ElevationComponent;
+    /// ElevationComponent::new().exclude_ids(["doc3"]);
+    /// ```
+    pub fn exclude_ids<S: Into<String>, I: IntoIterator<Item = S>, O: Into<Option<I>>>(
+        mut self,
+        ids: O,
+    ) -> Self {
+        self.exclude_ids = ids
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// Keep excluded documents in the result, but mark them. Use together with [ElevationComponent::excluded_flag].
+    /// # Examples
+    /// ```rust
+    /// use solrstice::ElevationComponent;
+    /// ElevationComponent::new().exclude_ids(["doc3"]).mark_excludes(true).excluded_flag(true);
+    /// ```
+    pub fn mark_excludes<O: Into<Option<bool>>>(mut self, mark_excludes: O) -> Self {
+        self.mark_excludes = mark_excludes.into();
+        self
+    }
+
+    /// Add the `[elevated]` field to every returned document.
+    ///
+    /// If no `fl` is set on the query, `*` is also requested so the stored fields are still returned.
+    /// # Examples
+    /// ```rust
+    /// use solrstice::ElevationComponent;
+    /// ElevationComponent::new().elevated_flag(true);
+    /// ```
+    pub fn elevated_flag(mut self, elevated_flag: bool) -> Self {
+        self.elevated_flag = elevated_flag;
+        self
+    }
+
+    /// Add the `[excluded]` field to every returned document. Only useful together with [ElevationComponent::mark_excludes].
+    /// # Examples
+    /// ```rust
+    /// use solrstice::ElevationComponent;
+    /// ElevationComponent::new().mark_excludes(true).excluded_flag(true);
+    /// ```
+    pub fn excluded_flag(mut self, excluded_flag: bool) -> Self {
+        self.excluded_flag = excluded_flag;
+        self
+    }
+
+    /// The doc transformers that should be added to `fl`.
+    pub(crate) fn flag_fields(&self) -> Vec<&'static str> {
+        let mut fields = Vec::new();
+        if self.elevated_flag {
+            fields.push("[elevated]");
+        }
+        if self.excluded_flag {
+            fields.push("[excluded]");
+        }
+        fields
+    }
+
+    /// Remove the flags, as they are not valid Solr parameters.
+    pub(crate) fn without_flags(mut self) -> Self {
+        self.elevated_flag = false;
+        self.excluded_flag = false;
+        self
+    }
+}
+
+impl Default for ElevationComponent {
+    fn default() -> Self {
+        ElevationComponent::new()
+    }
+}
+
+impl AsRef<ElevationComponent> for ElevationComponent {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&ElevationComponent> for ElevationComponent {
+    fn from(component: &ElevationComponent) -> Self {
+        component.clone()
+    }
+}
+
+impl ElevationComponent {
+    /// Take the elevation parameters from classic Solr parameters.
+    pub(crate) fn from_solr_params(params: &mut SolrParams) -> Result<Option<Self>, Error> {
+        let map = params.take_object(&[
+            ("enableElevation", ParamKind::Bool),
+            ("forceElevation", ParamKind::Bool),
+            ("elevateIds", ParamKind::Str),
+            ("excludeIds", ParamKind::Str),
+            ("markExcludes", ParamKind::Bool),
+        ])?;
+        if map.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_value(Value::Object(map))?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::queries::components::elevation::ElevationComponent;
+
+    #[test]
+    fn serialize_elevation_joins_ids() {
+        let component = ElevationComponent::new()
+            .elevate_ids(["doc1", "doc2"])
+            .exclude_ids(["doc3"])
+            .force(true);
+        let serialized = serde_json::to_value(&component).unwrap();
+        assert_eq!(serialized["elevateIds"], "doc1,doc2");
+        assert_eq!(serialized["excludeIds"], "doc3");
+        assert_eq!(serialized["forceElevation"], true);
+        assert!(serialized.get("elevatedFlag").is_none());
+        let deserialized = serde_json::from_value::<ElevationComponent>(serialized).unwrap();
+        assert_eq!(component, deserialized);
+    }
+
+    #[test]
+    fn serialize_elevation_escapes_ids() {
+        let component = ElevationComponent::new()
+            .elevate_ids(["a,b", "c"])
+            .exclude_ids(["d\\e", "f\\,"]);
+        let serialized = serde_json::to_value(&component).unwrap();
+        assert_eq!(serialized["elevateIds"], "a\\,b,c");
+        assert_eq!(serialized["excludeIds"], "d\\\\e,f\\\\\\,");
+        let deserialized = serde_json::from_value::<ElevationComponent>(serialized).unwrap();
+        assert_eq!(component, deserialized);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::queries::solr_params::{parse_solr_bool, ParamKind, SolrParams};
 use regex::Regex;
 use serde::de::Error;
 use serde::ser::SerializeMap;
@@ -152,6 +153,59 @@ impl FacetSetComponent {
     }
 }
 
+impl FacetSetComponent {
+    /// Take the facet parameters from classic Solr parameters. Requires `facet` to be enabled.
+    pub(crate) fn from_solr_params(
+        params: &mut SolrParams,
+    ) -> Result<Option<Self>, crate::error::Error> {
+        if params.get("facet").and_then(parse_solr_bool) != Some(true) {
+            return Ok(None);
+        }
+        params.take("facet");
+        let mut component = FacetSetComponent::new().queries(params.take_all("facet.query"));
+        if let Some(pivots) = params.take_list("facet.pivot", false) {
+            let min_count = params.take_parsed::<usize>("facet.pivot.mincount")?;
+            component = component.pivots(PivotFacetComponent::new(pivots).min_count(min_count));
+        }
+        let mut entries = Vec::new();
+        for field in params.take_all("facet.field") {
+            let prefix = format!("f.{}.facet.", field);
+            let mut map = serde_json::Map::new();
+            map.insert("facet.field".to_string(), serde_json::json!([field]));
+            for (suffix, kind) in FIELD_FACET_PARAMS {
+                let key = format!("{}{}", prefix, suffix);
+                if let Some(value) = params.take_value(&key, *kind)? {
+                    map.insert(key, value);
+                }
+            }
+            let mut fields: FieldFacetComponent =
+                serde_json::from_value(serde_json::Value::Object(map))?;
+            entries.append(&mut fields.fields);
+        }
+        if !entries.is_empty() {
+            let exclude_terms = params.take("facet.excludeTerms");
+            component = component.fields(
+                FieldFacetComponent::new(entries).exclude_terms::<String, _>(exclude_terms),
+            );
+        }
+        Ok(Some(component))
+    }
+}
+
+static FIELD_FACET_PARAMS: &[(&str, ParamKind)] = &[
+    ("prefix", ParamKind::Str),
+    ("contains", ParamKind::Str),
+    ("contains.ignoreCase", ParamKind::Bool),
+    ("sort", ParamKind::Str),
+    ("limit", ParamKind::Usize),
+    ("offset", ParamKind::Usize),
+    ("mincount", ParamKind::Usize),
+    ("missing", ParamKind::Bool),
+    ("method", ParamKind::Str),
+    ("enum.cache.minDf", ParamKind::Usize),
+    ("exists", ParamKind::Bool),
+];
+
 impl Default for FacetSetComponent {
     fn default() -> Self {
         FacetSetComponent::new()
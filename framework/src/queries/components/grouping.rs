@@ -1,4 +1,7 @@
+use crate::error::Error;
+use crate::queries::solr_params::{ParamKind, SolrParams};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::fmt;
 
 /// How to format groups. The default is GroupFormatting::Grouped.
@@ -230,3 +233,26 @@ impl From<&GroupingComponent> for GroupingComponent {
         component.clone()
     }
 }
+
+impl GroupingComponent {
+    /// Take the grouping parameters from classic Solr parameters. Requires `group` to be set.
+    pub(crate) fn from_solr_params(params: &mut SolrParams) -> Result<Option<Self>, Error> {
+        if !params.contains("group") {
+            return Ok(None);
+        }
+        let map = params.take_object(&[
+            ("group", ParamKind::Bool),
+            ("group.field", ParamKind::List),
+            ("group.query", ParamKind::List),
+            ("group.limit", ParamKind::Usize),
+            ("group.offset", ParamKind::Usize),
+            ("group.sort", ParamKind::SplitList),
+            ("group.format", ParamKind::Str),
+            ("group.main", ParamKind::Bool),
+            ("group.ngroups", ParamKind::Bool),
+            ("group.truncate", ParamKind::Bool),
+            ("group.facet", ParamKind::Bool),
+        ])?;
+        Ok(Some(serde_json::from_value(Value::Object(map))?))
+    }
+}
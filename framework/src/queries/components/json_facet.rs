@@ -1,3 +1,5 @@
+use crate::error::Error;
+use crate::queries::solr_params::SolrParams;
 use std::collections::{BTreeMap, HashMap};
 
 use serde::{Deserialize, Serialize, Serializer};
@@ -414,6 +416,12 @@ pub struct JsonQueryFacet {
     facet: Option<HashMap<String, JsonFacetType>>,
 }
 
+impl Default for JsonQueryFacet {
+    fn default() -> Self {
+        JsonQueryFacet::new()
+    }
+}
+
 impl From<JsonQueryFacet> for JsonFacetType {
     fn from(facet: JsonQueryFacet) -> Self {
         JsonFacetType::Query(Box::new(facet))
@@ -572,3 +580,15 @@ impl JsonStatFacet {
         JsonStatFacet(stat.into())
     }
 }
+
+impl JsonFacetComponent {
+    /// Take the `json.facet` parameter from classic Solr parameters.
+    pub(crate) fn from_solr_params(params: &mut SolrParams) -> Result<Option<Self>, Error> {
+        match params.take("json.facet") {
+            None => Ok(None),
+            Some(facet) => Ok(Some(JsonFacetComponent {
+                facet: serde_json::from_str(&facet)?,
+            })),
+        }
+    }
+}
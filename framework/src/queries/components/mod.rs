@@ -6,6 +6,8 @@ pub(crate) mod grouping;
 /// Facet count component
 pub(crate) mod facet_set;
 
+/// Query elevation component
+pub(crate) mod elevation;
 /// Json facet component
 pub(crate) mod json_facet;
 /// Stats component
@@ -1,4 +1,7 @@
+use crate::error::Error;
+use crate::queries::solr_params::{ParamKind, SolrParams};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
 pub struct StatsComponent {
@@ -42,3 +45,15 @@ impl StatsComponent {
         self
     }
 }
+
+impl StatsComponent {
+    /// Take the stats parameters from classic Solr parameters. Requires `stats` to be set.
+    pub(crate) fn from_solr_params(params: &mut SolrParams) -> Result<Option<Self>, Error> {
+        if !params.contains("stats") {
+            return Ok(None);
+        }
+        let map =
+            params.take_object(&[("stats", ParamKind::Bool), ("stats.field", ParamKind::List)])?;
+        Ok(Some(serde_json::from_value(Value::Object(map))?))
+    }
+}
@@ -1,6 +1,7 @@
 use crate::error::Error;
 use crate::models::context::SolrServerContext;
 use crate::models::SolrResponse;
+use crate::queries::collection::{reload_collections_using_config, ReloadCollectionOptions};
 use crate::queries::request_builder::SolrRequestBuilder;
 use std::fs::File;
 use std::io::{Read, Seek, Write};
@@ -104,6 +105,32 @@ pub async fn delete_config<C: AsRef<SolrServerContext>, S: AsRef<str>>(
     Ok(())
 }
 
+/// Upload a new `elevate.xml` into an existing config set, and reload every collection using it.
+///
+/// Returns the names of the reloaded collections.
+/// Uploading a single file into a config set requires Solr 8.7 or newer.
+/// Example usage can be found at [AsyncSolrCloudClient::upload_elevation_config](crate::clients::async_cloud_client::AsyncSolrCloudClient::upload_elevation_config)
+pub async fn upload_elevation_config<C: AsRef<SolrServerContext>, S: AsRef<str>, P: AsRef<Path>>(
+    context: C,
+    name: S,
+    path: P,
+) -> Result<Vec<String>, Error> {
+    let query_params = [
+        ("action", "UPLOAD"),
+        ("name", name.as_ref()),
+        ("filePath", "elevate.xml"),
+        ("overwrite", "true"),
+    ];
+    let content = std::fs::read(path)?;
+    SolrRequestBuilder::new(context.as_ref(), "/solr/admin/configs")
+        .with_query_params(query_params.as_ref())
+        .with_headers(vec![("Content-Type", "application/octet-stream")])
+        .send_post_with_body::<_, SolrResponse>(content)
+        .await?;
+
+    reload_collections_using_config(context, name, ReloadCollectionOptions::new()).await
+}
+
 #[cfg(feature = "blocking")]
 use crate::runtime::RUNTIME;
 
@@ -138,3 +165,22 @@ pub fn delete_config_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
 ) -> Result<(), Error> {
     RUNTIME.handle().block_on(delete_config(context, name))
 }
+
+#[cfg(feature = "blocking")]
+/// Upload a new `elevate.xml` into an existing config set, and reload every collection using it.
+///
+/// Returns the names of the reloaded collections.
+/// Example usage can be found at [BlockingSolrCloudClient::upload_elevation_config](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::upload_elevation_config)
+pub fn upload_elevation_config_blocking<
+    C: AsRef<SolrServerContext>,
+    S: AsRef<str>,
+    P: AsRef<Path>,
+>(
+    context: C,
+    name: S,
+    path: P,
+) -> Result<Vec<String>, Error> {
+    RUNTIME
+        .handle()
+        .block_on(upload_elevation_config(context, name, path))
+}
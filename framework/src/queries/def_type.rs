@@ -1,4 +1,7 @@
+use crate::error::Error;
+use crate::queries::solr_params::{ParamKind, SolrParams};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
@@ -474,3 +477,68 @@ impl EdismaxQuery {
         self
     }
 }
+
+static LUCENE_PARAMS: &[(&str, ParamKind)] = &[
+    ("defType", ParamKind::Str),
+    ("q.op", ParamKind::Str),
+    ("df", ParamKind::Str),
+    ("sow", ParamKind::Bool),
+];
+
+static DISMAX_PARAMS: &[(&str, ParamKind)] = &[
+    ("defType", ParamKind::Str),
+    ("q.alt", ParamKind::Str),
+    ("qf", ParamKind::Str),
+    ("mm", ParamKind::Str),
+    ("pf", ParamKind::Str),
+    ("ps", ParamKind::Str),
+    ("qs", ParamKind::Str),
+    ("tie", ParamKind::Str),
+    ("bq", ParamKind::List),
+    ("bf", ParamKind::List),
+];
+
+static EDISMAX_PARAMS: &[(&str, ParamKind)] = &[
+    ("defType", ParamKind::Str),
+    ("q.alt", ParamKind::Str),
+    ("qf", ParamKind::Str),
+    ("mm", ParamKind::Str),
+    ("mm.autoRelax", ParamKind::Bool),
+    ("pf", ParamKind::Str),
+    ("pf2", ParamKind::Str),
+    ("pf3", ParamKind::Str),
+    ("ps", ParamKind::Str),
+    ("ps2", ParamKind::Str),
+    ("ps3", ParamKind::Str),
+    ("qs", ParamKind::Str),
+    ("tie", ParamKind::Str),
+    ("bq", ParamKind::List),
+    ("bf", ParamKind::List),
+    ("sow", ParamKind::Bool),
+    ("boost", ParamKind::List),
+    ("lowercase_operators", ParamKind::Bool),
+    ("stopwords", ParamKind::Bool),
+    ("uf", ParamKind::Str),
+];
+
+impl DefType {
+    /// Take the query parser parameters from classic Solr parameters.
+    /// Other parsers than `lucene`, `dismax` and `edismax` are left as they are.
+    pub(crate) fn from_solr_params(params: &mut SolrParams) -> Result<Option<Self>, Error> {
+        let def_type = match params.get("defType") {
+            Some(def_type @ ("lucene" | "dismax" | "edismax")) => def_type.to_string(),
+            _ => return Ok(None),
+        };
+        Ok(Some(match def_type.as_str() {
+            "lucene" => DefType::Lucene(serde_json::from_value(Value::Object(
+                params.take_object(LUCENE_PARAMS)?,
+            ))?),
+            "dismax" => DefType::Dismax(serde_json::from_value(Value::Object(
+                params.take_object(DISMAX_PARAMS)?,
+            ))?),
+            _ => DefType::Edismax(serde_json::from_value(Value::Object(
+                params.take_object(EDISMAX_PARAMS)?,
+            ))?),
+        }))
+    }
+}
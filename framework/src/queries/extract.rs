@@ -0,0 +1,406 @@
+use crate::error::Error;
+use crate::models::commit_type::CommitType;
+use crate::models::context::SolrServerContext;
+use crate::models::extract::SolrExtractResponse;
+use crate::models::response::SolrResponse;
+use crate::queries::file_update::update_query_params;
+use crate::queries::index::UpdateCommitOptions;
+use crate::queries::request_builder::{SolrRequestBuilder, SolrResponseType};
+use reqwest::multipart::{Form, Part};
+use reqwest::Body;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+
+/// The format of the content returned when only extracting.
+#[derive(Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum ExtractFormat {
+    /// XHTML, keeping the structure of the document. Solr's default.
+    #[default]
+    Xml,
+    /// Plain text.
+    Text,
+}
+
+impl ExtractFormat {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ExtractFormat::Xml => "xml",
+            ExtractFormat::Text => "text",
+        }
+    }
+}
+
+/// A builder for uploading binary files such as PDF or DOCX to Solr Cell, the `/update/extract` handler.
+///
+/// Solr extracts the text and metadata with Apache Tika, and indexes them as a document.
+/// The extraction module must be enabled in Solr.
+/// # Examples
+/// ```no_run
+/// use solrstice::{AsyncSolrCloudClient, CommitType, ExtractQuery, SolrServerContextBuilder, SolrSingleServerHost};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let query = ExtractQuery::new()
+///     .literals([("id", "report1"), ("category", "reports")])
+///     .fmap([("content", "text")])
+///     .uprefix("ignored_")
+///     .commit_type(CommitType::CommitWithin(10_000));
+/// let response = client.extract_file(&query, "collection_name", "report.pdf").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct ExtractQuery {
+    handler: String,
+    commit_options: UpdateCommitOptions,
+    literals: Option<Vec<(String, String)>>,
+    fmap: Option<Vec<(String, String)>>,
+    uprefix: Option<String>,
+    capture_attr: Option<bool>,
+    lowernames: Option<bool>,
+    extract_format: Option<ExtractFormat>,
+    resource_name: Option<String>,
+    content_type: Option<String>,
+}
+
+impl From<&ExtractQuery> for ExtractQuery {
+    fn from(query: &ExtractQuery) -> Self {
+        query.clone()
+    }
+}
+
+impl AsRef<ExtractQuery> for ExtractQuery {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl ExtractQuery {
+    /// Create a new instance of ExtractQuery.
+    pub fn new() -> Self {
+        ExtractQuery {
+            handler: "update/extract".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the handler for the query. Default is "update/extract".
+    pub fn handler<S: Into<String>>(mut self, handler: S) -> Self {
+        self.handler = handler.into();
+        self
+    }
+
+    /// Set the commit type for the query. Default is CommitType::Hard.
+    pub fn commit_type(mut self, commit_type: CommitType) -> Self {
+        self.commit_options = self.commit_options.commit_type(commit_type);
+        self
+    }
+
+    /// Set the commit type, overwrite and commit options for the query. Replaces an earlier [ExtractQuery::commit_type].
+    ///
+    /// Not used when only extracting.
+    pub fn commit_options<O: Into<UpdateCommitOptions>>(mut self, commit_options: O) -> Self {
+        self.commit_options = commit_options.into();
+        self
+    }
+
+    /// Fields added to the document as is, with `literal.<field>=<value>`. Usually includes the unique key.
+    ///
+    /// A field can be given several times for multivalued fields.
+    pub fn literals<K: Into<String>, V: Into<String>, I: IntoIterator<Item = (K, V)>>(
+        mut self,
+        literals: I,
+    ) -> Self {
+        self.literals = Some(
+            literals
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Rename extracted fields, with `fmap.<from>=<to>`. Eg: `("content", "text")`
+    pub fn fmap<K: Into<String>, V: Into<String>, I: IntoIterator<Item = (K, V)>>(
+        mut self,
+        fmap: I,
+    ) -> Self {
+        self.fmap = Some(
+            fmap.into_iter()
+                .map(|(from, to)| (from.into(), to.into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Prefix for extracted fields that are not in the schema. Eg: `ignored_` with a matching dynamic field.
+    pub fn uprefix<S: Into<String>, O: Into<Option<S>>>(mut self, uprefix: O) -> Self {
+        self.uprefix = uprefix.into().map(|x| x.into());
+        self
+    }
+
+    /// Whether attributes of XHTML elements should be indexed into separate fields.
+    pub fn capture_attr<O: Into<Option<bool>>>(mut self, capture_attr: O) -> Self {
+        self.capture_attr = capture_attr.into();
+        self
+    }
+
+    /// Whether extracted field names should be lowercased, with non alphanumeric characters replaced by `_`.
+    pub fn lowernames<O: Into<Option<bool>>>(mut self, lowernames: O) -> Self {
+        self.lowernames = lowernames.into();
+        self
+    }
+
+    /// The format of the content when only extracting. Default is [ExtractFormat::Xml].
+    pub fn extract_format<O: Into<Option<ExtractFormat>>>(mut self, extract_format: O) -> Self {
+        self.extract_format = extract_format.into();
+        self
+    }
+
+    /// The file name, which helps Tika detect the file type when sending a reader.
+    pub fn resource_name<S: Into<String>, O: Into<Option<S>>>(mut self, resource_name: O) -> Self {
+        self.resource_name = resource_name.into().map(|x| x.into());
+        self
+    }
+
+    /// The content type of the file. If unset, Tika detects it.
+    pub fn content_type<S: Into<String>, O: Into<Option<S>>>(mut self, content_type: O) -> Self {
+        self.content_type = content_type.into().map(|x| x.into());
+        self
+    }
+
+    fn to_query_params(&self, extract_only: bool) -> Vec<(String, String)> {
+        let mut query_params = match extract_only {
+            true => vec![("extractOnly".to_string(), "true".to_string())],
+            false => update_query_params(&self.commit_options),
+        };
+        for (field, value) in self.literals.iter().flatten() {
+            query_params.push((format!("literal.{}", field), value.clone()));
+        }
+        for (from, to) in self.fmap.iter().flatten() {
+            query_params.push((format!("fmap.{}", from), to.clone()));
+        }
+        if let Some(uprefix) = &self.uprefix {
+            query_params.push(("uprefix".to_string(), uprefix.clone()));
+        }
+        if let Some(capture_attr) = self.capture_attr {
+            query_params.push(("captureAttr".to_string(), capture_attr.to_string()));
+        }
+        if let Some(lowernames) = self.lowernames {
+            query_params.push(("lowernames".to_string(), lowernames.to_string()));
+        }
+        if let (true, Some(extract_format)) = (extract_only, self.extract_format) {
+            query_params.push((
+                "extractFormat".to_string(),
+                extract_format.as_str().to_string(),
+            ));
+        }
+        if let Some(resource_name) = &self.resource_name {
+            query_params.push(("resource.name".to_string(), resource_name.clone()));
+        }
+        query_params
+    }
+
+    /// Upload a file as a multipart form, and index the extracted document.
+    ///
+    /// Use [AsyncSolrCloudClient::extract_file](crate::clients::async_cloud_client::AsyncSolrCloudClient::extract_file) for a shorter call.
+    pub async fn execute_file<C: AsRef<SolrServerContext>, S: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        context: C,
+        collection: S,
+        path: P,
+    ) -> Result<SolrResponse, Error> {
+        self.send_file(context.as_ref(), collection.as_ref(), path.as_ref(), false)
+            .await
+    }
+
+    /// Stream a file from a reader as the request body, and index the extracted document.
+    pub async fn execute_reader<C, S, R>(
+        &self,
+        context: C,
+        collection: S,
+        reader: R,
+    ) -> Result<SolrResponse, Error>
+    where
+        C: AsRef<SolrServerContext>,
+        S: AsRef<str>,
+        R: AsyncRead + Send + 'static,
+    {
+        self.send_reader(context.as_ref(), collection.as_ref(), reader, false)
+            .await
+    }
+
+    /// Upload a file as a multipart form, and return the extracted text and metadata without indexing them.
+    ///
+    /// Use [AsyncSolrCloudClient::extract_only_file](crate::clients::async_cloud_client::AsyncSolrCloudClient::extract_only_file) for a shorter call.
+    pub async fn extract_only_file<C: AsRef<SolrServerContext>, S: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        context: C,
+        collection: S,
+        path: P,
+    ) -> Result<SolrExtractResponse, Error> {
+        let response: HashMap<String, Value> = self
+            .send_file(context.as_ref(), collection.as_ref(), path.as_ref(), true)
+            .await?;
+        SolrExtractResponse::from_response(response)
+    }
+
+    /// Stream a file from a reader, and return the extracted text and metadata without indexing them.
+    pub async fn extract_only_reader<C, S, R>(
+        &self,
+        context: C,
+        collection: S,
+        reader: R,
+    ) -> Result<SolrExtractResponse, Error>
+    where
+        C: AsRef<SolrServerContext>,
+        S: AsRef<str>,
+        R: AsyncRead + Send + 'static,
+    {
+        let response: HashMap<String, Value> = self
+            .send_reader(context.as_ref(), collection.as_ref(), reader, true)
+            .await?;
+        SolrExtractResponse::from_response(response)
+    }
+
+    async fn send_file<R: SolrResponseType>(
+        &self,
+        context: &SolrServerContext,
+        collection: &str,
+        path: &Path,
+        extract_only: bool,
+    ) -> Result<R, Error> {
+        let file = tokio::fs::File::open(path).await?;
+        let mut part = Part::stream(Body::wrap_stream(ReaderStream::new(file)));
+        if let Some(file_name) = path.file_name() {
+            part = part.file_name(file_name.to_string_lossy().to_string());
+        }
+        if let Some(content_type) = &self.content_type {
+            part = part.mime_str(content_type)?;
+        }
+        let query_params = self.to_query_params(extract_only);
+        let query_params = query_params
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect::<Vec<_>>();
+        SolrRequestBuilder::new(
+            context,
+            format!("/solr/{}/{}", collection, self.handler).as_str(),
+        )
+        .with_query_params(query_params.as_ref())
+        .send_post_with_multipart(Form::new().part("file", part))
+        .await
+    }
+
+    async fn send_reader<R: SolrResponseType, A: AsyncRead + Send + 'static>(
+        &self,
+        context: &SolrServerContext,
+        collection: &str,
+        reader: A,
+        extract_only: bool,
+    ) -> Result<R, Error> {
+        let query_params = self.to_query_params(extract_only);
+        let query_params = query_params
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect::<Vec<_>>();
+        let content_type = self
+            .content_type
+            .as_deref()
+            .unwrap_or("application/octet-stream");
+        SolrRequestBuilder::new(
+            context,
+            format!("/solr/{}/{}", collection, self.handler).as_str(),
+        )
+        .with_query_params(query_params.as_ref())
+        .with_headers([("Content-Type", content_type)])
+        .send_post_with_body(Body::wrap_stream(ReaderStream::new(reader)))
+        .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+use crate::runtime::RUNTIME;
+#[cfg(feature = "blocking")]
+impl ExtractQuery {
+    /// Upload a file as a multipart form, and index the extracted document.
+    ///
+    /// Use [BlockingSolrCloudClient::extract_file](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::extract_file) for a shorter call.
+    pub fn execute_file_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        context: C,
+        collection: S,
+        path: P,
+    ) -> Result<SolrResponse, Error> {
+        RUNTIME
+            .handle()
+            .block_on(self.execute_file(context, collection, path))
+    }
+
+    /// Upload a file as a multipart form, and return the extracted text and metadata without indexing them.
+    ///
+    /// Use [BlockingSolrCloudClient::extract_only_file](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::extract_only_file) for a shorter call.
+    pub fn extract_only_file_blocking<
+        C: AsRef<SolrServerContext>,
+        S: AsRef<str>,
+        P: AsRef<Path>,
+    >(
+        &self,
+        context: C,
+        collection: S,
+        path: P,
+    ) -> Result<SolrExtractResponse, Error> {
+        RUNTIME
+            .handle()
+            .block_on(self.extract_only_file(context, collection, path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::commit_type::CommitType;
+    use crate::queries::extract::{ExtractFormat, ExtractQuery};
+
+    #[test]
+    fn extract_query_params() {
+        let query = ExtractQuery::new()
+            .commit_type(CommitType::CommitWithin(1000))
+            .literals([("id", "report1"), ("tags", "a"), ("tags", "b")])
+            .fmap([("content", "text")])
+            .uprefix("ignored_")
+            .capture_attr(true)
+            .extract_format(ExtractFormat::Text);
+        assert_eq!(
+            query.to_query_params(false),
+            [
+                ("commitWithin", "1000"),
+                ("literal.id", "report1"),
+                ("literal.tags", "a"),
+                ("literal.tags", "b"),
+                ("fmap.content", "text"),
+                ("uprefix", "ignored_"),
+                ("captureAttr", "true"),
+            ]
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+        );
+        assert_eq!(
+            query.to_query_params(true),
+            [
+                ("extractOnly", "true"),
+                ("literal.id", "report1"),
+                ("literal.tags", "a"),
+                ("literal.tags", "b"),
+                ("fmap.content", "text"),
+                ("uprefix", "ignored_"),
+                ("captureAttr", "true"),
+                ("extractFormat", "text"),
+            ]
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+        );
+    }
+}
@@ -0,0 +1,474 @@
+use crate::error::Error;
+use crate::models::commit_type::CommitType;
+use crate::models::context::SolrServerContext;
+use crate::models::response::SolrResponse;
+use crate::queries::index::UpdateCommitOptions;
+use crate::queries::request_builder::SolrRequestBuilder;
+use reqwest::Body;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+
+/// A builder for indexing CSV data with the `/update/csv` handler.
+///
+/// The data is streamed to Solr as is, without being parsed by the client.
+/// # Examples
+/// ```no_run
+/// use solrstice::{AsyncSolrCloudClient, CommitType, CsvUpdateQuery, SolrServerContextBuilder, SolrSingleServerHost};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let query = CsvUpdateQuery::new()
+///     .separator(';')
+///     .split(["interests"])
+///     .commit_type(CommitType::CommitWithin(10_000));
+/// let response = client.index_csv_file(&query, "collection_name", "export.csv").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct CsvUpdateQuery {
+    handler: String,
+    commit_options: UpdateCommitOptions,
+    separator: Option<char>,
+    header: Option<bool>,
+    fieldnames: Option<Vec<String>>,
+    split: Option<Vec<String>>,
+    split_separator: Option<char>,
+    encapsulator: Option<char>,
+    skip: Option<Vec<String>>,
+    skip_lines: Option<usize>,
+}
+
+impl From<&CsvUpdateQuery> for CsvUpdateQuery {
+    fn from(query: &CsvUpdateQuery) -> Self {
+        query.clone()
+    }
+}
+
+impl AsRef<CsvUpdateQuery> for CsvUpdateQuery {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl CsvUpdateQuery {
+    /// Create a new instance of CsvUpdateQuery.
+    pub fn new() -> Self {
+        CsvUpdateQuery {
+            handler: "update/csv".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the handler for the query. Default is "update/csv".
+    pub fn handler<S: Into<String>>(mut self, handler: S) -> Self {
+        self.handler = handler.into();
+        self
+    }
+
+    /// Set the commit type for the query. Default is CommitType::Hard.
+    pub fn commit_type(mut self, commit_type: CommitType) -> Self {
+        self.commit_options = self.commit_options.commit_type(commit_type);
+        self
+    }
+
+    /// Set the commit type, overwrite and commit options for the query. Replaces an earlier [CsvUpdateQuery::commit_type].
+    pub fn commit_options<O: Into<UpdateCommitOptions>>(mut self, commit_options: O) -> Self {
+        self.commit_options = commit_options.into();
+        self
+    }
+
+    /// The character separating fields. Default is `,`.
+    pub fn separator<O: Into<Option<char>>>(mut self, separator: O) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Whether the first line contains the field names. Default is true, unless `fieldnames` is set.
+    pub fn header<O: Into<Option<bool>>>(mut self, header: O) -> Self {
+        self.header = header.into();
+        self
+    }
+
+    /// The field names of the columns. Overrides the header, and empty names skip the column.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::CsvUpdateQuery;
+    /// let builder = CsvUpdateQuery::new().header(false).fieldnames(["id", "", "city_name"]);
+    /// ```
+    pub fn fieldnames<S: Into<String>, V: IntoIterator<Item = S>, O: Into<Option<V>>>(
+        mut self,
+        fieldnames: O,
+    ) -> Self {
+        self.fieldnames = fieldnames
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// Fields whose values should be split into multiple values, with `f.<field>.split=true`.
+    pub fn split<S: Into<String>, V: IntoIterator<Item = S>, O: Into<Option<V>>>(
+        mut self,
+        split: O,
+    ) -> Self {
+        self.split = split
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// The character separating the values of the `split` fields. Default is `,`.
+    pub fn split_separator<O: Into<Option<char>>>(mut self, split_separator: O) -> Self {
+        self.split_separator = split_separator.into();
+        self
+    }
+
+    /// The character enclosing values containing the separator. Default is `"`.
+    pub fn encapsulator<O: Into<Option<char>>>(mut self, encapsulator: O) -> Self {
+        self.encapsulator = encapsulator.into();
+        self
+    }
+
+    /// Fields that should not be indexed.
+    pub fn skip<S: Into<String>, V: IntoIterator<Item = S>, O: Into<Option<V>>>(
+        mut self,
+        skip: O,
+    ) -> Self {
+        self.skip = skip
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// The number of lines to skip before the header or data.
+    pub fn skip_lines<O: Into<Option<usize>>>(mut self, skip_lines: O) -> Self {
+        self.skip_lines = skip_lines.into();
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        let mut query_params = update_query_params(&self.commit_options);
+        if let Some(separator) = self.separator {
+            query_params.push(("separator".to_string(), separator.to_string()));
+        }
+        if let Some(header) = self.header {
+            query_params.push(("header".to_string(), header.to_string()));
+        }
+        if let Some(fieldnames) = &self.fieldnames {
+            query_params.push(("fieldnames".to_string(), fieldnames.join(",")));
+        }
+        for field in self.split.iter().flatten() {
+            query_params.push((format!("f.{}.split", field), "true".to_string()));
+            if let Some(split_separator) = self.split_separator {
+                query_params.push((
+                    format!("f.{}.separator", field),
+                    split_separator.to_string(),
+                ));
+            }
+        }
+        if let Some(encapsulator) = self.encapsulator {
+            query_params.push(("encapsulator".to_string(), encapsulator.to_string()));
+        }
+        if let Some(skip) = &self.skip {
+            query_params.push(("skip".to_string(), skip.join(",")));
+        }
+        if let Some(skip_lines) = self.skip_lines {
+            query_params.push(("skipLines".to_string(), skip_lines.to_string()));
+        }
+        query_params
+    }
+
+    /// Stream CSV data from a reader to Solr.
+    pub async fn execute_reader<C, S, R>(
+        &self,
+        context: C,
+        collection: S,
+        reader: R,
+    ) -> Result<SolrResponse, Error>
+    where
+        C: AsRef<SolrServerContext>,
+        S: AsRef<str>,
+        R: AsyncRead + Send + 'static,
+    {
+        send_stream(
+            context.as_ref(),
+            collection.as_ref(),
+            &self.handler,
+            &self.to_query_params(),
+            "application/csv",
+            reader,
+        )
+        .await
+    }
+
+    /// Stream a CSV file to Solr.
+    ///
+    /// Use [AsyncSolrCloudClient::index_csv_file](crate::clients::async_cloud_client::AsyncSolrCloudClient::index_csv_file) for a shorter call.
+    pub async fn execute_file<C: AsRef<SolrServerContext>, S: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        context: C,
+        collection: S,
+        path: P,
+    ) -> Result<SolrResponse, Error> {
+        let file = tokio::fs::File::open(path).await?;
+        self.execute_reader(context, collection, file).await
+    }
+}
+
+/// A builder for indexing JSON documents with the `/update/json/docs` handler.
+///
+/// Accepts a single document, an array, or JSON lines with one document per line.
+/// The data is streamed to Solr as is, without being parsed by the client.
+/// # Examples
+/// ```no_run
+/// use solrstice::{AsyncSolrCloudClient, JsonDocsUpdateQuery, SolrServerContextBuilder, SolrSingleServerHost};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let query = JsonDocsUpdateQuery::new().split("/").f(["id:/id", "city_name:/city/name"]);
+/// let response = client.index_json_docs_file(&query, "collection_name", "export.ndjson").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct JsonDocsUpdateQuery {
+    handler: String,
+    commit_options: UpdateCommitOptions,
+    split: Option<String>,
+    f: Option<Vec<String>>,
+}
+
+impl From<&JsonDocsUpdateQuery> for JsonDocsUpdateQuery {
+    fn from(query: &JsonDocsUpdateQuery) -> Self {
+        query.clone()
+    }
+}
+
+impl AsRef<JsonDocsUpdateQuery> for JsonDocsUpdateQuery {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl JsonDocsUpdateQuery {
+    /// Create a new instance of JsonDocsUpdateQuery.
+    pub fn new() -> Self {
+        JsonDocsUpdateQuery {
+            handler: "update/json/docs".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the handler for the query. Default is "update/json/docs".
+    pub fn handler<S: Into<String>>(mut self, handler: S) -> Self {
+        self.handler = handler.into();
+        self
+    }
+
+    /// Set the commit type for the query. Default is CommitType::Hard.
+    pub fn commit_type(mut self, commit_type: CommitType) -> Self {
+        self.commit_options = self.commit_options.commit_type(commit_type);
+        self
+    }
+
+    /// Set the commit type, overwrite and commit options for the query. Replaces an earlier [JsonDocsUpdateQuery::commit_type].
+    pub fn commit_options<O: Into<UpdateCommitOptions>>(mut self, commit_options: O) -> Self {
+        self.commit_options = commit_options.into();
+        self
+    }
+
+    /// The path to split the input into documents on. Eg: `/` or `/exams`
+    pub fn split<S: Into<String>, O: Into<Option<S>>>(mut self, split: O) -> Self {
+        self.split = split.into().map(|x| x.into());
+        self
+    }
+
+    /// Field mappings, as `field:json-path`. Eg: `["first:/first", "/exams/**"]`
+    pub fn f<S: Into<String>, V: IntoIterator<Item = S>, O: Into<Option<V>>>(
+        mut self,
+        f: O,
+    ) -> Self {
+        self.f = f.into().map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    fn to_query_params(&self) -> Vec<(String, String)> {
+        let mut query_params = update_query_params(&self.commit_options);
+        if let Some(split) = &self.split {
+            query_params.push(("split".to_string(), split.clone()));
+        }
+        for f in self.f.iter().flatten() {
+            query_params.push(("f".to_string(), f.clone()));
+        }
+        query_params
+    }
+
+    /// Stream JSON documents from a reader to Solr.
+    pub async fn execute_reader<C, S, R>(
+        &self,
+        context: C,
+        collection: S,
+        reader: R,
+    ) -> Result<SolrResponse, Error>
+    where
+        C: AsRef<SolrServerContext>,
+        S: AsRef<str>,
+        R: AsyncRead + Send + 'static,
+    {
+        send_stream(
+            context.as_ref(),
+            collection.as_ref(),
+            &self.handler,
+            &self.to_query_params(),
+            "application/json",
+            reader,
+        )
+        .await
+    }
+
+    /// Stream a JSON or JSON lines file to Solr.
+    ///
+    /// Use [AsyncSolrCloudClient::index_json_docs_file](crate::clients::async_cloud_client::AsyncSolrCloudClient::index_json_docs_file) for a shorter call.
+    pub async fn execute_file<C: AsRef<SolrServerContext>, S: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        context: C,
+        collection: S,
+        path: P,
+    ) -> Result<SolrResponse, Error> {
+        let file = tokio::fs::File::open(path).await?;
+        self.execute_reader(context, collection, file).await
+    }
+}
+
+pub(crate) fn update_query_params(commit_options: &UpdateCommitOptions) -> Vec<(String, String)> {
+    commit_options
+        .to_query_params()
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect()
+}
+
+async fn send_stream<R: AsyncRead + Send + 'static>(
+    context: &SolrServerContext,
+    collection: &str,
+    handler: &str,
+    query_params: &[(String, String)],
+    content_type: &str,
+    reader: R,
+) -> Result<SolrResponse, Error> {
+    let query_params = query_params
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect::<Vec<_>>();
+    SolrRequestBuilder::new(
+        context,
+        format!("/solr/{}/{}", collection, handler).as_str(),
+    )
+    .with_query_params(query_params.as_ref())
+    .with_headers([("Content-Type", content_type)])
+    .send_post_with_body(Body::wrap_stream(ReaderStream::new(reader)))
+    .await
+}
+
+#[cfg(feature = "blocking")]
+use crate::runtime::RUNTIME;
+#[cfg(feature = "blocking")]
+impl CsvUpdateQuery {
+    /// Stream a CSV file to Solr.
+    ///
+    /// Use [BlockingSolrCloudClient::index_csv_file](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::index_csv_file) for a shorter call.
+    pub fn execute_file_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        context: C,
+        collection: S,
+        path: P,
+    ) -> Result<SolrResponse, Error> {
+        RUNTIME
+            .handle()
+            .block_on(self.execute_file(context, collection, path))
+    }
+}
+#[cfg(feature = "blocking")]
+impl JsonDocsUpdateQuery {
+    /// Stream a JSON or JSON lines file to Solr.
+    ///
+    /// Use [BlockingSolrCloudClient::index_json_docs_file](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::index_json_docs_file) for a shorter call.
+    pub fn execute_file_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>, P: AsRef<Path>>(
+        &self,
+        context: C,
+        collection: S,
+        path: P,
+    ) -> Result<SolrResponse, Error> {
+        RUNTIME
+            .handle()
+            .block_on(self.execute_file(context, collection, path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::commit_type::CommitType;
+    use crate::queries::file_update::{CsvUpdateQuery, JsonDocsUpdateQuery};
+    use crate::queries::index::UpdateCommitOptions;
+
+    fn params(params: &[(&str, &str)]) -> Vec<(String, String)> {
+        params
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn csv_update_query_params() {
+        let query = CsvUpdateQuery::new()
+            .commit_options(
+                UpdateCommitOptions::new()
+                    .commit_type(CommitType::CommitWithin(1000))
+                    .overwrite(false),
+            )
+            .separator(';')
+            .header(false)
+            .fieldnames(["id", "", "interests"])
+            .split(["interests"])
+            .split_separator('|')
+            .encapsulator('\'')
+            .skip(["ignored"])
+            .skip_lines(2);
+        assert_eq!(
+            query.to_query_params(),
+            params(&[
+                ("commitWithin", "1000"),
+                ("overwrite", "false"),
+                ("separator", ";"),
+                ("header", "false"),
+                ("fieldnames", "id,,interests"),
+                ("f.interests.split", "true"),
+                ("f.interests.separator", "|"),
+                ("encapsulator", "'"),
+                ("skip", "ignored"),
+                ("skipLines", "2"),
+            ])
+        );
+    }
+
+    #[test]
+    fn json_docs_update_query_params() {
+        let query = JsonDocsUpdateQuery::new()
+            .commit_type(CommitType::Soft)
+            .split("/exams")
+            .f(["first:/first", "/exams/**"]);
+        assert_eq!(
+            query.to_query_params(),
+            params(&[
+                ("softCommit", "true"),
+                ("split", "/exams"),
+                ("f", "first:/first"),
+                ("f", "/exams/**"),
+            ])
+        );
+    }
+}
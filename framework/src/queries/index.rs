@@ -2,8 +2,126 @@ use crate::error::Error;
 use crate::models::commit_type::CommitType;
 use crate::models::context::SolrServerContext;
 use crate::models::response::SolrResponse;
-use crate::queries::request_builder::SolrRequestBuilder;
+use crate::models::version::VersionConstraint;
+use crate::queries::request_builder::{SolrRequestBuilder, WireFormat};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Commit and overwrite options shared by the update queries.
+/// # Examples
+/// ```no_run
+/// use solrstice::{CommitType, UpdateCommitOptions, UpdateQuery};
+/// let options = UpdateCommitOptions::new().commit_type(CommitType::Soft).wait_searcher(false);
+/// let builder = UpdateQuery::new().commit_options(options);
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct UpdateCommitOptions {
+    pub(crate) commit_type: CommitType,
+    #[serde(default)]
+    overwrite: Option<bool>,
+    #[serde(default)]
+    wait_searcher: Option<bool>,
+    #[serde(default)]
+    open_searcher: Option<bool>,
+    #[serde(default)]
+    expunge_deletes: Option<bool>,
+}
+
+impl From<&UpdateCommitOptions> for UpdateCommitOptions {
+    fn from(options: &UpdateCommitOptions) -> Self {
+        options.clone()
+    }
+}
+
+impl AsRef<UpdateCommitOptions> for UpdateCommitOptions {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl UpdateCommitOptions {
+    /// Create a new instance of UpdateCommitOptions, doing a hard commit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the commit type. Default is CommitType::Hard.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{CommitType, UpdateCommitOptions};
+    /// let options = UpdateCommitOptions::new().commit_type(CommitType::CommitWithin(10_000));
+    /// ```
+    pub fn commit_type(mut self, commit_type: CommitType) -> Self {
+        self.commit_type = commit_type;
+        self
+    }
+
+    /// Whether documents with the same unique key should be replaced. Default is true.
+    /// Setting it to false speeds up indexing of documents that are known to be new. Not used by deletes and commits.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::UpdateCommitOptions;
+    /// let options = UpdateCommitOptions::new().overwrite(false);
+    /// ```
+    pub fn overwrite<O: Into<Option<bool>>>(mut self, overwrite: O) -> Self {
+        self.overwrite = overwrite.into();
+        self
+    }
+
+    /// Whether Solr should wait for the new searcher to be opened when committing. Default is true.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{CommitType, UpdateCommitOptions};
+    /// let options = UpdateCommitOptions::new().commit_type(CommitType::Soft).wait_searcher(false);
+    /// ```
+    pub fn wait_searcher<O: Into<Option<bool>>>(mut self, wait_searcher: O) -> Self {
+        self.wait_searcher = wait_searcher.into();
+        self
+    }
+
+    /// Whether a hard commit should open a new searcher, making the changes visible. Default is true.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::UpdateCommitOptions;
+    /// let options = UpdateCommitOptions::new().open_searcher(false);
+    /// ```
+    pub fn open_searcher<O: Into<Option<bool>>>(mut self, open_searcher: O) -> Self {
+        self.open_searcher = open_searcher.into();
+        self
+    }
+
+    /// Whether the commit should merge away segments with deleted documents. Default is false.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::UpdateCommitOptions;
+    /// let options = UpdateCommitOptions::new().expunge_deletes(true);
+    /// ```
+    pub fn expunge_deletes<O: Into<Option<bool>>>(mut self, expunge_deletes: O) -> Self {
+        self.expunge_deletes = expunge_deletes.into();
+        self
+    }
+
+    /// The commit options are only sent when the commit type commits.
+    pub(crate) fn to_query_params(&self) -> Vec<(&'static str, String)> {
+        let mut query_params = self.commit_type.to_query_params();
+        if self.commit_type.commits() {
+            let options = [
+                ("waitSearcher", self.wait_searcher),
+                ("openSearcher", self.open_searcher),
+                ("expungeDeletes", self.expunge_deletes),
+            ];
+            for (name, value) in options {
+                if let Some(value) = value {
+                    query_params.push((name, value.to_string()));
+                }
+            }
+        }
+        if let Some(overwrite) = self.overwrite {
+            query_params.push(("overwrite", overwrite.to_string()));
+        }
+        query_params
+    }
+}
 
 /// A builder for the update handler.
 /// # Examples
@@ -23,8 +141,17 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
 pub struct UpdateQuery {
-    handler: String,
-    commit_type: CommitType,
+    pub(crate) handler: String,
+    #[serde(default)]
+    pub(crate) commit_options: UpdateCommitOptions,
+    #[serde(default)]
+    return_versions: bool,
+    #[serde(default)]
+    max_errors: Option<i64>,
+    #[serde(default)]
+    update_chain: Option<String>,
+    #[serde(default)]
+    wire_format: Option<WireFormat>,
 }
 
 impl From<&UpdateQuery> for UpdateQuery {
@@ -60,7 +187,11 @@ impl UpdateQuery {
     pub fn new() -> Self {
         UpdateQuery {
             handler: "update".to_string(),
-            commit_type: CommitType::Hard,
+            commit_options: UpdateCommitOptions::new(),
+            return_versions: false,
+            max_errors: None,
+            update_chain: None,
+            wire_format: None,
         }
     }
 
@@ -83,10 +214,95 @@ impl UpdateQuery {
     /// let builder = UpdateQuery::new().commit_type(CommitType::Soft);
     /// ```
     pub fn commit_type(mut self, commit_type: CommitType) -> Self {
-        self.commit_type = commit_type;
+        self.commit_options = self.commit_options.commit_type(commit_type);
+        self
+    }
+
+    /// Set the commit type, overwrite and commit options for the query. Replaces an earlier [UpdateQuery::commit_type].
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{CommitType, UpdateCommitOptions, UpdateQuery};
+    /// let builder = UpdateQuery::new().commit_options(UpdateCommitOptions::new().commit_type(CommitType::Soft).overwrite(false));
+    /// ```
+    pub fn commit_options<O: Into<UpdateCommitOptions>>(mut self, commit_options: O) -> Self {
+        self.commit_options = commit_options.into();
+        self
+    }
+
+    /// Return the new `_version_` of every added document. Read them with [SolrResponse::get_added_versions].
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::UpdateQuery;
+    /// let builder = UpdateQuery::new().return_versions(true);
+    /// ```
+    pub fn return_versions(mut self, return_versions: bool) -> Self {
+        self.return_versions = return_versions;
         self
     }
 
+    /// The number of documents allowed to fail before the whole request fails, with `maxErrors`. `-1` allows any number.
+    ///
+    /// Requires an update chain with `TolerantUpdateProcessorFactory`, see [UpdateQuery::update_chain].
+    /// The failed documents are returned by [SolrResponse::get_update_errors].
+    /// # Examples
+    /// ```no_run
+    /// use serde_json::json;
+    /// use solrstice::{AsyncSolrCloudClient, SolrServerContextBuilder, SolrSingleServerHost, UpdateQuery};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let query = UpdateQuery::new().update_chain("tolerant").max_errors(-1);
+    /// let response = client.index(&query, "collection_name", &[json!({"id": "document1"})]).await?;
+    /// for error in response.get_update_errors() {
+    ///     println!("{} failed: {}", error.get_id(), error.get_message());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn max_errors<O: Into<Option<i64>>>(mut self, max_errors: O) -> Self {
+        self.max_errors = max_errors.into();
+        self
+    }
+
+    /// The update request processor chain to use, with `update.chain`.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::UpdateQuery;
+    /// let builder = UpdateQuery::new().update_chain("tolerant");
+    /// ```
+    pub fn update_chain<S: Into<String>, O: Into<Option<S>>>(mut self, update_chain: O) -> Self {
+        self.update_chain = update_chain.into().map(|x| x.into());
+        self
+    }
+
+    /// Override the wire format of the context for this query. With javabin the documents are also sent as javabin.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{UpdateQuery, WireFormat};
+    /// let builder = UpdateQuery::new().wire_format(WireFormat::Javabin);
+    /// ```
+    #[cfg(feature = "javabin")]
+    pub fn wire_format<O: Into<Option<WireFormat>>>(mut self, wire_format: O) -> Self {
+        self.wire_format = wire_format.into();
+        self
+    }
+
+    /// The query parameters of the update, also used for the batches of [BulkIndexer](crate::BulkIndexer).
+    pub(crate) fn to_query_params(&self) -> Vec<(&'static str, String)> {
+        let mut query_params = self.commit_options.to_query_params();
+        if self.return_versions {
+            query_params.push(("versions", "true".to_string()));
+        }
+        if let Some(max_errors) = self.max_errors {
+            query_params.push(("maxErrors", max_errors.to_string()));
+        }
+        if let Some(update_chain) = &self.update_chain {
+            query_params.push(("update.chain", update_chain.clone()));
+        }
+        query_params
+    }
+
     /// Execute the query.
     ///
     /// This is not meant to be used directly. Use [AsyncSolrCloudClient::index](crate::clients::async_cloud_client::AsyncSolrCloudClient::index) instead.
@@ -96,25 +312,39 @@ impl UpdateQuery {
         collection: S,
         data: &[D],
     ) -> Result<SolrResponse, Error> {
-        let mut query_params = vec![("overwrite", "true")];
-        match self.commit_type {
-            CommitType::Hard => query_params.push(("commit", "true")),
-            CommitType::Soft => query_params.push(("softCommit", "true")),
-        }
+        let query_params = self.to_query_params();
+        let query_params = borrow_query_params(&query_params);
 
-        SolrRequestBuilder::new(
-            context.as_ref(),
-            format!("/solr/{}/{}", collection.as_ref(), self.handler.as_str()).as_str(),
-        )
-        .with_query_params(query_params.as_ref())
-        .send_post_with_json(data)
-        .await
+        let context = context.as_ref();
+        let wire_format = self.wire_format.unwrap_or(context.wire_format);
+        let url = format!("/solr/{}/{}", collection.as_ref(), self.handler.as_str());
+        let builder = SolrRequestBuilder::new(context, url.as_str())
+            .with_query_params(query_params.as_ref())
+            .with_wire_format(wire_format);
+        match wire_format {
+            WireFormat::Json => builder.send_post_with_json(data).await,
+            #[cfg(feature = "javabin")]
+            WireFormat::Javabin => {
+                // Solr reads the options of added documents from the javabin body, not the URL
+                let body_params = query_params
+                    .iter()
+                    .filter(|(key, _)| ["overwrite", "commitWithin"].contains(key))
+                    .copied()
+                    .collect::<Vec<_>>();
+                let body = crate::javabin::encode_documents(data, &body_params)?;
+                builder
+                    .with_headers([("Content-Type", "application/javabin")])
+                    .send_post_with_body(body)
+                    .await
+            }
+        }
     }
 }
 
 /// A builder for deleting documents.
 ///
-/// Since there is no way to delete properly with JSON, it uses XML.
+/// Deletes are sent as Solr JSON update commands, so ids and queries need no escaping.
+/// Use [CommitType::CommitWithin] to have Solr commit the deletes, including deletes by query, within a time limit.
 /// # Examples
 /// ```no_run
 /// use serde::Serialize;
@@ -134,12 +364,16 @@ impl UpdateQuery {
 pub struct DeleteQuery {
     /// The handler for the query. Default is "update".
     handler: String,
-    /// The commit type for the query. Default is CommitType::Hard.
-    commit_type: CommitType,
+    /// The commit type and commit options for the query. Default is CommitType::Hard.
+    #[serde(default)]
+    commit_options: UpdateCommitOptions,
     /// Ids to delete
-    ids: Option<Vec<String>>,
+    ids: Option<Vec<DeleteById>>,
     /// Queries to delete
     queries: Option<Vec<String>>,
+    /// Return the `_version_` of the deletes
+    #[serde(default)]
+    return_versions: bool,
 }
 
 impl From<&DeleteQuery> for DeleteQuery {
@@ -174,9 +408,10 @@ impl DeleteQuery {
     pub fn new() -> Self {
         DeleteQuery {
             handler: "update".to_string(),
-            commit_type: CommitType::Hard,
+            commit_options: UpdateCommitOptions::new(),
             ids: None,
             queries: None,
+            return_versions: false,
         }
     }
 
@@ -199,7 +434,18 @@ impl DeleteQuery {
     /// let builder = DeleteQuery::new().commit_type(CommitType::Soft);
     /// ```
     pub fn commit_type(mut self, commit_type: CommitType) -> Self {
-        self.commit_type = commit_type;
+        self.commit_options = self.commit_options.commit_type(commit_type);
+        self
+    }
+
+    /// Set the commit type and commit options for the query. Replaces an earlier [DeleteQuery::commit_type].
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{CommitType, DeleteQuery, UpdateCommitOptions};
+    /// let builder = DeleteQuery::new().commit_options(UpdateCommitOptions::new().commit_type(CommitType::Hard).expunge_deletes(true));
+    /// ```
+    pub fn commit_options<O: Into<UpdateCommitOptions>>(mut self, commit_options: O) -> Self {
+        self.commit_options = commit_options.into();
         self
     }
 
@@ -209,7 +455,16 @@ impl DeleteQuery {
     /// use solrstice::DeleteQuery;
     /// let builder = DeleteQuery::new().ids(["document1", "document2"]);
     /// ```
-    pub fn ids<S: Into<String>, V: IntoIterator<Item = S>, O: Into<Option<V>>>(
+    ///
+    /// Use [DeleteById] to route the delete, or only delete if the `_version_` matches.
+    /// ```no_run
+    /// use solrstice::{DeleteById, DeleteQuery, VersionConstraint};
+    /// let builder = DeleteQuery::new().ids([
+    ///     DeleteById::new("document1").route("tenant1!"),
+    ///     DeleteById::new("document2").version(VersionConstraint::MustExist),
+    /// ]);
+    /// ```
+    pub fn ids<S: Into<DeleteById>, V: IntoIterator<Item = S>, O: Into<Option<V>>>(
         mut self,
         ids: O,
     ) -> Self {
@@ -235,6 +490,17 @@ impl DeleteQuery {
         self
     }
 
+    /// Return the `_version_` of every deleted id. Read them with [SolrResponse::get_deleted_versions].
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::DeleteQuery;
+    /// let builder = DeleteQuery::new().ids(["document1"]).return_versions(true);
+    /// ```
+    pub fn return_versions(mut self, return_versions: bool) -> Self {
+        self.return_versions = return_versions;
+        self
+    }
+
     /// Execute the query.
     ///
     /// This is not meant to be used directly. Use [AsyncSolrCloudClient::delete](crate::clients::async_cloud_client::AsyncSolrCloudClient::delete) instead.
@@ -243,39 +509,411 @@ impl DeleteQuery {
         context: C,
         collection: S,
     ) -> Result<SolrResponse, Error> {
-        let ids = self.ids.as_ref().map(|ids| {
-            ids.iter()
-                .map(|id| format!("<id>{}</id>", id))
-                .collect::<Vec<String>>()
-                .join("")
-        });
-        let queries = self.queries.as_ref().map(|queries| {
-            queries
-                .iter()
-                .map(|query| format!("<query>{}</query>", query))
-                .collect::<Vec<String>>()
-                .join("")
-        });
-
-        let mut query_params = vec![("overwrite", "true")];
-        match self.commit_type {
-            CommitType::Hard => query_params.push(("commit", "true")),
-            CommitType::Soft => query_params.push(("softCommit", "true")),
+        let mut query_params = self.commit_options.to_query_params();
+        if self.return_versions {
+            query_params.push(("versions", "true".to_string()));
         }
+        let query_params = borrow_query_params(&query_params);
 
         SolrRequestBuilder::new(
             context.as_ref(),
             format!("/solr/{}/{}", &collection.as_ref(), &self.handler).as_str(),
         )
         .with_query_params(query_params.as_ref())
-        .with_headers(vec![("Content-Type", "application/xml")])
-        .send_post_with_body(format!(
-            "<delete>{}{}</delete>",
-            ids.unwrap_or_default(),
-            queries.unwrap_or_default()
-        ))
+        .send_post_with_json(&self.to_body())
         .await
     }
+
+    /// The JSON update command, eg: `{"delete": ["id1", {"id": "id2", "_version_": 1}, {"query": "age:[* TO *]"}]}`
+    fn to_body(&self) -> Value {
+        let ids = self.ids.iter().flatten().map(DeleteById::to_value);
+        let queries = self
+            .queries
+            .iter()
+            .flatten()
+            .map(|query| serde_json::json!({ "query": query }));
+        serde_json::json!({ "delete": ids.chain(queries).collect::<Vec<Value>>() })
+    }
+}
+
+/// An id to delete, with an optional `_route_` and `_version_` constraint.
+///
+/// Strings convert into a plain delete by id.
+/// # Examples
+/// ```
+/// use solrstice::{DeleteById, VersionConstraint};
+/// let delete = DeleteById::new("document1").route("tenant1!").version(VersionConstraint::exact(1712345678901234567));
+/// let plain: DeleteById = "document2".into();
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct DeleteById {
+    id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    route: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    version: Option<VersionConstraint>,
+}
+
+impl DeleteById {
+    /// Delete the document with the given unique key.
+    pub fn new<S: Into<String>>(id: S) -> Self {
+        DeleteById {
+            id: id.into(),
+            route: None,
+            version: None,
+        }
+    }
+
+    /// Route the delete to the shard of this `_route_` value, needed with the implicit router or `router.field`.
+    pub fn route<S: Into<String>, O: Into<Option<S>>>(mut self, route: O) -> Self {
+        self.route = route.into().map(|x| x.into());
+        self
+    }
+
+    /// Only delete if the `_version_` of the document matches the constraint.
+    pub fn version<O: Into<Option<VersionConstraint>>>(mut self, version: O) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn get_route(&self) -> Option<&str> {
+        self.route.as_deref()
+    }
+
+    pub fn get_version(&self) -> Option<VersionConstraint> {
+        self.version
+    }
+
+    /// A plain string if there are no options, as Solr accepts both.
+    pub(crate) fn to_value(&self) -> Value {
+        if self.route.is_none() && self.version.is_none() {
+            return Value::String(self.id.clone());
+        }
+        let mut delete = serde_json::Map::new();
+        delete.insert("id".to_string(), Value::String(self.id.clone()));
+        if let Some(route) = &self.route {
+            delete.insert("_route_".to_string(), Value::String(route.clone()));
+        }
+        if let Some(version) = self.version {
+            delete.insert("_version_".to_string(), version.as_i64().into());
+        }
+        Value::Object(delete)
+    }
+}
+
+impl From<String> for DeleteById {
+    fn from(id: String) -> Self {
+        DeleteById::new(id)
+    }
+}
+
+impl From<&String> for DeleteById {
+    fn from(id: &String) -> Self {
+        DeleteById::new(id)
+    }
+}
+
+impl From<&str> for DeleteById {
+    fn from(id: &str) -> Self {
+        DeleteById::new(id)
+    }
+}
+
+impl From<&DeleteById> for DeleteById {
+    fn from(delete: &DeleteById) -> Self {
+        delete.clone()
+    }
+}
+
+/// A builder for an explicit commit, making pending changes durable and visible.
+///
+/// Useful together with [CommitType::NoCommit] or [CommitType::CommitWithin] for fast indexing.
+/// # Examples
+/// ```no_run
+/// use solrstice::{AsyncSolrCloudClient, CommitQuery, SolrServerContextBuilder, SolrSingleServerHost};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let response = client.commit(&CommitQuery::new().soft_commit(true), "collection_name").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct CommitQuery {
+    handler: String,
+    soft_commit: bool,
+    #[serde(default)]
+    commit_options: UpdateCommitOptions,
+}
+
+impl From<&CommitQuery> for CommitQuery {
+    fn from(query: &CommitQuery) -> Self {
+        query.clone()
+    }
+}
+
+impl AsRef<CommitQuery> for CommitQuery {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl CommitQuery {
+    /// Create a new instance of CommitQuery, doing a hard commit.
+    pub fn new() -> Self {
+        CommitQuery {
+            handler: "update".to_string(),
+            soft_commit: false,
+            commit_options: UpdateCommitOptions::new(),
+        }
+    }
+
+    /// Set the handler for the query. Default is "update".
+    pub fn handler<S: Into<String>>(mut self, handler: S) -> Self {
+        self.handler = handler.into();
+        self
+    }
+
+    /// Do a soft commit instead of a hard commit. Default is false.
+    pub fn soft_commit(mut self, soft_commit: bool) -> Self {
+        self.soft_commit = soft_commit;
+        self
+    }
+
+    /// Set wait_searcher, open_searcher and expunge_deletes for the commit.
+    /// The commit type and overwrite of the options are not used, see [CommitQuery::soft_commit].
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{CommitQuery, UpdateCommitOptions};
+    /// let builder = CommitQuery::new().commit_options(UpdateCommitOptions::new().wait_searcher(false));
+    /// ```
+    pub fn commit_options<O: Into<UpdateCommitOptions>>(mut self, commit_options: O) -> Self {
+        self.commit_options = commit_options.into();
+        self
+    }
+
+    /// The options as a JSON `commit` command, used by [UpdateBatch](crate::UpdateBatch).
+    pub(crate) fn to_command(&self) -> Value {
+        let mut command = serde_json::Map::new();
+        let options = [
+            ("softCommit", Some(self.soft_commit)),
+            ("waitSearcher", self.commit_options.wait_searcher),
+            ("openSearcher", self.commit_options.open_searcher),
+            ("expungeDeletes", self.commit_options.expunge_deletes),
+        ];
+        for (name, value) in options {
+            if let Some(value) = value {
+                command.insert(name.to_string(), value.into());
+            }
+        }
+        Value::Object(command)
+    }
+
+    /// Execute the query.
+    ///
+    /// This is not meant to be used directly. Use [AsyncSolrCloudClient::commit](crate::clients::async_cloud_client::AsyncSolrCloudClient::commit) instead.
+    pub async fn execute<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        &self,
+        context: C,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        let commit_type = match self.soft_commit {
+            true => CommitType::Soft,
+            false => CommitType::Hard,
+        };
+        let query_params = UpdateCommitOptions {
+            commit_type,
+            overwrite: None,
+            ..self.commit_options.clone()
+        }
+        .to_query_params();
+        let query_params = borrow_query_params(&query_params);
+        SolrRequestBuilder::new(
+            context.as_ref(),
+            format!("/solr/{}/{}", collection.as_ref(), self.handler).as_str(),
+        )
+        .with_query_params(query_params.as_ref())
+        .send_post_with_json(&[] as &[Value])
+        .await
+    }
+}
+
+/// A builder for optimizing, merging the segments of an index.
+///
+/// Optimizing is expensive, and rarely needed for indexes that are continuously updated.
+/// # Examples
+/// ```no_run
+/// use solrstice::{AsyncSolrCloudClient, OptimizeQuery, SolrServerContextBuilder, SolrSingleServerHost};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let response = client.optimize(&OptimizeQuery::new().max_segments(1), "collection_name").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct OptimizeQuery {
+    handler: String,
+    max_segments: Option<usize>,
+    wait_searcher: Option<bool>,
+}
+
+impl From<&OptimizeQuery> for OptimizeQuery {
+    fn from(query: &OptimizeQuery) -> Self {
+        query.clone()
+    }
+}
+
+impl AsRef<OptimizeQuery> for OptimizeQuery {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl OptimizeQuery {
+    /// Create a new instance of OptimizeQuery.
+    pub fn new() -> Self {
+        OptimizeQuery {
+            handler: "update".to_string(),
+            max_segments: None,
+            wait_searcher: None,
+        }
+    }
+
+    /// Set the handler for the query. Default is "update".
+    pub fn handler<S: Into<String>>(mut self, handler: S) -> Self {
+        self.handler = handler.into();
+        self
+    }
+
+    /// Merge down to at most this many segments. Default is 1.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::OptimizeQuery;
+    /// let builder = OptimizeQuery::new().max_segments(4);
+    /// ```
+    pub fn max_segments<O: Into<Option<usize>>>(mut self, max_segments: O) -> Self {
+        self.max_segments = max_segments.into();
+        self
+    }
+
+    /// Whether Solr should wait for the new searcher to be opened. Default is true.
+    pub fn wait_searcher<O: Into<Option<bool>>>(mut self, wait_searcher: O) -> Self {
+        self.wait_searcher = wait_searcher.into();
+        self
+    }
+
+    /// The options as a JSON `optimize` command, used by [UpdateBatch](crate::UpdateBatch).
+    pub(crate) fn to_command(&self) -> Value {
+        let mut command = serde_json::Map::new();
+        if let Some(max_segments) = self.max_segments {
+            command.insert("maxSegments".to_string(), max_segments.into());
+        }
+        if let Some(wait_searcher) = self.wait_searcher {
+            command.insert("waitSearcher".to_string(), wait_searcher.into());
+        }
+        Value::Object(command)
+    }
+
+    /// Execute the query.
+    ///
+    /// This is not meant to be used directly. Use [AsyncSolrCloudClient::optimize](crate::clients::async_cloud_client::AsyncSolrCloudClient::optimize) instead.
+    pub async fn execute<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        &self,
+        context: C,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        let mut query_params = vec![("optimize", "true".to_string())];
+        if let Some(max_segments) = self.max_segments {
+            query_params.push(("maxSegments", max_segments.to_string()));
+        }
+        if let Some(wait_searcher) = self.wait_searcher {
+            query_params.push(("waitSearcher", wait_searcher.to_string()));
+        }
+        let query_params = borrow_query_params(&query_params);
+        SolrRequestBuilder::new(
+            context.as_ref(),
+            format!("/solr/{}/{}", collection.as_ref(), self.handler).as_str(),
+        )
+        .with_query_params(query_params.as_ref())
+        .send_post_with_json(&[] as &[Value])
+        .await
+    }
+}
+
+/// A builder for rolling back all changes since the last commit.
+///
+/// Solr does not support rollbacks in SolrCloud mode, only on standalone servers.
+/// # Examples
+/// ```no_run
+/// use solrstice::{AsyncSolrCloudClient, RollbackQuery, SolrServerContextBuilder, SolrSingleServerHost};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let response = client.rollback(&RollbackQuery::new(), "core_name").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct RollbackQuery {
+    handler: String,
+}
+
+impl From<&RollbackQuery> for RollbackQuery {
+    fn from(query: &RollbackQuery) -> Self {
+        query.clone()
+    }
+}
+
+impl AsRef<RollbackQuery> for RollbackQuery {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl RollbackQuery {
+    /// Create a new instance of RollbackQuery.
+    pub fn new() -> Self {
+        RollbackQuery {
+            handler: "update".to_string(),
+        }
+    }
+
+    /// Set the handler for the query. Default is "update".
+    pub fn handler<S: Into<String>>(mut self, handler: S) -> Self {
+        self.handler = handler.into();
+        self
+    }
+
+    /// Execute the query.
+    ///
+    /// This is not meant to be used directly. Use [AsyncSolrCloudClient::rollback](crate::clients::async_cloud_client::AsyncSolrCloudClient::rollback) instead.
+    pub async fn execute<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        &self,
+        context: C,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        SolrRequestBuilder::new(
+            context.as_ref(),
+            format!("/solr/{}/{}", collection.as_ref(), self.handler).as_str(),
+        )
+        .send_post_with_json(&serde_json::json!({"rollback": {}}))
+        .await
+    }
+}
+
+fn borrow_query_params<'a>(query_params: &'a [(&'a str, String)]) -> Vec<(&'a str, &'a str)> {
+    query_params
+        .iter()
+        .map(|(key, value)| (*key, value.as_str()))
+        .collect()
 }
 
 #[cfg(feature = "blocking")]
@@ -309,3 +947,105 @@ impl DeleteQuery {
         RUNTIME.handle().block_on(self.execute(context, collection))
     }
 }
+#[cfg(feature = "blocking")]
+impl CommitQuery {
+    /// Execute the query.
+    ///
+    /// This is not meant to be used directly. Use [BlockingSolrCloudClient::commit](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::commit) instead.
+    pub fn execute_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        &self,
+        context: C,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        RUNTIME.handle().block_on(self.execute(context, collection))
+    }
+}
+#[cfg(feature = "blocking")]
+impl OptimizeQuery {
+    /// Execute the query.
+    ///
+    /// This is not meant to be used directly. Use [BlockingSolrCloudClient::optimize](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::optimize) instead.
+    pub fn execute_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        &self,
+        context: C,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        RUNTIME.handle().block_on(self.execute(context, collection))
+    }
+}
+#[cfg(feature = "blocking")]
+impl RollbackQuery {
+    /// Execute the query.
+    ///
+    /// This is not meant to be used directly. Use [BlockingSolrCloudClient::rollback](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::rollback) instead.
+    pub fn execute_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        &self,
+        context: C,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        RUNTIME.handle().block_on(self.execute(context, collection))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::commit_type::CommitType;
+    use crate::models::version::VersionConstraint;
+    use crate::queries::index::{DeleteById, DeleteQuery, UpdateCommitOptions};
+    use serde_json::json;
+
+    #[test]
+    fn delete_query_is_sent_as_json() {
+        let query = DeleteQuery::new()
+            .ids([
+                DeleteById::new("plain"),
+                DeleteById::new("</id><query>*:*</query>").route("tenant1!"),
+                DeleteById::new("versioned").version(VersionConstraint::MustExist),
+            ])
+            .queries(["name:\"a & b\""]);
+        assert_eq!(
+            query.to_body(),
+            json!({"delete": [
+                "plain",
+                {"id": "</id><query>*:*</query>", "_route_": "tenant1!"},
+                {"id": "versioned", "_version_": 1},
+                {"query": "name:\"a & b\""}
+            ]})
+        );
+        assert_eq!(DeleteQuery::new().to_body(), json!({"delete": []}));
+    }
+
+    #[test]
+    fn commit_options_are_only_sent_with_commits() {
+        let options = UpdateCommitOptions::new()
+            .wait_searcher(false)
+            .expunge_deletes(true)
+            .overwrite(false);
+        assert_eq!(
+            options
+                .clone()
+                .commit_type(CommitType::Soft)
+                .to_query_params(),
+            vec![
+                ("softCommit", "true".to_string()),
+                ("waitSearcher", "false".to_string()),
+                ("expungeDeletes", "true".to_string()),
+                ("overwrite", "false".to_string())
+            ]
+        );
+        assert_eq!(
+            options
+                .clone()
+                .commit_type(CommitType::CommitWithin(1000))
+                .to_query_params(),
+            vec![
+                ("commitWithin", "1000".to_string()),
+                ("overwrite", "false".to_string())
+            ]
+        );
+        assert!(UpdateCommitOptions::new()
+            .commit_type(CommitType::NoCommit)
+            .to_query_params()
+            .is_empty());
+    }
+}
@@ -1,7 +1,13 @@
 //! The different query types this library supports.
 
+/// Polling of async Collections API requests
+pub(crate) mod admin_task;
 /// Alias API
 pub mod alias;
+/// Backup and restore of collections
+pub mod backup;
+/// Bulk indexing with batching, concurrency and retries
+pub(crate) mod bulk_index;
 /// Collection API
 pub mod collection;
 /// Components for select queries
@@ -10,9 +16,19 @@ pub mod components;
 pub mod config;
 /// Def types for select queries. Eg: `luscene`, `edismax`
 pub(crate) mod def_type;
+/// Solr Cell extraction of binary files
+pub(crate) mod extract;
+/// Indexing of CSV and JSON lines files
+pub(crate) mod file_update;
 /// Index and Delete API
 pub(crate) mod index;
+/// Copying of documents between collections, on the client or the server
+pub(crate) mod reindex;
 /// Request builder for queries
 pub(crate) mod request_builder;
 /// Select query API
 pub(crate) mod select;
+/// Parsing of classic Solr parameters
+pub(crate) mod solr_params;
+/// Several update commands in one request
+pub(crate) mod update_batch;
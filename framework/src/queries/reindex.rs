@@ -0,0 +1,672 @@
+use crate::error::Error;
+use crate::models::context::SolrServerContext;
+use crate::models::reindex::SolrReindexStatus;
+use crate::queries::bulk_index::{BulkIndexSummary, BulkIndexer};
+use crate::queries::request_builder::SolrRequestBuilder;
+use crate::queries::select::SelectQuery;
+#[cfg(feature = "blocking")]
+use crate::runtime::RUNTIME;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+type TransformFn = Arc<dyn Fn(Value) -> Option<Value> + Send + Sync>;
+type ProgressCallback = Arc<dyn Fn(&ReindexProgress) + Send + Sync>;
+
+/// Copy the documents of one collection into another, on the client.
+///
+/// Documents are read page by page with a cursor mark, optionally transformed, and indexed with a [BulkIndexer].
+/// The next page is read while the current one is indexed. The target collection can be on another [SolrServerContext].
+///
+/// Only stored and docValues fields can be copied. `_version_` is removed from the documents, so they are not rejected as version conflicts.
+/// Fields filled by `copyField` should be left out with [SelectQuery::fl] if they are stored.
+/// For nested documents, only read the parents, and include the children with `fl=*,[child]`.
+///
+/// A reindex can be resumed by passing the last cursor mark reported by [Reindexer::on_progress] to [Reindexer::cursor_mark].
+/// Take a look at [ReindexCollectionQuery] for reindexing on the server instead.
+/// # Examples
+/// ```no_run
+/// use solrstice::{AsyncSolrCloudClient, Reindexer, SolrServerContextBuilder, SolrSingleServerHost};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let reindexer = Reindexer::new()
+///     .transform(|mut doc| {
+///         doc["name_s"] = doc["name"].clone();
+///         Some(doc)
+///     })
+///     .on_progress(|progress| println!("{} docs indexed, resume from {}", progress.get_docs_indexed(), progress.get_cursor_mark()));
+/// let summary = client.reindex(&reindexer, "old_collection", "new_collection").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Reindexer {
+    query: SelectQuery,
+    cursor_mark: String,
+    indexer: BulkIndexer,
+    transform: Option<TransformFn>,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl Debug for Reindexer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Reindexer")
+            .field("query", &self.query)
+            .field("cursor_mark", &self.cursor_mark)
+            .field("indexer", &self.indexer)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Reindexer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsRef<Reindexer> for Reindexer {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&Reindexer> for Reindexer {
+    fn from(reindexer: &Reindexer) -> Self {
+        reindexer.clone()
+    }
+}
+
+impl Reindexer {
+    /// Create a new reindexer, copying all documents in pages of 1000, sorted by the unique key.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::Reindexer;
+    /// let reindexer = Reindexer::new();
+    /// ```
+    pub fn new() -> Self {
+        Reindexer {
+            query: SelectQuery::new().rows(1000),
+            cursor_mark: "*".to_string(),
+            indexer: BulkIndexer::new(),
+            transform: None,
+            on_progress: None,
+        }
+    }
+
+    /// The query for reading the source collection. `rows` is the page size.
+    ///
+    /// Without a sort, the documents are sorted by the unique key of the source collection, read from the schema API.
+    /// A sort must include the unique key, as required for cursor marks. Default is `*:*` with 1000 rows.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{Reindexer, SelectQuery};
+    /// let reindexer = Reindexer::new().query(
+    ///     SelectQuery::new().fq(["age:[* TO *]"]).fl(["id", "age", "name"]).sort(["id asc"]).rows(5000),
+    /// );
+    /// ```
+    pub fn query<Q: Into<SelectQuery>>(mut self, query: Q) -> Self {
+        self.query = query.into();
+        self
+    }
+
+    /// Resume from a cursor mark reported by an earlier run. Default is `*`, the start.
+    /// The query must be the same as in the earlier run.
+    pub fn cursor_mark<S: Into<String>>(mut self, cursor_mark: S) -> Self {
+        self.cursor_mark = cursor_mark.into();
+        self
+    }
+
+    /// The indexer for the target collection, for batch size, concurrency and retries.
+    ///
+    /// Its commit is issued once after the last page, or sent with every batch for `CommitType::CommitWithin`.
+    pub fn indexer<B: Into<BulkIndexer>>(mut self, indexer: B) -> Self {
+        self.indexer = indexer.into();
+        self
+    }
+
+    /// Transform every document before it is indexed. Documents the closure returns `None` for are skipped.
+    pub fn transform<F: Fn(Value) -> Option<Value> + Send + Sync + 'static>(
+        mut self,
+        transform: F,
+    ) -> Self {
+        self.transform = Some(Arc::new(transform));
+        self
+    }
+
+    /// Called after every page has been indexed, with the cursor mark to resume from.
+    pub fn on_progress<F: Fn(&ReindexProgress) + Send + Sync + 'static>(
+        mut self,
+        on_progress: F,
+    ) -> Self {
+        self.on_progress = Some(Arc::new(on_progress));
+        self
+    }
+
+    /// Copy the documents of `source` into `target`.
+    ///
+    /// The reindex stops after a page with failed documents, which are counted in the summary.
+    /// The cursor mark of the summary is then that of the last fully indexed page, so resuming from it retries the failed page.
+    /// An error is returned if reading a page fails, or if the final commit fails.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{Reindexer, SolrServerContextBuilder, SolrSingleServerHost};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let old = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://old-solr:8983")).build();
+    /// let new = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://new-solr:8983")).build();
+    /// let summary = Reindexer::new().execute(&old, "collection_name", &new, "collection_name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute<SC, S, TC, T>(
+        &self,
+        source_context: SC,
+        source: S,
+        target_context: TC,
+        target: T,
+    ) -> Result<ReindexSummary, Error>
+    where
+        SC: AsRef<SolrServerContext>,
+        S: AsRef<str>,
+        TC: AsRef<SolrServerContext>,
+        T: AsRef<str>,
+    {
+        let (source_context, source) = (source_context.as_ref(), source.as_ref());
+        let (target_context, target) = (target_context.as_ref(), target.as_ref());
+        let page_indexer = self.indexer.without_commit();
+        let query = self.page_query(source_context, source).await?;
+
+        let mut summary = ReindexSummary {
+            cursor_mark: self.cursor_mark.clone(),
+            ..ReindexSummary::default()
+        };
+        let mut page = read_page(&query, source_context, source, &self.cursor_mark).await?;
+        while !page.docs.is_empty() {
+            let docs_read = page.docs.len();
+            let docs = page
+                .docs
+                .into_iter()
+                .filter_map(|doc| self.prepare(doc))
+                .collect::<Vec<_>>();
+            let docs_skipped = docs_read - docs.len();
+            let (indexed, next_page) = futures::join!(
+                page_indexer.execute_iter(target_context, target, docs),
+                read_page(&query, source_context, source, &page.next_cursor_mark)
+            );
+            let indexed = indexed?;
+            summary.add_page(docs_read, docs_skipped, &indexed, page.next_cursor_mark);
+            if let Some(on_progress) = &self.on_progress {
+                on_progress(&ReindexProgress {
+                    num_found: page.num_found,
+                    summary: &summary,
+                });
+            }
+            if indexed.get_docs_failed() > 0 {
+                break;
+            }
+            page = next_page?;
+        }
+        self.indexer.commit(target_context, target).await?;
+        Ok(summary)
+    }
+
+    /// The query for reading the source collection, sorted by its unique key if no sort is set.
+    async fn page_query(
+        &self,
+        context: &SolrServerContext,
+        collection: &str,
+    ) -> Result<SelectQuery, Error> {
+        if self.query.sort.is_some() {
+            return Ok(self.query.clone());
+        }
+        let unique_key = get_unique_key(context, collection).await?;
+        Ok(self.query.clone().sort([format!("{} asc", unique_key)]))
+    }
+
+    fn prepare(&self, mut doc: Value) -> Option<Value> {
+        remove_versions(&mut doc);
+        match &self.transform {
+            Some(transform) => transform(doc),
+            None => Some(doc),
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Reindexer {
+    /// Copy the documents of `source` into `target`.
+    pub fn execute_blocking<SC, S, TC, T>(
+        &self,
+        source_context: SC,
+        source: S,
+        target_context: TC,
+        target: T,
+    ) -> Result<ReindexSummary, Error>
+    where
+        SC: AsRef<SolrServerContext>,
+        S: AsRef<str>,
+        TC: AsRef<SolrServerContext>,
+        T: AsRef<str>,
+    {
+        RUNTIME
+            .handle()
+            .block_on(self.execute(source_context, source, target_context, target))
+    }
+}
+
+async fn read_page(
+    query: &SelectQuery,
+    context: &SolrServerContext,
+    collection: &str,
+    cursor_mark: &str,
+) -> Result<ReindexPage, Error> {
+    let response = query
+        .clone()
+        .cursor_mark(cursor_mark)
+        .execute(context, collection)
+        .await?;
+    let next_cursor_mark = response.next_cursor_mark.clone().ok_or(Error::Unknown(
+        "Solr did not return a nextCursorMark".to_string(),
+    ))?;
+    let (num_found, docs) = match response.get_docs_response() {
+        Some(docs) => (docs.get_num_found(), docs.get_docs::<Value>()?),
+        None => (0, Vec::new()),
+    };
+    Ok(ReindexPage {
+        num_found,
+        docs,
+        next_cursor_mark,
+    })
+}
+
+/// Get the name of the unique key field of a collection from the schema API.
+async fn get_unique_key(context: &SolrServerContext, collection: &str) -> Result<String, Error> {
+    let response =
+        SolrRequestBuilder::new(context, &format!("/solr/{}/schema/uniquekey", collection))
+            .send_get::<HashMap<String, Value>>()
+            .await?;
+    response
+        .get("uniqueKey")
+        .and_then(|key| key.as_str())
+        .map(|key| key.to_string())
+        .ok_or(Error::Unknown(format!(
+            "Could not get the unique key of {}",
+            collection
+        )))
+}
+
+struct ReindexPage {
+    num_found: usize,
+    docs: Vec<Value>,
+    next_cursor_mark: String,
+}
+
+/// Remove `_version_` from a document and its child documents.
+fn remove_versions(doc: &mut Value) {
+    match doc {
+        Value::Object(map) => {
+            map.remove("_version_");
+            map.values_mut().for_each(remove_versions);
+        }
+        Value::Array(values) => values.iter_mut().for_each(remove_versions),
+        _ => {}
+    }
+}
+
+/// Progress of a [Reindexer], reported after every page.
+#[derive(Debug)]
+pub struct ReindexProgress<'a> {
+    num_found: usize,
+    summary: &'a ReindexSummary,
+}
+
+impl ReindexProgress<'_> {
+    /// The number of documents matching the query in the source collection.
+    pub fn get_num_found(&self) -> usize {
+        self.num_found
+    }
+
+    /// The number of pages indexed so far.
+    pub fn get_pages(&self) -> usize {
+        self.summary.pages
+    }
+
+    /// The total number of documents read so far.
+    pub fn get_docs_read(&self) -> usize {
+        self.summary.docs_read
+    }
+
+    /// The total number of documents indexed so far.
+    pub fn get_docs_indexed(&self) -> usize {
+        self.summary.docs_indexed
+    }
+
+    /// The total number of documents in failed batches so far.
+    pub fn get_docs_failed(&self) -> usize {
+        self.summary.docs_failed
+    }
+
+    /// The total number of documents skipped by the transform so far.
+    pub fn get_docs_skipped(&self) -> usize {
+        self.summary.docs_skipped
+    }
+
+    /// The cursor mark to pass to [Reindexer::cursor_mark] to resume after the last fully indexed page.
+    pub fn get_cursor_mark(&self) -> &str {
+        &self.summary.cursor_mark
+    }
+}
+
+/// The result of a [Reindexer] run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReindexSummary {
+    pages: usize,
+    docs_read: usize,
+    docs_indexed: usize,
+    docs_failed: usize,
+    docs_skipped: usize,
+    cursor_mark: String,
+}
+
+impl ReindexSummary {
+    /// The number of pages read from the source collection.
+    pub fn get_pages(&self) -> usize {
+        self.pages
+    }
+
+    /// The number of documents read from the source collection.
+    pub fn get_docs_read(&self) -> usize {
+        self.docs_read
+    }
+
+    /// The number of documents indexed into the target collection.
+    pub fn get_docs_indexed(&self) -> usize {
+        self.docs_indexed
+    }
+
+    /// The number of documents in failed batches.
+    pub fn get_docs_failed(&self) -> usize {
+        self.docs_failed
+    }
+
+    /// The number of documents skipped by the transform.
+    pub fn get_docs_skipped(&self) -> usize {
+        self.docs_skipped
+    }
+
+    /// The cursor mark after the last fully indexed page.
+    /// Resuming from it retries a page with failed documents, or only picks up documents added to the source collection since.
+    pub fn get_cursor_mark(&self) -> &str {
+        &self.cursor_mark
+    }
+
+    /// Count an indexed page. The cursor mark is not advanced past a page with failed documents.
+    fn add_page(
+        &mut self,
+        docs_read: usize,
+        docs_skipped: usize,
+        indexed: &BulkIndexSummary,
+        next_cursor_mark: String,
+    ) {
+        self.pages += 1;
+        self.docs_read += docs_read;
+        self.docs_skipped += docs_skipped;
+        self.docs_indexed += indexed.get_docs_indexed();
+        self.docs_failed += indexed.get_docs_failed();
+        if indexed.get_docs_failed() == 0 {
+            self.cursor_mark = next_cursor_mark;
+        }
+    }
+}
+
+/// A builder for Solr's `REINDEXCOLLECTION` action, which reindexes a collection on the server.
+///
+/// Without a target, Solr reindexes into a new collection and points an alias with the name of the source collection at it.
+/// Requires Solr 8.1 or later.
+/// # Examples
+/// ```no_run
+/// use solrstice::{AsyncSolrCloudClient, ReindexCollectionQuery, SolrServerContextBuilder, SolrSingleServerHost};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let query = ReindexCollectionQuery::new().target("new_collection").config_name("new_config");
+/// let status = client.reindex_collection(&query, "old_collection").await?;
+/// println!("{:?} docs processed", status.get_processed_docs());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct ReindexCollectionQuery {
+    target: Option<String>,
+    q: Option<String>,
+    fl: Option<Vec<String>>,
+    rows: Option<usize>,
+    config_name: Option<String>,
+    remove_source: Option<bool>,
+    async_id: Option<String>,
+}
+
+impl AsRef<ReindexCollectionQuery> for ReindexCollectionQuery {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl From<&ReindexCollectionQuery> for ReindexCollectionQuery {
+    fn from(query: &ReindexCollectionQuery) -> Self {
+        query.clone()
+    }
+}
+
+impl ReindexCollectionQuery {
+    /// Create a new server side reindex, of all documents into a new collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The collection to reindex into. It is created if it does not exist.
+    pub fn target<S: Into<String>, O: Into<Option<S>>>(mut self, target: O) -> Self {
+        self.target = target.into().map(|x| x.into());
+        self
+    }
+
+    /// Only reindex the documents matching the query. Default is `*:*`.
+    pub fn q<S: Into<String>, O: Into<Option<S>>>(mut self, q: O) -> Self {
+        self.q = q.into().map(|x| x.into());
+        self
+    }
+
+    /// The fields to copy. Default is `*`.
+    pub fn fl<S: Into<String>, V: IntoIterator<Item = S>, O: Into<Option<V>>>(
+        mut self,
+        fields: O,
+    ) -> Self {
+        self.fl = fields
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// The number of documents read per request. Default is 100.
+    pub fn rows<O: Into<Option<usize>>>(mut self, rows: O) -> Self {
+        self.rows = rows.into();
+        self
+    }
+
+    /// The config set of the target collection. Default is the config set of the source collection.
+    pub fn config_name<S: Into<String>, O: Into<Option<S>>>(mut self, config_name: O) -> Self {
+        self.config_name = config_name.into().map(|x| x.into());
+        self
+    }
+
+    /// Delete the source collection when the reindex has finished. Default is false.
+    pub fn remove_source<O: Into<Option<bool>>>(mut self, remove_source: O) -> Self {
+        self.remove_source = remove_source.into();
+        self
+    }
+
+    /// `async` request id, see [AsyncAdminTask](crate::AsyncAdminTask).
+    pub fn async_id<S: Into<String>, O: Into<Option<S>>>(mut self, async_id: O) -> Self {
+        self.async_id = async_id.into().map(|x| x.into());
+        self
+    }
+
+    /// Start the reindex, and wait for it to finish, unless a [ReindexCollectionQuery::async_id] is set.
+    ///
+    /// This is not meant to be used directly. Use [AsyncSolrCloudClient::reindex_collection](crate::clients::async_cloud_client::AsyncSolrCloudClient::reindex_collection) instead.
+    pub async fn execute<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        &self,
+        context: C,
+        collection: S,
+    ) -> Result<SolrReindexStatus, Error> {
+        let mut query_params = vec![("cmd", "start".to_string())];
+        let options = [
+            ("target", self.target.clone()),
+            ("q", self.q.clone()),
+            ("fl", self.fl.as_ref().map(|fl| fl.join(","))),
+            ("rows", self.rows.map(|rows| rows.to_string())),
+            ("configName", self.config_name.clone()),
+            ("removeSource", self.remove_source.map(|r| r.to_string())),
+            ("async", self.async_id.clone()),
+        ];
+        for (name, value) in options {
+            if let Some(value) = value {
+                query_params.push((name, value));
+            }
+        }
+        send_reindex_collection(context.as_ref(), collection.as_ref(), query_params).await
+    }
+
+    /// Get the status of a running reindex of `collection`.
+    pub async fn status<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        context: C,
+        collection: S,
+    ) -> Result<SolrReindexStatus, Error> {
+        let query_params = vec![("cmd", "status".to_string())];
+        send_reindex_collection(context.as_ref(), collection.as_ref(), query_params).await
+    }
+
+    /// Abort a running reindex of `collection`. The source collection is left as it was.
+    pub async fn abort<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        context: C,
+        collection: S,
+    ) -> Result<SolrReindexStatus, Error> {
+        let query_params = vec![("cmd", "abort".to_string())];
+        send_reindex_collection(context.as_ref(), collection.as_ref(), query_params).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ReindexCollectionQuery {
+    /// Start the reindex, and wait for it to finish, unless a [ReindexCollectionQuery::async_id] is set.
+    ///
+    /// This is not meant to be used directly. Use [BlockingSolrCloudClient::reindex_collection](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::reindex_collection) instead.
+    pub fn execute_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        &self,
+        context: C,
+        collection: S,
+    ) -> Result<SolrReindexStatus, Error> {
+        RUNTIME.handle().block_on(self.execute(context, collection))
+    }
+
+    /// Get the status of a running reindex of `collection`.
+    pub fn status_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        context: C,
+        collection: S,
+    ) -> Result<SolrReindexStatus, Error> {
+        RUNTIME.handle().block_on(Self::status(context, collection))
+    }
+
+    /// Abort a running reindex of `collection`.
+    pub fn abort_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        context: C,
+        collection: S,
+    ) -> Result<SolrReindexStatus, Error> {
+        RUNTIME.handle().block_on(Self::abort(context, collection))
+    }
+}
+
+async fn send_reindex_collection(
+    context: &SolrServerContext,
+    collection: &str,
+    mut query_params: Vec<(&str, String)>,
+) -> Result<SolrReindexStatus, Error> {
+    query_params.push(("action", "REINDEXCOLLECTION".to_string()));
+    query_params.push(("name", collection.to_string()));
+    let query_params = query_params
+        .iter()
+        .map(|(key, value)| (*key, value.as_str()))
+        .collect::<Vec<_>>();
+    let response = SolrRequestBuilder::new(context, "/solr/admin/collections")
+        .with_query_params(query_params.as_ref())
+        .send_get::<HashMap<String, Value>>()
+        .await?;
+    SolrReindexStatus::from_response(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::queries::bulk_index::BulkIndexSummary;
+    use crate::queries::reindex::{remove_versions, ReindexSummary, Reindexer};
+    use serde_json::json;
+
+    #[test]
+    fn remove_versions_removes_nested_versions() {
+        let mut doc = json!({
+            "id": "1",
+            "_version_": 1,
+            "children": [{"id": "1-1", "_version_": 1}],
+            "child": {"id": "1-2", "_version_": 1}
+        });
+        remove_versions(&mut doc);
+        assert_eq!(
+            doc,
+            json!({"id": "1", "children": [{"id": "1-1"}], "child": {"id": "1-2"}})
+        );
+    }
+
+    #[test]
+    fn prepare_applies_transform() {
+        let reindexer = Reindexer::new().transform(|mut doc| match doc["id"] == "skip" {
+            true => None,
+            false => {
+                doc["copied"] = json!(true);
+                Some(doc)
+            }
+        });
+        assert_eq!(
+            reindexer.prepare(json!({"id": "1", "_version_": 1})),
+            Some(json!({"id": "1", "copied": true}))
+        );
+        assert_eq!(reindexer.prepare(json!({"id": "skip"})), None);
+    }
+
+    #[test]
+    fn cursor_mark_is_not_advanced_past_failed_pages() {
+        let indexed = |docs_indexed: usize, docs_failed: usize| -> BulkIndexSummary {
+            serde_json::from_value(json!({
+                "batches": 1,
+                "failed_batches": usize::from(docs_failed > 0),
+                "docs_indexed": docs_indexed,
+                "docs_failed": docs_failed,
+                "retries": 0
+            }))
+            .unwrap()
+        };
+        let mut summary = ReindexSummary::default();
+        summary.add_page(10, 0, &indexed(10, 0), "AoE1".to_string());
+        assert_eq!(summary.get_cursor_mark(), "AoE1");
+
+        summary.add_page(10, 1, &indexed(0, 9), "AoE2".to_string());
+        assert_eq!(summary.get_cursor_mark(), "AoE1");
+        assert_eq!(summary.get_pages(), 2);
+        assert_eq!(summary.get_docs_indexed(), 10);
+        assert_eq!(summary.get_docs_failed(), 9);
+        assert_eq!(summary.get_docs_skipped(), 1);
+    }
+}
@@ -3,20 +3,49 @@ use crate::models::context::SolrServerContext;
 use crate::models::response::SolrResponse;
 use crate::models::SolrResponseError;
 use crate::Error::SolrConnectionError;
+use flate2::read::{GzDecoder, ZlibDecoder};
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use log::debug;
-use reqwest::header::HeaderMap;
-use reqwest::{Body, Request, RequestBuilder, Response, Url};
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_TYPE};
+use reqwest::multipart::Form;
+use reqwest::{Body, Method, Request, RequestBuilder, Response, Url};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::io::{Read, Write};
 
-#[derive(Deserialize, Serialize, Debug, Copy, Clone)]
-enum SolrRequestType {
+/// HTTP method used when sending a request to Solr
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum SolrRequestType {
     Get,
+    #[default]
     Post,
 }
 
+/// The format requests and responses are sent in
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum WireFormat {
+    #[default]
+    Json,
+    /// Solr's binary format, for setups that only accept javabin. Responses are decoded through the same
+    /// intermediate value as JSON, so it is not faster to parse. Requires the `javabin` feature.
+    #[cfg(feature = "javabin")]
+    Javabin,
+}
+
+impl WireFormat {
+    /// The `wt` parameter of the format
+    fn wt(&self) -> &'static str {
+        match self {
+            WireFormat::Json => "json",
+            #[cfg(feature = "javabin")]
+            WireFormat::Javabin => "javabin",
+        }
+    }
+}
+
 pub trait SolrResponseType: Serialize + DeserializeOwned {
     fn check_for_error(&self, url: String) -> Result<(), Error>;
 }
@@ -56,6 +85,7 @@ pub struct SolrRequestBuilder<'a> {
     url: &'a str,
     query_params: Option<&'a [(&'a str, &'a str)]>,
     headers: Option<Vec<(String, String)>>,
+    wire_format: WireFormat,
 }
 
 impl<'a> SolrRequestBuilder<'a> {
@@ -65,6 +95,7 @@ impl<'a> SolrRequestBuilder<'a> {
             url,
             query_params: None,
             headers: None,
+            wire_format: WireFormat::Json,
         }
     }
 
@@ -73,6 +104,13 @@ impl<'a> SolrRequestBuilder<'a> {
         self
     }
 
+    /// Use the given format, or the format of the context if `None`. Requests default to JSON,
+    /// so only endpoints that support other formats should call this.
+    pub fn with_wire_format<O: Into<Option<WireFormat>>>(mut self, wire_format: O) -> Self {
+        self.wire_format = wire_format.into().unwrap_or(self.context.wire_format);
+        self
+    }
+
     pub fn with_headers<S: Into<String>, I: IntoIterator<Item = (S, S)>>(
         mut self,
         headers: I,
@@ -87,74 +125,234 @@ impl<'a> SolrRequestBuilder<'a> {
     }
 
     pub async fn send_get<R: SolrResponseType>(self) -> Result<R, Error> {
+        let request = self.create_get().await?;
+        self.send(request).await
+    }
+
+    pub async fn send_post_with_json<T: Serialize + 'a + ?Sized, R: SolrResponseType>(
+        self,
+        json: &T,
+    ) -> Result<R, Error> {
+        let request = self.create_post_with_json(json).await?;
+        self.send(request).await
+    }
+
+    pub async fn send_post_with_body<T: Into<Body>, R: SolrResponseType>(
+        self,
+        data: T,
+    ) -> Result<R, Error> {
+        let request = self.create_post_with_body(data).await?;
+        self.send(request).await
+    }
+
+    pub async fn send_post_with_multipart<R: SolrResponseType>(
+        self,
+        form: Form,
+    ) -> Result<R, Error> {
         let request = create_standard_request(
             self.context,
             self.url,
-            SolrRequestType::Get,
+            SolrRequestType::Post,
             self.query_params,
             self.headers.as_ref(),
+            self.wire_format,
         )
         .await?;
+        self.send(request.multipart(form)).await
+    }
 
-        let (client, request) = request.build_split();
-        let request = request?;
-        log_request_info(&request, self.context.logging_policy);
+    /// Render the GET request that [SolrRequestBuilder::send_get] would send, without sending it.
+    pub async fn render_get(self) -> Result<SolrRequestInfo, Error> {
+        let (_, request) = self.create_get().await?.build_split();
+        Ok(SolrRequestInfo::from(&request?))
+    }
 
-        let response = client.execute(request).await?;
-        handle_solr_response::<R>(response).await
+    /// Render the POST request that [SolrRequestBuilder::send_post_with_json] would send, without sending it.
+    pub async fn render_post_with_json<T: Serialize + 'a + ?Sized>(
+        self,
+        json: &T,
+    ) -> Result<SolrRequestInfo, Error> {
+        let (_, request) = self.create_post_with_json(json).await?.build_split();
+        Ok(SolrRequestInfo::from(&request?))
     }
 
-    pub async fn send_post_with_json<T: Serialize + 'a + ?Sized, R: SolrResponseType>(
+    /// Render the POST request that [SolrRequestBuilder::send_post_with_body] would send, without sending it.
+    pub async fn render_post_with_body<T: Into<Body>>(
         self,
+        data: T,
+    ) -> Result<SolrRequestInfo, Error> {
+        let (_, request) = self.create_post_with_body(data).await?.build_split();
+        Ok(SolrRequestInfo::from(&request?))
+    }
+
+    async fn create_get(&self) -> Result<RequestBuilder, Error> {
+        create_standard_request(
+            self.context,
+            self.url,
+            SolrRequestType::Get,
+            self.query_params,
+            self.headers.as_ref(),
+            self.wire_format,
+        )
+        .await
+    }
+
+    async fn create_post_with_json<T: Serialize + 'a + ?Sized>(
+        &self,
         json: &T,
-    ) -> Result<R, Error> {
-        let mut request = create_standard_request(
+    ) -> Result<RequestBuilder, Error> {
+        let request = create_standard_request(
             self.context,
             self.url,
             SolrRequestType::Post,
             self.query_params,
             self.headers.as_ref(),
+            self.wire_format,
         )
         .await?;
-        request = request.json(&json);
-
-        let (client, request) = request.build_split();
-        let request = request?;
-        log_request_info(&request, self.context.logging_policy);
-
-        let response = client.execute(request).await?;
-        handle_solr_response::<R>(response).await
+        Ok(request.json(&json))
     }
 
-    pub async fn send_post_with_body<T: Into<Body>, R: SolrResponseType>(
-        self,
-        data: T,
-    ) -> Result<R, Error> {
-        let mut request = create_standard_request(
+    async fn create_post_with_body<T: Into<Body>>(&self, data: T) -> Result<RequestBuilder, Error> {
+        let request = create_standard_request(
             self.context,
             self.url,
             SolrRequestType::Post,
             self.query_params,
             self.headers.as_ref(),
+            self.wire_format,
         )
         .await?;
-        request = request.body(data.into());
+        Ok(request.body(data.into()))
+    }
 
+    async fn send<R: SolrResponseType>(&self, request: RequestBuilder) -> Result<R, Error> {
         let (client, request) = request.build_split();
-        let request = request?;
+        let mut request = request?;
         log_request_info(&request, self.context.logging_policy);
+        if let Some(min_size) = self.context.request_compression {
+            compress_body(&mut request, min_size)?;
+        }
 
         let response = client.execute(request).await?;
         handle_solr_response::<R>(response).await
     }
 }
 
+/// A request as it would be sent to Solr, with secrets masked.
+///
+/// Useful for reproducing a request outside of the library, for example in a support ticket.
+/// # Examples
+/// ```no_run
+/// # use solrstice::{SelectQuery, SolrServerContextBuilder, SolrSingleServerHost};
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let info = SelectQuery::new().fq(["age:[* TO *]"]).request_info(&context, "collection_name").await?;
+/// println!("{}", info.to_curl());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolrRequestInfo {
+    method: SolrRequestType,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+}
+
+impl SolrRequestInfo {
+    /// The HTTP method of the request
+    pub fn get_method(&self) -> SolrRequestType {
+        self.method
+    }
+
+    /// The full URL, including the resolved host and query parameters
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    /// The headers of the request. Secrets such as `Authorization` are masked.
+    pub fn get_headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// The body of the request, if any
+    pub fn get_body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    /// Render the request as a `curl` command.
+    pub fn to_curl(&self) -> String {
+        let mut parts = vec!["curl".to_string()];
+        if self.method == SolrRequestType::Post {
+            parts.push("-X POST".to_string());
+        }
+        parts.push(shell_quote(&self.url));
+        for (key, value) in &self.headers {
+            parts.push(format!(
+                "-H {}",
+                shell_quote(&format!("{}: {}", key, value))
+            ));
+        }
+        if let Some(body) = &self.body {
+            parts.push(format!("--data-raw {}", shell_quote(body)));
+        }
+        parts.join(" ")
+    }
+}
+
+static SECRET_HEADERS: &[&str] = &["authorization", "proxy-authorization", "cookie"];
+static MASK: &str = "****";
+
+fn mask_header_value(key: &str, value: &HeaderValue) -> String {
+    if !value.is_sensitive() && !SECRET_HEADERS.contains(&key.to_lowercase().as_str()) {
+        return String::from_utf8_lossy(value.as_bytes()).to_string();
+    }
+    match String::from_utf8_lossy(value.as_bytes()).split_once(' ') {
+        Some((scheme, _)) if key.eq_ignore_ascii_case("authorization") => {
+            format!("{} {}", scheme, MASK)
+        }
+        _ => MASK.to_string(),
+    }
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+impl From<&Request> for SolrRequestInfo {
+    fn from(request: &Request) -> Self {
+        let mut url = request.url().clone();
+        if url.password().is_some() {
+            let _ = url.set_password(Some(MASK));
+        }
+        let method = match *request.method() == Method::GET {
+            true => SolrRequestType::Get,
+            false => SolrRequestType::Post,
+        };
+        SolrRequestInfo {
+            method,
+            url: url.to_string(),
+            headers: request
+                .headers()
+                .iter()
+                .map(|(key, value)| (key.to_string(), mask_header_value(key.as_str(), value)))
+                .collect(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(|body| String::from_utf8_lossy(body).to_string()),
+        }
+    }
+}
+
 async fn create_standard_request<'a>(
     context: &'a SolrServerContext,
     url: &'a str,
     request_type: SolrRequestType,
     query_params: Option<&'a [(&'a str, &'a str)]>,
     headers: Option<&Vec<(String, String)>>,
+    wire_format: WireFormat,
 ) -> Result<RequestBuilder, Error> {
     let url = format!("{}{}", context.host.get_solr_node().await?, url);
     let mut request = match request_type {
@@ -164,7 +362,10 @@ async fn create_standard_request<'a>(
     if let Some(query_params) = query_params {
         request = request.query(query_params);
     }
-    request = request.query(&[("wt", "json")]);
+    request = request.query(&[("wt", wire_format.wt())]);
+    if context.response_compression {
+        request = request.header(ACCEPT_ENCODING, "gzip, deflate");
+    }
     if let Some(headers) = headers {
         for (key, value) in headers {
             request = request.header(key, value);
@@ -179,8 +380,29 @@ async fn create_standard_request<'a>(
 async fn handle_solr_response<R: SolrResponseType>(response: Response) -> Result<R, Error> {
     let url = response.url().clone();
     let status_code = response.status();
-    let body = response.text().await.unwrap_or_default();
-    let solr_response = serde_json::from_str::<R>(&body);
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let content_encoding = response
+        .headers()
+        .get(CONTENT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let body = response.bytes().await.unwrap_or_default();
+    // A truncated or corrupt body should still be reported with the status and url of the response
+    let body = decompress_body(&content_encoding, &body).map_err(|e| SolrConnectionError {
+        url: url.to_string(),
+        code: status_code.as_u16(),
+        msg: format!(
+            "Could not decompress the {} response body: {}",
+            content_encoding, e
+        ),
+    })?;
+    let solr_response = parse_response_body::<R>(&content_type, &body);
     if let Ok(r) = solr_response {
         r.check_for_error(url.to_string())?;
         return Ok(r);
@@ -189,16 +411,54 @@ async fn handle_solr_response<R: SolrResponseType>(response: Response) -> Result
         return Err(Error::SolrAuthError {
             code: status_code.as_u16(),
             url: url.to_string(),
-            msg: body,
+            msg: String::from_utf8_lossy(&body).to_string(),
         });
     }
     Err(SolrConnectionError {
         url: url.to_string(),
         code: status_code.as_u16(),
-        msg: body,
+        msg: String::from_utf8_lossy(&body).to_string(),
     })
 }
 
+/// Gzip the body if it is at least `min_size` bytes. Streamed bodies have no known size, and are sent as is.
+fn compress_body(request: &mut Request, min_size: usize) -> Result<(), Error> {
+    let body = match request.body().and_then(|body| body.as_bytes()) {
+        Some(body) if body.len() >= min_size => body,
+        _ => return Ok(()),
+    };
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body)?;
+    let compressed = encoder.finish()?;
+    request
+        .headers_mut()
+        .insert(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+    *request.body_mut() = Some(compressed.into());
+    Ok(())
+}
+
+/// Responses are only compressed if asked for with `Accept-Encoding`.
+/// `deflate` is zlib wrapped, as specified by HTTP.
+fn decompress_body<'a>(content_encoding: &str, body: &'a [u8]) -> Result<Cow<'a, [u8]>, Error> {
+    let mut decompressed = Vec::new();
+    match content_encoding.trim().to_lowercase().as_str() {
+        "gzip" | "x-gzip" => GzDecoder::new(body).read_to_end(&mut decompressed)?,
+        "deflate" => ZlibDecoder::new(body).read_to_end(&mut decompressed)?,
+        _ => return Ok(Cow::Borrowed(body)),
+    };
+    Ok(Cow::Owned(decompressed))
+}
+
+#[cfg_attr(not(feature = "javabin"), allow(unused_variables))]
+fn parse_response_body<R: DeserializeOwned>(content_type: &str, body: &[u8]) -> Result<R, Error> {
+    // Solr answers `wt=javabin` with `application/octet-stream`, also for errors.
+    #[cfg(feature = "javabin")]
+    if content_type.starts_with("application/octet-stream") {
+        return crate::javabin::from_slice(body);
+    }
+    Ok(serde_json::from_slice(body)?)
+}
+
 static NO_BODY: &[u8] = "No body".as_bytes();
 static ERROR_BODY: &str = "Error while getting body";
 fn body_too_long(actual: usize, max: usize) -> String {
@@ -253,3 +513,56 @@ fn log_request_info(request: &Request, logging: LoggingPolicy) {
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::queries::request_builder::{compress_body, decompress_body};
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use reqwest::header::CONTENT_ENCODING;
+    use reqwest::{Client, Request};
+    use std::io::Write;
+
+    fn request_with_body(body: &str) -> Request {
+        Client::new()
+            .post("http://localhost:8983/solr/collection/update")
+            .body(body.to_string())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn compress_body_gzips_bodies_above_threshold() {
+        let body = "{\"id\": \"1\"}".repeat(100);
+        let mut request = request_with_body(&body);
+        compress_body(&mut request, 1024).unwrap();
+        assert_eq!(request.headers().get(CONTENT_ENCODING).unwrap(), "gzip");
+        let compressed = request.body().unwrap().as_bytes().unwrap();
+        assert!(compressed.len() < body.len());
+        assert_eq!(
+            decompress_body("gzip", compressed).unwrap().as_ref(),
+            body.as_bytes()
+        );
+    }
+
+    #[test]
+    fn compress_body_skips_bodies_below_threshold() {
+        let mut request = request_with_body("{}");
+        compress_body(&mut request, 1024).unwrap();
+        assert!(request.headers().get(CONTENT_ENCODING).is_none());
+        assert_eq!(request.body().unwrap().as_bytes().unwrap(), b"{}");
+    }
+
+    #[test]
+    fn decompress_body_handles_deflate_and_identity() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"{\"status\": 0}").unwrap();
+        let compressed = encoder.finish().unwrap();
+        assert_eq!(
+            decompress_body("deflate", &compressed).unwrap().as_ref(),
+            b"{\"status\": 0}"
+        );
+        assert_eq!(decompress_body("", b"{}").unwrap().as_ref(), b"{}");
+        assert!(decompress_body("gzip", b"not gzip").is_err());
+    }
+}
@@ -1,14 +1,19 @@
 use crate::error::Error;
 use crate::models::context::SolrServerContext;
 use crate::models::response::SolrResponse;
+use crate::queries::components::elevation::ElevationComponent;
 use crate::queries::components::facet_set::FacetSetComponent;
 use crate::queries::components::grouping::GroupingComponent;
 use crate::queries::components::json_facet::JsonFacetComponent;
 use crate::queries::components::stats::StatsComponent;
 use crate::queries::def_type::DefType;
-use crate::queries::request_builder::SolrRequestBuilder;
+use crate::queries::request_builder::{
+    SolrRequestBuilder, SolrRequestInfo, SolrRequestType, SolrResponseType, WireFormat,
+};
+use crate::queries::solr_params::SolrParams;
 #[cfg(feature = "blocking")]
 use crate::runtime::RUNTIME;
+use reqwest::Url;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -28,6 +33,16 @@ where
     Ok(opt.filter(|map: &HashMap<String, Value>| !map.is_empty()))
 }
 
+/// Flattened components with only optional fields deserialize as `Some` even when absent.
+fn deserialize_default_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default + PartialEq,
+{
+    let opt = Option::deserialize(deserializer)?;
+    Ok(opt.filter(|component: &T| *component != T::default()))
+}
+
 /// Builder for a select query.
 ///
 /// Also take a look at [AsyncSolrCloudClient::select](crate::AsyncSolrCloudClient::select)
@@ -43,7 +58,7 @@ pub struct SelectQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     fl: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    sort: Option<Vec<String>>,
+    pub(crate) sort: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     rows: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -51,7 +66,37 @@ pub struct SelectQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "cursorMark")]
     cursor_mark: Option<String>,
-    #[serde(flatten)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "timeAllowed")]
+    time_allowed: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "cpuAllowed")]
+    cpu_allowed: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "memAllowed")]
+    mem_allowed: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "omitHeader")]
+    omit_header: Option<bool>,
+    /// Not a Solr parameter, removed before sending.
+    #[serde(
+        rename = "failOnPartialResults",
+        default,
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    fail_on_partial_results: bool,
+    /// Not a Solr parameter, removed before sending.
+    #[serde(
+        rename = "wireFormat",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    wire_format: Option<WireFormat>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        flatten,
+        deserialize_with = "deserialize_default_as_none"
+    )]
     grouping: Option<GroupingComponent>,
     #[serde(flatten)]
     def_type: Option<DefType>,
@@ -61,6 +106,12 @@ pub struct SelectQuery {
     json_facet: Option<JsonFacetComponent>,
     #[serde(flatten)]
     stats: Option<StatsComponent>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        flatten,
+        deserialize_with = "deserialize_default_as_none"
+    )]
+    elevation: Option<ElevationComponent>,
     #[serde(
         skip_serializing_if = "Option::is_none",
         flatten,
@@ -98,11 +149,18 @@ impl SelectQuery {
             rows: None,
             start: None,
             cursor_mark: None,
+            time_allowed: None,
+            cpu_allowed: None,
+            mem_allowed: None,
+            omit_header: None,
+            fail_on_partial_results: false,
+            wire_format: None,
             grouping: None,
             def_type: None,
             facet_set: None,
             json_facet: None,
             stats: None,
+            elevation: None,
             additional_params: None,
         }
     }
@@ -206,6 +264,74 @@ impl SelectQuery {
         self
     }
 
+    /// The maximum time in milliseconds Solr may spend on the search. If exceeded, the results found so far are returned.
+    ///
+    /// Combine with [SelectQuery::fail_on_partial_results] to get an error instead of partial results.
+    pub fn time_allowed<O: Into<Option<usize>>>(mut self, time_allowed: O) -> Self {
+        self.time_allowed = time_allowed.into();
+        self
+    }
+
+    /// The maximum CPU time in milliseconds Solr may spend on the search. If exceeded, the results found so far are returned.
+    pub fn cpu_allowed<O: Into<Option<usize>>>(mut self, cpu_allowed: O) -> Self {
+        self.cpu_allowed = cpu_allowed.into();
+        self
+    }
+
+    /// The maximum memory in megabytes Solr may allocate for the search. If exceeded, the results found so far are returned.
+    pub fn mem_allowed<O: Into<Option<f64>>>(mut self, mem_allowed: O) -> Self {
+        self.mem_allowed = mem_allowed.into();
+        self
+    }
+
+    /// Ask Solr to leave out the response header. [SolrResponse::get_response_header] will then be `None`.
+    ///
+    /// Ignored if [SelectQuery::fail_on_partial_results] is set, as the header is needed to detect partial results.
+    pub fn omit_header<O: Into<Option<bool>>>(mut self, omit_header: O) -> Self {
+        self.omit_header = omit_header.into();
+        self
+    }
+
+    /// Return [Error::SolrPartialResultsError] instead of a response if Solr returns partial results.
+    ///
+    /// Solr returns partial results if a limit such as [SelectQuery::time_allowed] is exceeded, or if shards did not respond with
+    /// [SelectDestination::shards_tolerant] set. By default, this is only visible through [SolrResponse::get_partial_results].
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{Error, SelectQuery, SolrSingleServerHost};
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = AsyncSolrCloudClient::new(SolrServerContextBuilder::new(SolrSingleServerHost::new("localhost:8983")).build());
+    /// let builder = SelectQuery::new()
+    ///     .q("interests:cars")
+    ///     .time_allowed(100)
+    ///     .fail_on_partial_results(true);
+    /// match client.select(&builder, "collection").await {
+    ///     Err(Error::SolrPartialResultsError(msg)) => println!("Search took too long: {}", msg),
+    ///     result => println!("{:?}", result?.get_docs_response()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fail_on_partial_results(mut self, fail_on_partial_results: bool) -> Self {
+        self.fail_on_partial_results = fail_on_partial_results;
+        self
+    }
+
+    /// Override the wire format of the context for this query.
+    /// Javabin responses are decoded into the same [SolrResponse] as JSON responses.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{SelectQuery, WireFormat};
+    /// let builder = SelectQuery::new().wire_format(WireFormat::Javabin);
+    /// ```
+    #[cfg(feature = "javabin")]
+    pub fn wire_format<O: Into<Option<WireFormat>>>(mut self, wire_format: O) -> Self {
+        self.wire_format = wire_format.into();
+        self
+    }
+
     /// Do a grouping query. Also take a look at [SolrGroupResult](crate::models::group::SolrGroupResult) and [SolrGroupFieldResult](crate::models::group::SolrGroupFieldResult)
     /// # Examples
     /// ```no_run
@@ -274,6 +400,29 @@ impl SelectQuery {
         self
     }
 
+    /// Pin or exclude documents using the `QueryElevationComponent`.
+    /// # Examples
+    /// ```no_run
+    /// use solrstice::{ElevationComponent, SelectQuery, SolrSingleServerHost};
+    /// # use solrstice::AsyncSolrCloudClient;
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = AsyncSolrCloudClient::new(SolrServerContextBuilder::new(SolrSingleServerHost::new("localhost:8983")).build());
+    /// let builder = SelectQuery::new()
+    ///     .q("city_name:Alta")
+    ///     .elevation(ElevationComponent::new().elevate_ids(["city_Tromsø"]).elevated_flag(true));
+    /// let response = client.select(&builder, "collection").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn elevation<T: Into<ElevationComponent>, O: Into<Option<T>>>(
+        mut self,
+        elevation: O,
+    ) -> Self {
+        self.elevation = elevation.into().map(|x| x.into());
+        self
+    }
+
     /// Additional parameters to send to Solr in the `params` field of the JSON Query DSL.
     /// ```json
     /// {
@@ -298,24 +447,245 @@ impl SelectQuery {
         self
     }
 
-    pub async fn execute<D: Into<SelectDestination>, C: AsRef<SolrServerContext>>(
+    /// The query as it should be sent to Solr. Component options that are not Solr parameters are resolved here.
+    fn to_request(&self) -> SelectQuery {
+        let mut query = self.clone();
+        if query.fail_on_partial_results {
+            query.fail_on_partial_results = false;
+            query.omit_header = None;
+        }
+        query.wire_format = None;
+        if let Some(elevation) = query.elevation.take() {
+            let flag_fields = elevation.flag_fields();
+            if !flag_fields.is_empty() {
+                let mut fl = query.fl.take().unwrap_or_else(|| vec!["*".to_string()]);
+                for field in flag_fields {
+                    if !fl.iter().any(|f| f == field) {
+                        fl.push(field.to_string());
+                    }
+                }
+                query.fl = Some(fl);
+            }
+            query.elevation = Some(elevation.without_flags());
+        }
+        query
+    }
+
+    /// The query as classic Solr request parameters, as they would be sent in a GET request.
+    ///
+    /// Multivalued parameters such as `fq` are repeated, while `sort` and `group.sort` are comma separated.
+    /// Parameters are ordered by name.
+    /// # Examples
+    /// ```rust
+    /// use solrstice::{GroupingComponent, SelectQuery};
+    /// let params = SelectQuery::new()
+    ///     .q("age:[* TO *]")
+    ///     .fq(["city_name:Alta", "interests:cars"])
+    ///     .grouping(GroupingComponent::new().fields(["age"]))
+    ///     .to_solr_params()?;
+    /// assert!(params.contains(&("fq".to_string(), "interests:cars".to_string())));
+    /// assert!(params.contains(&("group.field".to_string(), "age".to_string())));
+    /// # Ok::<(), solrstice::Error>(())
+    /// ```
+    pub fn to_solr_params(&self) -> Result<Vec<(String, String)>, Error> {
+        let value = serde_json::to_value(self.to_request())?;
+        let mut params = Vec::new();
+        if let Value::Object(map) = value {
+            for (key, value) in map {
+                match value {
+                    Value::Null => {}
+                    Value::Array(values) if SINGLE_VALUED_LIST_PARAMS.contains(&key.as_str()) => {
+                        let values = values.iter().map(param_value).collect::<Vec<_>>();
+                        params.push((key, values.join(",")));
+                    }
+                    Value::Array(values) => {
+                        for value in values.iter().filter(|v| !v.is_null()) {
+                            params.push((key.clone(), param_value(value)));
+                        }
+                    }
+                    value => params.push((key, param_value(&value))),
+                }
+            }
+        }
+        Ok(params)
+    }
+
+    /// Parse classic Solr parameters, as copied from the admin UI or an access log, into a query.
+    ///
+    /// Parameters belonging to a known component are parsed into that component,
+    /// while unknown parameters end up in `additional_params`. The `wt` parameter is dropped, as it is always set to `json`.
+    /// # Examples
+    /// ```rust
+    /// use solrstice::{GroupingComponent, SelectQuery};
+    /// let query = SelectQuery::from_query_string("q=*:*&fq=age:[*+TO+*]&group=true&group.field=age")?;
+    /// assert_eq!(
+    ///     query,
+    ///     SelectQuery::new()
+    ///         .fq(["age:[* TO *]"])
+    ///         .grouping(GroupingComponent::new().fields(["age"]))
+    /// );
+    /// # Ok::<(), solrstice::Error>(())
+    /// ```
+    pub fn from_query_string<S: AsRef<str>>(query_string: S) -> Result<Self, Error> {
+        let mut params = SolrParams::parse(query_string.as_ref());
+        params.take_all("wt");
+        let mut query = SelectQuery::new();
+        if let Some(q) = params.take("q") {
+            query.q = q;
+        }
+        query.fq = params.take_list("fq", false);
+        query.fl = params.take_list("fl", true);
+        query.sort = params.take_list("sort", true);
+        query.rows = params.take_parsed("rows")?;
+        query.start = params.take_parsed("start")?;
+        query.cursor_mark = params.take("cursorMark");
+        query.time_allowed = params.take_parsed("timeAllowed")?;
+        query.cpu_allowed = params.take_parsed("cpuAllowed")?;
+        query.mem_allowed = params.take_parsed("memAllowed")?;
+        query.omit_header = params.take_bool("omitHeader")?;
+        query.grouping = GroupingComponent::from_solr_params(&mut params)?;
+        query.def_type = DefType::from_solr_params(&mut params)?;
+        query.facet_set = FacetSetComponent::from_solr_params(&mut params)?;
+        query.json_facet = JsonFacetComponent::from_solr_params(&mut params)?;
+        query.stats = StatsComponent::from_solr_params(&mut params)?;
+        query.elevation = ElevationComponent::from_solr_params(&mut params)?;
+        let remaining = params.into_remaining();
+        if !remaining.is_empty() {
+            query.additional_params = Some(remaining.into_iter().collect());
+        }
+        Ok(query)
+    }
+
+    /// The URL parameters of the request. With GET the whole query is sent here, with POST only the destination parameters.
+    fn query_params(
+        &self,
+        destination: &SelectDestination,
+    ) -> Result<Vec<(String, String)>, Error> {
+        let mut params = match destination.request_type {
+            SolrRequestType::Get => self.to_solr_params()?,
+            SolrRequestType::Post => Vec::new(),
+        };
+        params.extend(destination.query_params());
+        Ok(params)
+    }
+
+    async fn send<R: SolrResponseType, C: AsRef<SolrServerContext>>(
+        &self,
+        context: C,
+        destination: SelectDestination,
+    ) -> Result<R, Error> {
+        let solr_url = destination.url();
+        let params = self.query_params(&destination)?;
+        let query_params = params
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect::<Vec<_>>();
+        let builder = SolrRequestBuilder::new(context.as_ref(), solr_url.as_str())
+            .with_query_params(query_params.as_ref())
+            .with_wire_format(self.wire_format);
+        match destination.request_type {
+            SolrRequestType::Get => builder.send_get::<R>().await,
+            SolrRequestType::Post => {
+                let wrapper = PostQueryWrapper {
+                    params: self.to_request(),
+                };
+                builder
+                    .send_post_with_json::<PostQueryWrapper, R>(&wrapper)
+                    .await
+            }
+        }
+    }
+
+    /// Render the request that [SelectQuery::execute] would send, with secrets masked.
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::{SelectQuery, SolrServerContextBuilder, SolrSingleServerHost};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let info = SelectQuery::new().request_info(&context, "collection_name").await?;
+    /// assert_eq!(info.get_url(), "http://localhost:8983/solr/collection_name/select?wt=json");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request_info<D: Into<SelectDestination>, C: AsRef<SolrServerContext>>(
         &self,
         context: C,
         destination: D,
-    ) -> Result<SolrResponse, Error> {
+    ) -> Result<SolrRequestInfo, Error> {
         let destination = destination.into();
-        let solr_url = format!(
-            "/solr/{}/{}",
-            destination.collection.as_str(),
-            destination.handler.as_str()
-        );
-        let wrapper = PostQueryWrapper {
-            params: self.clone(),
+        let solr_url = destination.url();
+        let params = self.query_params(&destination)?;
+        let query_params = params
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect::<Vec<_>>();
+        let builder = SolrRequestBuilder::new(context.as_ref(), solr_url.as_str())
+            .with_query_params(query_params.as_ref())
+            .with_wire_format(self.wire_format);
+        match destination.request_type {
+            SolrRequestType::Get => builder.render_get().await,
+            SolrRequestType::Post => {
+                let wrapper = PostQueryWrapper {
+                    params: self.to_request(),
+                };
+                builder.render_post_with_json(&wrapper).await
+            }
+        }
+    }
+
+    /// Render the request that [SelectQuery::execute] would send as a `curl` command, with secrets masked.
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::{SelectQuery, SolrServerContextBuilder, SolrSingleServerHost};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let curl = SelectQuery::new().fq(["age:[* TO *]"]).to_curl(&context, "collection_name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn to_curl<D: Into<SelectDestination>, C: AsRef<SolrServerContext>>(
+        &self,
+        context: C,
+        destination: D,
+    ) -> Result<String, Error> {
+        Ok(self.request_info(context, destination).await?.to_curl())
+    }
+
+    /// Render the query as a full URL with classic Solr parameters, which can be opened in a browser.
+    ///
+    /// The URL is always the GET form of the query with JSON responses, regardless of [SelectDestination::request_type] and the wire format.
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::{SelectQuery, SolrServerContextBuilder, SolrSingleServerHost};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let url = SelectQuery::new().fq(["age:[* TO *]"]).to_url(&context, "collection_name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn to_url<D: Into<SelectDestination>, C: AsRef<SolrServerContext>>(
+        &self,
+        context: C,
+        destination: D,
+    ) -> Result<String, Error> {
+        let destination = destination.into().request_type(SolrRequestType::Get);
+        // A browser can only display JSON
+        let query = SelectQuery {
+            wire_format: Some(WireFormat::Json),
+            ..self.clone()
         };
-        let data = SolrRequestBuilder::new(context.as_ref(), solr_url.as_str())
-            .send_post_with_json::<PostQueryWrapper, SolrResponse>(&wrapper)
-            .await?;
-        Ok(data)
+        let info = query.request_info(context, destination).await?;
+        Ok(info.get_url().to_string())
+    }
+
+    pub async fn execute<D: Into<SelectDestination>, C: AsRef<SolrServerContext>>(
+        &self,
+        context: C,
+        destination: D,
+    ) -> Result<SolrResponse, Error> {
+        let response: SolrResponse = self.send(context, destination.into()).await?;
+        self.check_partial_results(response.get_partial_results())?;
+        Ok(response)
     }
 
     pub async fn execute_raw<D: Into<SelectDestination>, C: AsRef<SolrServerContext>>(
@@ -323,19 +693,55 @@ impl SelectQuery {
         context: C,
         destination: D,
     ) -> Result<HashMap<String, Value>, Error> {
-        let destination = destination.into();
-        let solr_url = format!(
-            "/solr/{}/{}",
-            destination.collection.as_str(),
-            destination.handler.as_str()
-        );
-        let wrapper = PostQueryWrapper {
-            params: self.clone(),
+        let response: HashMap<String, Value> = self.send(context, destination.into()).await?;
+        let partial_results = response
+            .get("responseHeader")
+            .and_then(|header| header.get("partialResults"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        self.check_partial_results(partial_results)?;
+        Ok(response)
+    }
+
+    fn check_partial_results(&self, partial_results: bool) -> Result<(), Error> {
+        if !self.fail_on_partial_results || !partial_results {
+            return Ok(());
+        }
+        let limits = [
+            ("timeAllowed", self.time_allowed.map(|v| v.to_string())),
+            ("cpuAllowed", self.cpu_allowed.map(|v| v.to_string())),
+            ("memAllowed", self.mem_allowed.map(|v| v.to_string())),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| value.map(|value| format!("{key}={value}")))
+        .collect::<Vec<_>>();
+        let msg = match limits.is_empty() {
+            true => "a limit was exceeded or shards did not respond".to_string(),
+            false => format!(
+                "a limit was exceeded or shards did not respond ({})",
+                limits.join(", ")
+            ),
         };
-        let data = SolrRequestBuilder::new(context.as_ref(), solr_url.as_str())
-            .send_post_with_json::<PostQueryWrapper, HashMap<String, Value>>(&wrapper)
-            .await?;
-        Ok(data)
+        Err(Error::SolrPartialResultsError(msg))
+    }
+}
+
+/// Parameters that Solr only reads once, so list values have to be joined.
+static SINGLE_VALUED_LIST_PARAMS: &[&str] = &["sort", "group.sort"];
+
+fn param_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.to_string(),
+        value => value.to_string(),
+    }
+}
+
+impl TryFrom<&Url> for SelectQuery {
+    type Error = Error;
+
+    /// Parse the query parameters of a Solr URL. The collection and handler in the path are ignored.
+    fn try_from(url: &Url) -> Result<Self, Self::Error> {
+        SelectQuery::from_query_string(url.query().unwrap_or_default())
     }
 }
 
@@ -360,12 +766,46 @@ impl SelectQuery {
             .handle()
             .block_on(self.execute_raw(context, destination.into()))
     }
+
+    pub fn to_curl_blocking<C: AsRef<SolrServerContext>, D: Into<SelectDestination>>(
+        &self,
+        context: C,
+        destination: D,
+    ) -> Result<String, Error> {
+        RUNTIME
+            .handle()
+            .block_on(self.to_curl(context, destination.into()))
+    }
+
+    pub fn to_url_blocking<C: AsRef<SolrServerContext>, D: Into<SelectDestination>>(
+        &self,
+        context: C,
+        destination: D,
+    ) -> Result<String, Error> {
+        RUNTIME
+            .handle()
+            .block_on(self.to_url(context, destination.into()))
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SelectDestination {
     collection: String,
     handler: String,
+    #[serde(default)]
+    request_type: SolrRequestType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    collections: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shards: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    route: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shards_preference: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shards_tolerant: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shards_info: Option<bool>,
 }
 
 impl SelectDestination {
@@ -373,6 +813,13 @@ impl SelectDestination {
         SelectDestination {
             collection: collection.into(),
             handler: "select".to_string(),
+            request_type: SolrRequestType::Post,
+            collections: None,
+            shards: None,
+            route: None,
+            shards_preference: None,
+            shards_tolerant: None,
+            shards_info: None,
         }
     }
 
@@ -380,6 +827,148 @@ impl SelectDestination {
         self.handler = handler.into();
         self
     }
+
+    /// How the query is sent. Default is [SolrRequestType::Post], which uses the JSON Request API.
+    ///
+    /// With [SolrRequestType::Get] the query is sent as URL parameters, see [SelectQuery::to_solr_params].
+    /// This lets HTTP caches cache the response.
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::{AsyncSolrCloudClient, SelectDestination, SelectQuery, SolrRequestType, SolrSingleServerHost};
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let destination = SelectDestination::new("collection_name").request_type(SolrRequestType::Get);
+    /// let response = client.select(&SelectQuery::new(), destination).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn request_type(mut self, request_type: SolrRequestType) -> Self {
+        self.request_type = request_type;
+        self
+    }
+
+    /// Search across several collections, sent as the `collection` parameter.
+    ///
+    /// The collection given in [SelectDestination::new] handles the request, and is only searched if it is in the list.
+    /// # Examples
+    /// ```no_run
+    /// # use solrstice::{AsyncSolrCloudClient, SelectDestination, SelectQuery, SolrSingleServerHost};
+    /// # use solrstice::SolrServerContextBuilder;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+    /// let client = AsyncSolrCloudClient::new(context);
+    /// let destination = SelectDestination::new("collection_a").collections(["collection_a", "collection_b"]);
+    /// let response = client.select(&SelectQuery::new(), destination).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn collections<S: Into<String>, I: IntoIterator<Item = S>, O: Into<Option<I>>>(
+        mut self,
+        collections: O,
+    ) -> Self {
+        self.collections = collections
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// Only query the given shards. Either shard names like `shard1`, or replica URLs.
+    /// Replicas of the same shard can be separated by `|` to load balance between them.
+    /// # Examples
+    /// ```rust
+    /// use solrstice::SelectDestination;
+    /// SelectDestination::new("collection_name").shards(["shard1", "shard2"]);
+    /// ```
+    pub fn shards<S: Into<String>, I: IntoIterator<Item = S>, O: Into<Option<I>>>(
+        mut self,
+        shards: O,
+    ) -> Self {
+        self.shards = shards
+            .into()
+            .map(|x| x.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// Only query the shards holding documents with the given route key, sent as `_route_`.
+    /// # Examples
+    /// ```rust
+    /// use solrstice::SelectDestination;
+    /// SelectDestination::new("collection_name").route("tenant1!");
+    /// ```
+    pub fn route<S: Into<String>, O: Into<Option<S>>>(mut self, route: O) -> Self {
+        self.route = route.into().map(|x| x.into());
+        self
+    }
+
+    /// Which replicas to prefer, sent as `shards.preference`.
+    /// # Examples
+    /// ```rust
+    /// use solrstice::SelectDestination;
+    /// SelectDestination::new("collection_name").shards_preference("replica.type:PULL,replica.location:local");
+    /// ```
+    pub fn shards_preference<S: Into<String>, O: Into<Option<S>>>(
+        mut self,
+        shards_preference: O,
+    ) -> Self {
+        self.shards_preference = shards_preference.into().map(|x| x.into());
+        self
+    }
+
+    /// Return partial results instead of an error if some shards are unavailable.
+    /// See [SolrResponse::get_partial_results](crate::models::SolrResponse::get_partial_results).
+    /// # Examples
+    /// ```rust
+    /// use solrstice::SelectDestination;
+    /// SelectDestination::new("collection_name").shards_tolerant(true);
+    /// ```
+    pub fn shards_tolerant<O: Into<Option<bool>>>(mut self, shards_tolerant: O) -> Self {
+        self.shards_tolerant = shards_tolerant.into();
+        self
+    }
+
+    /// Return information about each shard that was queried.
+    /// See [SolrResponse::get_shards_info](crate::models::SolrResponse::get_shards_info).
+    /// # Examples
+    /// ```rust
+    /// use solrstice::SelectDestination;
+    /// SelectDestination::new("collection_name").shards_info(true);
+    /// ```
+    pub fn shards_info<O: Into<Option<bool>>>(mut self, shards_info: O) -> Self {
+        self.shards_info = shards_info.into();
+        self
+    }
+
+    fn url(&self) -> String {
+        format!("/solr/{}/{}", self.collection, self.handler)
+    }
+
+    fn query_params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+        if let Some(collections) = &self.collections {
+            params.push(("collection".to_string(), collections.join(",")));
+        }
+        if let Some(shards) = &self.shards {
+            params.push(("shards".to_string(), shards.join(",")));
+        }
+        if let Some(route) = &self.route {
+            params.push(("_route_".to_string(), route.to_string()));
+        }
+        if let Some(shards_preference) = &self.shards_preference {
+            params.push((
+                "shards.preference".to_string(),
+                shards_preference.to_string(),
+            ));
+        }
+        if let Some(shards_tolerant) = self.shards_tolerant {
+            params.push(("shards.tolerant".to_string(), shards_tolerant.to_string()));
+        }
+        if let Some(shards_info) = self.shards_info {
+            params.push(("shards.info".to_string(), shards_info.to_string()));
+        }
+        params
+    }
 }
 
 impl From<String> for SelectDestination {
@@ -408,8 +997,17 @@ impl From<&String> for SelectDestination {
 
 #[cfg(test)]
 pub mod tests {
+    use crate::error::Error;
+    use crate::queries::components::elevation::ElevationComponent;
+    use crate::queries::components::facet_set::{
+        FacetSetComponent, FieldFacetComponent, FieldFacetEntry, PivotFacetComponent,
+    };
     use crate::queries::components::grouping::GroupingComponent;
-    use crate::queries::select::SelectQuery;
+    use crate::queries::components::stats::StatsComponent;
+    use crate::queries::def_type::{DefType, EdismaxQuery};
+    use crate::queries::select::{SelectDestination, SelectQuery};
+    use crate::{SolrBasicAuth, SolrServerContextBuilder, SolrSingleServerHost};
+    use reqwest::Url;
 
     #[test]
     pub fn serialize_select_arguments_work() {
@@ -432,4 +1030,237 @@ pub mod tests {
         let deserialized = serde_json::from_str::<SelectQuery>(&serialized).unwrap();
         assert_eq!(builder, deserialized);
     }
+
+    #[test]
+    pub fn elevation_flags_are_moved_to_fl() {
+        let builder = SelectQuery::new().elevation(
+            ElevationComponent::new()
+                .elevate_ids(["id1"])
+                .elevated_flag(true)
+                .excluded_flag(true),
+        );
+        let serialized = serde_json::to_string(&builder).unwrap();
+        let deserialized = serde_json::from_str::<SelectQuery>(&serialized).unwrap();
+        assert_eq!(builder, deserialized);
+
+        let request = serde_json::to_value(builder.to_request()).unwrap();
+        assert_eq!(
+            request["fl"],
+            serde_json::json!(["*", "[elevated]", "[excluded]"])
+        );
+        assert_eq!(request["elevateIds"], "id1");
+        assert!(request.get("elevatedFlag").is_none());
+    }
+
+    #[test]
+    pub fn fail_on_partial_results_is_not_sent() {
+        let builder = SelectQuery::new()
+            .time_allowed(10)
+            .omit_header(true)
+            .fail_on_partial_results(true);
+        let serialized = serde_json::to_string(&builder).unwrap();
+        assert_eq!(
+            builder,
+            serde_json::from_str::<SelectQuery>(&serialized).unwrap()
+        );
+
+        let params = builder.to_solr_params().unwrap();
+        assert!(params.contains(&("timeAllowed".to_string(), "10".to_string())));
+        assert!(!params.iter().any(|(key, _)| key == "failOnPartialResults"));
+        assert!(!params.iter().any(|(key, _)| key == "omitHeader"));
+
+        assert!(builder.check_partial_results(false).is_ok());
+        assert!(matches!(
+            builder.check_partial_results(true),
+            Err(Error::SolrPartialResultsError(msg)) if msg.contains("timeAllowed=10")
+        ));
+        assert!(SelectQuery::new().check_partial_results(true).is_ok());
+    }
+
+    #[test]
+    pub fn to_solr_params_flattens_components() {
+        let params = SelectQuery::new()
+            .q("age:[* TO *]")
+            .fq(["id:1", "id:2"])
+            .sort(["age desc", "id asc"])
+            .rows(5)
+            .grouping(GroupingComponent::new().fields(["age"]).limit(2))
+            .def_type(DefType::Edismax(EdismaxQuery::new().qf("city_name")))
+            .facet_set(
+                FacetSetComponent::new()
+                    .pivots(PivotFacetComponent::new(["age,city_name"]))
+                    .fields(FieldFacetComponent::new([
+                        FieldFacetEntry::new("age").limit(3)
+                    ])),
+            )
+            .stats(StatsComponent::new().fields(["age"]))
+            .elevation(ElevationComponent::new().elevate_ids(["id1", "id2"]))
+            .to_solr_params()
+            .unwrap();
+        let expected = [
+            ("q", "age:[* TO *]"),
+            ("fq", "id:1"),
+            ("fq", "id:2"),
+            ("sort", "age desc,id asc"),
+            ("rows", "5"),
+            ("group", "true"),
+            ("group.field", "age"),
+            ("group.limit", "2"),
+            ("defType", "edismax"),
+            ("qf", "city_name"),
+            ("facet", "true"),
+            ("facet.pivot", "age,city_name"),
+            ("facet.field", "age"),
+            ("f.age.facet.limit", "3"),
+            ("stats", "true"),
+            ("stats.field", "age"),
+            ("elevateIds", "id1,id2"),
+        ];
+        for (key, value) in expected {
+            assert!(
+                params.contains(&(key.to_string(), value.to_string())),
+                "Missing {key}={value} in {params:?}"
+            );
+        }
+        assert!(!params.iter().any(|(key, _)| key == "facet.pivot.mincount"));
+    }
+
+    #[test]
+    pub fn from_query_string_round_trips_solr_params() {
+        let query = SelectQuery::new()
+            .q("age:[* TO *]")
+            .fq(["id:1", "id:2"])
+            .fl(["id", "age"])
+            .sort(["sum(age,1) desc", "id asc"])
+            .rows(5)
+            .start(10)
+            .grouping(
+                GroupingComponent::new()
+                    .fields(["age"])
+                    .limit(2)
+                    .sort(["id asc"])
+                    .n_groups(true),
+            )
+            .def_type(DefType::Edismax(
+                EdismaxQuery::new().qf("city_name").bq(["age:20"]).sow(true),
+            ))
+            .facet_set(
+                FacetSetComponent::new()
+                    .queries(["age:[* TO 59]"])
+                    .pivots(PivotFacetComponent::new(["age,city_name"]).min_count(1))
+                    .fields(FieldFacetComponent::new([
+                        FieldFacetEntry::new("age").limit(3).missing(true),
+                        FieldFacetEntry::new("city_name").prefix("T"),
+                    ])),
+            )
+            .stats(StatsComponent::new().fields(["age"]))
+            .elevation(ElevationComponent::new().elevate_ids(["id1", "id2"]))
+            .time_allowed(100)
+            .cpu_allowed(50)
+            .mem_allowed(1.5)
+            .omit_header(false)
+            .additional_params([("debug", "true")]);
+        let query_string = form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(query.to_solr_params().unwrap())
+            .finish();
+        let parsed = SelectQuery::from_query_string(&query_string).unwrap();
+        assert_eq!(query, parsed);
+
+        let url = Url::parse(&format!(
+            "http://localhost:8983/solr/collection/select?{query_string}&wt=json"
+        ))
+        .unwrap();
+        assert_eq!(query, SelectQuery::try_from(&url).unwrap());
+
+        let parsed = SelectQuery::from_query_string("?q=*:*&omitHeader=on").unwrap();
+        assert_eq!(parsed, SelectQuery::new().omit_header(true));
+    }
+
+    #[test]
+    pub fn from_query_string_keeps_unknown_params() {
+        let parsed =
+            SelectQuery::from_query_string("?q=*:*&defType=func&hl=true&hl.fl=a&hl.fl=b").unwrap();
+        let expected = SelectQuery::new().additional_params([
+            ("defType", serde_json::json!("func")),
+            ("hl", serde_json::json!("true")),
+            ("hl.fl", serde_json::json!(["a", "b"])),
+        ]);
+        assert_eq!(parsed, expected);
+    }
+
+    #[tokio::test]
+    pub async fn to_curl_masks_auth() {
+        let context =
+            SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983"))
+                .with_auth(SolrBasicAuth::new("solr", Some("SolrRocks")))
+                .build();
+        let query = SelectQuery::new().fq(["city_name:'Alta'"]);
+        let curl = query.to_curl(&context, "collection").await.unwrap();
+        assert!(curl.starts_with(
+            "curl -X POST 'http://localhost:8983/solr/collection/select?wt=json' -H 'authorization: Basic ****'"
+        ));
+        assert!(!curl.contains("SolrRocks"));
+        assert!(curl
+            .ends_with(r#"--data-raw '{"params":{"q":"*:*","fq":["city_name:'\''Alta'\''"]}}'"#));
+
+        let url = query.to_url(&context, "collection").await.unwrap();
+        assert_eq!(
+            url,
+            "http://localhost:8983/solr/collection/select?fq=city_name%3A%27Alta%27&q=*%3A*&wt=json"
+        );
+    }
+
+    #[tokio::test]
+    pub async fn destination_params_are_sent_as_query_params() {
+        let context =
+            SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983"))
+                .build();
+        let destination = SelectDestination::new("a")
+            .collections(["a", "b"])
+            .shards(["shard1", "shard2"])
+            .route("tenant1!")
+            .shards_preference("replica.type:PULL")
+            .shards_tolerant(true)
+            .shards_info(true);
+        let info = SelectQuery::new()
+            .request_info(&context, &destination)
+            .await
+            .unwrap();
+        assert_eq!(
+            info.get_url(),
+            "http://localhost:8983/solr/a/select?collection=a%2Cb&shards=shard1%2Cshard2&_route_=tenant1%21&shards.preference=replica.type%3APULL&shards.tolerant=true&shards.info=true&wt=json"
+        );
+
+        let url = SelectQuery::new()
+            .rows(1)
+            .to_url(&context, SelectDestination::new("a").route("tenant1!"))
+            .await
+            .unwrap();
+        assert_eq!(
+            url,
+            "http://localhost:8983/solr/a/select?q=*%3A*&rows=1&_route_=tenant1%21&wt=json"
+        );
+    }
+
+    #[test]
+    pub fn from_query_string_accepts_solr_booleans() {
+        let parsed =
+            SelectQuery::from_query_string("?facet=on&facet.field=age&group=yes&group.field=age")
+                .unwrap();
+        let expected = SelectQuery::new()
+            .facet_set(
+                FacetSetComponent::new()
+                    .fields(FieldFacetComponent::new([FieldFacetEntry::new("age")])),
+            )
+            .grouping(GroupingComponent::new().fields(["age"]));
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    pub fn empty_elevation_deserializes_as_none() {
+        let builder = SelectQuery::new().fl(["id"]);
+        let serialized = serde_json::to_string(&builder).unwrap();
+        let deserialized = serde_json::from_str::<SelectQuery>(&serialized).unwrap();
+        assert_eq!(builder, deserialized);
+    }
 }
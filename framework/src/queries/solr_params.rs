@@ -0,0 +1,222 @@
+use crate::error::Error;
+use serde_json::{Map, Value};
+use std::str::FromStr;
+
+/// How a classic Solr parameter should be represented in the typed components.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum ParamKind {
+    /// A single string, first value wins
+    Str,
+    /// Every value of a repeated parameter
+    List,
+    /// Every value of a repeated parameter, also splitting on top level commas. Eg: `sort=age desc,id asc`
+    SplitList,
+    Bool,
+    Usize,
+}
+
+/// Classic Solr parameters, as found in a query string.
+///
+/// Components take the parameters they understand, and the rest ends up in `additional_params`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct SolrParams(Vec<(String, String)>);
+
+impl SolrParams {
+    pub fn parse(query: &str) -> Self {
+        let query = query.trim_start_matches('?');
+        SolrParams(
+            form_urlencoded::parse(query.as_bytes())
+                .map(|(k, v)| (k.into_owned(), v.into_owned()))
+                .collect(),
+        )
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.0.iter().any(|(k, _)| k == key)
+    }
+
+    /// The first value of `key`, without removing it.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Remove and return every value of `key`.
+    pub fn take_all(&mut self, key: &str) -> Vec<String> {
+        let mut values = Vec::new();
+        self.0.retain(|(k, v)| {
+            if k == key {
+                values.push(v.to_string());
+                false
+            } else {
+                true
+            }
+        });
+        values
+    }
+
+    /// Remove every value of `key`, returning the first, as that is the one Solr uses.
+    pub fn take(&mut self, key: &str) -> Option<String> {
+        self.take_all(key).into_iter().next()
+    }
+
+    /// Remove `key` and parse the first value.
+    pub fn take_parsed<T: FromStr>(&mut self, key: &str) -> Result<Option<T>, Error> {
+        self.take(key)
+            .map(|value| {
+                value.parse::<T>().map_err(|_| {
+                    Error::Unknown(format!("Invalid value for parameter {key}: {value}"))
+                })
+            })
+            .transpose()
+    }
+
+    /// Remove `key` and parse the first value as a Solr boolean, see [parse_solr_bool].
+    pub fn take_bool(&mut self, key: &str) -> Result<Option<bool>, Error> {
+        self.take(key)
+            .map(|value| {
+                parse_solr_bool(&value).ok_or_else(|| {
+                    Error::Unknown(format!("Invalid value for parameter {key}: {value}"))
+                })
+            })
+            .transpose()
+    }
+
+    /// Remove every value of `key`. Values are split on top level commas if `split` is set.
+    pub fn take_list(&mut self, key: &str, split: bool) -> Option<Vec<String>> {
+        let values = self.take_all(key);
+        let values = match split {
+            true => values
+                .iter()
+                .flat_map(|value| split_top_level_commas(value))
+                .collect::<Vec<_>>(),
+            false => values,
+        };
+        match values.is_empty() {
+            true => None,
+            false => Some(values),
+        }
+    }
+
+    /// Remove `key` and convert it to a json value of the given kind.
+    pub fn take_value(&mut self, key: &str, kind: ParamKind) -> Result<Option<Value>, Error> {
+        Ok(match kind {
+            ParamKind::Str => self.take(key).map(Value::String),
+            ParamKind::List => self.take_list(key, false).map(Value::from),
+            ParamKind::SplitList => self.take_list(key, true).map(Value::from),
+            ParamKind::Bool => self.take_bool(key)?.map(Value::Bool),
+            ParamKind::Usize => self.take_parsed::<usize>(key)?.map(Value::from),
+        })
+    }
+
+    /// Remove the given keys, and build a json object that can be deserialized into a component.
+    pub fn take_object(
+        &mut self,
+        fields: &[(&str, ParamKind)],
+    ) -> Result<Map<String, Value>, Error> {
+        let mut map = Map::new();
+        for (key, kind) in fields {
+            if let Some(value) = self.take_value(key, *kind)? {
+                map.insert(key.to_string(), value);
+            }
+        }
+        Ok(map)
+    }
+
+    /// The parameters not taken by any component. Repeated parameters become lists.
+    pub fn into_remaining(self) -> Vec<(String, Value)> {
+        let mut remaining: Vec<(String, Value)> = Vec::new();
+        for (key, value) in self.0 {
+            match remaining.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, Value::Array(values))) => values.push(Value::String(value)),
+                Some((_, existing)) => {
+                    *existing = Value::Array(vec![existing.take(), Value::String(value)])
+                }
+                None => remaining.push((key, Value::String(value))),
+            }
+        }
+        remaining
+    }
+}
+
+/// Split on commas that are not inside parentheses, so function queries are kept whole.
+fn split_top_level_commas(value: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut current = String::new();
+    for c in value.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    parts.push(current.trim().to_string());
+    parts.into_iter().filter(|part| !part.is_empty()).collect()
+}
+
+/// Parse a boolean the way Solr does, which also accepts `on`/`off` and `yes`/`no`.
+pub(crate) fn parse_solr_bool(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "on" | "yes" => Some(true),
+        "false" | "off" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::queries::solr_params::{
+        parse_solr_bool, split_top_level_commas, ParamKind, SolrParams,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn split_keeps_function_queries() {
+        assert_eq!(
+            split_top_level_commas("sum(age,1) desc, id asc"),
+            vec!["sum(age,1) desc", "id asc"]
+        );
+    }
+
+    #[test]
+    fn take_value_uses_kind() {
+        let mut params = SolrParams::parse("?fq=a&fq=b&rows=10&group=true&rest=1&rest=2");
+        assert_eq!(
+            params.take_value("fq", ParamKind::List).unwrap(),
+            Some(json!(["a", "b"]))
+        );
+        assert_eq!(
+            params.take_value("rows", ParamKind::Usize).unwrap(),
+            Some(json!(10))
+        );
+        assert_eq!(
+            params.take_value("group", ParamKind::Bool).unwrap(),
+            Some(json!(true))
+        );
+        assert_eq!(
+            params.into_remaining(),
+            vec![("rest".to_string(), json!(["1", "2"]))]
+        );
+    }
+
+    #[test]
+    fn bool_accepts_solr_spellings() {
+        assert_eq!(parse_solr_bool("on"), Some(true));
+        assert_eq!(parse_solr_bool("Yes"), Some(true));
+        assert_eq!(parse_solr_bool("off"), Some(false));
+        assert_eq!(parse_solr_bool("no"), Some(false));
+        assert_eq!(parse_solr_bool("1"), None);
+        let mut params = SolrParams::parse("?group=on&group.main=no");
+        assert_eq!(params.take_bool("group").unwrap(), Some(true));
+        assert_eq!(params.take_bool("group.main").unwrap(), Some(false));
+    }
+}
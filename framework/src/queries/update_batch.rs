@@ -0,0 +1,353 @@
+use crate::error::Error;
+use crate::models::atomic_update::AtomicUpdate;
+use crate::models::context::SolrServerContext;
+use crate::models::response::SolrResponse;
+use crate::queries::index::{CommitQuery, DeleteById, OptimizeQuery};
+use crate::queries::request_builder::SolrRequestBuilder;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A document to add in an [UpdateBatch], with its own options.
+/// # Examples
+/// ```
+/// use serde::Serialize;
+/// use solrstice::AddCommand;
+///
+/// #[derive(Serialize)]
+/// struct Data {id: String}
+///
+/// let add = AddCommand::new(&Data {id: "document1".to_string()})?.overwrite(false).commit_within(1000);
+/// let from_value: AddCommand = serde_json::json!({"id": "document2"}).into();
+/// # Ok::<(), solrstice::Error>(())
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct AddCommand {
+    doc: Value,
+    overwrite: Option<bool>,
+    commit_within: Option<u64>,
+    boost: Option<f64>,
+}
+
+impl AddCommand {
+    /// Create an add command from anything that serializes to a document.
+    pub fn new<D: Serialize>(doc: &D) -> Result<Self, Error> {
+        Ok(serde_json::to_value(doc)?.into())
+    }
+
+    /// Whether a document with the same unique key should be replaced. Default is true.
+    pub fn overwrite<O: Into<Option<bool>>>(mut self, overwrite: O) -> Self {
+        self.overwrite = overwrite.into();
+        self
+    }
+
+    /// Commit the document within this many milliseconds.
+    pub fn commit_within<O: Into<Option<u64>>>(mut self, commit_within: O) -> Self {
+        self.commit_within = commit_within.into();
+        self
+    }
+
+    /// Index time boost of the document. Solr 7 and later ignore index time boosts.
+    pub fn boost<O: Into<Option<f64>>>(mut self, boost: O) -> Self {
+        self.boost = boost.into();
+        self
+    }
+
+    fn to_command(&self) -> Value {
+        let mut command = Map::new();
+        command.insert("doc".to_string(), self.doc.clone());
+        if let Some(overwrite) = self.overwrite {
+            command.insert("overwrite".to_string(), overwrite.into());
+        }
+        if let Some(commit_within) = self.commit_within {
+            command.insert("commitWithin".to_string(), commit_within.into());
+        }
+        if let Some(boost) = self.boost {
+            command.insert("boost".to_string(), boost.into());
+        }
+        Value::Object(command)
+    }
+}
+
+impl From<Value> for AddCommand {
+    fn from(doc: Value) -> Self {
+        AddCommand {
+            doc,
+            overwrite: None,
+            commit_within: None,
+            boost: None,
+        }
+    }
+}
+
+impl From<AtomicUpdate> for AddCommand {
+    fn from(update: AtomicUpdate) -> Self {
+        Value::from(update).into()
+    }
+}
+
+/// A delete by id or query in an [UpdateBatch], with its own `commitWithin`.
+/// # Examples
+/// ```
+/// use solrstice::{DeleteById, DeleteCommand};
+///
+/// let by_id = DeleteCommand::id(DeleteById::new("document1").route("tenant1!"));
+/// let by_query = DeleteCommand::query("age:[* TO 10]").commit_within(1000);
+/// ```
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+pub struct DeleteCommand {
+    id: Option<DeleteById>,
+    query: Option<String>,
+    commit_within: Option<u64>,
+}
+
+impl DeleteCommand {
+    /// Delete a document by id. Strings and [DeleteById] are accepted.
+    pub fn id<D: Into<DeleteById>>(id: D) -> Self {
+        DeleteCommand {
+            id: Some(id.into()),
+            query: None,
+            commit_within: None,
+        }
+    }
+
+    /// Delete all documents matching the query.
+    pub fn query<S: Into<String>>(query: S) -> Self {
+        DeleteCommand {
+            id: None,
+            query: Some(query.into()),
+            commit_within: None,
+        }
+    }
+
+    /// Commit the delete within this many milliseconds.
+    pub fn commit_within<O: Into<Option<u64>>>(mut self, commit_within: O) -> Self {
+        self.commit_within = commit_within.into();
+        self
+    }
+
+    fn to_command(&self) -> Value {
+        let mut command = match (&self.id, &self.query) {
+            (Some(id), _) => match id.to_value() {
+                Value::Object(command) => command,
+                id => Map::from_iter([("id".to_string(), id)]),
+            },
+            (None, query) => Map::from_iter([("query".to_string(), query.clone().into())]),
+        };
+        if let Some(commit_within) = self.commit_within {
+            command.insert("commitWithin".to_string(), commit_within.into());
+        }
+        Value::Object(command)
+    }
+}
+
+impl From<DeleteById> for DeleteCommand {
+    fn from(id: DeleteById) -> Self {
+        DeleteCommand::id(id)
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+enum UpdateCommand {
+    Add(AddCommand),
+    Delete(DeleteCommand),
+    Commit(CommitQuery),
+    Optimize(OptimizeQuery),
+}
+
+impl UpdateCommand {
+    fn name(&self) -> &'static str {
+        match self {
+            UpdateCommand::Add(_) => "add",
+            UpdateCommand::Delete(_) => "delete",
+            UpdateCommand::Commit(_) => "commit",
+            UpdateCommand::Optimize(_) => "optimize",
+        }
+    }
+
+    fn to_command(&self) -> Value {
+        match self {
+            UpdateCommand::Add(add) => add.to_command(),
+            UpdateCommand::Delete(delete) => delete.to_command(),
+            UpdateCommand::Commit(commit) => commit.to_command(),
+            UpdateCommand::Optimize(optimize) => optimize.to_command(),
+        }
+    }
+}
+
+/// A builder for sending several update commands in one request, applied by Solr in order.
+///
+/// Adds, deletes by id or query, commits and optimizes can be mixed, each with its own options.
+/// # Examples
+/// ```no_run
+/// use serde_json::json;
+/// use solrstice::{AddCommand, AsyncSolrCloudClient, CommitQuery, DeleteCommand, SolrServerContextBuilder, SolrSingleServerHost, UpdateBatch};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = SolrServerContextBuilder::new(SolrSingleServerHost::new("http://localhost:8983")).build();
+/// let client = AsyncSolrCloudClient::new(context);
+/// let batch = UpdateBatch::new()
+///     .delete(DeleteCommand::query("category:outdated"))
+///     .add_doc(json!({"id": "document1", "category": "current"}))
+///     .add_doc(AddCommand::from(json!({"id": "document2"})).overwrite(false))
+///     .commit(CommitQuery::new());
+/// let response = client.update(&batch, "collection_name").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Debug)]
+pub struct UpdateBatch {
+    handler: String,
+    commands: Vec<UpdateCommand>,
+}
+
+impl From<&UpdateBatch> for UpdateBatch {
+    fn from(batch: &UpdateBatch) -> Self {
+        batch.clone()
+    }
+}
+
+impl AsRef<UpdateBatch> for UpdateBatch {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl UpdateBatch {
+    /// Create a new, empty UpdateBatch.
+    pub fn new() -> Self {
+        UpdateBatch {
+            handler: "update".to_string(),
+            commands: vec![],
+        }
+    }
+
+    /// Set the handler for the batch. Default is "update".
+    pub fn handler<S: Into<String>>(mut self, handler: S) -> Self {
+        self.handler = handler.into();
+        self
+    }
+
+    /// Add a document. Accepts [AddCommand], a [serde_json::Value] or an [AtomicUpdate].
+    pub fn add_doc<A: Into<AddCommand>>(mut self, add: A) -> Self {
+        self.commands.push(UpdateCommand::Add(add.into()));
+        self
+    }
+
+    /// Delete by id or query. Accepts [DeleteCommand] or [DeleteById].
+    pub fn delete<D: Into<DeleteCommand>>(mut self, delete: D) -> Self {
+        self.commands.push(UpdateCommand::Delete(delete.into()));
+        self
+    }
+
+    /// Commit, with the options of the [CommitQuery]. Its handler is ignored.
+    pub fn commit<C: Into<CommitQuery>>(mut self, commit: C) -> Self {
+        self.commands.push(UpdateCommand::Commit(commit.into()));
+        self
+    }
+
+    /// Optimize, with the options of the [OptimizeQuery]. Its handler is ignored.
+    pub fn optimize<O: Into<OptimizeQuery>>(mut self, optimize: O) -> Self {
+        self.commands.push(UpdateCommand::Optimize(optimize.into()));
+        self
+    }
+
+    /// The number of commands in the batch.
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// The JSON body. Commands are written as repeated keys in one object, since Solr reads them in order.
+    fn to_body(&self) -> Result<String, Error> {
+        let commands = self
+            .commands
+            .iter()
+            .map(|command| {
+                Ok(format!(
+                    "\"{}\":{}",
+                    command.name(),
+                    serde_json::to_string(&command.to_command())?
+                ))
+            })
+            .collect::<Result<Vec<String>, Error>>()?;
+        Ok(format!("{{{}}}", commands.join(",")))
+    }
+
+    /// Execute the batch.
+    ///
+    /// This is not meant to be used directly. Use [AsyncSolrCloudClient::update](crate::clients::async_cloud_client::AsyncSolrCloudClient::update) instead.
+    pub async fn execute<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        &self,
+        context: C,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        SolrRequestBuilder::new(
+            context.as_ref(),
+            format!("/solr/{}/{}", collection.as_ref(), self.handler).as_str(),
+        )
+        .with_headers([("Content-Type", "application/json")])
+        .send_post_with_body(self.to_body()?)
+        .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+use crate::runtime::RUNTIME;
+#[cfg(feature = "blocking")]
+impl UpdateBatch {
+    /// Execute the batch.
+    ///
+    /// This is not meant to be used directly. Use [BlockingSolrCloudClient::update](crate::clients::blocking_cloud_client::BlockingSolrCloudClient::update) instead.
+    pub fn execute_blocking<C: AsRef<SolrServerContext>, S: AsRef<str>>(
+        &self,
+        context: C,
+        collection: S,
+    ) -> Result<SolrResponse, Error> {
+        RUNTIME.handle().block_on(self.execute(context, collection))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::queries::index::{CommitQuery, DeleteById, OptimizeQuery, UpdateCommitOptions};
+    use crate::queries::update_batch::{AddCommand, DeleteCommand, UpdateBatch};
+    use crate::AtomicUpdate;
+    use serde_json::json;
+
+    #[test]
+    fn update_batch_keeps_command_order() {
+        let batch = UpdateBatch::new()
+            .add_doc(json!({"id": "document1"}))
+            .add_doc(
+                AddCommand::from(json!({"id": "document2"}))
+                    .overwrite(false)
+                    .commit_within(500)
+                    .boost(2.0),
+            )
+            .add_doc(AtomicUpdate::new("document3").inc("count", 1))
+            .delete(DeleteById::new("document4").route("tenant1!"))
+            .delete(DeleteCommand::id("document5"))
+            .delete(DeleteCommand::query("age:[* TO 10]").commit_within(1000))
+            .commit(
+                CommitQuery::new().commit_options(UpdateCommitOptions::new().wait_searcher(false)),
+            )
+            .optimize(OptimizeQuery::new().max_segments(1));
+        assert_eq!(
+            batch.to_body().unwrap(),
+            concat!(
+                r#"{"add":{"doc":{"id":"document1"}},"#,
+                r#""add":{"boost":2.0,"commitWithin":500,"doc":{"id":"document2"},"overwrite":false},"#,
+                r#""add":{"doc":{"count":{"inc":1},"id":"document3"}},"#,
+                r#""delete":{"_route_":"tenant1!","id":"document4"},"#,
+                r#""delete":{"id":"document5"},"#,
+                r#""delete":{"commitWithin":1000,"query":"age:[* TO 10]"},"#,
+                r#""commit":{"softCommit":false,"waitSearcher":false},"#,
+                r#""optimize":{"maxSegments":1}}"#
+            )
+        );
+        assert_eq!(UpdateBatch::new().to_body().unwrap(), "{}");
+    }
+}
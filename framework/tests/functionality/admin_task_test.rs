@@ -0,0 +1,125 @@
+use crate::structures::BaseTestsBuildup;
+use serial_test::parallel;
+use solrstice::models::AdminTaskState;
+use solrstice::queries::alias::{
+    alias_exists, create_alias_with_options, delete_alias, delete_alias_with_options,
+    CreateAliasOptions, DeleteAliasOptions,
+};
+use solrstice::queries::collection::{
+    collection_exists, create_collection, create_collection_with_options, delete_collection,
+    delete_collection_with_options, CreateCollectionOptions, DeleteCollectionOptions,
+};
+use solrstice::queries::config::{delete_config, upload_config};
+use solrstice::{AsyncAdminTask, Error};
+use std::path::Path;
+use std::time::Duration;
+
+#[tokio::test]
+#[parallel]
+async fn async_admin_task_waits_for_completion() -> Result<(), Error> {
+    let name = "AsyncAdminTaskCreate";
+    let config = BaseTestsBuildup::new().await;
+    let task = AsyncAdminTask::new(name).poll_interval(Duration::from_millis(200));
+    let _ = delete_collection(&config.context, name).await;
+    let _ = delete_config(&config.context, name).await;
+    let _ = task.delete_status(&config.context).await;
+    upload_config(&config.context, name, Path::new(&config.config_path)).await?;
+
+    let options = CreateCollectionOptions::new()
+        .config_name(name)
+        .num_shards(2)
+        .async_id(name);
+    create_collection_with_options(&config.context, name, &options).await?;
+    let status = task.wait(&config.context).await?;
+    assert_eq!(status.get_state(), AdminTaskState::Completed);
+    assert!(collection_exists(&config.context, name).await?);
+
+    task.delete_status(&config.context).await?;
+    assert_eq!(
+        task.status(&config.context).await?.get_state(),
+        AdminTaskState::NotFound
+    );
+
+    delete_collection(&config.context, name).await?;
+    let _ = delete_config(&config.context, name).await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn async_admin_task_waits_for_alias_and_collection_deletion() -> Result<(), Error> {
+    let name = "AsyncAdminTaskAlias";
+    let alias = "AsyncAdminTaskAliasAlias";
+    let config = BaseTestsBuildup::new().await;
+    let create_task =
+        AsyncAdminTask::new("AsyncAdminTaskAliasCreate").poll_interval(Duration::from_millis(200));
+    let delete_alias_task =
+        AsyncAdminTask::new("AsyncAdminTaskAliasDelete").poll_interval(Duration::from_millis(200));
+    let delete_collection_task = AsyncAdminTask::new("AsyncAdminTaskAliasDeleteCollection")
+        .poll_interval(Duration::from_millis(200));
+    let _ = delete_alias(&config.context, alias).await;
+    let _ = delete_collection(&config.context, name).await;
+    let _ = delete_config(&config.context, name).await;
+    for task in [&create_task, &delete_alias_task, &delete_collection_task] {
+        let _ = task.delete_status(&config.context).await;
+    }
+    upload_config(&config.context, name, Path::new(&config.config_path)).await?;
+    create_collection(&config.context, name, name, 1, 1).await?;
+
+    let options = CreateAliasOptions::new().async_id(create_task.get_request_id());
+    create_alias_with_options(&config.context, alias, &[name], &options).await?;
+    create_task.wait(&config.context).await?;
+    assert!(alias_exists(&config.context, alias).await?);
+
+    let options = DeleteAliasOptions::new().async_id(delete_alias_task.get_request_id());
+    delete_alias_with_options(&config.context, alias, &options).await?;
+    delete_alias_task.wait(&config.context).await?;
+    assert!(!alias_exists(&config.context, alias).await?);
+
+    let options = DeleteCollectionOptions::new().async_id(delete_collection_task.get_request_id());
+    delete_collection_with_options(&config.context, name, &options).await?;
+    delete_collection_task.wait(&config.context).await?;
+    assert!(!collection_exists(&config.context, name).await?);
+
+    for task in [&create_task, &delete_alias_task, &delete_collection_task] {
+        let _ = task.delete_status(&config.context).await;
+    }
+    let _ = delete_config(&config.context, name).await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn async_admin_task_returns_failure_as_error() -> Result<(), Error> {
+    let name = "AsyncAdminTaskFailure";
+    let config = BaseTestsBuildup::new().await;
+    let task = AsyncAdminTask::new(name).poll_interval(Duration::from_millis(200));
+    let _ = task.delete_status(&config.context).await;
+
+    let options = CreateCollectionOptions::new()
+        .config_name("AsyncAdminTaskMissingConfig")
+        .async_id(name);
+    let result = match create_collection_with_options(&config.context, name, &options).await {
+        Ok(_) => task.wait(&config.context).await,
+        Err(e) => Err(e),
+    };
+    assert!(matches!(
+        result,
+        Err(Error::SolrAdminTaskFailed { .. } | Error::SolrResponseError { .. })
+    ));
+
+    let _ = task.delete_status(&config.context).await;
+    let _ = delete_collection(&config.context, name).await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn async_admin_task_unknown_id_is_error() -> Result<(), Error> {
+    let config = BaseTestsBuildup::new().await;
+    let result = AsyncAdminTask::new("AsyncAdminTaskUnknown")
+        .wait(&config.context)
+        .await;
+    assert!(matches!(result, Err(Error::SolrAdminTaskFailed { .. })));
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use crate::structures::{get_solr_version, BaseTestsBuildup};
+use serde_json::json;
+use serial_test::parallel;
+use solrstice::queries::backup::{
+    backup_collection, delete_backup, list_backups, restore_collection, BackupOptions,
+    DeleteBackupOptions, ListBackupsOptions, RestoreOptions,
+};
+use solrstice::queries::collection::{create_collection, delete_collection};
+use solrstice::queries::config::{delete_config, upload_config};
+use solrstice::{CommitType, Error, SelectQuery, UpdateQuery};
+use std::path::Path;
+
+/// Allowed by `solr.allowPaths` in the docker setups.
+const BACKUP_LOCATION: &str = "/var/solr/backups";
+
+#[tokio::test]
+#[parallel]
+async fn backup_list_restore_and_delete_backup() -> Result<(), Error> {
+    let config_name = "BackupConfig".to_string();
+    let collection_name = "BackupCollection".to_string();
+    let restored_name = "BackupRestoredCollection".to_string();
+    let backup_name = "BackupCollectionBackup".to_string();
+
+    let config = BaseTestsBuildup::new().await;
+    // Incremental backups, LISTBACKUP and DELETEBACKUP require Solr 8.9
+    if get_solr_version(&config.context).await < (8, 9) {
+        return Ok(());
+    }
+    let _ = delete_collection(&config.context, &restored_name).await;
+    let _ = delete_collection(&config.context, &collection_name).await;
+    let _ = delete_config(&config.context, &config_name).await;
+    let list_options = ListBackupsOptions::new().location(BACKUP_LOCATION);
+    if let Ok(backups) = list_backups(&config.context, &backup_name, &list_options).await {
+        for backup in backups {
+            let options = DeleteBackupOptions::new()
+                .location(BACKUP_LOCATION)
+                .backup_id(backup.get_backup_id());
+            delete_backup(&config.context, &backup_name, options).await?;
+        }
+    }
+    upload_config(
+        &config.context,
+        &config_name,
+        Path::new(&config.config_path),
+    )
+    .await?;
+    create_collection(&config.context, &collection_name, &config_name, 2, 1).await?;
+    UpdateQuery::new()
+        .commit_type(CommitType::Hard)
+        .execute(
+            &config.context,
+            &collection_name,
+            &[json!({"id": "1"}), json!({"id": "2"})],
+        )
+        .await?;
+
+    let options = BackupOptions::new()
+        .location(BACKUP_LOCATION)
+        .incremental(true);
+    let first =
+        backup_collection(&config.context, &collection_name, &backup_name, &options).await?;
+    assert_eq!(first.get_collection(), Some(collection_name.as_str()));
+    assert_eq!(first.get_num_shards(), Some(2));
+    assert_eq!(first.get_shards().len(), 2);
+    for shard in first.get_shards() {
+        assert!(shard.get_index_file_count().unwrap() > 0);
+    }
+    let first_id = first.get_backup_id().unwrap();
+
+    let second =
+        backup_collection(&config.context, &collection_name, &backup_name, &options).await?;
+    assert_eq!(second.get_backup_id(), Some(first_id + 1));
+    assert_eq!(second.get_uploaded_index_file_count(), Some(0));
+
+    let backups = list_backups(&config.context, &backup_name, &list_options).await?;
+    assert_eq!(backups.len(), 2);
+    assert_eq!(backups[0].get_backup_id(), first_id);
+    assert_eq!(backups[0].get_config_name(), Some(config_name.as_str()));
+    assert_eq!(backups[0].get_shard_backup_ids().len(), 2);
+
+    let options = RestoreOptions::new()
+        .location(BACKUP_LOCATION)
+        .backup_id(first_id);
+    restore_collection(&config.context, &restored_name, &backup_name, options).await?;
+    let response = SelectQuery::new()
+        .execute(&config.context, &restored_name)
+        .await?;
+    assert_eq!(response.get_docs_response().unwrap().get_num_found(), 2);
+
+    let options = DeleteBackupOptions::new()
+        .location(BACKUP_LOCATION)
+        .max_num_backup_points(1);
+    let deleted = delete_backup(&config.context, &backup_name, options).await?;
+    assert_eq!(deleted.len(), 1);
+    assert_eq!(deleted[0].get_backup_id(), Some(first_id));
+    let options = DeleteBackupOptions::new()
+        .location(BACKUP_LOCATION)
+        .purge_unused(true);
+    delete_backup(&config.context, &backup_name, options).await?;
+    let backups = list_backups(&config.context, &backup_name, &list_options).await?;
+    assert_eq!(backups.len(), 1);
+
+    delete_collection(&config.context, &restored_name).await?;
+    delete_collection(&config.context, &collection_name).await?;
+    let _ = delete_config(&config.context, &config_name).await;
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::structures::{get_test_data, FunctionalityTestsBuildup};
 use serial_test::parallel;
 use solrstice::queries::config::get_configs_blocking;
-use solrstice::{Error, SelectQuery, UpdateQuery};
+use solrstice::{BlockingSolrCloudClient, BulkIndexer, Error, SelectQuery, UpdateQuery};
 use std::thread;
 
 #[test]
@@ -53,3 +53,26 @@ fn select_raw_works_with_blocking() -> Result<(), Error> {
     runtime.block_on(async { config.tear_down().await.unwrap() });
     Ok(())
 }
+
+#[test]
+#[parallel]
+fn bulk_index_works_with_blocking_client() -> Result<(), Error> {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let config = runtime.block_on(async {
+        FunctionalityTestsBuildup::build_up("BulkIndexBlocking")
+            .await
+            .unwrap()
+    });
+
+    let client = BlockingSolrCloudClient::new(config.context.clone());
+    let summary = client
+        .bulk_index(
+            BulkIndexer::new().batch_size(2),
+            &config.collection_name,
+            get_test_data(),
+        )
+        .unwrap();
+    assert_eq!(summary.get_docs_indexed(), get_test_data().len());
+    runtime.block_on(async { config.tear_down().await.unwrap() });
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use crate::structures::FunctionalityTestsBuildup;
+use serde_json::json;
+use serial_test::parallel;
+use solrstice::{BulkIndexer, Error, SelectQuery, UpdateQuery};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+#[parallel]
+async fn bulk_index_indexes_all_documents() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("BulkIndex")
+        .await
+        .unwrap();
+
+    let progress_calls = Arc::new(AtomicUsize::new(0));
+    let calls = progress_calls.clone();
+    let indexer = BulkIndexer::new()
+        .batch_size(100)
+        .concurrency(3)
+        .on_progress(move |progress| {
+            assert!(progress.get_error().is_none());
+            calls.fetch_add(1, Ordering::SeqCst);
+        });
+    let docs = (0..1050).map(|i| json!({"id": format!("bulk_{i}"), "city_name": "Bulk"}));
+    let summary = indexer
+        .execute_iter(&config.context, &config.collection_name, docs)
+        .await
+        .unwrap();
+    assert_eq!(summary.get_batches(), 11);
+    assert_eq!(summary.get_docs_indexed(), 1050);
+    assert_eq!(summary.get_failed_batches(), 0);
+    assert_eq!(progress_calls.load(Ordering::SeqCst), 11);
+
+    let result = SelectQuery::new()
+        .fq(["city_name:Bulk"])
+        .rows(0)
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap();
+    assert_eq!(result.get_docs_response().unwrap().get_num_found(), 1050);
+    let _ = config.tear_down().await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn bulk_index_reports_failed_batches() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("BulkIndexFailedBatch")
+        .await
+        .unwrap();
+
+    let (sender, receiver) = tokio::sync::mpsc::channel(10);
+    tokio::spawn(async move {
+        sender.send(json!({"id": "bulk_ok"})).await.unwrap();
+        sender
+            .send(json!({"id": "bulk_bad", "age": "not a number"}))
+            .await
+            .unwrap();
+    });
+    let summary = BulkIndexer::new()
+        .batch_size(1)
+        .execute_channel(&config.context, &config.collection_name, receiver)
+        .await
+        .unwrap();
+    assert_eq!(summary.get_docs_indexed(), 1);
+    assert_eq!(summary.get_failed_batches(), 1);
+    assert_eq!(summary.get_retries(), 0);
+    let _ = config.tear_down().await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn bulk_index_reports_update_errors_of_tolerant_chain() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("BulkIndexTolerant")
+        .await
+        .unwrap();
+
+    let rejected = Arc::new(Mutex::new(Vec::new()));
+    let ids = rejected.clone();
+    let summary = BulkIndexer::new()
+        .update_query(UpdateQuery::new().update_chain("tolerant").max_errors(-1))
+        .on_progress(move |progress| {
+            let mut ids = ids.lock().unwrap();
+            ids.extend(
+                progress
+                    .get_update_errors()
+                    .iter()
+                    .map(|e| e.get_id().to_string()),
+            );
+        })
+        .execute_iter(
+            &config.context,
+            &config.collection_name,
+            [
+                json!({"id": "bulk_tolerant_1", "age": 20}),
+                json!({"id": "bulk_tolerant_2", "age": "not a number"}),
+            ],
+        )
+        .await
+        .unwrap();
+    assert_eq!(summary.get_failed_batches(), 0);
+    assert_eq!(summary.get_docs_indexed(), 1);
+    assert_eq!(summary.get_docs_failed(), 1);
+    assert_eq!(
+        *rejected.lock().unwrap(),
+        vec!["bulk_tolerant_2".to_string()]
+    );
+    let _ = config.tear_down().await;
+    Ok(())
+}
@@ -1,7 +1,17 @@
-use crate::structures::BaseTestsBuildup;
+use crate::structures::{get_solr_version, BaseTestsBuildup};
+use serde_json::json;
 use serial_test::parallel;
-use solrstice::queries::collection::{collection_exists, create_collection, delete_collection};
+use solrstice::models::{ReplicaType, ShardState};
+use solrstice::queries::alias::{alias_exists, delete_alias};
+use solrstice::queries::collection::{
+    collection_exists, create_collection, create_collection_with_options, delete_collection,
+    get_cluster_status, get_col_status, modify_collection, reload_collection,
+    reload_collections_using_config, rename_collection, ColStatusOptions, CollectionRouter,
+    CreateCollectionOptions, ModifyCollectionOptions, ReloadCollectionOptions,
+    RenameCollectionOptions,
+};
 use solrstice::queries::config::{delete_config, upload_config};
+use solrstice::{AsyncAdminTask, CommitType, Error, SelectDestination, SelectQuery, UpdateQuery};
 use std::path::Path;
 
 #[tokio::test]
@@ -42,3 +52,217 @@ async fn create_collection_creates_collection() {
         .unwrap();
     let _ = delete_config(&config.context, &config_name);
 }
+
+#[tokio::test]
+#[parallel]
+async fn create_collection_with_options_creates_named_shards() -> Result<(), Error> {
+    let config_name = "CreateCollectionOptionsConfig".to_string();
+    let collection_name = "CreateCollectionOptionsCollection".to_string();
+
+    let config = BaseTestsBuildup::new().await;
+    let _ = delete_collection(&config.context, &collection_name).await;
+    let _ = delete_config(&config.context, &config_name).await;
+    upload_config(
+        &config.context,
+        &config_name,
+        Path::new(&config.config_path),
+    )
+    .await?;
+
+    let options = CreateCollectionOptions::new()
+        .config_name(&config_name)
+        .router(CollectionRouter::Implicit)
+        .router_field("city_name")
+        .shards(["Alta", "Bergen"])
+        .nrt_replicas(1)
+        .properties([("solr.autoSoftCommit.maxTime", "1000")])
+        .wait_for_final_state(true);
+    create_collection_with_options(&config.context, &collection_name, &options).await?;
+
+    UpdateQuery::new()
+        .execute(
+            &config.context,
+            &collection_name,
+            &[json!({"id": "1", "city_name": "Alta"})],
+        )
+        .await?;
+    let response = SelectQuery::new()
+        .execute(
+            &config.context,
+            SelectDestination::new(&collection_name).shards(["Alta"]),
+        )
+        .await?;
+    assert_eq!(response.get_docs_response().unwrap().get_num_found(), 1);
+    let response = SelectQuery::new()
+        .execute(
+            &config.context,
+            SelectDestination::new(&collection_name).shards(["Bergen"]),
+        )
+        .await?;
+    assert_eq!(response.get_docs_response().unwrap().get_num_found(), 0);
+
+    delete_collection(&config.context, &collection_name).await?;
+    let _ = delete_config(&config.context, &config_name).await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn get_cluster_status_returns_shards_and_replicas() -> Result<(), Error> {
+    let config_name = "ClusterStatusConfig".to_string();
+    let collection_name = "ClusterStatusCollection".to_string();
+
+    let config = BaseTestsBuildup::new().await;
+    let _ = delete_collection(&config.context, &collection_name).await;
+    let _ = delete_config(&config.context, &config_name).await;
+    upload_config(
+        &config.context,
+        &config_name,
+        Path::new(&config.config_path),
+    )
+    .await?;
+    let options = CreateCollectionOptions::new()
+        .config_name(&config_name)
+        .num_shards(2)
+        .nrt_replicas(1)
+        .wait_for_final_state(true);
+    create_collection_with_options(&config.context, &collection_name, &options).await?;
+
+    let status = get_cluster_status(&config.context, Some(&collection_name), &[]).await?;
+    assert!(!status.get_live_nodes().is_empty());
+    let collection = status.get_collection(&collection_name).unwrap();
+    assert_eq!(collection.get_name(), collection_name);
+    assert_eq!(collection.get_config_name(), Some(config_name.as_str()));
+    assert_eq!(collection.get_router(), Some(CollectionRouter::CompositeId));
+    assert_eq!(collection.get_shards().len(), 2);
+    for shard in collection.get_shards().values() {
+        assert_eq!(shard.get_state(), ShardState::Active);
+        assert!(shard.get_range().is_some());
+        let leader = shard.get_leader().unwrap();
+        assert_eq!(leader.get_type(), ReplicaType::Nrt);
+        assert!(status
+            .get_live_nodes()
+            .contains(&leader.get_node_name().to_string()));
+    }
+
+    let status = get_cluster_status(&config.context, Some(&collection_name), &["shard1"]).await?;
+    let collection = status.get_collection(&collection_name).unwrap();
+    assert_eq!(collection.get_shards().len(), 1);
+    assert!(collection.get_shard("shard1").is_some());
+
+    delete_collection(&config.context, &collection_name).await?;
+    let _ = delete_config(&config.context, &config_name).await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn modify_and_reload_collection() -> Result<(), Error> {
+    let config_name = "ModifyCollectionConfig".to_string();
+    let collection_name = "ModifyCollectionCollection".to_string();
+
+    let config = BaseTestsBuildup::new().await;
+    let _ = delete_collection(&config.context, &collection_name).await;
+    let _ = delete_config(&config.context, &config_name).await;
+    upload_config(
+        &config.context,
+        &config_name,
+        Path::new(&config.config_path),
+    )
+    .await?;
+    create_collection(&config.context, &collection_name, &config_name, 1, 1).await?;
+
+    let options = ModifyCollectionOptions::new()
+        .read_only(true)
+        .properties([("team", "search")]);
+    modify_collection(&config.context, &collection_name, &options).await?;
+    let update = UpdateQuery::new()
+        .execute(&config.context, &collection_name, &[json!({"id": "1"})])
+        .await;
+    assert!(update.is_err());
+    modify_collection(
+        &config.context,
+        &collection_name,
+        ModifyCollectionOptions::new().read_only(false),
+    )
+    .await?;
+    reload_collection(
+        &config.context,
+        &collection_name,
+        ReloadCollectionOptions::new(),
+    )
+    .await?;
+    let reloaded = reload_collections_using_config(
+        &config.context,
+        &config_name,
+        ReloadCollectionOptions::new(),
+    )
+    .await?;
+    assert_eq!(reloaded, vec![collection_name.clone()]);
+
+    UpdateQuery::new()
+        .commit_type(CommitType::Hard)
+        .execute(&config.context, &collection_name, &[json!({"id": "1"})])
+        .await?;
+    // COLSTATUS requires Solr 8.1
+    if get_solr_version(&config.context).await >= (8, 1) {
+        let options = ColStatusOptions::new().core_info(true).segments(true);
+        let status = get_col_status(&config.context, Some(&collection_name), &options).await?;
+        let collection = status.get_collection(&collection_name).unwrap();
+        assert_eq!(collection.get_active_shards(), 1);
+        let leader = collection
+            .get_shard("shard1")
+            .unwrap()
+            .get_leader()
+            .unwrap();
+        let segments_info = leader.get_segments_info().unwrap();
+        assert_eq!(segments_info.get_num_segments(), 1);
+        assert_eq!(segments_info.get_total_max_doc(), 1);
+        assert!(segments_info.get_size_in_gb().is_some());
+        assert_eq!(segments_info.get_segments().len(), 1);
+    }
+
+    delete_collection(&config.context, &collection_name).await?;
+    let _ = delete_config(&config.context, &config_name).await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn rename_collection_creates_alias() -> Result<(), Error> {
+    let config_name = "RenameCollectionConfig".to_string();
+    let collection_name = "RenameCollectionCollection".to_string();
+    let target_name = "RenameCollectionTarget".to_string();
+
+    let config = BaseTestsBuildup::new().await;
+    // RENAME requires Solr 8.1
+    if get_solr_version(&config.context).await < (8, 1) {
+        return Ok(());
+    }
+    let task = AsyncAdminTask::new("RenameCollectionTask");
+    let _ = task.delete_status(&config.context).await;
+    let _ = delete_alias(&config.context, &target_name).await;
+    let _ = delete_collection(&config.context, &collection_name).await;
+    let _ = delete_config(&config.context, &config_name).await;
+    upload_config(
+        &config.context,
+        &config_name,
+        Path::new(&config.config_path),
+    )
+    .await?;
+    create_collection(&config.context, &collection_name, &config_name, 1, 1).await?;
+
+    let options = RenameCollectionOptions::new().async_id(task.get_request_id());
+    rename_collection(&config.context, &collection_name, &target_name, &options).await?;
+    task.wait(&config.context).await?;
+    assert!(alias_exists(&config.context, &target_name).await?);
+    SelectQuery::new()
+        .execute(&config.context, &target_name)
+        .await?;
+
+    let _ = task.delete_status(&config.context).await;
+    delete_alias(&config.context, &target_name).await?;
+    delete_collection(&config.context, &collection_name).await?;
+    let _ = delete_config(&config.context, &config_name).await;
+    Ok(())
+}
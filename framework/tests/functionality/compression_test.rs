@@ -0,0 +1,44 @@
+use crate::structures::{get_test_data, BaseTestsBuildup, City, FunctionalityTestsBuildup};
+use serial_test::parallel;
+use solrstice::{AsyncSolrCloudClient, Error, SelectQuery, SolrServerContextBuilder, UpdateQuery};
+
+async fn compressed_client() -> AsyncSolrCloudClient {
+    let base = BaseTestsBuildup::new().await;
+    let mut context = SolrServerContextBuilder::new(base.host)
+        .with_request_compression(0)
+        .with_response_compression(true);
+    if let Some(auth) = base.auth {
+        context = context.with_auth(auth);
+    }
+    AsyncSolrCloudClient::new(context.build())
+}
+
+#[tokio::test]
+#[parallel]
+async fn compression_indexes_and_selects_documents() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("CompressionIndexSelect").await?;
+    let client = compressed_client().await;
+
+    client
+        .index(
+            &UpdateQuery::new(),
+            &config.collection_name,
+            &get_test_data(),
+        )
+        .await?;
+    let docs = client
+        .select(
+            &SelectQuery::new()
+                .fl(["*", "[child]"])
+                .fq(["city_name:[* TO *]"]),
+            &config.collection_name,
+        )
+        .await?
+        .get_docs_response()
+        .unwrap()
+        .get_docs::<City>()?;
+    assert_eq!(docs, get_test_data());
+
+    let _ = config.tear_down().await;
+    Ok(())
+}
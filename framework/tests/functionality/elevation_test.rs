@@ -0,0 +1,86 @@
+use crate::structures::{get_solr_version, get_test_data, FunctionalityTestsBuildup};
+use serde_json::Value;
+use serial_test::parallel;
+use solrstice::{ElevationComponent, Error, SelectQuery, UpdateQuery};
+
+#[tokio::test]
+#[parallel]
+async fn elevation_elevates_and_excludes_ids() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("ElevationIds")
+        .await
+        .unwrap();
+    UpdateQuery::new()
+        .execute(&config.context, &config.collection_name, &get_test_data())
+        .await
+        .unwrap();
+
+    let result = SelectQuery::new()
+        .q("city_name:Alta")
+        .elevation(
+            ElevationComponent::new()
+                .elevate_ids(["city_Tromsø"])
+                .exclude_ids(["city_Alta"])
+                .elevated_flag(true),
+        )
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap();
+    let docs = result
+        .get_docs_response()
+        .unwrap()
+        .get_docs::<Value>()
+        .unwrap();
+    assert_eq!(docs[0]["id"], "city_Tromsø");
+    assert_eq!(docs[0]["[elevated]"], true);
+    assert!(docs.iter().all(|doc| doc["id"] != "city_Alta"));
+    let _ = config.tear_down().await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn upload_elevation_config_reloads_collections() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("ElevationUpload")
+        .await
+        .unwrap();
+    // Uploading a single file into a config set requires Solr 8.7
+    if get_solr_version(&config.context).await < (8, 7) {
+        return config.tear_down().await;
+    }
+    UpdateQuery::new()
+        .execute(&config.context, &config.collection_name, &get_test_data())
+        .await
+        .unwrap();
+
+    let path = std::env::temp_dir().join("solrstice_elevation_upload.xml");
+    std::fs::write(
+        &path,
+        r#"<?xml version="1.0" encoding="UTF-8" ?>
+<elevate>
+    <query text="city_name:Alta">
+        <doc id="city_Tromsø"/>
+    </query>
+</elevate>"#,
+    )?;
+    let reloaded = config
+        .async_client
+        .upload_elevation_config(&config.config_name, &path)
+        .await
+        .unwrap();
+    assert_eq!(reloaded, vec![config.collection_name.clone()]);
+
+    let result = SelectQuery::new()
+        .q("city_name:Alta")
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap();
+    let docs = result
+        .get_docs_response()
+        .unwrap()
+        .get_docs::<Value>()
+        .unwrap();
+    assert_eq!(docs[0]["id"], "city_Tromsø");
+    let _ = std::fs::remove_file(&path);
+    let _ = config.tear_down().await;
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use crate::structures::FunctionalityTestsBuildup;
+use serde_json::{json, Value};
+use serial_test::parallel;
+use solrstice::{ExtractFormat, ExtractQuery, SelectQuery};
+use std::io::Write;
+
+#[tokio::test]
+#[parallel]
+async fn extract_indexes_file_with_literals() {
+    let config = FunctionalityTestsBuildup::build_up("ExtractIndex")
+        .await
+        .unwrap();
+    let mut file = tempfile::Builder::new().suffix(".txt").tempfile().unwrap();
+    write!(file, "A report about Tromsø").unwrap();
+
+    ExtractQuery::new()
+        .literals([("id", "extract_1"), ("city_name", "Tromsø")])
+        .uprefix("ignored_")
+        .execute_file(&config.context, &config.collection_name, file.path())
+        .await
+        .unwrap();
+
+    let docs = SelectQuery::new()
+        .fl(["id", "city_name"])
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap()
+        .get_docs_response()
+        .unwrap()
+        .get_docs::<Value>()
+        .unwrap();
+    assert_eq!(
+        docs,
+        vec![json!({"id": "extract_1", "city_name": "Tromsø"})]
+    );
+    let _ = config.tear_down().await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn extract_only_returns_text_and_metadata() {
+    let config = FunctionalityTestsBuildup::build_up("ExtractOnly")
+        .await
+        .unwrap();
+    let mut file = tempfile::Builder::new().suffix(".txt").tempfile().unwrap();
+    write!(file, "A report about Tromsø").unwrap();
+
+    let response = ExtractQuery::new()
+        .extract_format(ExtractFormat::Text)
+        .extract_only_file(&config.context, &config.collection_name, file.path())
+        .await
+        .unwrap();
+    assert!(response.get_content().contains("A report about Tromsø"));
+    assert!(response
+        .get_metadata()
+        .get("Content-Type")
+        .unwrap()
+        .iter()
+        .any(|content_type| content_type.starts_with("text/plain")));
+
+    let num_found = SelectQuery::new()
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap()
+        .get_docs_response()
+        .unwrap()
+        .get_num_found();
+    assert_eq!(num_found, 0);
+    let _ = config.tear_down().await;
+}
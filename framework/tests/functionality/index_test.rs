@@ -1,10 +1,18 @@
 use crate::structures::{get_test_data, BaseTestsBuildup, City, FunctionalityTestsBuildup};
+use serde_json::{json, Value};
 use serial_test::parallel;
+use solrstice::models::SolrUpdateErrorType;
 use solrstice::queries::collection::{create_collection, delete_collection};
 use solrstice::queries::config::{delete_config, upload_config};
+use solrstice::AtomicUpdate;
 use solrstice::Error;
 use solrstice::SelectQuery;
-use solrstice::{DeleteQuery, UpdateQuery};
+use solrstice::{
+    AddCommand, CommitQuery, CommitType, CsvUpdateQuery, DeleteById, DeleteCommand, DeleteQuery,
+    JsonDocsUpdateQuery, OptimizeQuery, UpdateBatch, UpdateCommitOptions, UpdateQuery,
+    VersionConstraint, VersionedDocument,
+};
+use std::io::Write;
 use std::path::Path;
 
 #[tokio::test]
@@ -121,3 +129,321 @@ async fn delete_deletes_documents_by_id() {
         .get_num_found();
     assert_eq!(num_found, 0);
 }
+
+#[tokio::test]
+#[parallel]
+async fn atomic_update_updates_fields() {
+    let config = FunctionalityTestsBuildup::build_up("AtomicUpdate")
+        .await
+        .unwrap();
+    UpdateQuery::new()
+        .execute(
+            &config.context,
+            &config.collection_name,
+            &[json!({"id": "atomic_1", "city_name": "Old", "count": 1, "interests": ["Cars", "Outdoors"]})],
+        )
+        .await
+        .unwrap();
+
+    let update = AtomicUpdate::new("atomic_1")
+        .set("city_name", "New")
+        .inc("count", 2)
+        .add_distinct("interests", vec!["Cars", "Fishing"])
+        .remove("interests", "Outdoors");
+    UpdateQuery::new()
+        .execute(&config.context, &config.collection_name, &[update])
+        .await
+        .unwrap();
+
+    let docs = SelectQuery::new()
+        .fq(["id:atomic_1"])
+        .fl(["id", "city_name", "count", "interests"])
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap()
+        .get_docs_response()
+        .unwrap()
+        .get_docs::<Value>()
+        .unwrap();
+    assert_eq!(
+        docs,
+        vec![
+            json!({"id": "atomic_1", "city_name": "New", "count": 3, "interests": ["Cars", "Fishing"]})
+        ]
+    );
+    let _ = config.tear_down().await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn version_constraints_are_enforced() {
+    let config = FunctionalityTestsBuildup::build_up("VersionConstraint")
+        .await
+        .unwrap();
+    let response = UpdateQuery::new()
+        .return_versions(true)
+        .execute(
+            &config.context,
+            &config.collection_name,
+            &[VersionedDocument::new(
+                json!({"id": "versioned_1"}),
+                VersionConstraint::MustNotExist,
+            )],
+        )
+        .await
+        .unwrap();
+    let version = response.get_added_versions().unwrap()["versioned_1"];
+
+    let result = UpdateQuery::new()
+        .execute(
+            &config.context,
+            &config.collection_name,
+            &[VersionedDocument::new(
+                json!({"id": "versioned_1"}),
+                VersionConstraint::MustNotExist,
+            )],
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::SolrVersionConflict { id: Some(id), .. }) if id == "versioned_1"
+    ));
+
+    let result = UpdateQuery::new()
+        .execute(
+            &config.context,
+            &config.collection_name,
+            &[VersionedDocument::new(
+                json!({"id": "versioned_1"}),
+                VersionConstraint::exact(version + 1).unwrap(),
+            )],
+        )
+        .await;
+    assert!(matches!(result, Err(Error::SolrVersionConflict { .. })));
+
+    let result = DeleteQuery::new()
+        .ids([DeleteById::new("versioned_1").version(VersionConstraint::exact(version + 1))])
+        .execute(&config.context, &config.collection_name)
+        .await;
+    assert!(matches!(result, Err(Error::SolrVersionConflict { .. })));
+
+    let response = DeleteQuery::new()
+        .ids([DeleteById::new("versioned_1").version(VersionConstraint::exact(version))])
+        .return_versions(true)
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap();
+    assert!(response
+        .get_deleted_versions()
+        .unwrap()
+        .contains_key("versioned_1"));
+    let _ = config.tear_down().await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn explicit_commit_makes_documents_visible() {
+    let config = FunctionalityTestsBuildup::build_up("ExplicitCommit")
+        .await
+        .unwrap();
+    let num_found = || async {
+        SelectQuery::new()
+            .fq(["id:uncommitted_1"])
+            .execute(&config.context, &config.collection_name)
+            .await
+            .unwrap()
+            .get_docs_response()
+            .unwrap()
+            .get_num_found()
+    };
+    UpdateQuery::new()
+        .commit_options(
+            UpdateCommitOptions::new()
+                .commit_type(CommitType::NoCommit)
+                .overwrite(false),
+        )
+        .execute(
+            &config.context,
+            &config.collection_name,
+            &[json!({"id": "uncommitted_1"})],
+        )
+        .await
+        .unwrap();
+    assert_eq!(num_found().await, 0);
+
+    CommitQuery::new()
+        .commit_options(UpdateCommitOptions::new().wait_searcher(true))
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap();
+    assert_eq!(num_found().await, 1);
+
+    OptimizeQuery::new()
+        .max_segments(1)
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap();
+    let _ = config.tear_down().await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn delete_escapes_ids_and_queries() {
+    let config = FunctionalityTestsBuildup::build_up("DeleteEscapes")
+        .await
+        .unwrap();
+    let ids = ["a&b", "<id>c</id>", "d\"e"];
+    UpdateQuery::new()
+        .execute(
+            &config.context,
+            &config.collection_name,
+            &ids.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>(),
+        )
+        .await
+        .unwrap();
+
+    DeleteQuery::new()
+        .ids([
+            DeleteById::new("a&b"),
+            DeleteById::new("<id>c</id>").version(VersionConstraint::MustExist),
+        ])
+        .queries([r#"id:"d\"e""#])
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap();
+
+    let num_found = SelectQuery::new()
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap()
+        .get_docs_response()
+        .unwrap()
+        .get_num_found();
+    assert_eq!(num_found, 0);
+    let _ = config.tear_down().await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn update_batch_applies_commands_in_order() {
+    let config = FunctionalityTestsBuildup::build_up("UpdateBatch")
+        .await
+        .unwrap();
+    UpdateBatch::new()
+        .add_doc(json!({"id": "batch_1"}))
+        .add_doc(AddCommand::from(json!({"id": "batch_2"})).overwrite(false))
+        .add_doc(AddCommand::from(json!({"id": "batch_3"})).commit_within(1000))
+        .delete(DeleteById::new("batch_1"))
+        .delete(DeleteCommand::query("id:batch_3"))
+        .commit(CommitQuery::new())
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap();
+
+    let docs = SelectQuery::new()
+        .fl(["id"])
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap()
+        .get_docs_response()
+        .unwrap()
+        .get_docs::<Value>()
+        .unwrap();
+    assert_eq!(docs, vec![json!({"id": "batch_2"})]);
+    let _ = config.tear_down().await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn csv_and_json_docs_files_are_streamed() {
+    let config = FunctionalityTestsBuildup::build_up("FileUpdate")
+        .await
+        .unwrap();
+    let mut csv = tempfile::NamedTempFile::new().unwrap();
+    write!(
+        csv,
+        "skipped line\nid;city_name;interests;ignored\ncsv_1;Alta;fishing|hiking;x\ncsv_2;Bergen;'rain;umbrellas';y\n"
+    )
+    .unwrap();
+    CsvUpdateQuery::new()
+        .skip_lines(1)
+        .separator(';')
+        .encapsulator('\'')
+        .split(["interests"])
+        .split_separator('|')
+        .skip(["ignored"])
+        .execute_file(&config.context, &config.collection_name, csv.path())
+        .await
+        .unwrap();
+
+    let mut ndjson = tempfile::NamedTempFile::new().unwrap();
+    writeln!(
+        ndjson,
+        r#"{{"id": "json_1", "city": {{"name": "Tromsø"}}}}"#
+    )
+    .unwrap();
+    writeln!(ndjson, r#"{{"id": "json_2", "city": {{"name": "Oslo"}}}}"#).unwrap();
+    JsonDocsUpdateQuery::new()
+        .split("/")
+        .f(["id:/id", "city_name:/city/name"])
+        .execute_file(&config.context, &config.collection_name, ndjson.path())
+        .await
+        .unwrap();
+
+    let docs = SelectQuery::new()
+        .fl(["id", "city_name", "interests"])
+        .sort(["id asc"])
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap()
+        .get_docs_response()
+        .unwrap()
+        .get_docs::<Value>()
+        .unwrap();
+    assert_eq!(
+        docs,
+        vec![
+            json!({"id": "csv_1", "city_name": "Alta", "interests": ["fishing", "hiking"]}),
+            json!({"id": "csv_2", "city_name": "Bergen", "interests": ["rain;umbrellas"]}),
+            json!({"id": "json_1", "city_name": "Tromsø"}),
+            json!({"id": "json_2", "city_name": "Oslo"}),
+        ]
+    );
+    let _ = config.tear_down().await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn tolerant_update_reports_failed_documents() {
+    let config = FunctionalityTestsBuildup::build_up("TolerantUpdate")
+        .await
+        .unwrap();
+    let response = UpdateQuery::new()
+        .update_chain("tolerant")
+        .max_errors(-1)
+        .execute(
+            &config.context,
+            &config.collection_name,
+            &[
+                json!({"id": "tolerant_1", "age": 20}),
+                json!({"id": "tolerant_2", "age": "not a number"}),
+                json!({"id": "tolerant_3", "age": 30}),
+            ],
+        )
+        .await
+        .unwrap();
+    let errors = response.get_update_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].get_id(), "tolerant_2");
+    assert_eq!(errors[0].get_error_type(), SolrUpdateErrorType::Add);
+
+    let num_found = SelectQuery::new()
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap()
+        .get_docs_response()
+        .unwrap()
+        .get_num_found();
+    assert_eq!(num_found, 2);
+    let _ = config.tear_down().await;
+}
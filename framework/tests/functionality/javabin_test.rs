@@ -0,0 +1,76 @@
+use crate::structures::{get_test_data, BaseTestsBuildup, City, FunctionalityTestsBuildup};
+use serial_test::parallel;
+use solrstice::{
+    AsyncSolrCloudClient, Error, FacetSetComponent, FieldFacetComponent, FieldFacetEntry,
+    SelectQuery, SolrServerContextBuilder, UpdateQuery, WireFormat,
+};
+
+#[tokio::test]
+#[parallel]
+async fn javabin_indexes_and_selects_documents() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("JavabinIndexSelect").await?;
+
+    UpdateQuery::new()
+        .wire_format(WireFormat::Javabin)
+        .execute(&config.context, &config.collection_name, &get_test_data())
+        .await?;
+
+    let query = SelectQuery::new()
+        .fl(["*", "[child]"])
+        .fq(["city_name:[* TO *]"]);
+    let javabin = query
+        .clone()
+        .wire_format(WireFormat::Javabin)
+        .execute(&config.context, &config.collection_name)
+        .await?;
+    let json = query
+        .execute(&config.context, &config.collection_name)
+        .await?;
+
+    let javabin_docs = javabin.get_docs_response().unwrap();
+    let json_docs = json.get_docs_response().unwrap();
+    assert_eq!(javabin_docs.get_num_found(), json_docs.get_num_found());
+    assert_eq!(javabin_docs.get_docs::<City>()?, get_test_data());
+
+    let _ = config.tear_down().await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn javabin_context_matches_json_facets() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("JavabinFacets").await?;
+    config
+        .async_client
+        .index(
+            &UpdateQuery::new(),
+            &config.collection_name,
+            &get_test_data(),
+        )
+        .await?;
+
+    let base = BaseTestsBuildup::new().await;
+    let mut builder =
+        SolrServerContextBuilder::new(base.host).with_wire_format(WireFormat::Javabin);
+    if let Some(auth) = base.auth {
+        builder = builder.with_auth(auth);
+    }
+    let javabin_client = AsyncSolrCloudClient::new(builder.build());
+    let query = SelectQuery::new().facet_set(
+        FacetSetComponent::new().fields(FieldFacetComponent::new([FieldFacetEntry::new("age")])),
+    );
+    let javabin = javabin_client
+        .select(&query, &config.collection_name)
+        .await?;
+    let json = config
+        .async_client
+        .select(&query, &config.collection_name)
+        .await?;
+    assert_eq!(
+        javabin.get_facet_set().unwrap().get_fields(),
+        json.get_facet_set().unwrap().get_fields()
+    );
+
+    let _ = config.tear_down().await;
+    Ok(())
+}
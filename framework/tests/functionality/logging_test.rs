@@ -1,10 +1,12 @@
-use crate::structures::{BaseTestsBuildup, ErrrorTestsSetup, FunctionalityTestsBuildup};
+use crate::structures::{
+    get_test_data, BaseTestsBuildup, ErrrorTestsSetup, FunctionalityTestsBuildup,
+};
 use log::{Metadata, Record};
 use serial_test::serial;
 use solrstice::Error;
 use solrstice::LoggingPolicy;
 use solrstice::SolrServerContextBuilder;
-use solrstice::{AsyncSolrCloudClient, SelectQuery};
+use solrstice::{AsyncSolrCloudClient, SelectQuery, UpdateQuery};
 use std::sync::{Arc, Mutex, OnceLock};
 
 struct TestLogger {
@@ -143,3 +145,45 @@ async fn logging_works_if_request_never_reaches_solr() -> Result<(), Error> {
     }
     Err(Error::Unknown("No log message found".to_string()))
 }
+
+#[tokio::test]
+#[serial]
+async fn logging_logs_uncompressed_body() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("CompressedLogging")
+        .await
+        .unwrap();
+    let base = BaseTestsBuildup::new().await;
+    let mut context = SolrServerContextBuilder::new(base.host).with_request_compression(0);
+    if base.auth.is_some() {
+        context = context.with_auth(base.auth.unwrap());
+    }
+    let client = AsyncSolrCloudClient::new(context.build());
+
+    LOGGER_MESSAGES
+        .get_or_init(init_logger)
+        .lock()
+        .unwrap()
+        .clear();
+
+    client
+        .index(
+            &UpdateQuery::new(),
+            &config.collection_name,
+            &get_test_data(),
+        )
+        .await?;
+    let found = LOGGER_MESSAGES
+        .get()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|message| {
+            message.contains("Sending Solr request to") && message.contains("city_name")
+        });
+    let _ = config.tear_down().await;
+    match found {
+        true => Ok(()),
+        false => Err(Error::Unknown("No uncompressed body logged".to_string())),
+    }
+}
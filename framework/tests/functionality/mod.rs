@@ -1,14 +1,24 @@
+pub mod admin_task_test;
 pub mod alias_tests;
+pub mod backup_test;
+pub mod bulk_index_test;
 pub mod client_tests;
 pub mod collection_test;
+pub mod compression_test;
 pub mod config_test;
 pub mod def_type_test;
+pub mod extract_test;
 pub mod facetset_test;
 pub mod grouping_tests;
 pub mod index_test;
+#[cfg(feature = "javabin")]
+pub mod javabin_test;
 pub mod json_facet_test;
 pub mod readme_test;
+pub mod reindex_test;
+pub mod replica_test;
 pub mod select_test;
+pub mod shard_test;
 pub mod zk_test;
 
 pub mod auth_test;
@@ -18,4 +28,5 @@ pub mod logging_test;
 
 #[cfg(feature = "blocking")]
 pub mod blocking_tests;
+pub mod elevation_test;
 mod stats_test;
@@ -0,0 +1,168 @@
+use crate::structures::{get_solr_version, get_test_data, City, FunctionalityTestsBuildup};
+use serial_test::parallel;
+use solrstice::queries::collection::{create_collection, delete_collection};
+use solrstice::{Error, ReindexCollectionQuery, Reindexer, SelectQuery, UpdateQuery};
+use std::sync::{Arc, Mutex};
+
+fn city_query() -> SelectQuery {
+    SelectQuery::new()
+        .fl(["*", "[child]"])
+        .fq(["city_name:[* TO *]"])
+        .sort(["id asc"])
+}
+
+#[tokio::test]
+#[parallel]
+async fn reindex_copies_documents_in_pages() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("ReindexPages").await?;
+    let target = "ReindexPagesTargetCollection";
+    let _ = delete_collection(&config.context, target).await;
+    create_collection(&config.context, target, &config.config_name, 1, 1).await?;
+    UpdateQuery::new()
+        .execute(&config.context, &config.collection_name, &get_test_data())
+        .await?;
+
+    let cursor_marks = Arc::new(Mutex::new(Vec::new()));
+    let progress_marks = cursor_marks.clone();
+    let reindexer = Reindexer::new()
+        .query(city_query().rows(1))
+        .on_progress(move |progress| {
+            progress_marks
+                .lock()
+                .unwrap()
+                .push(progress.get_cursor_mark().to_string())
+        });
+    let summary = config
+        .async_client
+        .reindex(&reindexer, &config.collection_name, target)
+        .await?;
+    assert_eq!(summary.get_pages(), 2);
+    assert_eq!(summary.get_docs_indexed(), 2);
+    assert_eq!(cursor_marks.lock().unwrap().len(), 2);
+
+    let docs = city_query()
+        .execute(&config.context, target)
+        .await?
+        .get_docs_response()
+        .unwrap()
+        .get_docs::<City>()?;
+    assert_eq!(docs, get_test_data());
+
+    delete_collection(&config.context, target).await?;
+    let _ = config.tear_down().await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn reindex_resumes_from_cursor_mark_and_transforms() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("ReindexResume").await?;
+    let target = "ReindexResumeTargetCollection";
+    let _ = delete_collection(&config.context, target).await;
+    create_collection(&config.context, target, &config.config_name, 1, 1).await?;
+    UpdateQuery::new()
+        .execute(&config.context, &config.collection_name, &get_test_data())
+        .await?;
+
+    let first_cursor_mark = Arc::new(Mutex::new(None));
+    let progress_mark = first_cursor_mark.clone();
+    let skipped = Reindexer::new()
+        .query(city_query().rows(1))
+        .on_progress(move |progress| {
+            progress_mark
+                .lock()
+                .unwrap()
+                .get_or_insert(progress.get_cursor_mark().to_string());
+        })
+        .transform(|_| None)
+        .execute(
+            &config.context,
+            &config.collection_name,
+            &config.context,
+            target,
+        )
+        .await?;
+    assert_eq!(skipped.get_docs_skipped(), 2);
+    let cursor_mark = first_cursor_mark.lock().unwrap().clone().unwrap();
+
+    let summary = Reindexer::new()
+        .query(city_query().rows(1))
+        .cursor_mark(cursor_mark)
+        .execute(
+            &config.context,
+            &config.collection_name,
+            &config.context,
+            target,
+        )
+        .await?;
+    assert_eq!(summary.get_docs_indexed(), 1);
+    let docs = city_query()
+        .execute(&config.context, target)
+        .await?
+        .get_docs_response()
+        .unwrap()
+        .get_docs::<City>()?;
+    assert_eq!(docs.len(), 1);
+    assert_eq!(docs[0], get_test_data().remove(1));
+
+    delete_collection(&config.context, target).await?;
+    let _ = config.tear_down().await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn reindex_sorts_by_unique_key_without_sort() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("ReindexUniqueKey").await?;
+    let target = "ReindexUniqueKeyTargetCollection";
+    let _ = delete_collection(&config.context, target).await;
+    create_collection(&config.context, target, &config.config_name, 1, 1).await?;
+    UpdateQuery::new()
+        .execute(&config.context, &config.collection_name, &get_test_data())
+        .await?;
+
+    let reindexer = Reindexer::new().query(
+        SelectQuery::new()
+            .fl(["*", "[child]"])
+            .fq(["city_name:[* TO *]"])
+            .rows(1),
+    );
+    let summary = config
+        .async_client
+        .reindex(&reindexer, &config.collection_name, target)
+        .await?;
+    assert_eq!(summary.get_pages(), 2);
+    assert_eq!(summary.get_docs_indexed(), 2);
+
+    delete_collection(&config.context, target).await?;
+    let _ = config.tear_down().await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn reindex_collection_reindexes_on_server() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("ReindexCollection").await?;
+    // REINDEXCOLLECTION requires Solr 8.1
+    if get_solr_version(&config.context).await < (8, 1) {
+        return config.tear_down().await;
+    }
+    let target = "ReindexCollectionTargetCollection";
+    let _ = delete_collection(&config.context, target).await;
+    UpdateQuery::new()
+        .execute(&config.context, &config.collection_name, &get_test_data())
+        .await?;
+
+    let status = config
+        .async_client
+        .reindex_collection(
+            &ReindexCollectionQuery::new().target(target),
+            &config.collection_name,
+        )
+        .await?;
+    assert_eq!(status.get_state(), Some("finished"));
+
+    delete_collection(&config.context, target).await?;
+    let _ = config.tear_down().await;
+    Ok(())
+}
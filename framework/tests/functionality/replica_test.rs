@@ -0,0 +1,98 @@
+use crate::structures::BaseTestsBuildup;
+use serial_test::parallel;
+use solrstice::models::ReplicaType;
+use solrstice::queries::collection::{
+    add_replica, add_replica_property, create_collection_with_options, delete_collection,
+    delete_replica, delete_replica_property, get_cluster_status, AddReplicaOptions,
+    CreateCollectionOptions, DeleteReplicaOptions, ReplicaPropertyOptions,
+};
+use solrstice::queries::config::{delete_config, upload_config};
+use solrstice::Error;
+use std::path::Path;
+
+#[tokio::test]
+#[parallel]
+async fn add_and_delete_replicas() -> Result<(), Error> {
+    let name = "ReplicaCollection";
+    let config = BaseTestsBuildup::new().await;
+    let _ = delete_collection(&config.context, name).await;
+    let _ = delete_config(&config.context, name).await;
+    upload_config(&config.context, name, Path::new(&config.config_path)).await?;
+    let options = CreateCollectionOptions::new()
+        .config_name(name)
+        .num_shards(1)
+        .nrt_replicas(1)
+        .wait_for_final_state(true);
+    create_collection_with_options(&config.context, name, &options).await?;
+
+    let options = AddReplicaOptions::new()
+        .replica_type(ReplicaType::Tlog)
+        .wait_for_final_state(true);
+    add_replica(&config.context, name, "shard1", &options).await?;
+    let options = AddReplicaOptions::new()
+        .pull_replicas(2)
+        .wait_for_final_state(true);
+    add_replica(&config.context, name, "shard1", &options).await?;
+
+    let status = get_cluster_status(&config.context, Some(name), &[]).await?;
+    let shard = status
+        .get_collection(name)
+        .unwrap()
+        .get_shard("shard1")
+        .unwrap();
+    let replicas_of = |replica_type: ReplicaType| {
+        shard
+            .get_replicas()
+            .values()
+            .filter(|replica| replica.get_type() == replica_type)
+            .map(|replica| replica.get_name().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(replicas_of(ReplicaType::Nrt).len(), 1);
+    assert_eq!(replicas_of(ReplicaType::Tlog).len(), 1);
+    assert_eq!(replicas_of(ReplicaType::Pull).len(), 2);
+    let tlog_replica = replicas_of(ReplicaType::Tlog).remove(0);
+
+    add_replica_property(
+        &config.context,
+        name,
+        "shard1",
+        &tlog_replica,
+        "preferredLeader",
+        "true",
+        ReplicaPropertyOptions::new().shard_unique(true),
+    )
+    .await?;
+    delete_replica_property(
+        &config.context,
+        name,
+        "shard1",
+        &tlog_replica,
+        "preferredLeader",
+        ReplicaPropertyOptions::new(),
+    )
+    .await?;
+
+    let options = DeleteReplicaOptions::new()
+        .shard("shard1")
+        .replica(&tlog_replica);
+    delete_replica(&config.context, name, &options).await?;
+    let options = DeleteReplicaOptions::new()
+        .shard("shard1")
+        .count(1)
+        .replica_type(ReplicaType::Pull);
+    delete_replica(&config.context, name, &options).await?;
+
+    let status = get_cluster_status(&config.context, Some(name), &[]).await?;
+    let shard = status
+        .get_collection(name)
+        .unwrap()
+        .get_shard("shard1")
+        .unwrap();
+    assert_eq!(shard.get_replicas().len(), 2);
+    assert!(!shard.get_replicas().contains_key(&tlog_replica));
+
+    delete_collection(&config.context, name).await?;
+    let _ = delete_config(&config.context, name).await;
+    Ok(())
+}
@@ -4,7 +4,7 @@ use serial_test::parallel;
 use solrstice::models::SolrDocsResponse;
 use solrstice::SelectQuery;
 use solrstice::UpdateQuery;
-use solrstice::{Error, SelectDestination};
+use solrstice::{Error, SelectDestination, SolrRequestType};
 use std::collections::HashMap;
 
 #[tokio::test]
@@ -190,3 +190,106 @@ async fn create_collection_with_data() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[parallel]
+async fn select_works_with_get_request() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("SelectGetRequest")
+        .await
+        .unwrap();
+    UpdateQuery::new()
+        .execute(&config.context, &config.collection_name, &get_test_data())
+        .await
+        .unwrap();
+
+    let query = SelectQuery::new()
+        .q("city_name:*")
+        .fq(["id:city_*"])
+        .sort(["id asc"])
+        .rows(1);
+    let post_result = query
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap();
+    let get_result = query
+        .execute(
+            &config.context,
+            SelectDestination::new(&config.collection_name).request_type(SolrRequestType::Get),
+        )
+        .await
+        .unwrap();
+    let post_docs = post_result.get_docs_response().unwrap();
+    let get_docs = get_result.get_docs_response().unwrap();
+    assert_eq!(post_docs.get_num_found(), get_docs.get_num_found());
+    assert_eq!(
+        post_docs.get_docs::<Value>().unwrap(),
+        get_docs.get_docs::<Value>().unwrap()
+    );
+    let _ = config.tear_down().await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn select_works_with_shard_params() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("SelectShardParams")
+        .await
+        .unwrap();
+    UpdateQuery::new()
+        .execute(&config.context, &config.collection_name, &get_test_data())
+        .await
+        .unwrap();
+
+    let result = SelectQuery::new()
+        .fq(["city_name:*"])
+        .execute(
+            &config.context,
+            SelectDestination::new(&config.collection_name)
+                .collections([&config.collection_name])
+                .shards_tolerant(true)
+                .shards_info(true),
+        )
+        .await
+        .unwrap();
+    assert!(!result.get_partial_results());
+    let shards_info = result.get_shards_info().unwrap();
+    assert_eq!(shards_info.len(), 1);
+    let shard_info = shards_info.values().next().unwrap();
+    assert_eq!(
+        shard_info.get_num_found(),
+        Some(result.get_docs_response().unwrap().get_num_found())
+    );
+    let _ = config.tear_down().await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn select_response_header_can_be_omitted() -> Result<(), Error> {
+    let config = FunctionalityTestsBuildup::build_up("SelectResponseHeader")
+        .await
+        .unwrap();
+
+    let result = SelectQuery::new()
+        .time_allowed(10000)
+        .fail_on_partial_results(true)
+        .additional_params([("echoParams", "explicit")])
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap();
+    let header = result.get_response_header().unwrap();
+    assert_eq!(header.status, 0);
+    assert_eq!(
+        header.params.as_ref().unwrap()["timeAllowed"],
+        Value::from("10000")
+    );
+
+    let result = SelectQuery::new()
+        .omit_header(true)
+        .execute(&config.context, &config.collection_name)
+        .await
+        .unwrap();
+    assert!(result.get_response_header().is_none());
+    let _ = config.tear_down().await;
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use crate::structures::BaseTestsBuildup;
+use serial_test::parallel;
+use solrstice::models::ShardState;
+use solrstice::queries::collection::{
+    create_collection_with_options, create_shard, delete_collection, delete_shard,
+    get_cluster_status, split_shard, CollectionRouter, CreateCollectionOptions, CreateShardOptions,
+    DeleteShardOptions, SplitShardOptions,
+};
+use solrstice::queries::config::{delete_config, upload_config};
+use solrstice::{AsyncAdminTask, Error};
+use std::path::Path;
+use std::time::Duration;
+
+#[tokio::test]
+#[parallel]
+async fn split_shard_creates_sub_shards() -> Result<(), Error> {
+    let name = "SplitShardCollection";
+    let config = BaseTestsBuildup::new().await;
+    let task = AsyncAdminTask::new(name).poll_interval(Duration::from_millis(200));
+    let _ = delete_collection(&config.context, name).await;
+    let _ = delete_config(&config.context, name).await;
+    let _ = task.delete_status(&config.context).await;
+    upload_config(&config.context, name, Path::new(&config.config_path)).await?;
+    let options = CreateCollectionOptions::new()
+        .config_name(name)
+        .num_shards(1)
+        .wait_for_final_state(true);
+    create_collection_with_options(&config.context, name, &options).await?;
+
+    let options = SplitShardOptions::new()
+        .shard("shard1")
+        .num_sub_shards(3)
+        .async_id(name);
+    split_shard(&config.context, name, &options).await?;
+    task.wait(&config.context).await?;
+    task.delete_status(&config.context).await?;
+
+    let status = get_cluster_status(&config.context, Some(name), &[]).await?;
+    let collection = status.get_collection(name).unwrap();
+    assert_eq!(
+        collection.get_shard("shard1").unwrap().get_state(),
+        ShardState::Inactive
+    );
+    for sub_shard in ["shard1_0", "shard1_1", "shard1_2"] {
+        assert_eq!(
+            collection.get_shard(sub_shard).unwrap().get_state(),
+            ShardState::Active
+        );
+    }
+
+    delete_shard(&config.context, name, "shard1", DeleteShardOptions::new()).await?;
+    let status = get_cluster_status(&config.context, Some(name), &[]).await?;
+    let collection = status.get_collection(name).unwrap();
+    assert!(collection.get_shard("shard1").is_none());
+    assert_eq!(collection.get_shards().len(), 3);
+
+    delete_collection(&config.context, name).await?;
+    let _ = delete_config(&config.context, name).await;
+    Ok(())
+}
+
+#[tokio::test]
+#[parallel]
+async fn create_shard_adds_shard_to_implicit_collection() -> Result<(), Error> {
+    let name = "CreateShardCollection";
+    let config = BaseTestsBuildup::new().await;
+    let _ = delete_collection(&config.context, name).await;
+    let _ = delete_config(&config.context, name).await;
+    upload_config(&config.context, name, Path::new(&config.config_path)).await?;
+    let options = CreateCollectionOptions::new()
+        .config_name(name)
+        .router(CollectionRouter::Implicit)
+        .shards(["2024"])
+        .wait_for_final_state(true);
+    create_collection_with_options(&config.context, name, &options).await?;
+
+    let options = CreateShardOptions::new()
+        .nrt_replicas(1)
+        .wait_for_final_state(true);
+    create_shard(&config.context, name, "2025", &options).await?;
+    let status = get_cluster_status(&config.context, Some(name), &[]).await?;
+    let shard = status
+        .get_collection(name)
+        .unwrap()
+        .get_shard("2025")
+        .unwrap();
+    assert_eq!(shard.get_state(), ShardState::Active);
+    assert_eq!(shard.get_replicas().len(), 1);
+
+    delete_shard(&config.context, name, "2024", DeleteShardOptions::new()).await?;
+    let status = get_cluster_status(&config.context, Some(name), &[]).await?;
+    let collection = status.get_collection(name).unwrap();
+    assert_eq!(collection.get_shards().len(), 1);
+
+    delete_collection(&config.context, name).await?;
+    let _ = delete_config(&config.context, name).await;
+    Ok(())
+}
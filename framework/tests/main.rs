@@ -1,2 +1,8 @@
+#![allow(
+    clippy::bool_assert_comparison,
+    clippy::len_zero,
+    clippy::let_underscore_future,
+    clippy::let_unit_value
+)]
 pub mod functionality;
 pub mod structures;
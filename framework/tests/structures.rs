@@ -1,4 +1,6 @@
+#![allow(clippy::single_component_path_imports)]
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use solrstice;
 use solrstice::models::SolrResponse;
 use solrstice::queries::collection::{create_collection, delete_collection};
@@ -8,6 +10,7 @@ use solrstice::SolrRequestBuilder;
 use solrstice::SolrSingleServerHost;
 use solrstice::{AsyncSolrCloudClient, Error};
 use solrstice::{SolrServerContext, SolrServerContextBuilder};
+use std::collections::HashMap;
 use std::path::Path;
 use std::string::ToString;
 use std::time::Duration;
@@ -199,3 +202,14 @@ pub async fn wait_for_error_nginx(host: &str, max_time: Duration) {
         tokio::time::sleep(Duration::from_secs(1)).await;
     }
 }
+
+/// Get the `(major, minor)` version of the Solr server, for tests of features missing in older versions.
+pub async fn get_solr_version(context: &SolrServerContext) -> (u32, u32) {
+    let response = SolrRequestBuilder::new(context, "/solr/admin/info/system")
+        .send_get::<HashMap<String, Value>>()
+        .await
+        .unwrap();
+    let version = response["lucene"]["solr-spec-version"].as_str().unwrap();
+    let mut parts = version.split('.').map(|part| part.parse::<u32>().unwrap());
+    (parts.next().unwrap(), parts.next().unwrap())
+}
@@ -1,13 +1,35 @@
 from ._solrstice import (
+    AddReplicaOptions,
     AsyncSolrCloudClient,
+    AsyncAdminTask,
+    AtomicUpdate,
+    BackupOptions,
     BlockingSolrCloudClient,
+    BulkIndexer,
+    ColStatusOptions,
+    CommitQuery,
+    CollectionRouter,
     CommitType,
+    CreateAliasOptions,
+    CreateCollectionOptions,
+    CreateShardOptions,
+    CsvUpdateQuery,
     DefType,
+    DeleteBackupOptions,
+    DeleteAliasOptions,
+    DeleteById,
+    DeleteCollectionOptions,
+    DeleteNodeOptions,
     DeleteQuery,
+    DeleteReplicaOptions,
+    DeleteShardOptions,
     DismaxQuery,
     EdismaxQuery,
     FacetSetComponent,
     StatsComponent,
+    ElevationComponent,
+    ExtractFormat,
+    ExtractQuery,
     FastLoggingPolicy,
     FieldFacetComponent,
     FieldFacetEntry,
@@ -15,6 +37,7 @@ from ._solrstice import (
     FieldFacetSort,
     GroupFormatting,
     GroupingComponent,
+    JsonDocsUpdateQuery,
     JsonFacetComponent,
     JsonFacetType,
     JsonFacetSortDirection,
@@ -22,12 +45,24 @@ from ._solrstice import (
     JsonStatFacet,
     JsonTermsFacet,
     JsonTermsFacetMethod,
+    ListBackupsOptions,
     LoggingPolicy,
     LuceneQuery,
+    ModifyCollectionOptions,
+    MoveReplicaOptions,
     OffLoggingPolicy,
+    OptimizeQuery,
     PivotFacetComponent,
     PrettyLoggingPolicy,
     QueryOperator,
+    ReindexCollectionQuery,
+    Reindexer,
+    ReloadCollectionOptions,
+    RenameCollectionOptions,
+    ReplaceNodeOptions,
+    ReplicaPropertyOptions,
+    RestoreOptions,
+    RollbackQuery,
     SelectQuery,
     SolrAuth,
     SolrBasicAuth,
@@ -35,6 +70,9 @@ from ._solrstice import (
     SolrMultipleServerHost,
     SolrServerContext,
     SolrSingleServerHost,
+    SplitMethod,
+    SplitShardOptions,
+    UpdateBatch,
     UpdateQuery,
     ZookeeperEnsembleHost,
     ZookeeperEnsembleHostConnector,
@@ -50,6 +88,7 @@ __all__ = [
     "EdismaxQuery",
     "FacetSetComponent",
     "StatsComponent",
+    "ElevationComponent",
     "PivotFacetComponent",
     "FieldFacetComponent",
     "FieldFacetSort",
@@ -77,7 +116,44 @@ __all__ = [
     "SelectQuery",
     "CommitType",
     "UpdateQuery",
+    "AtomicUpdate",
     "DeleteQuery",
+    "DeleteById",
+    "CommitQuery",
+    "OptimizeQuery",
+    "RollbackQuery",
+    "UpdateBatch",
+    "CsvUpdateQuery",
+    "JsonDocsUpdateQuery",
+    "ExtractFormat",
+    "ExtractQuery",
+    "BulkIndexer",
+    "Reindexer",
+    "ReindexCollectionQuery",
+    "CollectionRouter",
+    "CreateCollectionOptions",
+    "DeleteCollectionOptions",
+    "CreateAliasOptions",
+    "DeleteAliasOptions",
+    "SplitMethod",
+    "SplitShardOptions",
+    "CreateShardOptions",
+    "DeleteShardOptions",
+    "AddReplicaOptions",
+    "DeleteReplicaOptions",
+    "MoveReplicaOptions",
+    "DeleteNodeOptions",
+    "ReplaceNodeOptions",
+    "ReplicaPropertyOptions",
+    "ReloadCollectionOptions",
+    "ModifyCollectionOptions",
+    "RenameCollectionOptions",
+    "ColStatusOptions",
+    "BackupOptions",
+    "RestoreOptions",
+    "ListBackupsOptions",
+    "DeleteBackupOptions",
+    "AsyncAdminTask",
     "AsyncSolrCloudClient",
     "BlockingSolrCloudClient",
 ]
@@ -2,7 +2,7 @@ import abc
 from abc import ABC
 from enum import Enum
 from os import PathLike
-from typing import TYPE_CHECKING, Any, Dict, List, Optional, Union
+from typing import TYPE_CHECKING, Any, Callable, Dict, Iterable, List, Optional, Tuple, Union
 
 __all__ = [
     "SolrAuth",
@@ -14,6 +14,7 @@ __all__ = [
     "EdismaxQuery",
     "FacetSetComponent",
     "StatsComponent",
+    "ElevationComponent",
     "PivotFacetComponent",
     "FieldFacetComponent",
     "FieldFacetSort",
@@ -41,13 +42,65 @@ __all__ = [
     "SelectQuery",
     "CommitType",
     "UpdateQuery",
+    "AtomicUpdate",
     "DeleteQuery",
+    "DeleteById",
+    "CommitQuery",
+    "OptimizeQuery",
+    "RollbackQuery",
+    "UpdateBatch",
+    "CsvUpdateQuery",
+    "JsonDocsUpdateQuery",
+    "ExtractFormat",
+    "ExtractQuery",
+    "BulkIndexer",
+    "Reindexer",
+    "ReindexCollectionQuery",
+    "CollectionRouter",
+    "CreateCollectionOptions",
+    "DeleteCollectionOptions",
+    "CreateAliasOptions",
+    "DeleteAliasOptions",
+    "SplitMethod",
+    "SplitShardOptions",
+    "CreateShardOptions",
+    "DeleteShardOptions",
+    "AddReplicaOptions",
+    "DeleteReplicaOptions",
+    "MoveReplicaOptions",
+    "DeleteNodeOptions",
+    "ReplaceNodeOptions",
+    "ReplicaPropertyOptions",
+    "ReloadCollectionOptions",
+    "ModifyCollectionOptions",
+    "RenameCollectionOptions",
+    "ColStatusOptions",
+    "BackupOptions",
+    "RestoreOptions",
+    "ListBackupsOptions",
+    "DeleteBackupOptions",
+    "AsyncAdminTask",
     "AsyncSolrCloudClient",
     "BlockingSolrCloudClient",
 ]
 
 if TYPE_CHECKING:
-    from solrstice.models import SolrResponse
+    from solrstice.models import (
+        BackupPoint,
+        BackupResult,
+        BulkIndexProgress,
+        BulkIndexSummary,
+        ClusterStatus,
+        ColStatus,
+        DeletedBackup,
+        ReindexProgress,
+        ReindexSummary,
+        ReplicaType,
+        SolrExtractResponse,
+        SolrReindexStatus,
+        SolrRequestStatus,
+        SolrResponse,
+    )
 
 
 # region auth
@@ -306,6 +359,38 @@ class StatsComponent:
         pass
 
 
+# endregion
+
+# region elevation
+class ElevationComponent:
+    """
+    Query elevation component, pins or excludes documents for a query.
+    Requires the `elevator` search component to be configured for the handler.
+
+    :param enable: Turn elevation on or off for this request
+    :param force: Keep elevated documents on top even when sorting on something other than score
+    :param elevate_ids: Ids of documents to put on top of the result, in the given order
+    :param exclude_ids: Ids of documents to remove from the result
+    :param mark_excludes: Keep excluded documents in the result, but mark them
+    :param elevated_flag: Add the `[elevated]` field to every returned document
+    :param excluded_flag: Add the `[excluded]` field to every returned document
+    """
+
+    def __init__(
+            self,
+            enable: Optional[bool] = None,
+            force: Optional[bool] = None,
+            elevate_ids: Optional[List[str]] = None,
+            exclude_ids: Optional[List[str]] = None,
+            mark_excludes: Optional[bool] = None,
+            elevated_flag: Optional[bool] = None,
+            excluded_flag: Optional[bool] = None,
+    ):
+        pass
+
+
+# endregion
+
 # region json_facet
 
 class JsonFacetComponent:
@@ -518,6 +603,8 @@ class SolrServerContext:
     :param host: An instance of SolrHost specifying how to connect to a solr instance. If given as a string it creates a :class:`SolrSingleServerHost`
     :param auth: An instance of SolrAuth specifying how to authenticate with the solr instance
     :param logging_policy: How to log solr queries, valid values are :class:`OffLoggingPolicy`, :class:`FastLoggingPolicy`, and :class:`PrettyLoggingPolicy`
+    :param request_compression: Gzip request bodies of at least this many bytes. Jetty only inflates them when Solr is started with `-Djetty.gzip.inflateBufferSize` set
+    :param response_compression: Ask Solr for gzip or deflate compressed responses, and decompress them
     """
 
     def __init__(
@@ -525,6 +612,8 @@ class SolrServerContext:
             host: Union[SolrHost, str],
             auth: Optional[SolrAuth] = None,
             logging_policy: Optional[LoggingPolicy] = None,
+            request_compression: Optional[int] = None,
+            response_compression: Optional[bool] = None,
     ):
         pass
 
@@ -586,7 +675,14 @@ class SelectQuery:
     :param def_type: Set the query type
     :param facet_set: Facet counts
     :param json_facet: Json facets
+    :param stats: Stats component
+    :param elevation: Query elevation component
     :param additional_params: Additional parameters to send with the request, e.g. when using subqueries.
+    :param time_allowed: The maximum time in milliseconds Solr may spend on the search
+    :param cpu_allowed: The maximum CPU time in milliseconds Solr may spend on the search
+    :param mem_allowed: The maximum memory in megabytes Solr may allocate for the search
+    :param omit_header: Ask Solr to leave out the response header
+    :param fail_on_partial_results: Raise an error instead of returning partial results, e.g. when `time_allowed` is exceeded
     """
 
     def __init__(
@@ -603,7 +699,13 @@ class SelectQuery:
             facet_set: Optional["FacetSetComponent"] = None,
             json_facet: Optional["JsonFacetComponent"] = None,
             stats: Optional["StatsComponent"] = None,
+            elevation: Optional["ElevationComponent"] = None,
             additional_params: Optional[Dict[str, Any]] = None,
+            time_allowed: Optional[int] = None,
+            cpu_allowed: Optional[int] = None,
+            mem_allowed: Optional[float] = None,
+            omit_header: Optional[bool] = None,
+            fail_on_partial_results: Optional[bool] = None,
     ) -> None:
         pass
 
@@ -647,10 +749,60 @@ class SelectQuery:
         :param handler: The handler. Default is "select"
         """
 
+    def to_curl(
+            self, context: "SolrServerContext", collection: str, handler: Optional[str] = None
+    ) -> str:
+        """Render the request that `execute` would send as a curl command. Secrets such as the Authorization header are masked.
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to query
+        :param handler: The handler. Default is "select"
+
+        >>> from solrstice import SelectQuery, SolrServerContext
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> curl = SelectQuery(fq=["age:[* TO *]"]).to_curl(context, "collection_name")
+        """
+
+    def to_url(
+            self, context: "SolrServerContext", collection: str, handler: Optional[str] = None
+    ) -> str:
+        """Render the query as a full URL with classic Solr parameters, which can be opened in a browser.
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to query
+        :param handler: The handler. Default is "select"
+
+        >>> from solrstice import SelectQuery, SolrServerContext
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> url = SelectQuery(fq=["age:[* TO *]"]).to_url(context, "collection_name")
+        """
+
+    @staticmethod
+    def from_query_string(query_string: str) -> "SelectQuery":
+        """Parse classic Solr parameters, as copied from the admin UI or an access log, into a query.
+        Parameters belonging to a known component are parsed into that component,
+        while unknown parameters end up in `additional_params`.
+
+        :param query_string: The query string, with or without a leading `?`
+
+        >>> from solrstice import SelectQuery
+        >>> query = SelectQuery.from_query_string("q=*:*&fq=age:[*+TO+*]&group=true&group.field=age")
+        """
+
+    def to_solr_params(self) -> List[Tuple[str, str]]:
+        """Get the query as classic Solr request parameters, as they would be sent in a GET request.
+        Multivalued parameters such as `fq` are repeated.
+
+        >>> from solrstice import SelectQuery
+        >>> SelectQuery(q="*:*", fq=["age:[* TO *]"]).to_solr_params()
+        [('fq', 'age:[* TO *]'), ('q', '*:*')]
+        """
+
 
 class CommitType(Enum):
     Hard = "Hard"
     Soft = "Soft"
+    NoCommit = "NoCommit"
 
 
 class UpdateQuery:
@@ -658,17 +810,34 @@ class UpdateQuery:
 
     :param handler: The handler for the update query
     :param commit_type: The commit type for the update query
+    :param return_versions: Return the new `_version_` of every added document
+    :param commit_within: Commit within this many milliseconds. Takes precedence over `commit_type`
+    :param overwrite: Whether documents with the same unique key should be replaced. Default is True
+    :param wait_searcher: Whether Solr should wait for the new searcher to be opened when committing
+    :param open_searcher: Whether a hard commit should open a new searcher
+    :param expunge_deletes: Whether the commit should merge away segments with deleted documents
+    :param max_errors: The number of documents allowed to fail before the request fails. -1 allows any number.
+        Requires an `update_chain` with `TolerantUpdateProcessorFactory`. See :meth:`solrstice.models.SolrResponse.get_update_errors`
+    :param update_chain: The update request processor chain to use
     """
 
     def __init__(
             self,
             handler: Optional[str] = "update",
             commit_type: Optional[CommitType] = CommitType.Hard,
+            return_versions: Optional[bool] = False,
+            commit_within: Optional[int] = None,
+            overwrite: Optional[bool] = None,
+            wait_searcher: Optional[bool] = None,
+            open_searcher: Optional[bool] = None,
+            expunge_deletes: Optional[bool] = None,
+            max_errors: Optional[int] = None,
+            update_chain: Optional[str] = None,
     ) -> None:
         pass
 
     async def execute(
-            self, context: "SolrServerContext", collection: str, data: List[Dict[str, Any]]
+            self, context: "SolrServerContext", collection: str, data: List[Union[Dict[str, Any], "AtomicUpdate"]]
     ) -> "SolrResponse":
         """Execute the query
 
@@ -678,7 +847,7 @@ class UpdateQuery:
         """
 
     def execute_blocking(
-            self, context: "SolrServerContext", collection: str, data: List[Dict[str, Any]]
+            self, context: "SolrServerContext", collection: str, data: List[Union[Dict[str, Any], "AtomicUpdate"]]
     ) -> "SolrResponse":
         """Execute the query
 
@@ -688,473 +857,2582 @@ class UpdateQuery:
         """
 
 
-class DeleteQuery:
-    """Builder for a delete query
+class AtomicUpdate:
+    """An atomic update of an existing document, only changing the given fields.
+    Can be passed to :class:`UpdateQuery` and :class:`BulkIndexer` like any other document, and used as a child document.
+
+    :param id: The unique key of the document
+    :param unique_key: The name of the unique key field. Default is "id"
+    :param root: The `_root_` of the document. Needed when updating a child document directly
+    :param version: Only update if the `_version_` matches. 1 means the document must exist, -1 that it must not exist, and 0 no constraint
+    :param fields: Plain fields without an operation, e.g. for full child documents
+    :param set: Set or replace the value of fields. `None` removes the field
+    :param add: Add values to multivalued fields, or add child documents
+    :param add_distinct: Add values to multivalued fields, if they are not already present
+    :param remove: Remove values from multivalued fields, or remove child documents
+    :param remove_regex: Remove values matching the regular expressions from multivalued fields
+    :param inc: Increment numeric fields
+
+    >>> from solrstice import AtomicUpdate
+    >>> AtomicUpdate("city_Alta", set={"city_name": "Alta"}, inc={"count": 1}).to_dict()["count"]
+    {'inc': 1}
+    """
 
-    :param handler: The handler for the delete query
-    :param commit_type: The commit type for the delete query
+    def __init__(
+            self,
+            id: str,
+            unique_key: Optional[str] = "id",
+            root: Optional[str] = None,
+            version: Optional[int] = None,
+            fields: Optional[Dict[str, Any]] = None,
+            set: Optional[Dict[str, Any]] = None,
+            add: Optional[Dict[str, Any]] = None,
+            add_distinct: Optional[Dict[str, Any]] = None,
+            remove: Optional[Dict[str, Any]] = None,
+            remove_regex: Optional[Dict[str, Any]] = None,
+            inc: Optional[Dict[str, Any]] = None,
+    ) -> None:
+        pass
+
+    def to_dict(self) -> Dict[str, Any]:
+        """The update as it is sent to Solr"""
+
+
+class BulkIndexer:
+    """Index large amounts of documents, by splitting them into batches that are sent concurrently.
+    Documents are read lazily from the iterable, so generators can be used to keep memory use bounded.
+    Batches failing with a transient error are retried with exponential backoff, and a single commit is issued at the end.
+
+    :param update_query: The update query the batches are sent with, for the handler, commit type and update options such as `max_errors`. Its commit type is used for a final commit, except `commit_within` which is sent with every batch
+    :param batch_size: The maximum number of documents in a batch. Default is 1000
+    :param batch_bytes: The maximum size of a batch in bytes, as serialized JSON. Default is 10MB
+    :param concurrency: The maximum number of batches sent at the same time. Default is 4
+    :param max_retries: How many times a batch failing with a transient error is retried. Default is 3
+    :param initial_backoff: The wait in seconds before the first retry, doubled for every following retry. Default is 0.5
+    :param max_backoff: The maximum wait in seconds between retries. Default is 30
+    :param on_progress: Called after every batch, whether it succeeded or not
     """
 
     def __init__(
             self,
-            handler: Optional[str] = "update",
-            commit_type: Optional[CommitType] = CommitType.Hard,
-            ids: Optional[List[str]] = None,
-            queries: Optional[List[str]] = None,
+            update_query: Optional[UpdateQuery] = None,
+            batch_size: Optional[int] = None,
+            batch_bytes: Optional[int] = None,
+            concurrency: Optional[int] = None,
+            max_retries: Optional[int] = None,
+            initial_backoff: Optional[float] = None,
+            max_backoff: Optional[float] = None,
+            on_progress: Optional[Callable[["BulkIndexProgress"], None]] = None,
     ) -> None:
         pass
 
     async def execute(
-            self, context: "SolrServerContext", collection: str
-    ) -> "SolrResponse":
-        """Execute the query
+            self, context: "SolrServerContext", collection: str, docs: Iterable[Union[Dict[str, Any], "AtomicUpdate"]]
+    ) -> "BulkIndexSummary":
+        """Index the documents
 
         :param context: The context for the connection to the solr instance
-        :param collection: The collection to delete from
+        :param collection: The collection to index into
+        :param docs: The documents to index, e.g. a generator
         """
 
     def execute_blocking(
-            self, context: "SolrServerContext", collection: str
-    ) -> "SolrResponse":
-        """Execute the query
+            self, context: "SolrServerContext", collection: str, docs: Iterable[Union[Dict[str, Any], "AtomicUpdate"]]
+    ) -> "BulkIndexSummary":
+        """Index the documents
 
         :param context: The context for the connection to the solr instance
-        :param collection: The collection to delete from
+        :param collection: The collection to index into
+        :param docs: The documents to index, e.g. a generator
         """
 
 
-# endregion
+class Reindexer:
+    """Copy the documents of one collection into another, on the client.
+    Documents are read page by page with a cursor mark, optionally transformed, and indexed with a :class:`BulkIndexer`.
+    The target collection can be on another :class:`SolrServerContext`.
 
-# region clients
+    Only stored and docValues fields can be copied, and `_version_` is removed from the documents.
+    For nested documents, only read the parents, and include the children with `fl=["*", "[child]"]`.
+    The reindex stops after a page with failed documents, and the reported cursor mark is that of the last fully indexed page.
 
-class AsyncSolrCloudClient:
+    :param query: The query for reading the source collection. Without a sort, the documents are sorted by the unique key of the source collection. A sort must include the unique key. Default is `*:*` with 1000 rows
+    :param cursor_mark: Resume from a cursor mark reported by an earlier run, with the same query
+    :param indexer: The indexer for the target collection. Its commit is issued once after the last page
+    :param transform: Called with every document before it is indexed. Documents it returns `None` for are skipped
+    :param on_progress: Called after every page has been indexed, with the cursor mark to resume from
     """
-    A client for interacting with a SolrCloud cluster asynchronously.
 
-    :param context: The context of the Solr server.
+    def __init__(
+            self,
+            query: Optional["SelectQuery"] = None,
+            cursor_mark: Optional[str] = None,
+            indexer: Optional["BulkIndexer"] = None,
+            transform: Optional[Callable[[Dict[str, Any]], Optional[Dict[str, Any]]]] = None,
+            on_progress: Optional[Callable[["ReindexProgress"], None]] = None,
+    ) -> None:
+        pass
 
-    >>> from solrstice import SolrServerContext, SolrBasicAuth, AsyncSolrCloudClient
-    >>> context = SolrServerContext("http://localhost:8983", SolrBasicAuth("user", "pass"))
-    >>> client = AsyncSolrCloudClient(context)
+    async def execute(
+            self,
+            source_context: "SolrServerContext",
+            source: str,
+            target_context: "SolrServerContext",
+            target: str,
+    ) -> "ReindexSummary":
+        """Copy the documents of `source` into `target`
+
+        :param source_context: The context for the connection to the solr instance to read from
+        :param source: The collection to read from
+        :param target_context: The context for the connection to the solr instance to index into
+        :param target: The collection to index into
+        """
 
-    """
+    def execute_blocking(
+            self,
+            source_context: "SolrServerContext",
+            source: str,
+            target_context: "SolrServerContext",
+            target: str,
+    ) -> "ReindexSummary":
+        """Copy the documents of `source` into `target`
+
+        :param source_context: The context for the connection to the solr instance to read from
+        :param source: The collection to read from
+        :param target_context: The context for the connection to the solr instance to index into
+        :param target: The collection to index into
+        """
 
-    def __init__(self, context: "SolrServerContext"):
-        pass
 
-    async def upload_config(
-            self, config_name: str, config_path: Union[PathLike[str], str]
-    ) -> None:
-        """Uploads a Solr config to a Solr instance
+class ReindexCollectionQuery:
+    """Reindex a collection on the server, with the `REINDEXCOLLECTION` collections API action. Requires Solr 8.1 or later.
 
-        :param config_name: Name of the config
-        :param config_path: Path to the config
+    :param target: The collection to reindex into. Default is to replace the source collection, keeping its name as an alias
+    :param q: Only reindex documents matching this query
+    :param fl: The fields to copy
+    :param rows: The number of documents read per batch
+    :param config_name: The config set of the target collection, if it is created
+    :param remove_source: Delete the source collection when done
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
-        >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def upload_config() -> None:
-        ...     await client.upload_config("config_name", "/path/to/config")
+    def __init__(
+            self,
+            target: Optional[str] = None,
+            q: Optional[str] = None,
+            fl: Optional[List[str]] = None,
+            rows: Optional[int] = None,
+            config_name: Optional[str] = None,
+            remove_source: Optional[bool] = None,
+            async_id: Optional[str] = None,
+    ) -> None:
+        pass
 
-        """
+    async def execute(self, context: "SolrServerContext", collection: str) -> "SolrReindexStatus":
+        """Start the reindex of `collection`, and wait for it to finish
 
-    async def get_configs(self) -> List[str]:
-        """Gets a list of Solr configs on a Solr instance
-        :returns: A list of Solr configs
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to reindex
+        """
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
-        >>> from typing import List
-        >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def get_configs() -> List[str]:
-        ...     return await client.get_configs()
+    def execute_blocking(self, context: "SolrServerContext", collection: str) -> "SolrReindexStatus":
+        """Start the reindex of `collection`, and wait for it to finish
 
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to reindex
         """
 
-    async def config_exists(self, config_name: str) -> bool:
-        """Checks if a Solr config exists on a Solr instance
+    @staticmethod
+    async def status(context: "SolrServerContext", collection: str) -> "SolrReindexStatus":
+        """Get the status of a running reindex of `collection`
 
-        :param config_name: Name of the config
-        :returns: True if the config exists, False otherwise
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection being reindexed
+        """
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
-        >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def config_exists() -> bool:
-        ...     return await client.config_exists("config_name")
+    @staticmethod
+    def status_blocking(context: "SolrServerContext", collection: str) -> "SolrReindexStatus":
+        """Get the status of a running reindex of `collection`
 
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection being reindexed
         """
 
-    async def delete_config(self, config_name: str) -> None:
-        """Deletes a Solr config from a Solr instance
-        :param config_name: Name of the config
-
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
-        >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def delete_config() -> None:
-        ...     await client.delete_config("config_name")
+    @staticmethod
+    async def abort(context: "SolrServerContext", collection: str) -> "SolrReindexStatus":
+        """Abort a running reindex of `collection`. The source collection is left as it was
 
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection being reindexed
         """
 
-    pass
+    @staticmethod
+    def abort_blocking(context: "SolrServerContext", collection: str) -> "SolrReindexStatus":
+        """Abort a running reindex of `collection`. The source collection is left as it was
 
-    async def create_collection(
-            self,
-            name: str,
-            config: str,
-            shards: Optional[int] = 1,
-            replication_factor: Optional[int] = 1,
-    ) -> None:
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection being reindexed
         """
-        Create a collection on the Solr server.
 
-        :param name: The name of the collection to create.
-        :param config: The name of the config to use for the collection.
-        :param shards: The number of shards to create.
-        :param replication_factor: The replication factor to use.
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
-        >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def create_collection() -> None:
-        ...     await client.create_collection("collection_name", "config_name", 1, 1)
+class DeleteById:
+    """An id to delete, with an optional `_route_` and `_version_` constraint
 
-        """
+    :param id: The unique key of the document
+    :param route: Route the delete to the shard of this `_route_` value
+    :param version: Only delete if the `_version_` matches. 1 means the document must exist, -1 that it must not exist, and 0 no constraint
+    """
 
-    async def get_collections(self) -> List[str]:
-        """
-        Get the list of collections on the Solr server.
+    def __init__(self, id: str, route: Optional[str] = None, version: Optional[int] = None) -> None:
+        pass
 
-        :return: The list of collections on the Solr server.
+    def get_id(self) -> str:
+        """Get the unique key of the document"""
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
-        >>> from typing import List
-        >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def get_collections() -> List[str]:
-        ...     return await client.get_collections()
+    def get_route(self) -> Optional[str]:
+        """Get the `_route_` value"""
 
-        """
+    def get_version(self) -> Optional[int]:
+        """Get the `_version_` constraint"""
 
-    async def collection_exists(self, name: str) -> bool:
-        """
-        Check if a collection exists on the Solr server.
 
-        :param name: The name of the collection to check.
-        :return: True if the collection exists, False otherwise.
+class DeleteQuery:
+    """Builder for a delete query
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
-        >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def collection_exists() -> bool:
-        ...     return await client.collection_exists("collection_name")
+    :param handler: The handler for the delete query
+    :param commit_type: The commit type for the delete query. Deletes are sent as JSON, so ids and queries need no escaping
+    :param ids: Ids to delete, as strings or :class:`DeleteById`
+    :param queries: Queries to delete
+    :param return_versions: Return the `_version_` of every delete
+    :param commit_within: Commit within this many milliseconds. Takes precedence over `commit_type`
+    :param wait_searcher: Whether Solr should wait for the new searcher to be opened when committing
+    :param open_searcher: Whether a hard commit should open a new searcher
+    :param expunge_deletes: Whether the commit should merge away segments with deleted documents
+    """
 
-        """
+    def __init__(
+            self,
+            handler: Optional[str] = "update",
+            commit_type: Optional[CommitType] = CommitType.Hard,
+            ids: Optional[List[Union[str, "DeleteById"]]] = None,
+            queries: Optional[List[str]] = None,
+            return_versions: Optional[bool] = False,
+            commit_within: Optional[int] = None,
+            wait_searcher: Optional[bool] = None,
+            open_searcher: Optional[bool] = None,
+            expunge_deletes: Optional[bool] = None,
+    ) -> None:
+        pass
 
-    async def delete_collection(self, name: str) -> None:
+    async def execute(
+            self, context: "SolrServerContext", collection: str
+    ) -> "SolrResponse":
+        """Execute the query
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to delete from
         """
-        Delete a config from the Solr server.
-        :param name: The name of the collection to delete.
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
-        >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def delete_collection() -> None:
-        ...     await client.delete_collection("collection_name")
+    def execute_blocking(
+            self, context: "SolrServerContext", collection: str
+    ) -> "SolrResponse":
+        """Execute the query
 
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to delete from
         """
 
-    async def create_alias(self, name: str, collections: List[str]) -> None:
-        """
-        Create an alias for a collection on the Solr server
 
-        :param name: The name of the alias to create
-        :param collections: The collections to alias
+class CommitQuery:
+    """Builder for an explicit commit, making pending changes durable and visible
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
-        >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def create_alias() -> None:
-        ...     await client.create_alias("alias_name", ["collection_name"])
+    :param handler: The handler for the commit
+    :param soft_commit: Do a soft commit instead of a hard commit. Default is False
+    :param wait_searcher: Whether Solr should wait for the new searcher to be opened when committing
+    :param open_searcher: Whether a hard commit should open a new searcher
+    :param expunge_deletes: Whether the commit should merge away segments with deleted documents
+    """
 
-        """
+    def __init__(
+            self,
+            handler: Optional[str] = "update",
+            soft_commit: Optional[bool] = False,
+            wait_searcher: Optional[bool] = None,
+            open_searcher: Optional[bool] = None,
+            expunge_deletes: Optional[bool] = None,
+    ) -> None:
+        pass
 
-    async def get_aliases(self) -> Dict[str, List[str]]:
-        """
-        Get all aliases on the Solr server
+    async def execute(
+            self, context: "SolrServerContext", collection: str
+    ) -> "SolrResponse":
+        """Execute the query
 
-        :return: A dictionary of aliases to collections
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to commit
+        """
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
-        >>> from typing import Dict, List
-        >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def get_aliases() -> Dict[str, List[str]]:
-        ...     return await client.get_aliases()
+    def execute_blocking(
+            self, context: "SolrServerContext", collection: str
+    ) -> "SolrResponse":
+        """Execute the query
 
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to commit
         """
 
-    async def alias_exists(self, name: str) -> bool:
-        """
-        Check if an alias exists on the Solr server
 
-        :param name: The name of the alias to check
-        :return: True if the alias exists, False otherwise
+class OptimizeQuery:
+    """Builder for optimizing, merging the segments of an index
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
-        >>> context = SolrServerContext("http://localhost:8983")
+    :param handler: The handler for the optimize
+    :param max_segments: Merge down to at most this many segments. Default is 1
+    :param wait_searcher: Whether Solr should wait for the new searcher to be opened
+    """
+
+    def __init__(
+            self,
+            handler: Optional[str] = "update",
+            max_segments: Optional[int] = None,
+            wait_searcher: Optional[bool] = None,
+    ) -> None:
+        pass
+
+    async def execute(
+            self, context: "SolrServerContext", collection: str
+    ) -> "SolrResponse":
+        """Execute the query
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to optimize
+        """
+
+    def execute_blocking(
+            self, context: "SolrServerContext", collection: str
+    ) -> "SolrResponse":
+        """Execute the query
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to optimize
+        """
+
+
+class RollbackQuery:
+    """Builder for rolling back all changes since the last commit.
+    Only supported on standalone servers, not in SolrCloud mode
+
+    :param handler: The handler for the rollback
+    """
+
+    def __init__(self, handler: Optional[str] = "update") -> None:
+        pass
+
+    async def execute(
+            self, context: "SolrServerContext", collection: str
+    ) -> "SolrResponse":
+        """Execute the query
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to roll back
+        """
+
+    def execute_blocking(
+            self, context: "SolrServerContext", collection: str
+    ) -> "SolrResponse":
+        """Execute the query
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to roll back
+        """
+
+
+class UpdateBatch:
+    """Several update commands sent in one request, applied by Solr in order.
+    Every method returns the batch, so calls can be chained
+
+    :param handler: The handler for the batch
+
+    >>> from solrstice import CommitQuery, UpdateBatch
+    >>> batch = UpdateBatch().delete_by_query("category:outdated").add_doc({"id": "1"}, overwrite=False).commit(CommitQuery())
+    >>> len(batch)
+    3
+    """
+
+    def __init__(self, handler: Optional[str] = "update") -> None:
+        pass
+
+    def add_doc(
+            self,
+            doc: Union[Dict[str, Any], "AtomicUpdate"],
+            overwrite: Optional[bool] = None,
+            commit_within: Optional[int] = None,
+            boost: Optional[float] = None,
+    ) -> "UpdateBatch":
+        """Add a document
+
+        :param doc: The document
+        :param overwrite: Whether a document with the same unique key should be replaced
+        :param commit_within: Commit the document within this many milliseconds
+        :param boost: Index time boost. Ignored by Solr 7 and later
+        """
+
+    def delete_by_id(
+            self, id: Union[str, "DeleteById"], commit_within: Optional[int] = None
+    ) -> "UpdateBatch":
+        """Delete a document by id
+
+        :param id: The id, or a :class:`DeleteById` with `_route_` and `_version_`
+        :param commit_within: Commit the delete within this many milliseconds
+        """
+
+    def delete_by_query(self, query: str, commit_within: Optional[int] = None) -> "UpdateBatch":
+        """Delete all documents matching a query
+
+        :param query: The query
+        :param commit_within: Commit the delete within this many milliseconds
+        """
+
+    def commit(self, commit: Optional["CommitQuery"] = None) -> "UpdateBatch":
+        """Commit, with the options of the :class:`CommitQuery`
+
+        :param commit: The commit options. Its handler is ignored
+        """
+
+    def optimize(self, optimize: Optional["OptimizeQuery"] = None) -> "UpdateBatch":
+        """Optimize, with the options of the :class:`OptimizeQuery`
+
+        :param optimize: The optimize options. Its handler is ignored
+        """
+
+    def __len__(self) -> int:
+        pass
+
+    async def execute(
+            self, context: "SolrServerContext", collection: str
+    ) -> "SolrResponse":
+        """Execute the batch
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to update
+        """
+
+    def execute_blocking(
+            self, context: "SolrServerContext", collection: str
+    ) -> "SolrResponse":
+        """Execute the batch
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to update
+        """
+
+
+class CsvUpdateQuery:
+    """Builder for streaming CSV files to the `/update/csv` handler, without parsing them in the client
+
+    :param handler: The handler for the query
+    :param commit_type: The commit type for the query
+    :param commit_within: Commit within this many milliseconds. Takes precedence over `commit_type`
+    :param overwrite: Whether documents with the same unique key should be replaced. Default is True
+    :param wait_searcher: Whether Solr should wait for the new searcher to be opened when committing
+    :param open_searcher: Whether a hard commit should open a new searcher
+    :param expunge_deletes: Whether the commit should merge away segments with deleted documents
+    :param separator: The character separating fields. Default is `,`
+    :param header: Whether the first line contains the field names
+    :param fieldnames: The field names of the columns. Empty names skip the column
+    :param split: Fields whose values should be split into multiple values
+    :param split_separator: The character separating the values of the `split` fields
+    :param encapsulator: The character enclosing values containing the separator. Default is `"`
+    :param skip: Fields that should not be indexed
+    :param skip_lines: The number of lines to skip before the header or data
+    """
+
+    def __init__(
+            self,
+            handler: Optional[str] = "update/csv",
+            commit_type: Optional[CommitType] = CommitType.Hard,
+            commit_within: Optional[int] = None,
+            overwrite: Optional[bool] = None,
+            wait_searcher: Optional[bool] = None,
+            open_searcher: Optional[bool] = None,
+            expunge_deletes: Optional[bool] = None,
+            separator: Optional[str] = None,
+            header: Optional[bool] = None,
+            fieldnames: Optional[List[str]] = None,
+            split: Optional[List[str]] = None,
+            split_separator: Optional[str] = None,
+            encapsulator: Optional[str] = None,
+            skip: Optional[List[str]] = None,
+            skip_lines: Optional[int] = None,
+    ) -> None:
+        pass
+
+    async def execute_file(
+            self, context: "SolrServerContext", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrResponse":
+        """Stream a CSV file to Solr
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to index into
+        :param path: The path to the file
+        """
+
+    def execute_file_blocking(
+            self, context: "SolrServerContext", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrResponse":
+        """Stream a CSV file to Solr
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to index into
+        :param path: The path to the file
+        """
+
+
+class JsonDocsUpdateQuery:
+    """Builder for streaming JSON or JSON lines files to the `/update/json/docs` handler, without parsing them in the client
+
+    :param handler: The handler for the query
+    :param commit_type: The commit type for the query
+    :param commit_within: Commit within this many milliseconds. Takes precedence over `commit_type`
+    :param overwrite: Whether documents with the same unique key should be replaced. Default is True
+    :param wait_searcher: Whether Solr should wait for the new searcher to be opened when committing
+    :param open_searcher: Whether a hard commit should open a new searcher
+    :param expunge_deletes: Whether the commit should merge away segments with deleted documents
+    :param split: The path to split the input into documents on. Eg: `/`
+    :param f: Field mappings, as `field:json-path`. Eg: `["first:/first", "/exams/**"]`
+    """
+
+    def __init__(
+            self,
+            handler: Optional[str] = "update/json/docs",
+            commit_type: Optional[CommitType] = CommitType.Hard,
+            commit_within: Optional[int] = None,
+            overwrite: Optional[bool] = None,
+            wait_searcher: Optional[bool] = None,
+            open_searcher: Optional[bool] = None,
+            expunge_deletes: Optional[bool] = None,
+            split: Optional[str] = None,
+            f: Optional[List[str]] = None,
+    ) -> None:
+        pass
+
+    async def execute_file(
+            self, context: "SolrServerContext", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrResponse":
+        """Stream a JSON or JSON lines file to Solr
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to index into
+        :param path: The path to the file
+        """
+
+    def execute_file_blocking(
+            self, context: "SolrServerContext", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrResponse":
+        """Stream a JSON or JSON lines file to Solr
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to index into
+        :param path: The path to the file
+        """
+
+
+class ExtractFormat(Enum):
+    Xml = "Xml"
+    Text = "Text"
+
+
+class ExtractQuery:
+    """Builder for uploading binary files such as PDF or DOCX to Solr Cell, the `/update/extract` handler.
+    The extraction module must be enabled in Solr
+
+    :param handler: The handler for the query
+    :param commit_type: The commit type for the query
+    :param commit_within: Commit within this many milliseconds. Takes precedence over `commit_type`
+    :param overwrite: Whether a document with the same unique key should be replaced. Default is True
+    :param wait_searcher: Whether Solr should wait for the new searcher to be opened when committing
+    :param open_searcher: Whether a hard commit should open a new searcher
+    :param expunge_deletes: Whether the commit should merge away segments with deleted documents
+    :param literals: Fields added to the document as is. Usually includes the unique key
+    :param fmap: Rename extracted fields. Eg: `{"content": "text"}`
+    :param uprefix: Prefix for extracted fields that are not in the schema
+    :param capture_attr: Whether attributes of XHTML elements should be indexed into separate fields
+    :param lowernames: Whether extracted field names should be lowercased
+    :param extract_format: The format of the content when only extracting. Default is Xml
+    :param resource_name: The file name, which helps detecting the file type
+    :param content_type: The content type of the file. If unset, it is detected
+
+    >>> from solrstice import ExtractQuery
+    >>> query = ExtractQuery(literals={"id": "report1", "tags": ["a", "b"]}, uprefix="ignored_")
+    """
+
+    def __init__(
+            self,
+            handler: Optional[str] = "update/extract",
+            commit_type: Optional[CommitType] = CommitType.Hard,
+            commit_within: Optional[int] = None,
+            overwrite: Optional[bool] = None,
+            wait_searcher: Optional[bool] = None,
+            open_searcher: Optional[bool] = None,
+            expunge_deletes: Optional[bool] = None,
+            literals: Optional[Dict[str, Union[str, List[str]]]] = None,
+            fmap: Optional[Dict[str, str]] = None,
+            uprefix: Optional[str] = None,
+            capture_attr: Optional[bool] = None,
+            lowernames: Optional[bool] = None,
+            extract_format: Optional[ExtractFormat] = None,
+            resource_name: Optional[str] = None,
+            content_type: Optional[str] = None,
+    ) -> None:
+        pass
+
+    async def execute_file(
+            self, context: "SolrServerContext", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrResponse":
+        """Upload a file, and index the extracted document
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to index into
+        :param path: The path to the file
+        """
+
+    def execute_file_blocking(
+            self, context: "SolrServerContext", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrResponse":
+        """Upload a file, and index the extracted document
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection to index into
+        :param path: The path to the file
+        """
+
+    async def extract_only_file(
+            self, context: "SolrServerContext", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrExtractResponse":
+        """Upload a file, and return the extracted text and metadata without indexing them
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection whose handler to use
+        :param path: The path to the file
+        """
+
+    def extract_only_file_blocking(
+            self, context: "SolrServerContext", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrExtractResponse":
+        """Upload a file, and return the extracted text and metadata without indexing them
+
+        :param context: The context for the connection to the solr instance
+        :param collection: The collection whose handler to use
+        :param path: The path to the file
+        """
+
+
+# endregion
+
+# region collection
+class CollectionRouter(Enum):
+    """How documents are assigned to the shards of a collection"""
+
+    CompositeId = "CompositeId"
+    Implicit = "Implicit"
+
+
+class CreateCollectionOptions:
+    """Options for the Collections API `CREATE` action
+
+    :param config_name: The config set of the collection. Default is `_default`
+    :param num_shards: The number of shards, for the `compositeId` router
+    :param replication_factor: The number of NRT replicas per shard
+    :param router: The router of the collection. Default is :class:`CollectionRouter.CompositeId`
+    :param router_field: Route on this field instead of the unique key. With the implicit router it holds the name of the shard
+    :param shards: The names of the shards, required for the implicit router
+    :param nrt_replicas: The number of NRT replicas per shard, which index and search
+    :param tlog_replicas: The number of TLOG replicas per shard, which copy the index from the leader but can become leader
+    :param pull_replicas: The number of PULL replicas per shard, which only copy the index from the leader
+    :param create_node_set: Only place replicas on these nodes. Eg: `localhost:8983_solr`. Pass `EMPTY` to create no replicas
+    :param per_replica_state: Keep the state of each replica in its own ZooKeeper node
+    :param properties: Core properties, sent as `property.name=value`
+    :param wait_for_final_state: Wait until all replicas are active before returning
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(
+            self,
+            config_name: Optional[str] = None,
+            num_shards: Optional[int] = None,
+            replication_factor: Optional[int] = None,
+            router: Optional[CollectionRouter] = None,
+            router_field: Optional[str] = None,
+            shards: Optional[List[str]] = None,
+            nrt_replicas: Optional[int] = None,
+            tlog_replicas: Optional[int] = None,
+            pull_replicas: Optional[int] = None,
+            create_node_set: Optional[List[str]] = None,
+            per_replica_state: Optional[bool] = None,
+            properties: Optional[Dict[str, str]] = None,
+            wait_for_final_state: Optional[bool] = None,
+            async_id: Optional[str] = None,
+    ) -> None:
+        pass
+
+
+class SplitMethod(Enum):
+    """How a shard split divides the index of the parent shard"""
+
+    Rewrite = "Rewrite"
+    Link = "Link"
+
+
+class SplitShardOptions:
+    """Options for the Collections API `SPLITSHARD` action. Either the shard or a split key has to be given
+
+    :param shard: The shard to split. Eg: `shard1`
+    :param split_key: Split the shard holding this route key. Eg: `customer_a!`
+    :param ranges: The hash ranges of the sub-shards, in hexadecimal. Eg: `["0-1f4", "1f5-3e8"]`
+    :param num_sub_shards: Split into this many sub-shards of equal ranges. Default is 2
+    :param split_method: How to divide the index. Default is :class:`SplitMethod.Rewrite`
+    :param wait_for_final_state: Wait until the replicas of the sub-shards are active before returning
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(
+            self,
+            shard: Optional[str] = None,
+            split_key: Optional[str] = None,
+            ranges: Optional[List[str]] = None,
+            num_sub_shards: Optional[int] = None,
+            split_method: Optional[SplitMethod] = None,
+            wait_for_final_state: Optional[bool] = None,
+            async_id: Optional[str] = None,
+    ) -> None:
+        pass
+
+
+class CreateShardOptions:
+    """Options for the Collections API `CREATESHARD` action. Replicas default to the replica counts of the collection
+
+    :param nrt_replicas: The number of NRT replicas of the shard
+    :param tlog_replicas: The number of TLOG replicas of the shard
+    :param pull_replicas: The number of PULL replicas of the shard
+    :param create_node_set: Only place replicas on these nodes. Eg: `localhost:8983_solr`
+    :param properties: Core properties, sent as `property.name=value`
+    :param wait_for_final_state: Wait until all replicas are active before returning
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(
+            self,
+            nrt_replicas: Optional[int] = None,
+            tlog_replicas: Optional[int] = None,
+            pull_replicas: Optional[int] = None,
+            create_node_set: Optional[List[str]] = None,
+            properties: Optional[Dict[str, str]] = None,
+            wait_for_final_state: Optional[bool] = None,
+            async_id: Optional[str] = None,
+    ) -> None:
+        pass
+
+
+class DeleteShardOptions:
+    """Options for the Collections API `DELETESHARD` action
+
+    :param delete_instance_dir: Delete the instance directories of the cores. Default is True
+    :param delete_data_dir: Delete the data directories of the cores. Default is True
+    :param delete_index: Delete the indexes of the cores. Default is True
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(
+            self,
+            delete_instance_dir: Optional[bool] = None,
+            delete_data_dir: Optional[bool] = None,
+            delete_index: Optional[bool] = None,
+            async_id: Optional[str] = None,
+    ) -> None:
+        pass
+
+
+class AddReplicaOptions:
+    """Options for the Collections API `ADDREPLICA` action. By default one NRT replica is added on the node Solr picks
+
+    :param replica_type: The type of the replica. Default is :class:`solrstice.models.ReplicaType.Nrt`
+    :param node: Place the replica on this node. Eg: `localhost:8983_solr`
+    :param create_node_set: Place the replicas on these nodes
+    :param nrt_replicas: Add this many NRT replicas, instead of a single replica of `replica_type`
+    :param tlog_replicas: Add this many TLOG replicas
+    :param pull_replicas: Add this many PULL replicas
+    :param properties: Core properties, sent as `property.name=value`
+    :param wait_for_final_state: Wait until the replicas are active before returning
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(
+            self,
+            replica_type: Optional["ReplicaType"] = None,
+            node: Optional[str] = None,
+            create_node_set: Optional[List[str]] = None,
+            nrt_replicas: Optional[int] = None,
+            tlog_replicas: Optional[int] = None,
+            pull_replicas: Optional[int] = None,
+            properties: Optional[Dict[str, str]] = None,
+            wait_for_final_state: Optional[bool] = None,
+            async_id: Optional[str] = None,
+    ) -> None:
+        pass
+
+
+class DeleteReplicaOptions:
+    """Options for the Collections API `DELETEREPLICA` action.
+    Replicas are deleted either by name, with the shard and replica, by count, or by node
+
+    :param shard: The shard of the replica. Without it, replicas are deleted by count from every shard
+    :param replica: The name of the replica to delete. Eg: `core_node3`
+    :param count: Delete this many replicas per shard. Nothing is deleted if it would leave a shard without replicas
+    :param replica_type: Only delete replicas of this type, when deleting by count
+    :param node: Delete the replicas of the collection on this node. Eg: `localhost:8983_solr`
+    :param only_if_down: Only delete the replica if it is not active
+    :param delete_instance_dir: Delete the instance directory of the core. Default is True
+    :param delete_data_dir: Delete the data directory of the core. Default is True
+    :param delete_index: Delete the index of the core. Default is True
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(
+            self,
+            shard: Optional[str] = None,
+            replica: Optional[str] = None,
+            count: Optional[int] = None,
+            replica_type: Optional["ReplicaType"] = None,
+            node: Optional[str] = None,
+            only_if_down: Optional[bool] = None,
+            delete_instance_dir: Optional[bool] = None,
+            delete_data_dir: Optional[bool] = None,
+            delete_index: Optional[bool] = None,
+            async_id: Optional[str] = None,
+    ) -> None:
+        pass
+
+
+class MoveReplicaOptions:
+    """Options for the Collections API `MOVEREPLICA` action. The replica is given either by name, or by its shard and node
+
+    :param replica: The name of the replica to move. Eg: `core_node3`
+    :param shard: The shard of the replica, used with `source_node`
+    :param source_node: The node the replica is on. Eg: `localhost:8983_solr`
+    :param in_place_move: Move the replica without copying the index, for indexes on a shared file system
+    :param timeout: How many seconds Solr waits for the new replica to become active. Default is 600
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(
+            self,
+            replica: Optional[str] = None,
+            shard: Optional[str] = None,
+            source_node: Optional[str] = None,
+            in_place_move: Optional[bool] = None,
+            timeout: Optional[float] = None,
+            async_id: Optional[str] = None,
+    ) -> None:
+        pass
+
+
+class DeleteNodeOptions:
+    """Options for the Collections API `DELETENODE` action
+
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(self, async_id: Optional[str] = None) -> None:
+        pass
+
+
+class ReplaceNodeOptions:
+    """Options for the Collections API `REPLACENODE` action
+
+    :param target_node: Move the replicas to this node. Default is spreading them over the other live nodes
+    :param timeout: How many seconds Solr waits for each new replica to become active. Default is 300
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(
+            self, target_node: Optional[str] = None, timeout: Optional[float] = None, async_id: Optional[str] = None
+    ) -> None:
+        pass
+
+
+class ReloadCollectionOptions:
+    """Options for the Collections API `RELOAD` action
+
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`. When reloading the collections using a config set, each is reloaded under `<async_id>-<collection>`
+    """
+
+    def __init__(self, async_id: Optional[str] = None) -> None:
+        pass
+
+
+class ModifyCollectionOptions:
+    """Options for the Collections API `MODIFYCOLLECTION` action
+
+    :param replication_factor: The number of NRT replicas new shards get. Existing shards keep their replicas
+    :param config_name: Use another config set. The collection must be reloaded for it to take effect
+    :param read_only: Reject updates to the collection while true. Queries are still served
+    :param properties: Collection properties, sent as `property.name=value`. An empty value removes the property
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(
+            self,
+            replication_factor: Optional[int] = None,
+            config_name: Optional[str] = None,
+            read_only: Optional[bool] = None,
+            properties: Optional[Dict[str, str]] = None,
+            async_id: Optional[str] = None,
+    ) -> None:
+        pass
+
+
+class RenameCollectionOptions:
+    """Options for the Collections API `RENAME` action
+
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(self, async_id: Optional[str] = None) -> None:
+        pass
+
+
+class ColStatusOptions:
+    """Options for the Collections API `COLSTATUS` action.
+    By default only the number of segments and documents of each leader is reported
+
+    :param core_info: Report the index size and directories of each leader
+    :param segments: Report the segments of each leader
+    :param field_info: Report the fields of each segment, and the fields that do not comply with the schema. Implies `segments`
+    :param size_info: Report the largest files of each segment. Implies `segments`
+    :param raw_size: Estimate the size of the index by field and type. This reads the index, and can be slow
+    :param raw_size_sampling_percent: The percentage of documents read to estimate the raw size. Default is 5
+    """
+
+    def __init__(
+            self,
+            core_info: Optional[bool] = None,
+            segments: Optional[bool] = None,
+            field_info: Optional[bool] = None,
+            size_info: Optional[bool] = None,
+            raw_size: Optional[bool] = None,
+            raw_size_sampling_percent: Optional[float] = None,
+    ) -> None:
+        pass
+
+
+class ReplicaPropertyOptions:
+    """Options for the Collections API `ADDREPLICAPROP` and `DELETEREPLICAPROP` actions
+
+    :param shard_unique: Remove the property from the other replicas of the shard. Only used when adding a property
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(self, shard_unique: Optional[bool] = None, async_id: Optional[str] = None) -> None:
+        pass
+
+
+class DeleteCollectionOptions:
+    """Options for the Collections API `DELETE` action
+
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(self, async_id: Optional[str] = None) -> None:
+        pass
+
+
+class CreateAliasOptions:
+    """Options for the Collections API `CREATEALIAS` action
+
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(self, async_id: Optional[str] = None) -> None:
+        pass
+
+
+class DeleteAliasOptions:
+    """Options for the Collections API `DELETEALIAS` action
+
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(self, async_id: Optional[str] = None) -> None:
+        pass
+
+
+class BackupOptions:
+    """Options for the Collections API `BACKUP` action
+
+    :param location: The directory in the repository to write the backup to. Default is the `location` of the repository
+    :param repository: The backup repository from `solr.xml` to use. Default is the local filesystem
+    :param incremental: Only copy the index files that are not in an earlier backup point of the same name. Requires Solr 8.9 or later, and is the default since Solr 9
+    :param max_num_backup_points: Delete the oldest backup points, so that at most this many are kept. Only for incremental backups
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(
+            self,
+            location: Optional[str] = None,
+            repository: Optional[str] = None,
+            incremental: Optional[bool] = None,
+            max_num_backup_points: Optional[int] = None,
+            async_id: Optional[str] = None,
+    ) -> None:
+        pass
+
+
+class RestoreOptions:
+    """Options for the Collections API `RESTORE` action
+
+    :param location: The directory in the repository the backup was written to
+    :param repository: The backup repository from `solr.xml` to use. Default is the local filesystem
+    :param backup_id: The backup point to restore. Default is the latest
+    :param config_name: The config set of the restored collection. Default is the config set in the backup, which is uploaded if missing
+    :param replication_factor: The number of replicas of each shard of the restored collection
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(
+            self,
+            location: Optional[str] = None,
+            repository: Optional[str] = None,
+            backup_id: Optional[int] = None,
+            config_name: Optional[str] = None,
+            replication_factor: Optional[int] = None,
+            async_id: Optional[str] = None,
+    ) -> None:
+        pass
+
+
+class ListBackupsOptions:
+    """Options for the Collections API `LISTBACKUP` action
+
+    :param location: The directory in the repository the backup was written to
+    :param repository: The backup repository from `solr.xml` to use. Default is the local filesystem
+    """
+
+    def __init__(self, location: Optional[str] = None, repository: Optional[str] = None) -> None:
+        pass
+
+
+class DeleteBackupOptions:
+    """Options for the Collections API `DELETEBACKUP` action.
+    One of `backup_id`, `max_num_backup_points` or `purge_unused` has to be set
+
+    :param location: The directory in the repository the backup was written to
+    :param repository: The backup repository from `solr.xml` to use. Default is the local filesystem
+    :param backup_id: Delete this backup point
+    :param max_num_backup_points: Delete the oldest backup points, so that this many are kept
+    :param purge_unused: Delete the files that no backup point refers to, eg. left by a failed backup
+    :param async_id: `async` request id, see :class:`AsyncAdminTask`
+    """
+
+    def __init__(
+            self,
+            location: Optional[str] = None,
+            repository: Optional[str] = None,
+            backup_id: Optional[int] = None,
+            max_num_backup_points: Optional[int] = None,
+            purge_unused: Optional[bool] = None,
+            async_id: Optional[str] = None,
+    ) -> None:
+        pass
+
+
+class AsyncAdminTask:
+    """A handle to a Collections API request sent with an `async` request id.
+    Sent with an `async` id, Solr returns at once and runs the operation in the background.
+    Polls `REQUESTSTATUS` until the operation is completed or failed.
+    Solr rejects a new request with an id that is still in use, so remove the status with `delete_status` once the result has been read.
+
+    :param request_id: The `async` id of the request
+    :param poll_interval: The wait in seconds between polls. Default is 1
+    :param timeout: Give up waiting after this many seconds. The operation keeps running on the server. Default is no timeout
+    """
+
+    def __init__(self, request_id: str, poll_interval: Optional[float] = None, timeout: Optional[float] = None) -> None:
+        pass
+
+    def get_request_id(self) -> str:
+        """The `async` id of the request"""
+
+    async def status(self, context: "SolrServerContext") -> "SolrRequestStatus":
+        """Get the current status of the request
+
+        :param context: The context for the connection to the solr instance
+        """
+
+    def status_blocking(self, context: "SolrServerContext") -> "SolrRequestStatus":
+        """Get the current status of the request
+
+        :param context: The context for the connection to the solr instance
+        """
+
+    async def wait(self, context: "SolrServerContext") -> "SolrRequestStatus":
+        """Poll the status until the request is completed. Raises if it failed, timed out or is unknown to Solr
+
+        :param context: The context for the connection to the solr instance
+        """
+
+    def wait_blocking(self, context: "SolrServerContext") -> "SolrRequestStatus":
+        """Poll the status until the request is completed. Raises if it failed, timed out or is unknown to Solr
+
+        :param context: The context for the connection to the solr instance
+        """
+
+    async def delete_status(self, context: "SolrServerContext") -> None:
+        """Delete the stored status of the finished request, so its id can be used again
+
+        :param context: The context for the connection to the solr instance
+        """
+
+    def delete_status_blocking(self, context: "SolrServerContext") -> None:
+        """Delete the stored status of the finished request, so its id can be used again
+
+        :param context: The context for the connection to the solr instance
+        """
+
+    @staticmethod
+    async def delete_all_statuses(context: "SolrServerContext") -> None:
+        """Delete the stored statuses of all finished requests
+
+        :param context: The context for the connection to the solr instance
+        """
+
+    @staticmethod
+    def delete_all_statuses_blocking(context: "SolrServerContext") -> None:
+        """Delete the stored statuses of all finished requests
+
+        :param context: The context for the connection to the solr instance
+        """
+
+
+# endregion
+
+# region clients
+
+class AsyncSolrCloudClient:
+    """
+    A client for interacting with a SolrCloud cluster asynchronously.
+
+    :param context: The context of the Solr server.
+
+    >>> from solrstice import SolrServerContext, SolrBasicAuth, AsyncSolrCloudClient
+    >>> context = SolrServerContext("http://localhost:8983", SolrBasicAuth("user", "pass"))
+    >>> client = AsyncSolrCloudClient(context)
+
+    """
+
+    def __init__(self, context: "SolrServerContext"):
+        pass
+
+    async def upload_config(
+            self, config_name: str, config_path: Union[PathLike[str], str]
+    ) -> None:
+        """Uploads a Solr config to a Solr instance
+
+        :param config_name: Name of the config
+        :param config_path: Path to the config
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def upload_config() -> None:
+        ...     await client.upload_config("config_name", "/path/to/config")
+
+        """
+
+    async def upload_elevation_config(
+            self, config_name: str, elevation_path: Union[PathLike[str], str]
+    ) -> List[str]:
+        """Uploads a new elevate.xml into an existing config, and reloads every collection using it
+
+        :param config_name: Name of the config
+        :param elevation_path: Path to the elevate.xml file
+        :returns: The names of the reloaded collections
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> from typing import List
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def upload_elevation_config() -> List[str]:
+        ...     return await client.upload_elevation_config("config_name", "/path/to/elevate.xml")
+
+        """
+
+    async def get_configs(self) -> List[str]:
+        """Gets a list of Solr configs on a Solr instance
+        :returns: A list of Solr configs
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> from typing import List
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def get_configs() -> List[str]:
+        ...     return await client.get_configs()
+
+        """
+
+    async def config_exists(self, config_name: str) -> bool:
+        """Checks if a Solr config exists on a Solr instance
+
+        :param config_name: Name of the config
+        :returns: True if the config exists, False otherwise
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def config_exists() -> bool:
+        ...     return await client.config_exists("config_name")
+
+        """
+
+    async def delete_config(self, config_name: str) -> None:
+        """Deletes a Solr config from a Solr instance
+        :param config_name: Name of the config
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def delete_config() -> None:
+        ...     await client.delete_config("config_name")
+
+        """
+
+    pass
+
+    async def create_collection(
+            self,
+            name: str,
+            config: str,
+            shards: Optional[int] = 1,
+            replication_factor: Optional[int] = 1,
+    ) -> None:
+        """
+        Create a collection on the Solr server.
+
+        :param name: The name of the collection to create.
+        :param config: The name of the config to use for the collection.
+        :param shards: The number of shards to create.
+        :param replication_factor: The replication factor to use.
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def create_collection() -> None:
+        ...     await client.create_collection("collection_name", "config_name", 1, 1)
+
+        """
+
+    async def create_collection_with_options(self, name: str, options: "CreateCollectionOptions") -> None:
+        """
+        Create a collection on the Solr server, with all options of the `CREATE` action.
+
+        :param name: The name of the collection to create.
+        :param options: The options for the collection.
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, CollectionRouter, CreateCollectionOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def create_collection_with_options() -> None:
+        ...     options = CreateCollectionOptions(config_name="config_name", router=CollectionRouter.Implicit, shards=["a", "b"])
+        ...     await client.create_collection_with_options("collection_name", options)
+
+        """
+
+    async def get_collections(self) -> List[str]:
+        """
+        Get the list of collections on the Solr server.
+
+        :return: The list of collections on the Solr server.
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> from typing import List
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def get_collections() -> List[str]:
+        ...     return await client.get_collections()
+
+        """
+
+    async def collection_exists(self, name: str) -> bool:
+        """
+        Check if a collection exists on the Solr server.
+
+        :param name: The name of the collection to check.
+        :return: True if the collection exists, False otherwise.
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def collection_exists() -> bool:
+        ...     return await client.collection_exists("collection_name")
+
+        """
+
+    async def get_cluster_status(
+            self, collection: Optional[str] = None, shards: Optional[List[str]] = None
+    ) -> "ClusterStatus":
+        """
+        Get the state of the cluster: its collections with their shards and replicas, aliases and live nodes.
+
+        :param collection: Only return this collection, or the collections of this alias.
+        :param shards: Only return these shards of the collection.
+        :return: The state of the cluster.
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def get_leader_nodes() -> list:
+        ...     status = await client.get_cluster_status("collection_name")
+        ...     collection = status.get_collection("collection_name")
+        ...     return [shard.get_leader().get_node_name() for shard in collection.get_shards().values()]
+
+        """
+
+    async def get_col_status(
+            self, collection: Optional[str] = None, options: Optional["ColStatusOptions"] = None
+    ) -> "ColStatus":
+        """
+        Get the index status of collections: index size, segments and fields not complying with the schema.
+        Requires Solr 8.1 or later.
+
+        :param collection: Only return this collection.
+        :param options: What to report about the index of each shard leader.
+        :return: The index status of the collections.
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, ColStatusOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def get_index_sizes() -> list:
+        ...     status = await client.get_col_status("collection_name", ColStatusOptions(core_info=True))
+        ...     collection = status.get_collection("collection_name")
+        ...     return [shard.get_leader().get_segments_info().get_size_in_gb() for shard in collection.get_shards().values()]
+
+        """
+
+    async def delete_collection(self, name: str, options: Optional["DeleteCollectionOptions"] = None) -> None:
+        """
+        Delete a config from the Solr server.
+        :param name: The name of the collection to delete.
+        :param options: Options for the `DELETE` action, such as an async id
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def delete_collection() -> None:
+        ...     await client.delete_collection("collection_name")
+
+        """
+
+    async def reload_collection(self, name: str, options: Optional["ReloadCollectionOptions"] = None) -> None:
+        """
+        Reload a collection, so that changes to its config set take effect
+
+        :param name: The name of the collection
+        :param options: Options for the `RELOAD` action, such as an async id
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def reload_collection() -> None:
+        ...     await client.reload_collection("collection_name")
+
+        """
+
+    async def reload_collections_using_config(
+            self, config: str, options: Optional["ReloadCollectionOptions"] = None
+    ) -> List[str]:
+        """
+        Reload every collection using a config set, after it has been changed
+
+        :param config: The name of the config set
+        :param options: Options for the `RELOAD` action of each collection, such as an async id
+        :returns: The names of the reloaded collections
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def update_config() -> List[str]:
+        ...     await client.upload_config("config_name", "path/to/config")
+        ...     return await client.reload_collections_using_config("config_name")
+
+        """
+
+    async def modify_collection(self, collection: str, options: "ModifyCollectionOptions") -> None:
+        """
+        Change the replication factor, config set, properties or read only state of a collection
+
+        :param collection: The name of the collection
+        :param options: The attributes to change
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, ModifyCollectionOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def make_read_only() -> None:
+        ...     await client.modify_collection("collection_name", ModifyCollectionOptions(read_only=True))
+
+        """
+
+    async def rename_collection(self, name: str, target: str, options: Optional["RenameCollectionOptions"] = None) -> None:
+        """
+        Rename a collection. Solr keeps the data under the old name, behind an alias with the new name. Requires Solr 8.1 or later
+
+        :param name: The name of the collection
+        :param target: The new name of the collection
+        :param options: Options for the `RENAME` action, such as an async id
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def rename_collection() -> None:
+        ...     await client.rename_collection("collection_name", "new_collection_name")
+
+        """
+
+    async def split_shard(self, collection: str, options: Optional["SplitShardOptions"] = None) -> None:
+        """
+        Split a shard into sub-shards. The parent shard is kept as `inactive` until it is deleted
+
+        :param collection: The collection of the shard
+        :param options: The shard to split, and how
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, SplitShardOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def split_shard() -> None:
+        ...     await client.split_shard("collection_name", SplitShardOptions(shard="shard1", num_sub_shards=4))
+        ...     await client.delete_shard("collection_name", "shard1")
+
+        """
+
+    async def create_shard(self, collection: str, shard: str, options: Optional["CreateShardOptions"] = None) -> None:
+        """
+        Add a shard to a collection using the implicit router
+
+        :param collection: The collection to add the shard to
+        :param shard: The name of the shard
+        :param options: The replicas of the shard
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, CreateShardOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def create_shard() -> None:
+        ...     await client.create_shard("collection_name", "2025", CreateShardOptions(nrt_replicas=1))
+
+        """
+
+    async def delete_shard(self, collection: str, shard: str, options: Optional["DeleteShardOptions"] = None) -> None:
+        """
+        Delete an inactive shard, or a shard of a collection using the implicit router
+
+        :param collection: The collection of the shard
+        :param shard: The name of the shard
+        :param options: What to delete of the cores of the shard
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def delete_shard() -> None:
+        ...     await client.delete_shard("collection_name", "shard1")
+
+        """
+
+    async def add_replica(self, collection: str, shard: str, options: Optional["AddReplicaOptions"] = None) -> None:
+        """
+        Add replicas to a shard
+
+        :param collection: The collection of the shard
+        :param shard: The shard to add replicas to
+        :param options: The type, number and placement of the replicas
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, AddReplicaOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> from solrstice.models import ReplicaType
+        >>> async def add_replica() -> None:
+        ...     await client.add_replica("collection_name", "shard1", AddReplicaOptions(replica_type=ReplicaType.Pull))
+
+        """
+
+    async def delete_replica(self, collection: str, options: "DeleteReplicaOptions") -> None:
+        """
+        Delete replicas by name, count or node
+
+        :param collection: The collection of the replicas
+        :param options: The replicas to delete
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, DeleteReplicaOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def delete_replica() -> None:
+        ...     await client.delete_replica("collection_name", DeleteReplicaOptions(shard="shard1", replica="core_node3"))
+
+        """
+
+    async def move_replica(self, collection: str, target_node: str, options: "MoveReplicaOptions") -> None:
+        """
+        Move a replica to another node
+
+        :param collection: The collection of the replica
+        :param target_node: The node to move the replica to. Eg: `localhost:7574_solr`
+        :param options: The replica to move
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, MoveReplicaOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def move_replica() -> None:
+        ...     await client.move_replica("collection_name", "localhost:7574_solr", MoveReplicaOptions(replica="core_node3"))
+
+        """
+
+    async def delete_node(self, node: str, options: Optional["DeleteNodeOptions"] = None) -> None:
+        """
+        Delete all replicas on a node. Shards only on that node lose their data, so replace the node first
+
+        :param node: The node. Eg: `localhost:8983_solr`
+        :param options: Options for the `DELETENODE` action, such as an async id
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def decommission_node() -> None:
+        ...     await client.replace_node("localhost:8983_solr")
+        ...     await client.delete_node("localhost:8983_solr")
+
+        """
+
+    async def replace_node(self, source_node: str, options: Optional["ReplaceNodeOptions"] = None) -> None:
+        """
+        Move all replicas on a node to other nodes
+
+        :param source_node: The node to move replicas from. Eg: `localhost:8983_solr`
+        :param options: Where to move the replicas
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, ReplaceNodeOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def replace_node() -> None:
+        ...     await client.replace_node("localhost:8983_solr", ReplaceNodeOptions(target_node="localhost:7574_solr"))
+
+        """
+
+    async def add_replica_property(
+            self,
+            collection: str,
+            shard: str,
+            replica: str,
+            property: str,
+            value: str,
+            options: Optional["ReplicaPropertyOptions"] = None,
+    ) -> None:
+        """
+        Set a property of a replica. Eg: `preferredLeader`
+
+        :param collection: The collection of the replica
+        :param shard: The shard of the replica
+        :param replica: The name of the replica. Eg: `core_node3`
+        :param property: The name of the property
+        :param value: The value of the property
+        :param options: Whether to remove the property from the other replicas of the shard, and an async id
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def prefer_leader() -> None:
+        ...     await client.add_replica_property("collection_name", "shard1", "core_node3", "preferredLeader", "true")
+
+        """
+
+    async def delete_replica_property(
+            self, collection: str, shard: str, replica: str, property: str, options: Optional["ReplicaPropertyOptions"] = None
+    ) -> None:
+        """
+        Remove a property of a replica
+
+        :param collection: The collection of the replica
+        :param shard: The shard of the replica
+        :param replica: The name of the replica. Eg: `core_node3`
+        :param property: The name of the property
+        :param options: Options for the `DELETEREPLICAPROP` action, such as an async id
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def delete_replica_property() -> None:
+        ...     await client.delete_replica_property("collection_name", "shard1", "core_node3", "preferredLeader")
+
+        """
+
+    async def backup_collection(self, collection: str, name: str, options: Optional["BackupOptions"] = None) -> "BackupResult":
+        """
+        Back up a collection to a backup repository. Each incremental backup of the same name adds a backup point
+
+        :param collection: The collection to back up
+        :param name: The name of the backup
+        :param options: Where to write the backup, and how many backup points to keep
+        :return: The backup id, and the files of each shard
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, BackupOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def backup_collection() -> list:
+        ...     result = await client.backup_collection("collection_name", "nightly", BackupOptions(location="/var/solr/backups", max_num_backup_points=7))
+        ...     return [shard.get_index_file_count() for shard in result.get_shards()]
+
+        """
+
+    async def restore_collection(self, collection: str, name: str, options: Optional["RestoreOptions"] = None) -> None:
+        """
+        Restore a backup point into a new collection
+
+        :param collection: The collection to create. It must not exist
+        :param name: The name of the backup
+        :param options: Where the backup was written, and which backup point to restore
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, RestoreOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def restore_collection() -> None:
+        ...     await client.restore_collection("restored_collection", "nightly", RestoreOptions(location="/var/solr/backups", backup_id=3))
+
+        """
+
+    async def list_backups(self, name: str, options: Optional["ListBackupsOptions"] = None) -> List["BackupPoint"]:
+        """
+        List the backup points of a backup. Requires Solr 8.9 or later
+
+        :param name: The name of the backup
+        :param options: Where the backup was written
+        :return: The backup points, oldest first
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, ListBackupsOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def list_backups() -> list:
+        ...     backups = await client.list_backups("nightly", ListBackupsOptions(location="/var/solr/backups"))
+        ...     return [backup.get_backup_id() for backup in backups]
+
+        """
+
+    async def delete_backup(self, name: str, options: "DeleteBackupOptions") -> List["DeletedBackup"]:
+        """
+        Delete backup points, or the files no backup point refers to. Requires Solr 8.9 or later
+
+        :param name: The name of the backup
+        :param options: Where the backup was written, and what to delete
+        :return: The deleted backup points, or the number of purged files
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, DeleteBackupOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def delete_backup() -> None:
+        ...     await client.delete_backup("nightly", DeleteBackupOptions(location="/var/solr/backups", max_num_backup_points=3))
+
+        """
+
+    async def create_alias(
+            self, name: str, collections: List[str], options: Optional["CreateAliasOptions"] = None
+    ) -> None:
+        """
+        Create an alias for a collection on the Solr server
+
+        :param name: The name of the alias to create
+        :param collections: The collections to alias
+        :param options: Options for the `CREATEALIAS` action, such as an async id
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def create_alias() -> None:
+        ...     await client.create_alias("alias_name", ["collection_name"])
+
+        """
+
+    async def get_aliases(self) -> Dict[str, List[str]]:
+        """
+        Get all aliases on the Solr server
+
+        :return: A dictionary of aliases to collections
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> from typing import Dict, List
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def get_aliases() -> Dict[str, List[str]]:
+        ...     return await client.get_aliases()
+
+        """
+
+    async def alias_exists(self, name: str) -> bool:
+        """
+        Check if an alias exists on the Solr server
+
+        :param name: The name of the alias to check
+        :return: True if the alias exists, False otherwise
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def alias_exists() -> bool:
+        ...     return await client.alias_exists("alias_name")
+
+        """
+
+    async def delete_alias(self, name: str, options: Optional["DeleteAliasOptions"] = None) -> None:
+        """
+        Delete an alias from the Solr server
+
+        :param name: The name of the alias to delete
+        :param options: Options for the `DELETEALIAS` action, such as an async id
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def delete_alias() -> None:
+        ...     await client.delete_alias("alias_name")
+
+        """
+
+    async def select(self, builder: "SelectQuery", collection: str, handler: Optional[str] = None) -> "SolrResponse":
+        """Execute a select query
+
+        Also see :class:`SelectQuery`
+
+        :param builder: The query builder
+        :param collection: The collection to query
+        :param handler: The handler, default is "select"
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, SelectQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def select() -> SolrResponse:
+        ...     query = SelectQuery(q="*:*", rows=10)
+        ...     return await client.select(query, "collection_name")
+
+        """
+
+    async def select_raw(self, builder: "SelectQuery", collection: str, handler: Optional[str] = None) -> Dict[
+        str, Any]:
+        """Execute a select query. Return raw response from solr
+
+        Also see :class:`SelectQuery`
+
+        :param builder: The query builder
+        :param collection: The collection to query
+        :param handler: The handler, default is "select"
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, SelectQuery
+        >>> from typing import Dict, Any
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def select_raw() -> Dict[str, Any]:
+        ...     query = SelectQuery(q="*:*", rows=10)
+        ...     return await client.select_raw(query, "collection_name")
+
+        """
+
+    async def index(
+            self, builder: "UpdateQuery", collection: str, data: List[Union[Dict[str, Any], "AtomicUpdate"]]
+    ) -> "SolrResponse":
+        """Execute an index query
+
+        Also see :class:`UpdateQuery`
+
+        :param builder: The query builder
+        :param collection: The collection to index
+        :param data: The data to index
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, UpdateQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def index() -> SolrResponse:
+        ...     query = UpdateQuery()
+        ...     data_to_index = [{"id": "1", "name": "test"}]
+        ...     return await client.index(query, "collection_name", data_to_index)
+
+        """
+
+    async def bulk_index(
+            self, builder: "BulkIndexer", collection: str, docs: Iterable[Union[Dict[str, Any], "AtomicUpdate"]]
+    ) -> "BulkIndexSummary":
+        """Index documents in concurrent batches, with a single commit at the end
+
+        Also see :class:`BulkIndexer`
+
+        :param builder: The bulk indexer
+        :param collection: The collection to index into
+        :param docs: The documents to index, e.g. a generator
+        :returns: A summary of the indexing
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, BulkIndexer
+        >>> from solrstice.models import BulkIndexSummary
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def bulk_index() -> BulkIndexSummary:
+        ...     docs = ({"id": str(i)} for i in range(100_000))
+        ...     return await client.bulk_index(BulkIndexer(batch_size=5000), "collection_name", docs)
+
+        """
+
+    async def delete(self, builder: "DeleteQuery", collection: str) -> "SolrResponse":
+        """Execute a delete query
+
+        Also see :class:`DeleteQuery`
+
+        :param builder: The query builder
+        :param collection: The collection to delete from
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, DeleteQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def delete() -> SolrResponse:
+        ...     query = DeleteQuery(ids=["1"])
+        ...     return await client.delete(query, "collection_name")
+
+        """
+
+    async def commit(self, builder: "CommitQuery", collection: str) -> "SolrResponse":
+        """Commit pending changes
+
+        Also see :class:`CommitQuery`
+
+        :param builder: The query builder
+        :param collection: The collection to commit
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, CommitQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def commit() -> SolrResponse:
+        ...     return await client.commit(CommitQuery(soft_commit=True), "collection_name")
+
+        """
+
+    async def optimize(self, builder: "OptimizeQuery", collection: str) -> "SolrResponse":
+        """Optimize a collection, merging its segments
+
+        Also see :class:`OptimizeQuery`
+
+        :param builder: The query builder
+        :param collection: The collection to optimize
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, OptimizeQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def optimize() -> SolrResponse:
+        ...     return await client.optimize(OptimizeQuery(max_segments=1), "collection_name")
+
+        """
+
+    async def rollback(self, builder: "RollbackQuery", collection: str) -> "SolrResponse":
+        """Roll back all changes since the last commit. Only supported on standalone servers, not in SolrCloud mode
+
+        Also see :class:`RollbackQuery`
+
+        :param builder: The query builder
+        :param collection: The collection to rollback
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, RollbackQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def rollback() -> SolrResponse:
+        ...     return await client.rollback(RollbackQuery(), "core_name")
+
+        """
+
+    async def update(self, batch: "UpdateBatch", collection: str) -> "SolrResponse":
+        """Send several update commands in one request
+
+        Also see :class:`UpdateBatch`
+
+        :param batch: The batch of commands
+        :param collection: The collection to update
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, CommitQuery, UpdateBatch
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def update() -> SolrResponse:
+        ...     batch = UpdateBatch().delete_by_query("category:outdated").add_doc({"id": "1"}).commit(CommitQuery())
+        ...     return await client.update(batch, "collection_name")
+
+        """
+
+    async def index_csv_file(
+            self, builder: "CsvUpdateQuery", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrResponse":
+        """Stream a CSV file to Solr, without parsing it in the client
+
+        Also see :class:`CsvUpdateQuery`
+
+        :param builder: The CsvUpdateQuery builder
+        :param collection: The collection to index into
+        :param path: The path to the file
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, CsvUpdateQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def index_csv_file() -> SolrResponse:
+        ...     return await client.index_csv_file(CsvUpdateQuery(separator=";", split=["interests"]), "collection_name", "export.csv")
+
+        """
+
+    async def index_json_docs_file(
+            self, builder: "JsonDocsUpdateQuery", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrResponse":
+        """Stream a JSON or JSON lines file to Solr, without parsing it in the client
+
+        Also see :class:`JsonDocsUpdateQuery`
+
+        :param builder: The JsonDocsUpdateQuery builder
+        :param collection: The collection to index into
+        :param path: The path to the file
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, JsonDocsUpdateQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
         >>> client = AsyncSolrCloudClient(context)
-        >>> async def alias_exists() -> bool:
-        ...     return await client.alias_exists("alias_name")
+        >>> async def index_json_docs_file() -> SolrResponse:
+        ...     return await client.index_json_docs_file(JsonDocsUpdateQuery(split="/"), "collection_name", "export.ndjson")
 
         """
 
-    async def delete_alias(self, name: str) -> None:
+    async def extract_file(
+            self, builder: "ExtractQuery", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrResponse":
+        """Upload a binary file such as a PDF to Solr Cell, and index the extracted document
+
+        Also see :class:`ExtractQuery`
+
+        :param builder: The ExtractQuery builder
+        :param collection: The collection to use
+        :param path: The path to the file
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, ExtractQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def extract_file() -> SolrResponse:
+        ...     return await client.extract_file(ExtractQuery(literals={"id": "report1"}), "collection_name", "report.pdf")
+
         """
-        Delete an alias from the Solr server
 
-        :param name: The name of the alias to delete
+    async def extract_only_file(
+            self, builder: "ExtractQuery", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrExtractResponse":
+        """Extract the text and metadata of a binary file with Solr Cell, without indexing it
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient
+        Also see :class:`ExtractQuery`
+
+        :param builder: The ExtractQuery builder
+        :param collection: The collection to use
+        :param path: The path to the file
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, ExtractFormat, ExtractQuery
+        >>> from solrstice.models import SolrExtractResponse
         >>> context = SolrServerContext("http://localhost:8983")
         >>> client = AsyncSolrCloudClient(context)
-        >>> async def delete_alias() -> None:
-        ...     await client.delete_alias("alias_name")
+        >>> async def extract_only_file() -> SolrExtractResponse:
+        ...     return await client.extract_only_file(ExtractQuery(extract_format=ExtractFormat.Text), "collection_name", "report.pdf")
 
         """
 
-    async def select(self, builder: "SelectQuery", collection: str, handler: Optional[str] = None) -> "SolrResponse":
-        """Execute a select query
+    async def reindex(self, builder: "Reindexer", source: str, target: str) -> "ReindexSummary":
+        """Copy the documents of one collection into another, on the client
 
-        Also see :class:`SelectQuery`
+        Also see :class:`Reindexer`
+
+        :param builder: The reindexer
+        :param source: The collection to read from
+        :param target: The collection to index into
+        :returns: A summary of the reindex, with the cursor mark to resume from
+
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, Reindexer
+        >>> from solrstice.models import ReindexSummary
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = AsyncSolrCloudClient(context)
+        >>> async def reindex() -> ReindexSummary:
+        ...     reindexer = Reindexer(on_progress=lambda progress: print(progress.get_cursor_mark()))
+        ...     return await client.reindex(reindexer, "old_collection", "new_collection")
+
+        """
+
+    async def reindex_collection(self, builder: "ReindexCollectionQuery", collection: str) -> "SolrReindexStatus":
+        """Reindex a collection on the server, with `REINDEXCOLLECTION`
+
+        Also see :class:`ReindexCollectionQuery`
 
         :param builder: The query builder
-        :param collection: The collection to query
-        :param handler: The handler, default is "select"
-        :returns: The response from the query
+        :param collection: The collection to reindex
+        :returns: The status of the finished reindex
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, SelectQuery
-        >>> from solrstice.models import SolrResponse
+        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, ReindexCollectionQuery
+        >>> from solrstice.models import SolrReindexStatus
         >>> context = SolrServerContext("http://localhost:8983")
         >>> client = AsyncSolrCloudClient(context)
-        >>> async def select() -> SolrResponse:
-        ...     query = SelectQuery(q="*:*", rows=10)
-        ...     return await client.select(query, "collection_name")
+        >>> async def reindex_collection() -> SolrReindexStatus:
+        ...     query = ReindexCollectionQuery(target="new_collection", config_name="new_config")
+        ...     return await client.reindex_collection(query, "old_collection")
+
+        """
+
+
+class BlockingSolrCloudClient:
+    """
+    A client for interacting with a SolrCloud cluster non-asynchronously.
+
+    :param context: The context of the Solr server.
+
+    >>> from solrstice import SolrServerContext, SolrBasicAuth, BlockingSolrCloudClient
+    >>> context = SolrServerContext("http://localhost:8983", SolrBasicAuth("user", "pass"))
+    >>> client = BlockingSolrCloudClient(context)
+
+    """
+
+    def __init__(self, context: "SolrServerContext"):
+        pass
+
+    def upload_config(
+            self, config_name: str, config_path: Union[PathLike[str], str]
+    ) -> None:
+        """Uploads a Solr config to a Solr instance
+
+        :param config_name: Name of the config
+        :param config_path: Path to the config
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def upload_config() -> None:
+        ...     client.upload_config("config_name", "/path/to/config")
+
+        """
+
+    def upload_elevation_config(
+            self, config_name: str, elevation_path: Union[PathLike[str], str]
+    ) -> List[str]:
+        """Uploads a new elevate.xml into an existing config, and reloads every collection using it
+
+        :param config_name: Name of the config
+        :param elevation_path: Path to the elevate.xml file
+        :returns: The names of the reloaded collections
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> from typing import List
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def upload_elevation_config() -> List[str]:
+        ...     return client.upload_elevation_config("config_name", "/path/to/elevate.xml")
+
+        """
+
+    def get_configs(self) -> List[str]:
+        """
+        Gets a list of Solr configs on a Solr instance
+        :returns: A list of Solr configs
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> from typing import List
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def get_configs() -> List[str]:
+        ...     return client.get_configs()
+
+        """
+
+    def config_exists(self, config_name: str) -> bool:
+        """Checks if a Solr config exists on a Solr instance
+
+        :param config_name: Name of the config
+        :returns: True if the config exists, False otherwise
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def config_exists() -> bool:
+        ...     return client.config_exists("config_name")
+
+        """
+
+    def delete_config(self, config_name: str) -> None:
+        """Deletes a Solr config from a Solr instance
+
+        :param config_name: Name of the config
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def delete_config() -> None:
+        ...     client.delete_config("config_name")
+
+        """
+
+    def create_collection(
+            self,
+            name: str,
+            config: str,
+            shards: Optional[int] = 1,
+            replication_factor: Optional[int] = 1,
+    ) -> None:
+        """
+        Create a collection on the Solr server.
+
+        :param name: The name of the collection to create.
+        :param config: The name of the config to use for the collection.
+        :param shards: The number of shards to create.
+        :param replication_factor: The replication factor to use.
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def create_collection() -> None:
+        ...    client.create_collection("collection_name", "config_name", 1, 1)
+
+        """
+
+    def create_collection_with_options(self, name: str, options: "CreateCollectionOptions") -> None:
+        """
+        Create a collection on the Solr server, with all options of the `CREATE` action.
+
+        :param name: The name of the collection to create.
+        :param options: The options for the collection.
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, CollectionRouter, CreateCollectionOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def create_collection_with_options() -> None:
+        ...     options = CreateCollectionOptions(config_name="config_name", router=CollectionRouter.Implicit, shards=["a", "b"])
+        ...     client.create_collection_with_options("collection_name", options)
+
+        """
+
+    def get_collections(self) -> List[str]:
+        """
+        Get the list of collections on the Solr server.
+
+        :return: The list of collections on the Solr server.
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> from typing import List
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def get_collections() -> List[str]:
+        ...     return client.get_collections()
+
+        """
+
+    def collection_exists(self, name: str) -> bool:
+        """
+        Check if a collection exists on the Solr server.
+
+        :param name: The name of the collection to check.
+        :return: True if the collection exists, False otherwise.
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def collection_exists() -> bool:
+        ...     return client.collection_exists("collection_name")
+
+        """
+
+    def get_cluster_status(
+            self, collection: Optional[str] = None, shards: Optional[List[str]] = None
+    ) -> "ClusterStatus":
+        """
+        Get the state of the cluster: its collections with their shards and replicas, aliases and live nodes.
+
+        :param collection: Only return this collection, or the collections of this alias.
+        :param shards: Only return these shards of the collection.
+        :return: The state of the cluster.
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def get_leader_nodes() -> list:
+        ...     status = client.get_cluster_status("collection_name")
+        ...     collection = status.get_collection("collection_name")
+        ...     return [shard.get_leader().get_node_name() for shard in collection.get_shards().values()]
+
+        """
+
+    def get_col_status(
+            self, collection: Optional[str] = None, options: Optional["ColStatusOptions"] = None
+    ) -> "ColStatus":
+        """
+        Get the index status of collections: index size, segments and fields not complying with the schema.
+        Requires Solr 8.1 or later.
+
+        :param collection: Only return this collection.
+        :param options: What to report about the index of each shard leader.
+        :return: The index status of the collections.
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, ColStatusOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def get_index_sizes() -> list:
+        ...     status = client.get_col_status("collection_name", ColStatusOptions(core_info=True))
+        ...     collection = status.get_collection("collection_name")
+        ...     return [shard.get_leader().get_segments_info().get_size_in_gb() for shard in collection.get_shards().values()]
+
+        """
+
+    def delete_collection(self, name: str, options: Optional["DeleteCollectionOptions"] = None) -> None:
+        """
+        Delete a config from the Solr server.
+
+        :param context: The Solr server context.
+        :param name: The name of the collection to delete.
+        :param options: Options for the `DELETE` action, such as an async id
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+
+        >>> def delete_collection() -> None:
+        ...     client.delete_collection("collection_name")
+
+        """
+
+    def reload_collection(self, name: str, options: Optional["ReloadCollectionOptions"] = None) -> None:
+        """
+        Reload a collection, so that changes to its config set take effect
+
+        :param name: The name of the collection
+        :param options: Options for the `RELOAD` action, such as an async id
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def reload_collection() -> None:
+        ...     client.reload_collection("collection_name")
+
+        """
+
+    def reload_collections_using_config(
+            self, config: str, options: Optional["ReloadCollectionOptions"] = None
+    ) -> List[str]:
+        """
+        Reload every collection using a config set, after it has been changed
+
+        :param config: The name of the config set
+        :param options: Options for the `RELOAD` action of each collection, such as an async id
+        :returns: The names of the reloaded collections
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def update_config() -> List[str]:
+        ...     client.upload_config("config_name", "path/to/config")
+        ...     return client.reload_collections_using_config("config_name")
+
+        """
+
+    def modify_collection(self, collection: str, options: "ModifyCollectionOptions") -> None:
+        """
+        Change the replication factor, config set, properties or read only state of a collection
+
+        :param collection: The name of the collection
+        :param options: The attributes to change
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, ModifyCollectionOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def make_read_only() -> None:
+        ...     client.modify_collection("collection_name", ModifyCollectionOptions(read_only=True))
+
+        """
+
+    def rename_collection(self, name: str, target: str, options: Optional["RenameCollectionOptions"] = None) -> None:
+        """
+        Rename a collection. Solr keeps the data under the old name, behind an alias with the new name. Requires Solr 8.1 or later
+
+        :param name: The name of the collection
+        :param target: The new name of the collection
+        :param options: Options for the `RENAME` action, such as an async id
 
-        """
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def rename_collection() -> None:
+        ...     client.rename_collection("collection_name", "new_collection_name")
 
-    async def select_raw(self, builder: "SelectQuery", collection: str, handler: Optional[str] = None) -> Dict[
-        str, Any]:
-        """Execute a select query. Return raw response from solr
+        """
 
-        Also see :class:`SelectQuery`
+    def split_shard(self, collection: str, options: Optional["SplitShardOptions"] = None) -> None:
+        """
+        Split a shard into sub-shards. The parent shard is kept as `inactive` until it is deleted
 
-        :param builder: The query builder
-        :param collection: The collection to query
-        :param handler: The handler, default is "select"
-        :returns: The response from the query
+        :param collection: The collection of the shard
+        :param options: The shard to split, and how
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, SelectQuery
-        >>> from typing import Dict, Any
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, SplitShardOptions
         >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def select_raw() -> Dict[str, Any]:
-        ...     query = SelectQuery(q="*:*", rows=10)
-        ...     return await client.select_raw(query, "collection_name")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def split_shard() -> None:
+        ...     client.split_shard("collection_name", SplitShardOptions(shard="shard1", num_sub_shards=4))
+        ...     client.delete_shard("collection_name", "shard1")
 
         """
 
-    async def index(
-            self, builder: "UpdateQuery", collection: str, data: List[Dict[str, Any]]
-    ) -> "SolrResponse":
-        """Execute an index query
-
-        Also see :class:`UpdateQuery`
+    def create_shard(self, collection: str, shard: str, options: Optional["CreateShardOptions"] = None) -> None:
+        """
+        Add a shard to a collection using the implicit router
 
-        :param builder: The query builder
-        :param collection: The collection to index
-        :param data: The data to index
-        :returns: The response from the query
+        :param collection: The collection to add the shard to
+        :param shard: The name of the shard
+        :param options: The replicas of the shard
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, UpdateQuery
-        >>> from solrstice.models import SolrResponse
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, CreateShardOptions
         >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def index() -> SolrResponse:
-        ...     query = UpdateQuery()
-        ...     data_to_index = [{"id": "1", "name": "test"}]
-        ...     return await client.index(query, "collection_name", data_to_index)
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def create_shard() -> None:
+        ...     client.create_shard("collection_name", "2025", CreateShardOptions(nrt_replicas=1))
 
         """
 
-    async def delete(self, builder: "DeleteQuery", collection: str) -> "SolrResponse":
-        """Execute a delete query
-
-        Also see :class:`DeleteQuery`
+    def delete_shard(self, collection: str, shard: str, options: Optional["DeleteShardOptions"] = None) -> None:
+        """
+        Delete an inactive shard, or a shard of a collection using the implicit router
 
-        :param builder: The query builder
-        :param collection: The collection to delete from
-        :returns: The response from the query
+        :param collection: The collection of the shard
+        :param shard: The name of the shard
+        :param options: What to delete of the cores of the shard
 
-        >>> from solrstice import SolrServerContext, AsyncSolrCloudClient, DeleteQuery
-        >>> from solrstice.models import SolrResponse
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
         >>> context = SolrServerContext("http://localhost:8983")
-        >>> client = AsyncSolrCloudClient(context)
-        >>> async def delete() -> SolrResponse:
-        ...     query = DeleteQuery(ids=["1"])
-        ...     return await client.delete(query, "collection_name")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def delete_shard() -> None:
+        ...     client.delete_shard("collection_name", "shard1")
 
         """
 
+    def add_replica(self, collection: str, shard: str, options: Optional["AddReplicaOptions"] = None) -> None:
+        """
+        Add replicas to a shard
 
-class BlockingSolrCloudClient:
-    """
-    A client for interacting with a SolrCloud cluster non-asynchronously.
+        :param collection: The collection of the shard
+        :param shard: The shard to add replicas to
+        :param options: The type, number and placement of the replicas
 
-    :param context: The context of the Solr server.
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, AddReplicaOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> from solrstice.models import ReplicaType
+        >>> def add_replica() -> None:
+        ...     client.add_replica("collection_name", "shard1", AddReplicaOptions(replica_type=ReplicaType.Pull))
 
-    >>> from solrstice import SolrServerContext, SolrBasicAuth, BlockingSolrCloudClient
-    >>> context = SolrServerContext("http://localhost:8983", SolrBasicAuth("user", "pass"))
-    >>> client = BlockingSolrCloudClient(context)
+        """
 
-    """
+    def delete_replica(self, collection: str, options: "DeleteReplicaOptions") -> None:
+        """
+        Delete replicas by name, count or node
 
-    def __init__(self, context: "SolrServerContext"):
-        pass
+        :param collection: The collection of the replicas
+        :param options: The replicas to delete
 
-    def upload_config(
-            self, config_name: str, config_path: Union[PathLike[str], str]
-    ) -> None:
-        """Uploads a Solr config to a Solr instance
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, DeleteReplicaOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def delete_replica() -> None:
+        ...     client.delete_replica("collection_name", DeleteReplicaOptions(shard="shard1", replica="core_node3"))
 
-        :param config_name: Name of the config
-        :param config_path: Path to the config
+        """
 
-        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+    def move_replica(self, collection: str, target_node: str, options: "MoveReplicaOptions") -> None:
+        """
+        Move a replica to another node
+
+        :param collection: The collection of the replica
+        :param target_node: The node to move the replica to. Eg: `localhost:7574_solr`
+        :param options: The replica to move
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, MoveReplicaOptions
         >>> context = SolrServerContext("http://localhost:8983")
         >>> client = BlockingSolrCloudClient(context)
-        >>> def upload_config() -> None:
-        ...     client.upload_config("config_name", "/path/to/config")
+        >>> def move_replica() -> None:
+        ...     client.move_replica("collection_name", "localhost:7574_solr", MoveReplicaOptions(replica="core_node3"))
 
         """
 
-    def get_configs(self) -> List[str]:
+    def delete_node(self, node: str, options: Optional["DeleteNodeOptions"] = None) -> None:
         """
-        Gets a list of Solr configs on a Solr instance
-        :returns: A list of Solr configs
+        Delete all replicas on a node. Shards only on that node lose their data, so replace the node first
+
+        :param node: The node. Eg: `localhost:8983_solr`
+        :param options: Options for the `DELETENODE` action, such as an async id
 
         >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
-        >>> from typing import List
         >>> context = SolrServerContext("http://localhost:8983")
         >>> client = BlockingSolrCloudClient(context)
-        >>> def get_configs() -> List[str]:
-        ...     return client.get_configs()
+        >>> def decommission_node() -> None:
+        ...     client.replace_node("localhost:8983_solr")
+        ...     client.delete_node("localhost:8983_solr")
 
         """
 
-    def config_exists(self, config_name: str) -> bool:
-        """Checks if a Solr config exists on a Solr instance
+    def replace_node(self, source_node: str, options: Optional["ReplaceNodeOptions"] = None) -> None:
+        """
+        Move all replicas on a node to other nodes
 
-        :param config_name: Name of the config
-        :returns: True if the config exists, False otherwise
+        :param source_node: The node to move replicas from. Eg: `localhost:8983_solr`
+        :param options: Where to move the replicas
 
-        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, ReplaceNodeOptions
         >>> context = SolrServerContext("http://localhost:8983")
         >>> client = BlockingSolrCloudClient(context)
-        >>> def config_exists() -> bool:
-        ...     return client.config_exists("config_name")
+        >>> def replace_node() -> None:
+        ...     client.replace_node("localhost:8983_solr", ReplaceNodeOptions(target_node="localhost:7574_solr"))
 
         """
 
-    def delete_config(self, config_name: str) -> None:
-        """Deletes a Solr config from a Solr instance
+    def add_replica_property(
+            self,
+            collection: str,
+            shard: str,
+            replica: str,
+            property: str,
+            value: str,
+            options: Optional["ReplicaPropertyOptions"] = None,
+    ) -> None:
+        """
+        Set a property of a replica. Eg: `preferredLeader`
 
-        :param config_name: Name of the config
+        :param collection: The collection of the replica
+        :param shard: The shard of the replica
+        :param replica: The name of the replica. Eg: `core_node3`
+        :param property: The name of the property
+        :param value: The value of the property
+        :param options: Whether to remove the property from the other replicas of the shard, and an async id
 
         >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
         >>> context = SolrServerContext("http://localhost:8983")
         >>> client = BlockingSolrCloudClient(context)
-        >>> def delete_config() -> None:
-        ...     client.delete_config("config_name")
+        >>> def prefer_leader() -> None:
+        ...     client.add_replica_property("collection_name", "shard1", "core_node3", "preferredLeader", "true")
 
         """
 
-    def create_collection(
-            self,
-            name: str,
-            config: str,
-            shards: Optional[int] = 1,
-            replication_factor: Optional[int] = 1,
+    def delete_replica_property(
+            self, collection: str, shard: str, replica: str, property: str, options: Optional["ReplicaPropertyOptions"] = None
     ) -> None:
         """
-        Create a collection on the Solr server.
+        Remove a property of a replica
 
-        :param name: The name of the collection to create.
-        :param config: The name of the config to use for the collection.
-        :param shards: The number of shards to create.
-        :param replication_factor: The replication factor to use.
+        :param collection: The collection of the replica
+        :param shard: The shard of the replica
+        :param replica: The name of the replica. Eg: `core_node3`
+        :param property: The name of the property
+        :param options: Options for the `DELETEREPLICAPROP` action, such as an async id
 
         >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
         >>> context = SolrServerContext("http://localhost:8983")
         >>> client = BlockingSolrCloudClient(context)
-        >>> def create_collection() -> None:
-        ...    client.create_collection("collection_name", "config_name", 1, 1)
+        >>> def delete_replica_property() -> None:
+        ...     client.delete_replica_property("collection_name", "shard1", "core_node3", "preferredLeader")
 
         """
 
-    def get_collections(self) -> List[str]:
+    def backup_collection(self, collection: str, name: str, options: Optional["BackupOptions"] = None) -> "BackupResult":
         """
-        Get the list of collections on the Solr server.
+        Back up a collection to a backup repository. Each incremental backup of the same name adds a backup point
 
-        :return: The list of collections on the Solr server.
+        :param collection: The collection to back up
+        :param name: The name of the backup
+        :param options: Where to write the backup, and how many backup points to keep
+        :return: The backup id, and the files of each shard
 
-        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
-        >>> from typing import List
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, BackupOptions
         >>> context = SolrServerContext("http://localhost:8983")
         >>> client = BlockingSolrCloudClient(context)
-        >>> def get_collections() -> List[str]:
-        ...     return client.get_collections()
+        >>> def backup_collection() -> list:
+        ...     result = client.backup_collection("collection_name", "nightly", BackupOptions(location="/var/solr/backups", max_num_backup_points=7))
+        ...     return [shard.get_index_file_count() for shard in result.get_shards()]
 
         """
 
-    def collection_exists(self, name: str) -> bool:
+    def restore_collection(self, collection: str, name: str, options: Optional["RestoreOptions"] = None) -> None:
         """
-        Check if a collection exists on the Solr server.
+        Restore a backup point into a new collection
 
-        :param name: The name of the collection to check.
-        :return: True if the collection exists, False otherwise.
+        :param collection: The collection to create. It must not exist
+        :param name: The name of the backup
+        :param options: Where the backup was written, and which backup point to restore
 
-        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, RestoreOptions
         >>> context = SolrServerContext("http://localhost:8983")
         >>> client = BlockingSolrCloudClient(context)
-        >>> def collection_exists() -> bool:
-        ...     return client.collection_exists("collection_name")
+        >>> def restore_collection() -> None:
+        ...     client.restore_collection("restored_collection", "nightly", RestoreOptions(location="/var/solr/backups", backup_id=3))
 
         """
 
-    def delete_collection(self, name: str) -> None:
+    def list_backups(self, name: str, options: Optional["ListBackupsOptions"] = None) -> List["BackupPoint"]:
         """
-        Delete a config from the Solr server.
+        List the backup points of a backup. Requires Solr 8.9 or later
 
-        :param context: The Solr server context.
-        :param name: The name of the collection to delete.
+        :param name: The name of the backup
+        :param options: Where the backup was written
+        :return: The backup points, oldest first
 
-        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, ListBackupsOptions
         >>> context = SolrServerContext("http://localhost:8983")
         >>> client = BlockingSolrCloudClient(context)
+        >>> def list_backups() -> list:
+        ...     backups = client.list_backups("nightly", ListBackupsOptions(location="/var/solr/backups"))
+        ...     return [backup.get_backup_id() for backup in backups]
 
-        >>> def delete_collection() -> None:
-        ...     client.delete_collection("collection_name")
+        """
+
+    def delete_backup(self, name: str, options: "DeleteBackupOptions") -> List["DeletedBackup"]:
+        """
+        Delete backup points, or the files no backup point refers to. Requires Solr 8.9 or later
+
+        :param name: The name of the backup
+        :param options: Where the backup was written, and what to delete
+        :return: The deleted backup points, or the number of purged files
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, DeleteBackupOptions
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def delete_backup() -> None:
+        ...     client.delete_backup("nightly", DeleteBackupOptions(location="/var/solr/backups", max_num_backup_points=3))
 
         """
 
-    def create_alias(self, name: str, collections: List[str]) -> None:
+    def create_alias(
+            self, name: str, collections: List[str], options: Optional["CreateAliasOptions"] = None
+    ) -> None:
         """
         Create an alias for a collection on the Solr server
 
         :param name: The name of the alias to create
         :param collections: The collections to alias
+        :param options: Options for the `CREATEALIAS` action, such as an async id
 
         >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
         >>> context = SolrServerContext("http://localhost:8983")
@@ -1194,11 +3472,12 @@ class BlockingSolrCloudClient:
 
         """
 
-    def delete_alias(self, name: str) -> None:
+    def delete_alias(self, name: str, options: Optional["DeleteAliasOptions"] = None) -> None:
         """
         Delete an alias from the Solr server
 
         :param name: The name of the alias to delete
+        :param options: Options for the `DELETEALIAS` action, such as an async id
 
         >>> from solrstice import SolrServerContext, BlockingSolrCloudClient
         >>> context = SolrServerContext("http://localhost:8983")
@@ -1249,7 +3528,7 @@ class BlockingSolrCloudClient:
         """
 
     def index(
-            self, builder: "UpdateQuery", collection: str, data: List[Dict[str, Any]]
+            self, builder: "UpdateQuery", collection: str, data: List[Union[Dict[str, Any], "AtomicUpdate"]]
     ) -> "SolrResponse":
         """Execute an index query
 
@@ -1270,6 +3549,28 @@ class BlockingSolrCloudClient:
 
         """
 
+    def bulk_index(
+            self, builder: "BulkIndexer", collection: str, docs: Iterable[Union[Dict[str, Any], "AtomicUpdate"]]
+    ) -> "BulkIndexSummary":
+        """Index documents in concurrent batches, with a single commit at the end
+
+        Also see :class:`BulkIndexer`
+
+        :param builder: The bulk indexer
+        :param collection: The collection to index into
+        :param docs: The documents to index, e.g. a generator
+        :returns: A summary of the indexing
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, BulkIndexer
+        >>> from solrstice.models import BulkIndexSummary
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def bulk_index() -> BulkIndexSummary:
+        ...     docs = ({"id": str(i)} for i in range(100_000))
+        ...     return client.bulk_index(BulkIndexer(batch_size=5000), "collection_name", docs)
+
+        """
+
     def delete(self, builder: "DeleteQuery", collection: str) -> "SolrResponse":
         """Execute a delete query
 
@@ -1287,4 +3588,192 @@ class BlockingSolrCloudClient:
 
         """
 
+    def commit(self, builder: "CommitQuery", collection: str) -> "SolrResponse":
+        """Commit pending changes
+
+        :param builder: The query builder
+        :param collection: The collection to commit
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, CommitQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def commit() -> SolrResponse:
+        ...     return client.commit(CommitQuery(soft_commit=True), "collection_name")
+
+        """
+
+    def optimize(self, builder: "OptimizeQuery", collection: str) -> "SolrResponse":
+        """Optimize a collection, merging its segments
+
+        :param builder: The query builder
+        :param collection: The collection to optimize
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, OptimizeQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def optimize() -> SolrResponse:
+        ...     return client.optimize(OptimizeQuery(max_segments=1), "collection_name")
+
+        """
+
+    def rollback(self, builder: "RollbackQuery", collection: str) -> "SolrResponse":
+        """Roll back all changes since the last commit. Only supported on standalone servers, not in SolrCloud mode
+
+        :param builder: The query builder
+        :param collection: The collection to rollback
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, RollbackQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def rollback() -> SolrResponse:
+        ...     return client.rollback(RollbackQuery(), "core_name")
+
+        """
+
+    def update(self, batch: "UpdateBatch", collection: str) -> "SolrResponse":
+        """Send several update commands in one request
+
+        :param batch: The batch of commands
+        :param collection: The collection to update
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, CommitQuery, UpdateBatch
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def update() -> SolrResponse:
+        ...     batch = UpdateBatch().delete_by_query("category:outdated").add_doc({"id": "1"}).commit(CommitQuery())
+        ...     return client.update(batch, "collection_name")
+
+        """
+
+    def index_csv_file(
+            self, builder: "CsvUpdateQuery", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrResponse":
+        """Stream a CSV file to Solr, without parsing it in the client
+
+        Also see :class:`CsvUpdateQuery`
+
+        :param builder: The CsvUpdateQuery builder
+        :param collection: The collection to index into
+        :param path: The path to the file
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, CsvUpdateQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def index_csv_file() -> SolrResponse:
+        ...     return client.index_csv_file(CsvUpdateQuery(separator=";", split=["interests"]), "collection_name", "export.csv")
+
+        """
+
+    def index_json_docs_file(
+            self, builder: "JsonDocsUpdateQuery", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrResponse":
+        """Stream a JSON or JSON lines file to Solr, without parsing it in the client
+
+        Also see :class:`JsonDocsUpdateQuery`
+
+        :param builder: The JsonDocsUpdateQuery builder
+        :param collection: The collection to index into
+        :param path: The path to the file
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, JsonDocsUpdateQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def index_json_docs_file() -> SolrResponse:
+        ...     return client.index_json_docs_file(JsonDocsUpdateQuery(split="/"), "collection_name", "export.ndjson")
+
+        """
+
+    def extract_file(
+            self, builder: "ExtractQuery", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrResponse":
+        """Upload a binary file such as a PDF to Solr Cell, and index the extracted document
+
+        Also see :class:`ExtractQuery`
+
+        :param builder: The ExtractQuery builder
+        :param collection: The collection to use
+        :param path: The path to the file
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, ExtractQuery
+        >>> from solrstice.models import SolrResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def extract_file() -> SolrResponse:
+        ...     return client.extract_file(ExtractQuery(literals={"id": "report1"}), "collection_name", "report.pdf")
+
+        """
+
+    def extract_only_file(
+            self, builder: "ExtractQuery", collection: str, path: Union[PathLike[str], str]
+    ) -> "SolrExtractResponse":
+        """Extract the text and metadata of a binary file with Solr Cell, without indexing it
+
+        Also see :class:`ExtractQuery`
+
+        :param builder: The ExtractQuery builder
+        :param collection: The collection to use
+        :param path: The path to the file
+        :returns: The response from the query
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, ExtractFormat, ExtractQuery
+        >>> from solrstice.models import SolrExtractResponse
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def extract_only_file() -> SolrExtractResponse:
+        ...     return client.extract_only_file(ExtractQuery(extract_format=ExtractFormat.Text), "collection_name", "report.pdf")
+
+        """
+
+    def reindex(self, builder: "Reindexer", source: str, target: str) -> "ReindexSummary":
+        """Copy the documents of one collection into another, on the client
+
+        Also see :class:`Reindexer`
+
+        :param builder: The reindexer
+        :param source: The collection to read from
+        :param target: The collection to index into
+        :returns: A summary of the reindex, with the cursor mark to resume from
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, Reindexer
+        >>> from solrstice.models import ReindexSummary
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def reindex() -> ReindexSummary:
+        ...     reindexer = Reindexer(on_progress=lambda progress: print(progress.get_cursor_mark()))
+        ...     return client.reindex(reindexer, "old_collection", "new_collection")
+
+        """
+
+    def reindex_collection(self, builder: "ReindexCollectionQuery", collection: str) -> "SolrReindexStatus":
+        """Reindex a collection on the server, with `REINDEXCOLLECTION`
+
+        Also see :class:`ReindexCollectionQuery`
+
+        :param builder: The query builder
+        :param collection: The collection to reindex
+        :returns: The status of the finished reindex
+
+        >>> from solrstice import SolrServerContext, BlockingSolrCloudClient, ReindexCollectionQuery
+        >>> from solrstice.models import SolrReindexStatus
+        >>> context = SolrServerContext("http://localhost:8983")
+        >>> client = BlockingSolrCloudClient(context)
+        >>> def reindex_collection() -> SolrReindexStatus:
+        ...     query = ReindexCollectionQuery(target="new_collection", config_name="new_config")
+        ...     return client.reindex_collection(query, "old_collection")
+
+        """
+
 # endregion
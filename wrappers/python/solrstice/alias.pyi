@@ -1,10 +1,13 @@
-from typing import TYPE_CHECKING, Dict, List
+from typing import TYPE_CHECKING, Dict, List, Optional
 
 if TYPE_CHECKING:
-    from solrstice import SolrServerContext
+    from solrstice import CreateAliasOptions, DeleteAliasOptions, SolrServerContext
 
 async def create_alias(
-    context: "SolrServerContext", name: str, collections: List[str]
+    context: "SolrServerContext",
+    name: str,
+    collections: List[str],
+    options: Optional["CreateAliasOptions"] = None,
 ) -> None:
     """
     Create an alias for a collection on the Solr server
@@ -12,10 +15,14 @@ async def create_alias(
     :param context: The Solr server context
     :param name: The name of the alias to create
     :param collections: The collections to alias
+    :param options: Options for the `CREATEALIAS` action, such as an async id
     """
 
 def create_alias_blocking(
-    context: "SolrServerContext", name: str, collections: List[str]
+    context: "SolrServerContext",
+    name: str,
+    collections: List[str],
+    options: Optional["CreateAliasOptions"] = None,
 ) -> None:
     """
     Create an alias for a collection on the Solr server
@@ -23,6 +30,7 @@ def create_alias_blocking(
     :param context: The Solr server context
     :param name: The name of the alias to create
     :param collections: The collections to alias
+    :param options: Options for the `CREATEALIAS` action, such as an async id
     """
 
 async def get_aliases(context: "SolrServerContext") -> Dict[str, List[str]]:
@@ -59,20 +67,30 @@ def alias_exists_blocking(context: "SolrServerContext", name: str) -> bool:
     :return: True if the alias exists, False otherwise
     """
 
-async def delete_alias(context: "SolrServerContext", name: str) -> None:
+async def delete_alias(
+    context: "SolrServerContext",
+    name: str,
+    options: Optional["DeleteAliasOptions"] = None,
+) -> None:
     """
     Delete an alias from the Solr server
 
     :param context: The Solr server context
     :param name: The name of the alias to delete
+    :param options: Options for the `DELETEALIAS` action, such as an async id
     """
 
-def delete_alias_blocking(context: "SolrServerContext", name: str) -> None:
+def delete_alias_blocking(
+    context: "SolrServerContext",
+    name: str,
+    options: Optional["DeleteAliasOptions"] = None,
+) -> None:
     """
     Delete an alias from the Solr server
 
     :param context: The Solr server context
     :param name: The name of the alias to delete
+    :param options: Options for the `DELETEALIAS` action, such as an async id
     """
 
 __all__ = [
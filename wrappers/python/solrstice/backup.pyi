@@ -0,0 +1,140 @@
+from typing import TYPE_CHECKING, List, Optional
+
+if TYPE_CHECKING:
+    from solrstice import (
+        BackupOptions,
+        DeleteBackupOptions,
+        ListBackupsOptions,
+        RestoreOptions,
+        SolrServerContext,
+    )
+    from solrstice.models import BackupPoint, BackupResult, DeletedBackup
+
+async def backup_collection(
+    context: "SolrServerContext",
+    collection: str,
+    name: str,
+    options: Optional["BackupOptions"] = None,
+) -> "BackupResult":
+    """
+    Back up a collection to a backup repository. Each incremental backup of the same name adds a backup point.
+
+    :param context: The Solr server context.
+    :param collection: The collection to back up.
+    :param name: The name of the backup.
+    :param options: Where to write the backup, and how many backup points to keep.
+    :return: The backup id, and the files of each shard.
+    """
+
+def backup_collection_blocking(
+    context: "SolrServerContext",
+    collection: str,
+    name: str,
+    options: Optional["BackupOptions"] = None,
+) -> "BackupResult":
+    """
+    Back up a collection to a backup repository. Each incremental backup of the same name adds a backup point.
+
+    :param context: The Solr server context.
+    :param collection: The collection to back up.
+    :param name: The name of the backup.
+    :param options: Where to write the backup, and how many backup points to keep.
+    :return: The backup id, and the files of each shard.
+    """
+
+async def restore_collection(
+    context: "SolrServerContext",
+    collection: str,
+    name: str,
+    options: Optional["RestoreOptions"] = None,
+) -> None:
+    """
+    Restore a backup point into a new collection.
+
+    :param context: The Solr server context.
+    :param collection: The collection to create. It must not exist.
+    :param name: The name of the backup.
+    :param options: Where the backup was written, and which backup point to restore.
+    """
+
+def restore_collection_blocking(
+    context: "SolrServerContext",
+    collection: str,
+    name: str,
+    options: Optional["RestoreOptions"] = None,
+) -> None:
+    """
+    Restore a backup point into a new collection.
+
+    :param context: The Solr server context.
+    :param collection: The collection to create. It must not exist.
+    :param name: The name of the backup.
+    :param options: Where the backup was written, and which backup point to restore.
+    """
+
+async def list_backups(
+    context: "SolrServerContext",
+    name: str,
+    options: Optional["ListBackupsOptions"] = None,
+) -> List["BackupPoint"]:
+    """
+    List the backup points of a backup. Requires Solr 8.9 or later.
+
+    :param context: The Solr server context.
+    :param name: The name of the backup.
+    :param options: Where the backup was written.
+    :return: The backup points, oldest first.
+    """
+
+def list_backups_blocking(
+    context: "SolrServerContext",
+    name: str,
+    options: Optional["ListBackupsOptions"] = None,
+) -> List["BackupPoint"]:
+    """
+    List the backup points of a backup. Requires Solr 8.9 or later.
+
+    :param context: The Solr server context.
+    :param name: The name of the backup.
+    :param options: Where the backup was written.
+    :return: The backup points, oldest first.
+    """
+
+async def delete_backup(
+    context: "SolrServerContext",
+    name: str,
+    options: "DeleteBackupOptions",
+) -> List["DeletedBackup"]:
+    """
+    Delete backup points, or the files no backup point refers to. Requires Solr 8.9 or later.
+
+    :param context: The Solr server context.
+    :param name: The name of the backup.
+    :param options: Where the backup was written, and what to delete.
+    :return: The deleted backup points, or the number of purged files.
+    """
+
+def delete_backup_blocking(
+    context: "SolrServerContext",
+    name: str,
+    options: "DeleteBackupOptions",
+) -> List["DeletedBackup"]:
+    """
+    Delete backup points, or the files no backup point refers to. Requires Solr 8.9 or later.
+
+    :param context: The Solr server context.
+    :param name: The name of the backup.
+    :param options: Where the backup was written, and what to delete.
+    :return: The deleted backup points, or the number of purged files.
+    """
+
+__all__ = [
+    "backup_collection",
+    "backup_collection_blocking",
+    "restore_collection",
+    "restore_collection_blocking",
+    "list_backups",
+    "list_backups_blocking",
+    "delete_backup",
+    "delete_backup_blocking",
+]
@@ -1,7 +1,25 @@
 from typing import TYPE_CHECKING, List, Optional
 
 if TYPE_CHECKING:
-    from solrstice import SolrServerContext
+    from solrstice import (
+        AddReplicaOptions,
+        ColStatusOptions,
+        CreateCollectionOptions,
+        CreateShardOptions,
+        DeleteCollectionOptions,
+        DeleteNodeOptions,
+        DeleteReplicaOptions,
+        DeleteShardOptions,
+        ModifyCollectionOptions,
+        MoveReplicaOptions,
+        ReloadCollectionOptions,
+        RenameCollectionOptions,
+        ReplaceNodeOptions,
+        ReplicaPropertyOptions,
+        SolrServerContext,
+        SplitShardOptions,
+    )
+    from solrstice.models import ClusterStatus, ColStatus
 
 async def create_collection(
     context: "SolrServerContext",
@@ -37,6 +55,32 @@ def create_collection_blocking(
     :param replication_factor: The replication factor to use.
     """
 
+async def create_collection_with_options(
+    context: "SolrServerContext",
+    name: str,
+    options: "CreateCollectionOptions",
+) -> None:
+    """
+    Create a collection on the Solr server, with all options of the `CREATE` action.
+
+    :param context: The Solr server context.
+    :param name: The name of the collection to create.
+    :param options: The options for the collection.
+    """
+
+def create_collection_with_options_blocking(
+    context: "SolrServerContext",
+    name: str,
+    options: "CreateCollectionOptions",
+) -> None:
+    """
+    Create a collection on the Solr server, with all options of the `CREATE` action.
+
+    :param context: The Solr server context.
+    :param name: The name of the collection to create.
+    :param options: The options for the collection.
+    """
+
 async def get_collections(context: "SolrServerContext") -> List[str]:
     """
     Get the list of collections on the Solr server.
@@ -71,29 +115,547 @@ def collection_exists_blocking(context: "SolrServerContext", name: str) -> bool:
     :return: True if the collection exists, False otherwise.
     """
 
-async def delete_collection(context: "SolrServerContext", name: str) -> None:
+async def get_cluster_status(
+    context: "SolrServerContext",
+    collection: Optional[str] = None,
+    shards: Optional[List[str]] = None,
+) -> "ClusterStatus":
+    """
+    Get the state of the cluster: its collections with their shards and replicas, aliases and live nodes.
+
+    :param context: The Solr server context.
+    :param collection: Only return this collection, or the collections of this alias.
+    :param shards: Only return these shards of the collection.
+    :return: The state of the cluster.
+    """
+
+def get_cluster_status_blocking(
+    context: "SolrServerContext",
+    collection: Optional[str] = None,
+    shards: Optional[List[str]] = None,
+) -> "ClusterStatus":
+    """
+    Get the state of the cluster: its collections with their shards and replicas, aliases and live nodes.
+
+    :param context: The Solr server context.
+    :param collection: Only return this collection, or the collections of this alias.
+    :param shards: Only return these shards of the collection.
+    :return: The state of the cluster.
+    """
+
+async def get_col_status(
+    context: "SolrServerContext",
+    collection: Optional[str] = None,
+    options: Optional["ColStatusOptions"] = None,
+) -> "ColStatus":
+    """
+    Get the index status of collections: index size, segments and fields not complying with the schema.
+    Requires Solr 8.1 or later.
+
+    :param context: The Solr server context.
+    :param collection: Only return this collection.
+    :param options: What to report about the index of each shard leader.
+    :return: The index status of the collections.
+    """
+
+def get_col_status_blocking(
+    context: "SolrServerContext",
+    collection: Optional[str] = None,
+    options: Optional["ColStatusOptions"] = None,
+) -> "ColStatus":
+    """
+    Get the index status of collections: index size, segments and fields not complying with the schema.
+    Requires Solr 8.1 or later.
+
+    :param context: The Solr server context.
+    :param collection: Only return this collection.
+    :param options: What to report about the index of each shard leader.
+    :return: The index status of the collections.
+    """
+
+async def delete_collection(
+    context: "SolrServerContext",
+    name: str,
+    options: Optional["DeleteCollectionOptions"] = None,
+) -> None:
     """
     Delete a config from the Solr server.
 
     :param context: The Solr server context.
     :param name: The name of the collection to delete.
+    :param options: Options for the `DELETE` action, such as an async id.
     """
 
-def delete_collection_blocking(context: "SolrServerContext", name: str) -> None:
+def delete_collection_blocking(
+    context: "SolrServerContext",
+    name: str,
+    options: Optional["DeleteCollectionOptions"] = None,
+) -> None:
     """
     Delete a config from the Solr server.
 
     :param context: The Solr server context.
     :param name: The name of the collection to delete.
+    :param options: Options for the `DELETE` action, such as an async id.
+    """
+
+async def reload_collection(
+    context: "SolrServerContext",
+    name: str,
+    options: Optional["ReloadCollectionOptions"] = None,
+) -> None:
+    """
+    Reload a collection, so that changes to its config set take effect.
+
+    :param context: The Solr server context.
+    :param name: The name of the collection.
+    :param options: Options for the `RELOAD` action, such as an async id.
+    """
+
+def reload_collection_blocking(
+    context: "SolrServerContext",
+    name: str,
+    options: Optional["ReloadCollectionOptions"] = None,
+) -> None:
+    """
+    Reload a collection, so that changes to its config set take effect.
+
+    :param context: The Solr server context.
+    :param name: The name of the collection.
+    :param options: Options for the `RELOAD` action, such as an async id.
+    """
+
+async def reload_collections_using_config(
+    context: "SolrServerContext",
+    config: str,
+    options: Optional["ReloadCollectionOptions"] = None,
+) -> List[str]:
+    """
+    Reload every collection using a config set, after it has been changed.
+
+    :param context: The Solr server context.
+    :param config: The name of the config set.
+    :param options: Options for the `RELOAD` action of each collection, such as an async id.
+    :return: The names of the reloaded collections.
+    """
+
+def reload_collections_using_config_blocking(
+    context: "SolrServerContext",
+    config: str,
+    options: Optional["ReloadCollectionOptions"] = None,
+) -> List[str]:
+    """
+    Reload every collection using a config set, after it has been changed.
+
+    :param context: The Solr server context.
+    :param config: The name of the config set.
+    :param options: Options for the `RELOAD` action of each collection, such as an async id.
+    :return: The names of the reloaded collections.
+    """
+
+async def modify_collection(
+    context: "SolrServerContext",
+    collection: str,
+    options: "ModifyCollectionOptions",
+) -> None:
+    """
+    Change the replication factor, config set, properties or read only state of a collection.
+
+    :param context: The Solr server context.
+    :param collection: The name of the collection.
+    :param options: The attributes to change.
+    """
+
+def modify_collection_blocking(
+    context: "SolrServerContext",
+    collection: str,
+    options: "ModifyCollectionOptions",
+) -> None:
+    """
+    Change the replication factor, config set, properties or read only state of a collection.
+
+    :param context: The Solr server context.
+    :param collection: The name of the collection.
+    :param options: The attributes to change.
+    """
+
+async def rename_collection(
+    context: "SolrServerContext",
+    name: str,
+    target: str,
+    options: Optional["RenameCollectionOptions"] = None,
+) -> None:
+    """
+    Rename a collection. Solr keeps the data under the old name, behind an alias with the new name.
+    Requires Solr 8.1 or later.
+
+    :param context: The Solr server context.
+    :param name: The name of the collection.
+    :param target: The new name of the collection.
+    :param options: Options for the `RENAME` action, such as an async id.
+    """
+
+def rename_collection_blocking(
+    context: "SolrServerContext",
+    name: str,
+    target: str,
+    options: Optional["RenameCollectionOptions"] = None,
+) -> None:
+    """
+    Rename a collection. Solr keeps the data under the old name, behind an alias with the new name.
+    Requires Solr 8.1 or later.
+
+    :param context: The Solr server context.
+    :param name: The name of the collection.
+    :param target: The new name of the collection.
+    :param options: Options for the `RENAME` action, such as an async id.
+    """
+
+async def split_shard(
+    context: "SolrServerContext",
+    collection: str,
+    options: Optional["SplitShardOptions"] = None,
+) -> None:
+    """
+    Split a shard into sub-shards. The parent shard is kept as `inactive` until it is deleted.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the shard.
+    :param options: The shard to split, and how.
+    """
+
+def split_shard_blocking(
+    context: "SolrServerContext",
+    collection: str,
+    options: Optional["SplitShardOptions"] = None,
+) -> None:
+    """
+    Split a shard into sub-shards. The parent shard is kept as `inactive` until it is deleted.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the shard.
+    :param options: The shard to split, and how.
+    """
+
+async def create_shard(
+    context: "SolrServerContext",
+    collection: str,
+    shard: str,
+    options: Optional["CreateShardOptions"] = None,
+) -> None:
+    """
+    Add a shard to a collection using the implicit router.
+
+    :param context: The Solr server context.
+    :param collection: The collection to add the shard to.
+    :param shard: The name of the shard.
+    :param options: The replicas of the shard.
+    """
+
+def create_shard_blocking(
+    context: "SolrServerContext",
+    collection: str,
+    shard: str,
+    options: Optional["CreateShardOptions"] = None,
+) -> None:
+    """
+    Add a shard to a collection using the implicit router.
+
+    :param context: The Solr server context.
+    :param collection: The collection to add the shard to.
+    :param shard: The name of the shard.
+    :param options: The replicas of the shard.
+    """
+
+async def delete_shard(
+    context: "SolrServerContext",
+    collection: str,
+    shard: str,
+    options: Optional["DeleteShardOptions"] = None,
+) -> None:
+    """
+    Delete an inactive shard, or a shard of a collection using the implicit router.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the shard.
+    :param shard: The name of the shard.
+    :param options: What to delete of the cores of the shard.
+    """
+
+def delete_shard_blocking(
+    context: "SolrServerContext",
+    collection: str,
+    shard: str,
+    options: Optional["DeleteShardOptions"] = None,
+) -> None:
+    """
+    Delete an inactive shard, or a shard of a collection using the implicit router.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the shard.
+    :param shard: The name of the shard.
+    :param options: What to delete of the cores of the shard.
+    """
+
+async def add_replica(
+    context: "SolrServerContext",
+    collection: str,
+    shard: str,
+    options: Optional["AddReplicaOptions"] = None,
+) -> None:
+    """
+    Add replicas to a shard.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the shard.
+    :param shard: The shard to add replicas to.
+    :param options: The type, number and placement of the replicas.
+    """
+
+def add_replica_blocking(
+    context: "SolrServerContext",
+    collection: str,
+    shard: str,
+    options: Optional["AddReplicaOptions"] = None,
+) -> None:
+    """
+    Add replicas to a shard.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the shard.
+    :param shard: The shard to add replicas to.
+    :param options: The type, number and placement of the replicas.
+    """
+
+async def delete_replica(
+    context: "SolrServerContext",
+    collection: str,
+    options: "DeleteReplicaOptions",
+) -> None:
+    """
+    Delete replicas by name, count or node.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the replicas.
+    :param options: The replicas to delete.
+    """
+
+def delete_replica_blocking(
+    context: "SolrServerContext",
+    collection: str,
+    options: "DeleteReplicaOptions",
+) -> None:
+    """
+    Delete replicas by name, count or node.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the replicas.
+    :param options: The replicas to delete.
+    """
+
+async def move_replica(
+    context: "SolrServerContext",
+    collection: str,
+    target_node: str,
+    options: "MoveReplicaOptions",
+) -> None:
+    """
+    Move a replica to another node.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the replica.
+    :param target_node: The node to move the replica to.
+    :param options: The replica to move.
+    """
+
+def move_replica_blocking(
+    context: "SolrServerContext",
+    collection: str,
+    target_node: str,
+    options: "MoveReplicaOptions",
+) -> None:
+    """
+    Move a replica to another node.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the replica.
+    :param target_node: The node to move the replica to.
+    :param options: The replica to move.
+    """
+
+async def delete_node(
+    context: "SolrServerContext",
+    node: str,
+    options: Optional["DeleteNodeOptions"] = None,
+) -> None:
+    """
+    Delete all replicas on a node.
+
+    :param context: The Solr server context.
+    :param node: The node. Eg: `localhost:8983_solr`.
+    :param options: Options for the `DELETENODE` action, such as an async id.
+    """
+
+def delete_node_blocking(
+    context: "SolrServerContext",
+    node: str,
+    options: Optional["DeleteNodeOptions"] = None,
+) -> None:
+    """
+    Delete all replicas on a node.
+
+    :param context: The Solr server context.
+    :param node: The node. Eg: `localhost:8983_solr`.
+    :param options: Options for the `DELETENODE` action, such as an async id.
+    """
+
+async def replace_node(
+    context: "SolrServerContext",
+    source_node: str,
+    options: Optional["ReplaceNodeOptions"] = None,
+) -> None:
+    """
+    Move all replicas on a node to other nodes.
+
+    :param context: The Solr server context.
+    :param source_node: The node to move replicas from.
+    :param options: Where to move the replicas.
+    """
+
+def replace_node_blocking(
+    context: "SolrServerContext",
+    source_node: str,
+    options: Optional["ReplaceNodeOptions"] = None,
+) -> None:
+    """
+    Move all replicas on a node to other nodes.
+
+    :param context: The Solr server context.
+    :param source_node: The node to move replicas from.
+    :param options: Where to move the replicas.
+    """
+
+async def add_replica_property(
+    context: "SolrServerContext",
+    collection: str,
+    shard: str,
+    replica: str,
+    property: str,
+    value: str,
+    options: Optional["ReplicaPropertyOptions"] = None,
+) -> None:
+    """
+    Set a property of a replica.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the replica.
+    :param shard: The shard of the replica.
+    :param replica: The name of the replica.
+    :param property: The name of the property. Eg: `preferredLeader`.
+    :param value: The value of the property.
+    :param options: Whether to remove the property from the other replicas of the shard, and an async id.
+    """
+
+def add_replica_property_blocking(
+    context: "SolrServerContext",
+    collection: str,
+    shard: str,
+    replica: str,
+    property: str,
+    value: str,
+    options: Optional["ReplicaPropertyOptions"] = None,
+) -> None:
+    """
+    Set a property of a replica.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the replica.
+    :param shard: The shard of the replica.
+    :param replica: The name of the replica.
+    :param property: The name of the property. Eg: `preferredLeader`.
+    :param value: The value of the property.
+    :param options: Whether to remove the property from the other replicas of the shard, and an async id.
+    """
+
+async def delete_replica_property(
+    context: "SolrServerContext",
+    collection: str,
+    shard: str,
+    replica: str,
+    property: str,
+    options: Optional["ReplicaPropertyOptions"] = None,
+) -> None:
+    """
+    Remove a property of a replica.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the replica.
+    :param shard: The shard of the replica.
+    :param replica: The name of the replica.
+    :param property: The name of the property.
+    :param options: Options for the `DELETEREPLICAPROP` action, such as an async id.
+    """
+
+def delete_replica_property_blocking(
+    context: "SolrServerContext",
+    collection: str,
+    shard: str,
+    replica: str,
+    property: str,
+    options: Optional["ReplicaPropertyOptions"] = None,
+) -> None:
+    """
+    Remove a property of a replica.
+
+    :param context: The Solr server context.
+    :param collection: The collection of the replica.
+    :param shard: The shard of the replica.
+    :param replica: The name of the replica.
+    :param property: The name of the property.
+    :param options: Options for the `DELETEREPLICAPROP` action, such as an async id.
     """
 
 __all__ = [
     "create_collection",
     "create_collection_blocking",
+    "create_collection_with_options",
+    "create_collection_with_options_blocking",
     "get_collections",
     "get_collections_blocking",
     "collection_exists",
     "collection_exists_blocking",
+    "get_cluster_status",
+    "get_cluster_status_blocking",
+    "get_col_status",
+    "get_col_status_blocking",
     "delete_collection",
     "delete_collection_blocking",
+    "reload_collection",
+    "reload_collection_blocking",
+    "reload_collections_using_config",
+    "reload_collections_using_config_blocking",
+    "modify_collection",
+    "modify_collection_blocking",
+    "rename_collection",
+    "rename_collection_blocking",
+    "split_shard",
+    "split_shard_blocking",
+    "create_shard",
+    "create_shard_blocking",
+    "delete_shard",
+    "delete_shard_blocking",
+    "add_replica",
+    "add_replica_blocking",
+    "delete_replica",
+    "delete_replica_blocking",
+    "move_replica",
+    "move_replica_blocking",
+    "delete_node",
+    "delete_node_blocking",
+    "replace_node",
+    "replace_node_blocking",
+    "add_replica_property",
+    "add_replica_property_blocking",
+    "delete_replica_property",
+    "delete_replica_property_blocking",
 ]
@@ -76,6 +76,30 @@ def get_configs_blocking(context: "SolrServerContext") -> List[str]:
     """
     pass
 
+async def upload_elevation_config(
+    context: "SolrServerContext", config_name: str, elevation_path: Somepath
+) -> List[str]:
+    """Uploads a new elevate.xml into an existing config, and reloads every collection using it
+
+    :param context: SolrServerRequest context
+    :param config_name: Name of the config
+    :param elevation_path: Path to the elevate.xml file
+    :returns: The names of the reloaded collections
+    """
+    pass
+
+def upload_elevation_config_blocking(
+    context: "SolrServerContext", config_name: str, elevation_path: Somepath
+) -> List[str]:
+    """Uploads a new elevate.xml into an existing config, and reloads every collection using it
+
+    :param context: SolrServerRequest context
+    :param config_name: Name of the config
+    :param elevation_path: Path to the elevate.xml file
+    :returns: The names of the reloaded collections
+    """
+    pass
+
 __all__ = [
     "upload_config",
     "upload_config_blocking",
@@ -85,4 +109,6 @@ __all__ = [
     "config_exists_blocking",
     "get_configs",
     "get_configs_blocking",
+    "upload_elevation_config",
+    "upload_elevation_config_blocking",
 ]
@@ -1,4 +1,5 @@
-from typing import Any, Dict, List, Optional
+from enum import Enum
+from typing import TYPE_CHECKING, Any, Dict, List, Optional
 
 __all__ = [
     "SolrFacetSetResult",
@@ -11,8 +12,39 @@ __all__ = [
     "SolrJsonFacetResponse",
     "SolrDocsResponse",
     "SolrResponse",
+    "BulkIndexSummary",
+    "BulkIndexProgress",
+    "SolrExtractResponse",
+    "SolrUpdateError",
+    "ReindexSummary",
+    "ReindexProgress",
+    "SolrReindexStatus",
+    "AdminTaskState",
+    "SolrRequestStatus",
+    "Health",
+    "ShardState",
+    "ReplicaState",
+    "ReplicaType",
+    "ClusterStatus",
+    "CollectionStatus",
+    "ShardStatus",
+    "ReplicaStatus",
+    "ColStatus",
+    "CollectionIndexStatus",
+    "ShardIndexStatus",
+    "ReplicaCounts",
+    "LeaderIndexStatus",
+    "SegmentsInfo",
+    "SegmentInfo",
+    "BackupResult",
+    "ShardBackup",
+    "BackupPoint",
+    "DeletedBackup",
 ]
 
+if TYPE_CHECKING:
+    from solrstice import CollectionRouter
+
 
 class SolrFacetSetResult:
     """
@@ -386,3 +418,638 @@ class SolrResponse:
 
     def get_stats(self) -> Optional["SolrStatsResult"]:
         """Get stats"""
+
+    def get_response_header(self) -> Optional[Dict[str, Any]]:
+        """Get the response header, with keys as given by Solr, e.g. `QTime`, `partialResults` and `params`.
+        None if `omit_header` was set"""
+
+    def get_partial_results(self) -> bool:
+        """Whether Solr returned partial results, because a limit was exceeded or shards did not respond"""
+
+    def get_added_versions(self) -> Optional[Dict[str, int]]:
+        """Get the new `_version_` of each added document, by id. Returned if `return_versions` was set"""
+
+    def get_deleted_versions(self) -> Optional[Dict[str, int]]:
+        """Get the `_version_` of each delete, by id. Returned if `return_versions` was set"""
+
+    def get_update_errors(self) -> List["SolrUpdateError"]:
+        """Get the updates that failed without failing the request.
+        Empty unless `max_errors` is used with a tolerant update chain"""
+
+
+class SolrUpdateError:
+    """A single failed update in a tolerant update"""
+
+    def get_error_type(self) -> str:
+        """`ADD`, `DELID` or `DELQ`, as given by Solr"""
+
+    def get_id(self) -> str:
+        """The id of the document, or the query for deletes by query"""
+
+    def get_message(self) -> str:
+        """The error message from Solr"""
+
+
+class BulkIndexSummary:
+    """The result of a :class:`solrstice.BulkIndexer` run"""
+
+    def get_batches(self) -> int:
+        """The number of batches sent"""
+
+    def get_failed_batches(self) -> int:
+        """The number of batches that failed after all retries"""
+
+    def get_docs_indexed(self) -> int:
+        """The number of documents indexed"""
+
+    def get_docs_failed(self) -> int:
+        """The number of documents in failed batches, or rejected by a tolerant update chain"""
+
+    def get_retries(self) -> int:
+        """The number of retries across all batches"""
+
+
+class BulkIndexProgress:
+    """Progress of a :class:`solrstice.BulkIndexer`, reported after every batch"""
+
+    def get_batch(self) -> int:
+        """The number of the batch, counting from 0 in the order the documents were read"""
+
+    def get_batch_docs(self) -> int:
+        """The number of documents in the batch"""
+
+    def get_attempts(self) -> int:
+        """The number of times the batch was sent, including retries"""
+
+    def get_docs_indexed(self) -> int:
+        """The total number of documents indexed so far"""
+
+    def get_docs_failed(self) -> int:
+        """The total number of documents in failed batches, or rejected by a tolerant update chain, so far"""
+
+    def get_error(self) -> Optional[str]:
+        """The error of the batch, if it failed after all retries"""
+
+    def get_update_errors(self) -> List["SolrUpdateError"]:
+        """The documents of the batch rejected by a tolerant update chain, see `max_errors` of :class:`solrstice.UpdateQuery`"""
+
+
+class SolrExtractResponse:
+    """The text and metadata Solr Cell extracted from a file, without indexing it"""
+
+    def get_name(self) -> str:
+        """The name Solr gave the file, usually the file name"""
+
+    def get_content(self) -> str:
+        """The extracted content. XHTML, unless the extract format is :class:`solrstice.ExtractFormat.Text`"""
+
+    def get_metadata(self) -> Dict[str, List[str]]:
+        """The extracted metadata. Eg: `Content-Type` or `stream_size`"""
+
+
+class ReindexSummary:
+    """The result of a :class:`solrstice.Reindexer` run"""
+
+    def get_pages(self) -> int:
+        """The number of pages read"""
+
+    def get_docs_read(self) -> int:
+        """The number of documents read from the source collection"""
+
+    def get_docs_indexed(self) -> int:
+        """The number of documents indexed into the target collection"""
+
+    def get_docs_failed(self) -> int:
+        """The number of documents in failed batches"""
+
+    def get_docs_skipped(self) -> int:
+        """The number of documents the transform returned `None` for"""
+
+    def get_cursor_mark(self) -> str:
+        """The cursor mark after the last fully indexed page. Resuming from it retries a page with failed documents, or only picks up documents added to the source collection since"""
+
+
+class ReindexProgress:
+    """Progress of a :class:`solrstice.Reindexer`, reported after every page"""
+
+    def get_num_found(self) -> int:
+        """The number of documents matching the query in the source collection"""
+
+    def get_pages(self) -> int:
+        """The number of pages indexed so far"""
+
+    def get_docs_read(self) -> int:
+        """The total number of documents read so far"""
+
+    def get_docs_indexed(self) -> int:
+        """The total number of documents indexed so far"""
+
+    def get_docs_failed(self) -> int:
+        """The total number of documents in failed batches so far"""
+
+    def get_docs_skipped(self) -> int:
+        """The total number of documents skipped by the transform so far"""
+
+    def get_cursor_mark(self) -> str:
+        """The cursor mark to resume from, after the last fully indexed page"""
+
+
+class SolrReindexStatus:
+    """The status of a server side reindex, returned by :class:`solrstice.ReindexCollectionQuery`"""
+
+    def get_state(self) -> Optional[str]:
+        """The state of the reindex. Eg: `running`, `finished` or `aborted`"""
+
+    def get_phase(self) -> Optional[str]:
+        """The phase of a running reindex. Eg: `copying` or `done`"""
+
+    def get_input_docs(self) -> Optional[int]:
+        """The number of documents in the source collection matching the query"""
+
+    def get_processed_docs(self) -> Optional[int]:
+        """The number of documents reindexed so far"""
+
+    def get_actual_source_collection(self) -> Optional[str]:
+        """The collection that was read, with any alias resolved"""
+
+    def get_actual_target_collection(self) -> Optional[str]:
+        """The collection that was written to, with any alias resolved"""
+
+    def get_checkpoint_collection(self) -> Optional[str]:
+        """The collection Solr keeps the progress of the reindex in"""
+
+
+class AdminTaskState(Enum):
+    """The state of an async Collections API request"""
+
+    Submitted = "Submitted"
+    Running = "Running"
+    Completed = "Completed"
+    Failed = "Failed"
+    NotFound = "NotFound"
+
+
+class SolrRequestStatus:
+    """The status of an async Collections API request, returned by :class:`solrstice.AsyncAdminTask`"""
+
+    def get_state(self) -> AdminTaskState:
+        """The state of the request"""
+
+    def get_msg(self) -> Optional[str]:
+        """The message of the status. Eg: `found [create-1] in completed tasks`"""
+
+    def get_response(self) -> Dict[str, Any]:
+        """The rest of the response, with the result of the operation"""
+
+
+class Health(Enum):
+    """The health of a collection or shard, from the share of its replicas that are active"""
+
+    Green = "Green"
+    Yellow = "Yellow"
+    Orange = "Orange"
+    Red = "Red"
+    Unknown = "Unknown"
+
+
+class ShardState(Enum):
+    """The state of a shard. Shards are `Construction` while being created by a split, and `Inactive` after they have been split"""
+
+    Active = "Active"
+    Inactive = "Inactive"
+    Construction = "Construction"
+    Recovery = "Recovery"
+    RecoveryFailed = "RecoveryFailed"
+    Unknown = "Unknown"
+
+
+class ReplicaState(Enum):
+    """The state of a replica"""
+
+    Active = "Active"
+    Down = "Down"
+    Recovering = "Recovering"
+    RecoveryFailed = "RecoveryFailed"
+    Unknown = "Unknown"
+
+
+class ReplicaType(Enum):
+    """How a replica keeps its index"""
+
+    Nrt = "Nrt"
+    Tlog = "Tlog"
+    Pull = "Pull"
+
+
+class ReplicaStatus:
+    """A replica of a :class:`ShardStatus`"""
+
+    def get_name(self) -> str:
+        """The name of the replica. Eg: `core_node3`"""
+
+    def get_core(self) -> str:
+        """The name of the core of the replica. Eg: `collection_shard1_replica_n1`"""
+
+    def get_base_url(self) -> Optional[str]:
+        """The URL of the node the replica is on. Eg: `http://localhost:8983/solr`"""
+
+    def get_node_name(self) -> str:
+        """The node the replica is on. Eg: `localhost:8983_solr`"""
+
+    def get_state(self) -> ReplicaState:
+        """The state of the replica"""
+
+    def get_type(self) -> ReplicaType:
+        """The type of the replica"""
+
+    def is_leader(self) -> bool:
+        """Whether the replica is the leader of its shard"""
+
+
+class ShardStatus:
+    """A shard of a :class:`CollectionStatus`"""
+
+    def get_name(self) -> str:
+        """The name of the shard"""
+
+    def get_range(self) -> Optional[str]:
+        """The hash range of the shard, for the `compositeId` router. Eg: `80000000-ffffffff`"""
+
+    def get_state(self) -> ShardState:
+        """The state of the shard"""
+
+    def get_replicas(self) -> Dict[str, ReplicaStatus]:
+        """The replicas, by name"""
+
+    def get_leader(self) -> Optional[ReplicaStatus]:
+        """The leader of the shard, if it has one"""
+
+    def get_health(self) -> Optional[Health]:
+        """The health of the shard"""
+
+
+class CollectionStatus:
+    """A collection in the :class:`ClusterStatus`"""
+
+    def get_name(self) -> str:
+        """The name of the collection"""
+
+    def get_shards(self) -> Dict[str, ShardStatus]:
+        """The shards, by name"""
+
+    def get_shard(self, name: str) -> Optional[ShardStatus]:
+        """A shard by name"""
+
+    def get_config_name(self) -> Optional[str]:
+        """The config set of the collection"""
+
+    def get_router(self) -> Optional["CollectionRouter"]:
+        """The router of the collection"""
+
+    def get_router_field(self) -> Optional[str]:
+        """The field routed on, if not the unique key"""
+
+    def get_replication_factor(self) -> Optional[int]:
+        """The replication factor the collection was created with"""
+
+    def get_nrt_replicas(self) -> Optional[int]:
+        """The number of NRT replicas per shard the collection was created with"""
+
+    def get_tlog_replicas(self) -> Optional[int]:
+        """The number of TLOG replicas per shard the collection was created with"""
+
+    def get_pull_replicas(self) -> Optional[int]:
+        """The number of PULL replicas per shard the collection was created with"""
+
+    def get_per_replica_state(self) -> Optional[bool]:
+        """Whether the state of each replica is kept in its own ZooKeeper node"""
+
+    def get_znode_version(self) -> Optional[int]:
+        """The version of the state of the collection in ZooKeeper"""
+
+    def get_creation_time_millis(self) -> Optional[int]:
+        """When the collection was created, in milliseconds since the epoch"""
+
+    def get_aliases(self) -> List[str]:
+        """The aliases pointing to the collection"""
+
+    def get_health(self) -> Optional[Health]:
+        """The health of the collection"""
+
+
+class ClusterStatus:
+    """The state of a SolrCloud cluster"""
+
+    def get_collections(self) -> Dict[str, CollectionStatus]:
+        """The collections, by name"""
+
+    def get_collection(self, name: str) -> Optional[CollectionStatus]:
+        """A collection by name"""
+
+    def get_aliases(self) -> Dict[str, List[str]]:
+        """The aliases, with the collections they point to"""
+
+    def get_live_nodes(self) -> List[str]:
+        """The nodes that are connected to ZooKeeper. Eg: `localhost:8983_solr`"""
+
+    def get_roles(self) -> Dict[str, List[str]]:
+        """The nodes with a role. Eg: `overseer`"""
+
+
+class SegmentInfo:
+    """A segment of the index in :class:`SegmentsInfo`"""
+
+    def get_name(self) -> str:
+        """The name of the segment. Eg: `_i`"""
+
+    def get_max_doc(self) -> int:
+        """The number of documents in the segment, including deleted documents"""
+
+    def get_del_count(self) -> int:
+        """The number of deleted documents in the segment"""
+
+    def get_soft_del_count(self) -> int:
+        """The number of soft deleted documents in the segment"""
+
+    def has_field_updates(self) -> bool:
+        """Whether the segment has doc values updates"""
+
+    def get_size_in_bytes(self) -> int:
+        """The size of the segment on disk"""
+
+    def get_age(self) -> Optional[str]:
+        """When the segment was written. Eg: `2024-01-01T12:00:00.000Z`"""
+
+    def get_source(self) -> Optional[str]:
+        """What wrote the segment. Eg: `flush` or `merge`"""
+
+    def get_version(self) -> Optional[str]:
+        """The Lucene version that wrote the segment. Eg: `9.8.0`"""
+
+    def get_merge_candidate(self) -> Optional[Any]:
+        """Whether the segment would be merged by the next merge, with the reason when it is"""
+
+    def get_fields(self) -> Dict[str, Any]:
+        """The fields in the segment, with their index options. Only reported with `field_info`"""
+
+
+class SegmentsInfo:
+    """The segments of the index of a :class:`LeaderIndexStatus`"""
+
+    def get_num_segments(self) -> int:
+        """The number of segments in the last commit"""
+
+    def get_total_max_doc(self) -> int:
+        """The number of documents in the index, including deleted documents"""
+
+    def get_segments_file_name(self) -> Optional[str]:
+        """The name of the segments file of the last commit. Eg: `segments_w`"""
+
+    def get_commit_lucene_version(self) -> Optional[str]:
+        """The Lucene version of the last commit. Eg: `9.8.0`"""
+
+    def get_min_segment_lucene_version(self) -> Optional[str]:
+        """The oldest Lucene version of any segment. Eg: `8.11.2`"""
+
+    def get_user_data(self) -> Dict[str, str]:
+        """The user data of the last commit. Eg: `commitTimeMSec`"""
+
+    def get_size_in_gb(self) -> Optional[float]:
+        """The size of the index in gigabytes. Only reported with `core_info`"""
+
+    def get_index_dir(self) -> Optional[str]:
+        """The index directory. Only reported with `core_info`"""
+
+    def get_data_dir(self) -> Optional[str]:
+        """The data directory. Only reported with `core_info`"""
+
+    def get_segments(self) -> Dict[str, SegmentInfo]:
+        """The segments, by name. Only reported with `segments`"""
+
+    def get_raw_size(self) -> Optional[Any]:
+        """The estimated size of the index by field and type. Only reported with `raw_size`"""
+
+
+class LeaderIndexStatus:
+    """The leader replica of a :class:`ShardIndexStatus`, with the status of its index"""
+
+    def get_name(self) -> Optional[str]:
+        """The name of the replica. Eg: `core_node3`"""
+
+    def get_core(self) -> str:
+        """The name of the core of the replica. Eg: `collection_shard1_replica_n1`"""
+
+    def get_base_url(self) -> Optional[str]:
+        """The URL of the node the replica is on. Eg: `http://localhost:8983/solr`"""
+
+    def get_node_name(self) -> str:
+        """The node the replica is on. Eg: `localhost:8983_solr`"""
+
+    def get_state(self) -> ReplicaState:
+        """The state of the replica"""
+
+    def get_type(self) -> ReplicaType:
+        """The type of the replica"""
+
+    def get_segments_info(self) -> Optional[SegmentsInfo]:
+        """The segments of the index. Missing if the index could not be read, see :meth:`get_error`"""
+
+    def get_error(self) -> Optional[str]:
+        """Why the index of the leader could not be read"""
+
+
+class ReplicaCounts:
+    """The number of replicas of a :class:`ShardIndexStatus`, by state"""
+
+    def get_total(self) -> int:
+        """The number of replicas"""
+
+    def get_active(self) -> int:
+        """The number of active replicas"""
+
+    def get_down(self) -> int:
+        """The number of down replicas"""
+
+    def get_recovering(self) -> int:
+        """The number of recovering replicas"""
+
+    def get_recovery_failed(self) -> int:
+        """The number of replicas that failed to recover"""
+
+
+class ShardIndexStatus:
+    """A shard of a :class:`CollectionIndexStatus`"""
+
+    def get_name(self) -> str:
+        """The name of the shard"""
+
+    def get_state(self) -> ShardState:
+        """The state of the shard"""
+
+    def get_range(self) -> Optional[str]:
+        """The hash range of the shard, for the `compositeId` router. Eg: `80000000-ffffffff`"""
+
+    def get_replicas(self) -> Optional[ReplicaCounts]:
+        """The number of replicas of the shard, by state"""
+
+    def get_leader(self) -> Optional[LeaderIndexStatus]:
+        """The leader of the shard, with the status of its index"""
+
+
+class CollectionIndexStatus:
+    """A collection in the :class:`ColStatus`"""
+
+    def get_name(self) -> str:
+        """The name of the collection"""
+
+    def get_znode_version(self) -> Optional[int]:
+        """The version of the state of the collection in ZooKeeper"""
+
+    def get_properties(self) -> Dict[str, Any]:
+        """The properties of the collection. Eg: `configName`, `replicationFactor`"""
+
+    def get_active_shards(self) -> int:
+        """The number of active shards"""
+
+    def get_inactive_shards(self) -> int:
+        """The number of inactive shards, such as shards that have been split"""
+
+    def get_schema_non_compliant(self) -> List[str]:
+        """Fields whose indexed data does not match the current schema. Only reported with `field_info`"""
+
+    def get_shards(self) -> Dict[str, ShardIndexStatus]:
+        """The shards, by name"""
+
+    def get_shard(self, name: str) -> Optional[ShardIndexStatus]:
+        """A shard by name"""
+
+
+class ColStatus:
+    """The index status of collections, returned by `get_col_status`"""
+
+    def get_collections(self) -> Dict[str, CollectionIndexStatus]:
+        """The collections, by name"""
+
+    def get_collection(self, name: str) -> Optional[CollectionIndexStatus]:
+        """A collection by name"""
+
+
+class BackupResult:
+    """The result of a backup. Only incremental backups report the backup id, files and sizes"""
+
+    def get_collection(self) -> Optional[str]:
+        """The collection that was backed up"""
+
+    def get_num_shards(self) -> Optional[int]:
+        """The number of shards that were backed up"""
+
+    def get_backup_id(self) -> Optional[int]:
+        """The id of the backup point, used to restore or delete it. The first backup of a name is `0`"""
+
+    def get_index_version(self) -> Optional[str]:
+        """The Lucene version of the index. Eg: `9.8.0`"""
+
+    def get_start_time(self) -> Optional[str]:
+        """When the backup started. Eg: `2024-01-01T12:00:00.000Z`"""
+
+    def get_end_time(self) -> Optional[str]:
+        """When the backup ended"""
+
+    def get_index_file_count(self) -> Optional[int]:
+        """The number of index files in the backup point, over all shards"""
+
+    def get_uploaded_index_file_count(self) -> Optional[int]:
+        """The number of index files that were not in an earlier backup point, and had to be copied"""
+
+    def get_index_size_mb(self) -> Optional[float]:
+        """The size of the index in the backup point, in megabytes"""
+
+    def get_uploaded_index_file_mb(self) -> Optional[float]:
+        """The size of the index files that had to be copied, in megabytes"""
+
+    def get_shards(self) -> List["ShardBackup"]:
+        """The backups of each shard"""
+
+
+class ShardBackup:
+    """The backup of one shard in a :class:`BackupResult`"""
+
+    def get_shard(self) -> Optional[str]:
+        """The name of the shard. Eg: `shard1`"""
+
+    def get_shard_backup_id(self) -> Optional[str]:
+        """The metadata file of the shard in the backup point. Eg: `md_shard1_0.json`"""
+
+    def get_start_time(self) -> Optional[str]:
+        """When the backup of the shard started"""
+
+    def get_end_time(self) -> Optional[str]:
+        """When the backup of the shard ended"""
+
+    def get_index_file_count(self) -> Optional[int]:
+        """The number of index files of the shard in the backup point"""
+
+    def get_uploaded_index_file_count(self) -> Optional[int]:
+        """The number of index files of the shard that had to be copied"""
+
+    def get_index_size_mb(self) -> Optional[float]:
+        """The size of the index of the shard, in megabytes"""
+
+    def get_uploaded_index_file_mb(self) -> Optional[float]:
+        """The size of the index files of the shard that had to be copied, in megabytes"""
+
+
+class BackupPoint:
+    """A backup point of a backup"""
+
+    def get_backup_id(self) -> int:
+        """The id of the backup point"""
+
+    def get_collection(self) -> Optional[str]:
+        """The collection that was backed up"""
+
+    def get_config_name(self) -> Optional[str]:
+        """The config set of the collection when it was backed up"""
+
+    def get_start_time(self) -> Optional[str]:
+        """When the backup started. Eg: `2024-01-01T12:00:00.000Z`"""
+
+    def get_end_time(self) -> Optional[str]:
+        """When the backup ended"""
+
+    def get_index_version(self) -> Optional[str]:
+        """The Lucene version of the index. Eg: `9.8.0`"""
+
+    def get_index_file_count(self) -> Optional[int]:
+        """The number of index files in the backup point, over all shards"""
+
+    def get_index_size_mb(self) -> Optional[float]:
+        """The size of the index in the backup point, in megabytes"""
+
+    def get_shard_backup_ids(self) -> Dict[str, str]:
+        """The metadata file of each shard, by shard name. Eg: `shard1` to `md_shard1_0.json`"""
+
+
+class DeletedBackup:
+    """What was deleted from a backup. Deleting by id reports each deleted backup point, purging reports the number of deleted files"""
+
+    def get_backup_id(self) -> Optional[int]:
+        """The id of the deleted backup point"""
+
+    def get_start_time(self) -> Optional[str]:
+        """When the deleted backup point was started"""
+
+    def get_size(self) -> Optional[int]:
+        """The size of the deleted backup point, in bytes"""
+
+    def get_num_files(self) -> Optional[int]:
+        """The number of files in the deleted backup point"""
+
+    def get_num_backup_ids(self) -> Optional[int]:
+        """The number of unused backup point files that were purged"""
+
+    def get_num_shard_backup_ids(self) -> Optional[int]:
+        """The number of unused shard metadata files that were purged"""
+
+    def get_num_index_files(self) -> Optional[int]:
+        """The number of unused index files that were purged"""
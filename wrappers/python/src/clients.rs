@@ -1,19 +1,56 @@
+use crate::models::backup::{BackupPointWrapper, BackupResultWrapper, DeletedBackupWrapper};
+use crate::models::bulk_index::BulkIndexSummaryWrapper;
+use crate::models::cluster_status::ClusterStatusWrapper;
+use crate::models::col_status::ColStatusWrapper;
 use crate::models::context::SolrServerContextWrapper;
+use crate::models::extract::SolrExtractResponseWrapper;
+use crate::models::reindex::{ReindexSummaryWrapper, SolrReindexStatusWrapper};
 use crate::models::response::SolrResponseWrapper;
 use crate::queries::alias::{
-    alias_exists, alias_exists_blocking, create_alias, create_alias_blocking,
-    delete_alias_blocking, get_aliases, get_aliases_blocking,
+    alias_exists, alias_exists_blocking, create_alias, create_alias_blocking, delete_alias,
+    delete_alias_blocking, get_aliases, get_aliases_blocking, CreateAliasOptionsWrapper,
+    DeleteAliasOptionsWrapper,
 };
+use crate::queries::backup::{
+    backup_collection, backup_collection_blocking, delete_backup, delete_backup_blocking,
+    list_backups, list_backups_blocking, restore_collection, restore_collection_blocking,
+    BackupOptionsWrapper, DeleteBackupOptionsWrapper, ListBackupsOptionsWrapper,
+    RestoreOptionsWrapper,
+};
+use crate::queries::bulk_index::BulkIndexerWrapper;
 use crate::queries::collection::{
+    add_replica, add_replica_blocking, add_replica_property, add_replica_property_blocking,
     collection_exists, collection_exists_blocking, create_collection, create_collection_blocking,
-    delete_collection, delete_collection_blocking, get_collections, get_collections_blocking,
+    create_collection_with_options, create_collection_with_options_blocking, create_shard,
+    create_shard_blocking, delete_collection, delete_collection_blocking, delete_node,
+    delete_node_blocking, delete_replica, delete_replica_blocking, delete_replica_property,
+    delete_replica_property_blocking, delete_shard, delete_shard_blocking, get_cluster_status,
+    get_cluster_status_blocking, get_col_status, get_col_status_blocking, get_collections,
+    get_collections_blocking, modify_collection, modify_collection_blocking, move_replica,
+    move_replica_blocking, reload_collection, reload_collection_blocking,
+    reload_collections_using_config, reload_collections_using_config_blocking, rename_collection,
+    rename_collection_blocking, replace_node, replace_node_blocking, split_shard,
+    split_shard_blocking, AddReplicaOptionsWrapper, ColStatusOptionsWrapper,
+    CreateCollectionOptionsWrapper, CreateShardOptionsWrapper, DeleteCollectionOptionsWrapper,
+    DeleteNodeOptionsWrapper, DeleteReplicaOptionsWrapper, DeleteShardOptionsWrapper,
+    ModifyCollectionOptionsWrapper, MoveReplicaOptionsWrapper, ReloadCollectionOptionsWrapper,
+    RenameCollectionOptionsWrapper, ReplaceNodeOptionsWrapper, ReplicaPropertyOptionsWrapper,
+    SplitShardOptionsWrapper,
 };
 use crate::queries::config::{
     config_exists, config_exists_blocking, delete_config, delete_config_blocking, get_configs,
-    get_configs_blocking, upload_config, upload_config_blocking,
+    get_configs_blocking, upload_config, upload_config_blocking, upload_elevation_config,
+    upload_elevation_config_blocking,
+};
+use crate::queries::extract::ExtractQueryWrapper;
+use crate::queries::file_update::{CsvUpdateQueryWrapper, JsonDocsUpdateQueryWrapper};
+use crate::queries::index::{
+    CommitQueryWrapper, DeleteQueryWrapper, OptimizeQueryWrapper, RollbackQueryWrapper,
+    UpdateQueryWrapper,
 };
-use crate::queries::index::{DeleteQueryWrapper, UpdateQueryWrapper};
+use crate::queries::reindex::{ReindexCollectionQueryWrapper, ReindexerWrapper};
 use crate::queries::select::SelectQueryWrapper;
+use crate::queries::update_batch::UpdateBatchWrapper;
 use pyo3::prelude::*;
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -39,6 +76,16 @@ impl AsyncSolrCloudClientWrapper {
         upload_config(py, context, name, path)
     }
 
+    pub fn upload_elevation_config<'py>(
+        &self,
+        py: Python<'py>,
+        name: String,
+        path: PathBuf,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        upload_elevation_config(py, context, name, path)
+    }
+
     pub fn get_configs<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let context = self.0.clone();
         get_configs(py, context)
@@ -66,6 +113,16 @@ impl AsyncSolrCloudClientWrapper {
         create_collection(py, context, name, config, shards, replication_factor)
     }
 
+    pub fn create_collection_with_options<'py>(
+        &self,
+        py: Python<'py>,
+        name: String,
+        options: CreateCollectionOptionsWrapper,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        create_collection_with_options(py, context, name, options)
+    }
+
     pub fn get_collections<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let context = self.0.clone();
         get_collections(py, context)
@@ -80,13 +137,231 @@ impl AsyncSolrCloudClientWrapper {
         collection_exists(py, context, name)
     }
 
+    pub fn get_cluster_status<'py>(
+        &self,
+        py: Python<'py>,
+        collection: Option<String>,
+        shards: Option<Vec<String>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        get_cluster_status(py, context, collection, shards)
+    }
+
+    pub fn get_col_status<'py>(
+        &self,
+        py: Python<'py>,
+        collection: Option<String>,
+        options: Option<ColStatusOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        get_col_status(py, context, collection, options)
+    }
+
     pub fn delete_collection<'py>(
         &self,
         py: Python<'py>,
         name: String,
+        options: Option<DeleteCollectionOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        delete_collection(py, context, name, options)
+    }
+
+    pub fn reload_collection<'py>(
+        &self,
+        py: Python<'py>,
+        name: String,
+        options: Option<ReloadCollectionOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        reload_collection(py, context, name, options)
+    }
+
+    pub fn reload_collections_using_config<'py>(
+        &self,
+        py: Python<'py>,
+        config: String,
+        options: Option<ReloadCollectionOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        reload_collections_using_config(py, context, config, options)
+    }
+
+    pub fn modify_collection<'py>(
+        &self,
+        py: Python<'py>,
+        collection: String,
+        options: ModifyCollectionOptionsWrapper,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        modify_collection(py, context, collection, options)
+    }
+
+    pub fn rename_collection<'py>(
+        &self,
+        py: Python<'py>,
+        name: String,
+        target: String,
+        options: Option<RenameCollectionOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        rename_collection(py, context, name, target, options)
+    }
+
+    pub fn split_shard<'py>(
+        &self,
+        py: Python<'py>,
+        collection: String,
+        options: Option<SplitShardOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        split_shard(py, context, collection, options)
+    }
+
+    pub fn create_shard<'py>(
+        &self,
+        py: Python<'py>,
+        collection: String,
+        shard: String,
+        options: Option<CreateShardOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        create_shard(py, context, collection, shard, options)
+    }
+
+    pub fn delete_shard<'py>(
+        &self,
+        py: Python<'py>,
+        collection: String,
+        shard: String,
+        options: Option<DeleteShardOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        delete_shard(py, context, collection, shard, options)
+    }
+
+    pub fn add_replica<'py>(
+        &self,
+        py: Python<'py>,
+        collection: String,
+        shard: String,
+        options: Option<AddReplicaOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        add_replica(py, context, collection, shard, options)
+    }
+
+    pub fn delete_replica<'py>(
+        &self,
+        py: Python<'py>,
+        collection: String,
+        options: DeleteReplicaOptionsWrapper,
     ) -> PyResult<Bound<'py, PyAny>> {
         let context = self.0.clone();
-        delete_collection(py, context, name)
+        delete_replica(py, context, collection, options)
+    }
+
+    pub fn move_replica<'py>(
+        &self,
+        py: Python<'py>,
+        collection: String,
+        target_node: String,
+        options: MoveReplicaOptionsWrapper,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        move_replica(py, context, collection, target_node, options)
+    }
+
+    pub fn delete_node<'py>(
+        &self,
+        py: Python<'py>,
+        node: String,
+        options: Option<DeleteNodeOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        delete_node(py, context, node, options)
+    }
+
+    pub fn replace_node<'py>(
+        &self,
+        py: Python<'py>,
+        source_node: String,
+        options: Option<ReplaceNodeOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        replace_node(py, context, source_node, options)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_replica_property<'py>(
+        &self,
+        py: Python<'py>,
+        collection: String,
+        shard: String,
+        replica: String,
+        property: String,
+        value: String,
+        options: Option<ReplicaPropertyOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        add_replica_property(
+            py, context, collection, shard, replica, property, value, options,
+        )
+    }
+
+    pub fn delete_replica_property<'py>(
+        &self,
+        py: Python<'py>,
+        collection: String,
+        shard: String,
+        replica: String,
+        property: String,
+        options: Option<ReplicaPropertyOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        delete_replica_property(py, context, collection, shard, replica, property, options)
+    }
+
+    pub fn backup_collection<'py>(
+        &self,
+        py: Python<'py>,
+        collection: String,
+        name: String,
+        options: Option<BackupOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        backup_collection(py, context, collection, name, options)
+    }
+
+    pub fn restore_collection<'py>(
+        &self,
+        py: Python<'py>,
+        collection: String,
+        name: String,
+        options: Option<RestoreOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        restore_collection(py, context, collection, name, options)
+    }
+
+    pub fn list_backups<'py>(
+        &self,
+        py: Python<'py>,
+        name: String,
+        options: Option<ListBackupsOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        list_backups(py, context, name, options)
+    }
+
+    pub fn delete_backup<'py>(
+        &self,
+        py: Python<'py>,
+        name: String,
+        options: DeleteBackupOptionsWrapper,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        delete_backup(py, context, name, options)
     }
 
     pub fn get_aliases<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
@@ -99,9 +374,10 @@ impl AsyncSolrCloudClientWrapper {
         py: Python<'py>,
         name: String,
         collections: Vec<String>,
+        options: Option<CreateAliasOptionsWrapper>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let context = self.0.clone();
-        create_alias(py, context, name, collections)
+        create_alias(py, context, name, collections, options)
     }
 
     pub fn alias_exists<'py>(&self, py: Python<'py>, name: String) -> PyResult<Bound<'py, PyAny>> {
@@ -109,6 +385,16 @@ impl AsyncSolrCloudClientWrapper {
         alias_exists(py, context, name)
     }
 
+    pub fn delete_alias<'py>(
+        &self,
+        py: Python<'py>,
+        name: String,
+        options: Option<DeleteAliasOptionsWrapper>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        delete_alias(py, context, name, options)
+    }
+
     pub fn index<'py>(
         &self,
         py: Python<'py>,
@@ -120,6 +406,17 @@ impl AsyncSolrCloudClientWrapper {
         builder.execute(py, context, collection, data)
     }
 
+    pub fn bulk_index<'py>(
+        &self,
+        py: Python<'py>,
+        builder: &BulkIndexerWrapper,
+        collection: String,
+        docs: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        builder.execute(py, context, collection, docs)
+    }
+
     pub fn select<'py>(
         &self,
         py: Python<'py>,
@@ -151,6 +448,111 @@ impl AsyncSolrCloudClientWrapper {
         let context = self.0.clone();
         builder.execute(py, context, collection)
     }
+
+    pub fn commit<'py>(
+        &self,
+        py: Python<'py>,
+        builder: &CommitQueryWrapper,
+        collection: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        builder.execute(py, context, collection)
+    }
+
+    pub fn optimize<'py>(
+        &self,
+        py: Python<'py>,
+        builder: &OptimizeQueryWrapper,
+        collection: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        builder.execute(py, context, collection)
+    }
+
+    pub fn rollback<'py>(
+        &self,
+        py: Python<'py>,
+        builder: &RollbackQueryWrapper,
+        collection: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        builder.execute(py, context, collection)
+    }
+
+    pub fn update<'py>(
+        &self,
+        py: Python<'py>,
+        batch: &UpdateBatchWrapper,
+        collection: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        batch.execute(py, context, collection)
+    }
+
+    pub fn index_csv_file<'py>(
+        &self,
+        py: Python<'py>,
+        builder: &CsvUpdateQueryWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        builder.execute_file(py, context, collection, path)
+    }
+
+    pub fn index_json_docs_file<'py>(
+        &self,
+        py: Python<'py>,
+        builder: &JsonDocsUpdateQueryWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        builder.execute_file(py, context, collection, path)
+    }
+
+    pub fn extract_file<'py>(
+        &self,
+        py: Python<'py>,
+        builder: &ExtractQueryWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        builder.execute_file(py, context, collection, path)
+    }
+
+    pub fn extract_only_file<'py>(
+        &self,
+        py: Python<'py>,
+        builder: &ExtractQueryWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        builder.extract_only_file(py, context, collection, path)
+    }
+
+    pub fn reindex<'py>(
+        &self,
+        py: Python<'py>,
+        builder: &ReindexerWrapper,
+        source: String,
+        target: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        builder.execute(py, context.clone(), source, context, target)
+    }
+
+    pub fn reindex_collection<'py>(
+        &self,
+        py: Python<'py>,
+        builder: &ReindexCollectionQueryWrapper,
+        collection: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let context = self.0.clone();
+        builder.execute(py, context, collection)
+    }
 }
 
 #[pyclass(name = "BlockingSolrCloudClient", module = "solrstice", subclass)]
@@ -169,6 +571,16 @@ impl BlockingSolrCloudClientWrapper {
         upload_config_blocking(py, context, name, path)
     }
 
+    pub fn upload_elevation_config(
+        &self,
+        py: Python,
+        name: String,
+        path: PathBuf,
+    ) -> PyResult<Vec<String>> {
+        let context = self.0.clone();
+        upload_elevation_config_blocking(py, context, name, path)
+    }
+
     pub fn get_configs(&self, py: Python) -> PyResult<Vec<String>> {
         let context = self.0.clone();
         get_configs_blocking(py, context)
@@ -196,6 +608,16 @@ impl BlockingSolrCloudClientWrapper {
         create_collection_blocking(py, context, name, config, shards, replication_factor)
     }
 
+    pub fn create_collection_with_options(
+        &self,
+        py: Python,
+        name: String,
+        options: CreateCollectionOptionsWrapper,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        create_collection_with_options_blocking(py, context, name, options)
+    }
+
     pub fn get_collections(&self, py: Python) -> PyResult<Vec<String>> {
         let context = self.0.clone();
         get_collections_blocking(py, context)
@@ -206,14 +628,242 @@ impl BlockingSolrCloudClientWrapper {
         collection_exists_blocking(py, context, name)
     }
 
-    pub fn delete_collection(&self, py: Python, name: String) -> PyResult<()> {
+    pub fn get_cluster_status(
+        &self,
+        py: Python,
+        collection: Option<String>,
+        shards: Option<Vec<String>>,
+    ) -> PyResult<ClusterStatusWrapper> {
+        let context = self.0.clone();
+        get_cluster_status_blocking(py, context, collection, shards)
+    }
+
+    pub fn get_col_status(
+        &self,
+        py: Python,
+        collection: Option<String>,
+        options: Option<ColStatusOptionsWrapper>,
+    ) -> PyResult<ColStatusWrapper> {
+        let context = self.0.clone();
+        get_col_status_blocking(py, context, collection, options)
+    }
+
+    pub fn delete_collection(
+        &self,
+        py: Python,
+        name: String,
+        options: Option<DeleteCollectionOptionsWrapper>,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        delete_collection_blocking(py, context, name, options)
+    }
+
+    pub fn reload_collection(
+        &self,
+        py: Python,
+        name: String,
+        options: Option<ReloadCollectionOptionsWrapper>,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        reload_collection_blocking(py, context, name, options)
+    }
+
+    pub fn reload_collections_using_config(
+        &self,
+        py: Python,
+        config: String,
+        options: Option<ReloadCollectionOptionsWrapper>,
+    ) -> PyResult<Vec<String>> {
+        let context = self.0.clone();
+        reload_collections_using_config_blocking(py, context, config, options)
+    }
+
+    pub fn modify_collection(
+        &self,
+        py: Python,
+        collection: String,
+        options: ModifyCollectionOptionsWrapper,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        modify_collection_blocking(py, context, collection, options)
+    }
+
+    pub fn rename_collection(
+        &self,
+        py: Python,
+        name: String,
+        target: String,
+        options: Option<RenameCollectionOptionsWrapper>,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        rename_collection_blocking(py, context, name, target, options)
+    }
+
+    pub fn split_shard(
+        &self,
+        py: Python,
+        collection: String,
+        options: Option<SplitShardOptionsWrapper>,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        split_shard_blocking(py, context, collection, options)
+    }
+
+    pub fn create_shard(
+        &self,
+        py: Python,
+        collection: String,
+        shard: String,
+        options: Option<CreateShardOptionsWrapper>,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        create_shard_blocking(py, context, collection, shard, options)
+    }
+
+    pub fn delete_shard(
+        &self,
+        py: Python,
+        collection: String,
+        shard: String,
+        options: Option<DeleteShardOptionsWrapper>,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        delete_shard_blocking(py, context, collection, shard, options)
+    }
+
+    pub fn add_replica(
+        &self,
+        py: Python,
+        collection: String,
+        shard: String,
+        options: Option<AddReplicaOptionsWrapper>,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        add_replica_blocking(py, context, collection, shard, options)
+    }
+
+    pub fn delete_replica(
+        &self,
+        py: Python,
+        collection: String,
+        options: DeleteReplicaOptionsWrapper,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        delete_replica_blocking(py, context, collection, options)
+    }
+
+    pub fn move_replica(
+        &self,
+        py: Python,
+        collection: String,
+        target_node: String,
+        options: MoveReplicaOptionsWrapper,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        move_replica_blocking(py, context, collection, target_node, options)
+    }
+
+    pub fn delete_node(
+        &self,
+        py: Python,
+        node: String,
+        options: Option<DeleteNodeOptionsWrapper>,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        delete_node_blocking(py, context, node, options)
+    }
+
+    pub fn replace_node(
+        &self,
+        py: Python,
+        source_node: String,
+        options: Option<ReplaceNodeOptionsWrapper>,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        replace_node_blocking(py, context, source_node, options)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_replica_property(
+        &self,
+        py: Python,
+        collection: String,
+        shard: String,
+        replica: String,
+        property: String,
+        value: String,
+        options: Option<ReplicaPropertyOptionsWrapper>,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        add_replica_property_blocking(
+            py, context, collection, shard, replica, property, value, options,
+        )
+    }
+
+    pub fn delete_replica_property(
+        &self,
+        py: Python,
+        collection: String,
+        shard: String,
+        replica: String,
+        property: String,
+        options: Option<ReplicaPropertyOptionsWrapper>,
+    ) -> PyResult<()> {
         let context = self.0.clone();
-        delete_collection_blocking(py, context, name)
+        delete_replica_property_blocking(py, context, collection, shard, replica, property, options)
     }
 
-    pub fn create_alias(&self, py: Python, name: String, collections: Vec<String>) -> PyResult<()> {
+    pub fn backup_collection(
+        &self,
+        py: Python,
+        collection: String,
+        name: String,
+        options: Option<BackupOptionsWrapper>,
+    ) -> PyResult<BackupResultWrapper> {
         let context = self.0.clone();
-        create_alias_blocking(py, context, name, collections)
+        backup_collection_blocking(py, context, collection, name, options)
+    }
+
+    pub fn restore_collection(
+        &self,
+        py: Python,
+        collection: String,
+        name: String,
+        options: Option<RestoreOptionsWrapper>,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        restore_collection_blocking(py, context, collection, name, options)
+    }
+
+    pub fn list_backups(
+        &self,
+        py: Python,
+        name: String,
+        options: Option<ListBackupsOptionsWrapper>,
+    ) -> PyResult<Vec<BackupPointWrapper>> {
+        let context = self.0.clone();
+        list_backups_blocking(py, context, name, options)
+    }
+
+    pub fn delete_backup(
+        &self,
+        py: Python,
+        name: String,
+        options: DeleteBackupOptionsWrapper,
+    ) -> PyResult<Vec<DeletedBackupWrapper>> {
+        let context = self.0.clone();
+        delete_backup_blocking(py, context, name, options)
+    }
+
+    pub fn create_alias(
+        &self,
+        py: Python,
+        name: String,
+        collections: Vec<String>,
+        options: Option<CreateAliasOptionsWrapper>,
+    ) -> PyResult<()> {
+        let context = self.0.clone();
+        create_alias_blocking(py, context, name, collections, options)
     }
 
     pub fn get_aliases(&self, py: Python) -> PyResult<HashMap<String, Vec<String>>> {
@@ -226,9 +876,14 @@ impl BlockingSolrCloudClientWrapper {
         alias_exists_blocking(py, context, name)
     }
 
-    pub fn delete_alias(&self, py: Python, name: String) -> PyResult<()> {
+    pub fn delete_alias(
+        &self,
+        py: Python,
+        name: String,
+        options: Option<DeleteAliasOptionsWrapper>,
+    ) -> PyResult<()> {
         let context = self.0.clone();
-        delete_alias_blocking(py, context, name)
+        delete_alias_blocking(py, context, name, options)
     }
 
     pub fn index(
@@ -242,6 +897,17 @@ impl BlockingSolrCloudClientWrapper {
         builder.execute_blocking(py, context, collection, data)
     }
 
+    pub fn bulk_index(
+        &self,
+        py: Python,
+        builder: &BulkIndexerWrapper,
+        collection: String,
+        docs: &Bound<'_, PyAny>,
+    ) -> PyResult<BulkIndexSummaryWrapper> {
+        let context = self.0.clone();
+        builder.execute_blocking(py, context, collection, docs)
+    }
+
     pub fn select(
         &self,
         py: Python,
@@ -273,4 +939,109 @@ impl BlockingSolrCloudClientWrapper {
         let context = self.0.clone();
         builder.execute_blocking(py, context, collection)
     }
+
+    pub fn commit(
+        &self,
+        py: Python,
+        builder: &CommitQueryWrapper,
+        collection: String,
+    ) -> PyResult<SolrResponseWrapper> {
+        let context = self.0.clone();
+        builder.execute_blocking(py, context, collection)
+    }
+
+    pub fn optimize(
+        &self,
+        py: Python,
+        builder: &OptimizeQueryWrapper,
+        collection: String,
+    ) -> PyResult<SolrResponseWrapper> {
+        let context = self.0.clone();
+        builder.execute_blocking(py, context, collection)
+    }
+
+    pub fn rollback(
+        &self,
+        py: Python,
+        builder: &RollbackQueryWrapper,
+        collection: String,
+    ) -> PyResult<SolrResponseWrapper> {
+        let context = self.0.clone();
+        builder.execute_blocking(py, context, collection)
+    }
+
+    pub fn update(
+        &self,
+        py: Python,
+        batch: &UpdateBatchWrapper,
+        collection: String,
+    ) -> PyResult<SolrResponseWrapper> {
+        let context = self.0.clone();
+        batch.execute_blocking(py, context, collection)
+    }
+
+    pub fn index_csv_file(
+        &self,
+        py: Python,
+        builder: &CsvUpdateQueryWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<SolrResponseWrapper> {
+        let context = self.0.clone();
+        builder.execute_file_blocking(py, context, collection, path)
+    }
+
+    pub fn index_json_docs_file(
+        &self,
+        py: Python,
+        builder: &JsonDocsUpdateQueryWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<SolrResponseWrapper> {
+        let context = self.0.clone();
+        builder.execute_file_blocking(py, context, collection, path)
+    }
+
+    pub fn extract_file(
+        &self,
+        py: Python,
+        builder: &ExtractQueryWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<SolrResponseWrapper> {
+        let context = self.0.clone();
+        builder.execute_file_blocking(py, context, collection, path)
+    }
+
+    pub fn extract_only_file(
+        &self,
+        py: Python,
+        builder: &ExtractQueryWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<SolrExtractResponseWrapper> {
+        let context = self.0.clone();
+        builder.extract_only_file_blocking(py, context, collection, path)
+    }
+
+    pub fn reindex(
+        &self,
+        py: Python,
+        builder: &ReindexerWrapper,
+        source: String,
+        target: String,
+    ) -> PyResult<ReindexSummaryWrapper> {
+        let context = self.0.clone();
+        builder.execute_blocking(py, context.clone(), source, context, target)
+    }
+
+    pub fn reindex_collection(
+        &self,
+        py: Python,
+        builder: &ReindexCollectionQueryWrapper,
+        collection: String,
+    ) -> PyResult<SolrReindexStatusWrapper> {
+        let context = self.0.clone();
+        builder.execute_blocking(py, context, collection)
+    }
 }
@@ -8,18 +8,54 @@ use crate::hosts::{
     SolrHostWrapper, SolrMultipleServerHostWrapper, SolrSingleServerHostWrapper,
     ZookeeperEnsembleHostConnectorWrapper, ZookeeperEnsembleHostWrapper,
 };
+use crate::models::admin_task::{AdminTaskStateWrapper, SolrRequestStatusWrapper};
+use crate::models::atomic_update::AtomicUpdateWrapper;
 use crate::models::auth::{SolrAuthWrapper, SolrBasicAuthWrapper};
+use crate::models::backup::{
+    BackupPointWrapper, BackupResultWrapper, DeletedBackupWrapper, ShardBackupWrapper,
+};
+use crate::models::bulk_index::{BulkIndexProgressWrapper, BulkIndexSummaryWrapper};
+use crate::models::cluster_status::{
+    ClusterStatusWrapper, CollectionStatusWrapper, HealthWrapper, ReplicaStateWrapper,
+    ReplicaStatusWrapper, ReplicaTypeWrapper, ShardStateWrapper, ShardStatusWrapper,
+};
+use crate::models::col_status::{
+    ColStatusWrapper, CollectionIndexStatusWrapper, LeaderIndexStatusWrapper, ReplicaCountsWrapper,
+    SegmentInfoWrapper, SegmentsInfoWrapper, ShardIndexStatusWrapper,
+};
 use crate::models::context::{
     FastLoggingPolicyWrapper, LoggingPolicyWrapper, OffLoggingPolicyWrapper,
     PrettyLoggingPolicyWrapper, SolrServerContextWrapper,
 };
+use crate::models::extract::SolrExtractResponseWrapper;
 use crate::models::facet_set::{SolrFacetSetResultWrapper, SolrPivotFacetResultWrapper};
 use crate::models::group::{SolrGroupFieldResultWrapper, SolrGroupResultWrapper};
 use crate::models::json_facet::SolrJsonFacetResponseWrapper;
-use crate::models::response::{SolrDocsResponseWrapper, SolrResponseWrapper};
+use crate::models::reindex::{
+    ReindexProgressWrapper, ReindexSummaryWrapper, SolrReindexStatusWrapper,
+};
+use crate::models::response::{
+    SolrDocsResponseWrapper, SolrResponseWrapper, SolrUpdateErrorWrapper,
+};
 use crate::models::stats::{SolrStatsFieldResultWrapper, SolrStatsResultWrapper};
-use crate::queries::alias::alias as alias_module;
-use crate::queries::collection::collection as collection_module;
+use crate::queries::admin_task::AsyncAdminTaskWrapper;
+use crate::queries::alias::{
+    alias as alias_module, CreateAliasOptionsWrapper, DeleteAliasOptionsWrapper,
+};
+use crate::queries::backup::{
+    backup as backup_module, BackupOptionsWrapper, DeleteBackupOptionsWrapper,
+    ListBackupsOptionsWrapper, RestoreOptionsWrapper,
+};
+use crate::queries::bulk_index::BulkIndexerWrapper;
+use crate::queries::collection::{
+    collection as collection_module, AddReplicaOptionsWrapper, ColStatusOptionsWrapper,
+    CollectionRouterWrapper, CreateCollectionOptionsWrapper, CreateShardOptionsWrapper,
+    DeleteCollectionOptionsWrapper, DeleteNodeOptionsWrapper, DeleteReplicaOptionsWrapper,
+    DeleteShardOptionsWrapper, ModifyCollectionOptionsWrapper, MoveReplicaOptionsWrapper,
+    ReloadCollectionOptionsWrapper, RenameCollectionOptionsWrapper, ReplaceNodeOptionsWrapper,
+    ReplicaPropertyOptionsWrapper, SplitMethodWrapper, SplitShardOptionsWrapper,
+};
+use crate::queries::components::elevation::ElevationComponentWrapper;
 use crate::queries::components::facet_set::{
     FacetSetComponentWrapper, FieldFacetComponentWrapper, FieldFacetEntryWrapper,
     FieldFacetMethodWrapper, FieldFacetSortWrapper, PivotFacetComponentWrapper,
@@ -36,8 +72,15 @@ use crate::queries::def_type::{
     DefTypeWrapper, DismaxQueryWrapper, EdismaxQueryWrapper, LuceneQueryWrapper,
     QueryOperatorWrapper,
 };
-use crate::queries::index::{CommitTypeWrapper, DeleteQueryWrapper, UpdateQueryWrapper};
+use crate::queries::extract::{ExtractFormatWrapper, ExtractQueryWrapper};
+use crate::queries::file_update::{CsvUpdateQueryWrapper, JsonDocsUpdateQueryWrapper};
+use crate::queries::index::{
+    CommitQueryWrapper, CommitTypeWrapper, DeleteByIdWrapper, DeleteQueryWrapper,
+    OptimizeQueryWrapper, RollbackQueryWrapper, UpdateQueryWrapper,
+};
+use crate::queries::reindex::{ReindexCollectionQueryWrapper, ReindexerWrapper};
 use crate::queries::select::SelectQueryWrapper;
+use crate::queries::update_batch::UpdateBatchWrapper;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use pyo3::wrap_pymodule;
@@ -56,6 +99,34 @@ fn models_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<SolrGroupFieldResultWrapper>()?;
     m.add_class::<SolrStatsResultWrapper>()?;
     m.add_class::<SolrStatsFieldResultWrapper>()?;
+    m.add_class::<BulkIndexSummaryWrapper>()?;
+    m.add_class::<BulkIndexProgressWrapper>()?;
+    m.add_class::<SolrExtractResponseWrapper>()?;
+    m.add_class::<SolrUpdateErrorWrapper>()?;
+    m.add_class::<ReindexSummaryWrapper>()?;
+    m.add_class::<ReindexProgressWrapper>()?;
+    m.add_class::<SolrReindexStatusWrapper>()?;
+    m.add_class::<AdminTaskStateWrapper>()?;
+    m.add_class::<SolrRequestStatusWrapper>()?;
+    m.add_class::<ClusterStatusWrapper>()?;
+    m.add_class::<CollectionStatusWrapper>()?;
+    m.add_class::<ShardStatusWrapper>()?;
+    m.add_class::<ReplicaStatusWrapper>()?;
+    m.add_class::<HealthWrapper>()?;
+    m.add_class::<ShardStateWrapper>()?;
+    m.add_class::<ReplicaStateWrapper>()?;
+    m.add_class::<ReplicaTypeWrapper>()?;
+    m.add_class::<ColStatusWrapper>()?;
+    m.add_class::<CollectionIndexStatusWrapper>()?;
+    m.add_class::<ShardIndexStatusWrapper>()?;
+    m.add_class::<ReplicaCountsWrapper>()?;
+    m.add_class::<LeaderIndexStatusWrapper>()?;
+    m.add_class::<SegmentsInfoWrapper>()?;
+    m.add_class::<SegmentInfoWrapper>()?;
+    m.add_class::<BackupResultWrapper>()?;
+    m.add_class::<ShardBackupWrapper>()?;
+    m.add_class::<BackupPointWrapper>()?;
+    m.add_class::<DeletedBackupWrapper>()?;
     Ok(())
 }
 
@@ -95,6 +166,8 @@ fn _solrstice(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     m.add_class::<StatsComponentWrapper>()?;
 
+    m.add_class::<ElevationComponentWrapper>()?;
+
     m.add_class::<SolrHostWrapper>()?;
     m.add_class::<SolrSingleServerHostWrapper>()?;
     m.add_class::<SolrMultipleServerHostWrapper>()?;
@@ -113,6 +186,43 @@ fn _solrstice(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<CommitTypeWrapper>()?;
     m.add_class::<UpdateQueryWrapper>()?;
     m.add_class::<DeleteQueryWrapper>()?;
+    m.add_class::<DeleteByIdWrapper>()?;
+    m.add_class::<CommitQueryWrapper>()?;
+    m.add_class::<OptimizeQueryWrapper>()?;
+    m.add_class::<RollbackQueryWrapper>()?;
+    m.add_class::<BulkIndexerWrapper>()?;
+    m.add_class::<ReindexerWrapper>()?;
+    m.add_class::<ReindexCollectionQueryWrapper>()?;
+    m.add_class::<UpdateBatchWrapper>()?;
+    m.add_class::<CsvUpdateQueryWrapper>()?;
+    m.add_class::<JsonDocsUpdateQueryWrapper>()?;
+    m.add_class::<ExtractFormatWrapper>()?;
+    m.add_class::<ExtractQueryWrapper>()?;
+    m.add_class::<AtomicUpdateWrapper>()?;
+    m.add_class::<CollectionRouterWrapper>()?;
+    m.add_class::<CreateCollectionOptionsWrapper>()?;
+    m.add_class::<DeleteCollectionOptionsWrapper>()?;
+    m.add_class::<CreateAliasOptionsWrapper>()?;
+    m.add_class::<DeleteAliasOptionsWrapper>()?;
+    m.add_class::<SplitMethodWrapper>()?;
+    m.add_class::<SplitShardOptionsWrapper>()?;
+    m.add_class::<CreateShardOptionsWrapper>()?;
+    m.add_class::<DeleteShardOptionsWrapper>()?;
+    m.add_class::<AddReplicaOptionsWrapper>()?;
+    m.add_class::<DeleteReplicaOptionsWrapper>()?;
+    m.add_class::<MoveReplicaOptionsWrapper>()?;
+    m.add_class::<DeleteNodeOptionsWrapper>()?;
+    m.add_class::<ReplaceNodeOptionsWrapper>()?;
+    m.add_class::<ReplicaPropertyOptionsWrapper>()?;
+    m.add_class::<ReloadCollectionOptionsWrapper>()?;
+    m.add_class::<ModifyCollectionOptionsWrapper>()?;
+    m.add_class::<RenameCollectionOptionsWrapper>()?;
+    m.add_class::<ColStatusOptionsWrapper>()?;
+    m.add_class::<BackupOptionsWrapper>()?;
+    m.add_class::<RestoreOptionsWrapper>()?;
+    m.add_class::<ListBackupsOptionsWrapper>()?;
+    m.add_class::<DeleteBackupOptionsWrapper>()?;
+    m.add_class::<AsyncAdminTaskWrapper>()?;
 
     m.add_class::<AsyncSolrCloudClientWrapper>()?;
     m.add_class::<BlockingSolrCloudClientWrapper>()?;
@@ -126,6 +236,9 @@ fn _solrstice(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(alias_module))?;
     sys_modules.set_item("solrstice.alias", m.getattr("alias")?)?;
 
+    m.add_wrapped(wrap_pymodule!(backup_module))?;
+    sys_modules.set_item("solrstice.backup", m.getattr("backup")?)?;
+
     m.add_wrapped(wrap_pymodule!(models_module))?;
     sys_modules.set_item("solrstice.models", m.getattr("models")?)?;
 
@@ -0,0 +1,52 @@
+use crate::models::error::PyErrWrapper;
+use pyo3::prelude::*;
+use pythonize::pythonize;
+use solrstice::models::{AdminTaskState, SolrRequestStatus};
+
+#[pyclass(name = "AdminTaskState", module = "solrstice.models")]
+#[derive(Clone, Copy, PartialEq)]
+pub enum AdminTaskStateWrapper {
+    Submitted,
+    Running,
+    Completed,
+    Failed,
+    NotFound,
+}
+
+impl From<AdminTaskState> for AdminTaskStateWrapper {
+    fn from(value: AdminTaskState) -> Self {
+        match value {
+            AdminTaskState::Submitted => AdminTaskStateWrapper::Submitted,
+            AdminTaskState::Running => AdminTaskStateWrapper::Running,
+            AdminTaskState::Completed => AdminTaskStateWrapper::Completed,
+            AdminTaskState::Failed => AdminTaskStateWrapper::Failed,
+            AdminTaskState::NotFound => AdminTaskStateWrapper::NotFound,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "SolrRequestStatus", module = "solrstice.models", subclass)]
+pub struct SolrRequestStatusWrapper(SolrRequestStatus);
+
+impl From<SolrRequestStatus> for SolrRequestStatusWrapper {
+    fn from(value: SolrRequestStatus) -> Self {
+        SolrRequestStatusWrapper(value)
+    }
+}
+
+#[pymethods]
+impl SolrRequestStatusWrapper {
+    pub fn get_state(&self) -> AdminTaskStateWrapper {
+        self.0.get_state().into()
+    }
+
+    pub fn get_msg(&self) -> Option<&str> {
+        self.0.get_msg()
+    }
+
+    pub fn get_response(&self) -> PyResult<PyObject> {
+        Python::with_gil(|py| pythonize(py, self.0.get_response()).map_err(PyErrWrapper::from))
+            .map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::models::error::PyErrWrapper;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyList};
+use pythonize::{depythonize_bound, pythonize};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solrstice::{AtomicOperation, AtomicUpdate, Error, VersionConstraint};
+use std::collections::HashMap;
+
+type OperationFn = fn(Value) -> AtomicOperation<Value>;
+
+#[derive(Clone, Serialize, Deserialize)]
+#[pyclass(name = "AtomicUpdate", module = "solrstice", subclass)]
+pub struct AtomicUpdateWrapper(AtomicUpdate);
+
+#[pymethods]
+impl AtomicUpdateWrapper {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        py: Python,
+        id: String,
+        unique_key: Option<String>,
+        root: Option<String>,
+        version: Option<i64>,
+        fields: Option<HashMap<String, PyObject>>,
+        set: Option<HashMap<String, PyObject>>,
+        add: Option<HashMap<String, PyObject>>,
+        add_distinct: Option<HashMap<String, PyObject>>,
+        remove: Option<HashMap<String, PyObject>>,
+        remove_regex: Option<HashMap<String, PyObject>>,
+        inc: Option<HashMap<String, PyObject>>,
+    ) -> PyResult<Self> {
+        let mut builder = AtomicUpdate::with_unique_key(unique_key.unwrap_or("id".to_string()), id);
+        if let Some(root) = root {
+            builder = builder.root(root);
+        }
+        if let Some(version) = version.and_then(VersionConstraint::from_version) {
+            builder = builder.version(version);
+        }
+        for (field, value) in fields.unwrap_or_default() {
+            builder = builder.field(field, doc_to_value(value.bind(py))?);
+        }
+        let operations: [(Option<HashMap<String, PyObject>>, OperationFn); 6] = [
+            (set, AtomicOperation::Set),
+            (add, AtomicOperation::Add),
+            (add_distinct, AtomicOperation::AddDistinct),
+            (remove, AtomicOperation::Remove),
+            (remove_regex, AtomicOperation::RemoveRegex),
+            (inc, AtomicOperation::Inc),
+        ];
+        for (values, operation) in operations {
+            for (field, value) in values.unwrap_or_default() {
+                builder = builder.operation(field, operation(doc_to_value(value.bind(py))?));
+            }
+        }
+        Ok(Self(builder))
+    }
+
+    pub fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        Ok(pythonize(py, &self.0).map_err(PyErrWrapper::from)?)
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+/// Convert a document to JSON. Documents are dictionaries or [AtomicUpdateWrapper], also as child documents in lists.
+pub fn doc_to_value(doc: &Bound<'_, PyAny>) -> PyResult<Value> {
+    if let Ok(update) = doc.extract::<AtomicUpdateWrapper>() {
+        return Ok(update.0.into());
+    }
+    if let Ok(list) = doc.downcast::<PyList>() {
+        return list
+            .iter()
+            .map(|doc| doc_to_value(&doc))
+            .collect::<PyResult<Vec<_>>>()
+            .map(Value::Array);
+    }
+    Ok(depythonize_bound(doc.clone()).map_err(PyErrWrapper::from)?)
+}
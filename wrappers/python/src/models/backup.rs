@@ -0,0 +1,199 @@
+use pyo3::prelude::*;
+use solrstice::models::{BackupPoint, BackupResult, DeletedBackup, ShardBackup};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+#[pyclass(name = "BackupResult", module = "solrstice.models", subclass)]
+pub struct BackupResultWrapper(BackupResult);
+
+impl From<BackupResult> for BackupResultWrapper {
+    fn from(value: BackupResult) -> Self {
+        BackupResultWrapper(value)
+    }
+}
+
+#[pymethods]
+impl BackupResultWrapper {
+    pub fn get_collection(&self) -> Option<&str> {
+        self.0.get_collection()
+    }
+
+    pub fn get_num_shards(&self) -> Option<usize> {
+        self.0.get_num_shards()
+    }
+
+    pub fn get_backup_id(&self) -> Option<u64> {
+        self.0.get_backup_id()
+    }
+
+    pub fn get_index_version(&self) -> Option<&str> {
+        self.0.get_index_version()
+    }
+
+    pub fn get_start_time(&self) -> Option<&str> {
+        self.0.get_start_time()
+    }
+
+    pub fn get_end_time(&self) -> Option<&str> {
+        self.0.get_end_time()
+    }
+
+    pub fn get_index_file_count(&self) -> Option<u64> {
+        self.0.get_index_file_count()
+    }
+
+    pub fn get_uploaded_index_file_count(&self) -> Option<u64> {
+        self.0.get_uploaded_index_file_count()
+    }
+
+    pub fn get_index_size_mb(&self) -> Option<f64> {
+        self.0.get_index_size_mb()
+    }
+
+    pub fn get_uploaded_index_file_mb(&self) -> Option<f64> {
+        self.0.get_uploaded_index_file_mb()
+    }
+
+    pub fn get_shards(&self) -> Vec<ShardBackupWrapper> {
+        self.0
+            .get_shards()
+            .iter()
+            .map(|shard| shard.clone().into())
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "ShardBackup", module = "solrstice.models", subclass)]
+pub struct ShardBackupWrapper(ShardBackup);
+
+impl From<ShardBackup> for ShardBackupWrapper {
+    fn from(value: ShardBackup) -> Self {
+        ShardBackupWrapper(value)
+    }
+}
+
+#[pymethods]
+impl ShardBackupWrapper {
+    pub fn get_shard(&self) -> Option<&str> {
+        self.0.get_shard()
+    }
+
+    pub fn get_shard_backup_id(&self) -> Option<&str> {
+        self.0.get_shard_backup_id()
+    }
+
+    pub fn get_start_time(&self) -> Option<&str> {
+        self.0.get_start_time()
+    }
+
+    pub fn get_end_time(&self) -> Option<&str> {
+        self.0.get_end_time()
+    }
+
+    pub fn get_index_file_count(&self) -> Option<u64> {
+        self.0.get_index_file_count()
+    }
+
+    pub fn get_uploaded_index_file_count(&self) -> Option<u64> {
+        self.0.get_uploaded_index_file_count()
+    }
+
+    pub fn get_index_size_mb(&self) -> Option<f64> {
+        self.0.get_index_size_mb()
+    }
+
+    pub fn get_uploaded_index_file_mb(&self) -> Option<f64> {
+        self.0.get_uploaded_index_file_mb()
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "BackupPoint", module = "solrstice.models", subclass)]
+pub struct BackupPointWrapper(BackupPoint);
+
+impl From<BackupPoint> for BackupPointWrapper {
+    fn from(value: BackupPoint) -> Self {
+        BackupPointWrapper(value)
+    }
+}
+
+#[pymethods]
+impl BackupPointWrapper {
+    pub fn get_backup_id(&self) -> u64 {
+        self.0.get_backup_id()
+    }
+
+    pub fn get_collection(&self) -> Option<&str> {
+        self.0.get_collection()
+    }
+
+    pub fn get_config_name(&self) -> Option<&str> {
+        self.0.get_config_name()
+    }
+
+    pub fn get_start_time(&self) -> Option<&str> {
+        self.0.get_start_time()
+    }
+
+    pub fn get_end_time(&self) -> Option<&str> {
+        self.0.get_end_time()
+    }
+
+    pub fn get_index_version(&self) -> Option<&str> {
+        self.0.get_index_version()
+    }
+
+    pub fn get_index_file_count(&self) -> Option<u64> {
+        self.0.get_index_file_count()
+    }
+
+    pub fn get_index_size_mb(&self) -> Option<f64> {
+        self.0.get_index_size_mb()
+    }
+
+    pub fn get_shard_backup_ids(&self) -> HashMap<String, String> {
+        self.0.get_shard_backup_ids().clone()
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "DeletedBackup", module = "solrstice.models", subclass)]
+pub struct DeletedBackupWrapper(DeletedBackup);
+
+impl From<DeletedBackup> for DeletedBackupWrapper {
+    fn from(value: DeletedBackup) -> Self {
+        DeletedBackupWrapper(value)
+    }
+}
+
+#[pymethods]
+impl DeletedBackupWrapper {
+    pub fn get_backup_id(&self) -> Option<u64> {
+        self.0.get_backup_id()
+    }
+
+    pub fn get_start_time(&self) -> Option<&str> {
+        self.0.get_start_time()
+    }
+
+    pub fn get_size(&self) -> Option<u64> {
+        self.0.get_size()
+    }
+
+    pub fn get_num_files(&self) -> Option<u64> {
+        self.0.get_num_files()
+    }
+
+    pub fn get_num_backup_ids(&self) -> Option<u64> {
+        self.0.get_num_backup_ids()
+    }
+
+    pub fn get_num_shard_backup_ids(&self) -> Option<u64> {
+        self.0.get_num_shard_backup_ids()
+    }
+
+    pub fn get_num_index_files(&self) -> Option<u64> {
+        self.0.get_num_index_files()
+    }
+}
@@ -0,0 +1,99 @@
+use crate::models::response::SolrUpdateErrorWrapper;
+use pyo3::prelude::*;
+use solrstice::models::SolrUpdateError;
+use solrstice::{BulkIndexProgress, BulkIndexSummary};
+
+#[derive(Clone)]
+#[pyclass(name = "BulkIndexSummary", module = "solrstice.models", subclass)]
+pub struct BulkIndexSummaryWrapper(BulkIndexSummary);
+
+impl From<BulkIndexSummary> for BulkIndexSummaryWrapper {
+    fn from(value: BulkIndexSummary) -> Self {
+        BulkIndexSummaryWrapper(value)
+    }
+}
+
+#[pymethods]
+impl BulkIndexSummaryWrapper {
+    pub fn get_batches(&self) -> usize {
+        self.0.get_batches()
+    }
+
+    pub fn get_failed_batches(&self) -> usize {
+        self.0.get_failed_batches()
+    }
+
+    pub fn get_docs_indexed(&self) -> usize {
+        self.0.get_docs_indexed()
+    }
+
+    pub fn get_docs_failed(&self) -> usize {
+        self.0.get_docs_failed()
+    }
+
+    pub fn get_retries(&self) -> usize {
+        self.0.get_retries()
+    }
+}
+
+/// Owned copy of [BulkIndexProgress], as it borrows the error of the batch.
+#[derive(Clone)]
+#[pyclass(name = "BulkIndexProgress", module = "solrstice.models", subclass)]
+pub struct BulkIndexProgressWrapper {
+    batch: usize,
+    batch_docs: usize,
+    attempts: usize,
+    docs_indexed: usize,
+    docs_failed: usize,
+    error: Option<String>,
+    update_errors: Vec<SolrUpdateError>,
+}
+
+impl From<&BulkIndexProgress<'_>> for BulkIndexProgressWrapper {
+    fn from(value: &BulkIndexProgress) -> Self {
+        BulkIndexProgressWrapper {
+            batch: value.get_batch(),
+            batch_docs: value.get_batch_docs(),
+            attempts: value.get_attempts(),
+            docs_indexed: value.get_docs_indexed(),
+            docs_failed: value.get_docs_failed(),
+            error: value.get_error().map(|e| e.to_string()),
+            update_errors: value.get_update_errors().to_vec(),
+        }
+    }
+}
+
+#[pymethods]
+impl BulkIndexProgressWrapper {
+    pub fn get_batch(&self) -> usize {
+        self.batch
+    }
+
+    pub fn get_batch_docs(&self) -> usize {
+        self.batch_docs
+    }
+
+    pub fn get_attempts(&self) -> usize {
+        self.attempts
+    }
+
+    pub fn get_docs_indexed(&self) -> usize {
+        self.docs_indexed
+    }
+
+    pub fn get_docs_failed(&self) -> usize {
+        self.docs_failed
+    }
+
+    pub fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn get_update_errors(&self) -> Vec<SolrUpdateErrorWrapper> {
+        self.update_errors
+            .iter()
+            .cloned()
+            .map(SolrUpdateErrorWrapper::from)
+            .collect()
+    }
+}
@@ -0,0 +1,301 @@
+use crate::queries::collection::CollectionRouterWrapper;
+use pyo3::prelude::*;
+use solrstice::models::{
+    ClusterStatus, CollectionStatus, Health, ReplicaState, ReplicaStatus, ReplicaType, ShardState,
+    ShardStatus,
+};
+use std::collections::HashMap;
+
+#[pyclass(name = "Health", module = "solrstice.models")]
+#[derive(Clone, Copy, PartialEq)]
+pub enum HealthWrapper {
+    Green,
+    Yellow,
+    Orange,
+    Red,
+    Unknown,
+}
+
+impl From<Health> for HealthWrapper {
+    fn from(value: Health) -> Self {
+        match value {
+            Health::Green => HealthWrapper::Green,
+            Health::Yellow => HealthWrapper::Yellow,
+            Health::Orange => HealthWrapper::Orange,
+            Health::Red => HealthWrapper::Red,
+            Health::Unknown => HealthWrapper::Unknown,
+        }
+    }
+}
+
+#[pyclass(name = "ShardState", module = "solrstice.models")]
+#[derive(Clone, Copy, PartialEq)]
+pub enum ShardStateWrapper {
+    Active,
+    Inactive,
+    Construction,
+    Recovery,
+    RecoveryFailed,
+    Unknown,
+}
+
+impl From<ShardState> for ShardStateWrapper {
+    fn from(value: ShardState) -> Self {
+        match value {
+            ShardState::Active => ShardStateWrapper::Active,
+            ShardState::Inactive => ShardStateWrapper::Inactive,
+            ShardState::Construction => ShardStateWrapper::Construction,
+            ShardState::Recovery => ShardStateWrapper::Recovery,
+            ShardState::RecoveryFailed => ShardStateWrapper::RecoveryFailed,
+            ShardState::Unknown => ShardStateWrapper::Unknown,
+        }
+    }
+}
+
+#[pyclass(name = "ReplicaState", module = "solrstice.models")]
+#[derive(Clone, Copy, PartialEq)]
+pub enum ReplicaStateWrapper {
+    Active,
+    Down,
+    Recovering,
+    RecoveryFailed,
+    Unknown,
+}
+
+impl From<ReplicaState> for ReplicaStateWrapper {
+    fn from(value: ReplicaState) -> Self {
+        match value {
+            ReplicaState::Active => ReplicaStateWrapper::Active,
+            ReplicaState::Down => ReplicaStateWrapper::Down,
+            ReplicaState::Recovering => ReplicaStateWrapper::Recovering,
+            ReplicaState::RecoveryFailed => ReplicaStateWrapper::RecoveryFailed,
+            ReplicaState::Unknown => ReplicaStateWrapper::Unknown,
+        }
+    }
+}
+
+#[pyclass(name = "ReplicaType", module = "solrstice.models")]
+#[derive(Clone, Copy, PartialEq)]
+pub enum ReplicaTypeWrapper {
+    Nrt,
+    Tlog,
+    Pull,
+}
+
+impl From<ReplicaType> for ReplicaTypeWrapper {
+    fn from(value: ReplicaType) -> Self {
+        match value {
+            ReplicaType::Nrt => ReplicaTypeWrapper::Nrt,
+            ReplicaType::Tlog => ReplicaTypeWrapper::Tlog,
+            ReplicaType::Pull => ReplicaTypeWrapper::Pull,
+        }
+    }
+}
+
+impl From<ReplicaTypeWrapper> for ReplicaType {
+    fn from(value: ReplicaTypeWrapper) -> Self {
+        match value {
+            ReplicaTypeWrapper::Nrt => ReplicaType::Nrt,
+            ReplicaTypeWrapper::Tlog => ReplicaType::Tlog,
+            ReplicaTypeWrapper::Pull => ReplicaType::Pull,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "ClusterStatus", module = "solrstice.models", subclass)]
+pub struct ClusterStatusWrapper(ClusterStatus);
+
+impl From<ClusterStatus> for ClusterStatusWrapper {
+    fn from(value: ClusterStatus) -> Self {
+        ClusterStatusWrapper(value)
+    }
+}
+
+#[pymethods]
+impl ClusterStatusWrapper {
+    pub fn get_collections(&self) -> HashMap<String, CollectionStatusWrapper> {
+        self.0
+            .get_collections()
+            .iter()
+            .map(|(name, collection)| (name.clone(), collection.clone().into()))
+            .collect()
+    }
+
+    pub fn get_collection(&self, name: &str) -> Option<CollectionStatusWrapper> {
+        self.0
+            .get_collection(name)
+            .map(|collection| collection.clone().into())
+    }
+
+    pub fn get_aliases(&self) -> HashMap<String, Vec<String>> {
+        self.0.get_aliases().clone()
+    }
+
+    pub fn get_live_nodes(&self) -> Vec<String> {
+        self.0.get_live_nodes().to_vec()
+    }
+
+    pub fn get_roles(&self) -> HashMap<String, Vec<String>> {
+        self.0.get_roles().clone()
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "CollectionStatus", module = "solrstice.models", subclass)]
+pub struct CollectionStatusWrapper(CollectionStatus);
+
+impl From<CollectionStatus> for CollectionStatusWrapper {
+    fn from(value: CollectionStatus) -> Self {
+        CollectionStatusWrapper(value)
+    }
+}
+
+#[pymethods]
+impl CollectionStatusWrapper {
+    pub fn get_name(&self) -> &str {
+        self.0.get_name()
+    }
+
+    pub fn get_shards(&self) -> HashMap<String, ShardStatusWrapper> {
+        self.0
+            .get_shards()
+            .iter()
+            .map(|(name, shard)| (name.clone(), shard.clone().into()))
+            .collect()
+    }
+
+    pub fn get_shard(&self, name: &str) -> Option<ShardStatusWrapper> {
+        self.0.get_shard(name).map(|shard| shard.clone().into())
+    }
+
+    pub fn get_config_name(&self) -> Option<&str> {
+        self.0.get_config_name()
+    }
+
+    pub fn get_router(&self) -> Option<CollectionRouterWrapper> {
+        self.0.get_router().map(CollectionRouterWrapper::from)
+    }
+
+    pub fn get_router_field(&self) -> Option<&str> {
+        self.0.get_router_field()
+    }
+
+    pub fn get_replication_factor(&self) -> Option<usize> {
+        self.0.get_replication_factor()
+    }
+
+    pub fn get_nrt_replicas(&self) -> Option<usize> {
+        self.0.get_nrt_replicas()
+    }
+
+    pub fn get_tlog_replicas(&self) -> Option<usize> {
+        self.0.get_tlog_replicas()
+    }
+
+    pub fn get_pull_replicas(&self) -> Option<usize> {
+        self.0.get_pull_replicas()
+    }
+
+    pub fn get_per_replica_state(&self) -> Option<bool> {
+        self.0.get_per_replica_state()
+    }
+
+    pub fn get_znode_version(&self) -> Option<u64> {
+        self.0.get_znode_version()
+    }
+
+    pub fn get_creation_time_millis(&self) -> Option<u64> {
+        self.0.get_creation_time_millis()
+    }
+
+    pub fn get_aliases(&self) -> Vec<String> {
+        self.0.get_aliases().to_vec()
+    }
+
+    pub fn get_health(&self) -> Option<HealthWrapper> {
+        self.0.get_health().map(HealthWrapper::from)
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "ShardStatus", module = "solrstice.models", subclass)]
+pub struct ShardStatusWrapper(ShardStatus);
+
+impl From<ShardStatus> for ShardStatusWrapper {
+    fn from(value: ShardStatus) -> Self {
+        ShardStatusWrapper(value)
+    }
+}
+
+#[pymethods]
+impl ShardStatusWrapper {
+    pub fn get_name(&self) -> &str {
+        self.0.get_name()
+    }
+
+    pub fn get_range(&self) -> Option<&str> {
+        self.0.get_range()
+    }
+
+    pub fn get_state(&self) -> ShardStateWrapper {
+        self.0.get_state().into()
+    }
+
+    pub fn get_replicas(&self) -> HashMap<String, ReplicaStatusWrapper> {
+        self.0
+            .get_replicas()
+            .iter()
+            .map(|(name, replica)| (name.clone(), replica.clone().into()))
+            .collect()
+    }
+
+    pub fn get_leader(&self) -> Option<ReplicaStatusWrapper> {
+        self.0.get_leader().map(|replica| replica.clone().into())
+    }
+
+    pub fn get_health(&self) -> Option<HealthWrapper> {
+        self.0.get_health().map(HealthWrapper::from)
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "ReplicaStatus", module = "solrstice.models", subclass)]
+pub struct ReplicaStatusWrapper(ReplicaStatus);
+
+impl From<ReplicaStatus> for ReplicaStatusWrapper {
+    fn from(value: ReplicaStatus) -> Self {
+        ReplicaStatusWrapper(value)
+    }
+}
+
+#[pymethods]
+impl ReplicaStatusWrapper {
+    pub fn get_name(&self) -> &str {
+        self.0.get_name()
+    }
+
+    pub fn get_core(&self) -> &str {
+        self.0.get_core()
+    }
+
+    pub fn get_base_url(&self) -> Option<&str> {
+        self.0.get_base_url()
+    }
+
+    pub fn get_node_name(&self) -> &str {
+        self.0.get_node_name()
+    }
+
+    pub fn get_state(&self) -> ReplicaStateWrapper {
+        self.0.get_state().into()
+    }
+
+    pub fn get_type(&self) -> ReplicaTypeWrapper {
+        self.0.get_type().into()
+    }
+
+    pub fn is_leader(&self) -> bool {
+        self.0.is_leader()
+    }
+}
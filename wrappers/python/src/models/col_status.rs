@@ -0,0 +1,323 @@
+use crate::models::cluster_status::{ReplicaStateWrapper, ReplicaTypeWrapper, ShardStateWrapper};
+use pyo3::prelude::*;
+use pythonize::pythonize;
+use solrstice::models::{
+    ColStatus, CollectionIndexStatus, LeaderIndexStatus, ReplicaCounts, SegmentInfo, SegmentsInfo,
+    ShardIndexStatus,
+};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+#[pyclass(name = "ColStatus", module = "solrstice.models", subclass)]
+pub struct ColStatusWrapper(ColStatus);
+
+impl From<ColStatus> for ColStatusWrapper {
+    fn from(value: ColStatus) -> Self {
+        ColStatusWrapper(value)
+    }
+}
+
+#[pymethods]
+impl ColStatusWrapper {
+    pub fn get_collections(&self) -> HashMap<String, CollectionIndexStatusWrapper> {
+        self.0
+            .get_collections()
+            .iter()
+            .map(|(name, collection)| (name.clone(), collection.clone().into()))
+            .collect()
+    }
+
+    pub fn get_collection(&self, name: &str) -> Option<CollectionIndexStatusWrapper> {
+        self.0
+            .get_collection(name)
+            .map(|collection| collection.clone().into())
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "CollectionIndexStatus", module = "solrstice.models", subclass)]
+pub struct CollectionIndexStatusWrapper(CollectionIndexStatus);
+
+impl From<CollectionIndexStatus> for CollectionIndexStatusWrapper {
+    fn from(value: CollectionIndexStatus) -> Self {
+        CollectionIndexStatusWrapper(value)
+    }
+}
+
+#[pymethods]
+impl CollectionIndexStatusWrapper {
+    pub fn get_name(&self) -> &str {
+        self.0.get_name()
+    }
+
+    pub fn get_znode_version(&self) -> Option<u64> {
+        self.0.get_znode_version()
+    }
+
+    pub fn get_properties(&self) -> PyResult<PyObject> {
+        Python::with_gil(|py| -> PyResult<PyObject> { Ok(pythonize(py, self.0.get_properties())?) })
+    }
+
+    pub fn get_active_shards(&self) -> usize {
+        self.0.get_active_shards()
+    }
+
+    pub fn get_inactive_shards(&self) -> usize {
+        self.0.get_inactive_shards()
+    }
+
+    pub fn get_schema_non_compliant(&self) -> Vec<String> {
+        self.0.get_schema_non_compliant().to_vec()
+    }
+
+    pub fn get_shards(&self) -> HashMap<String, ShardIndexStatusWrapper> {
+        self.0
+            .get_shards()
+            .iter()
+            .map(|(name, shard)| (name.clone(), shard.clone().into()))
+            .collect()
+    }
+
+    pub fn get_shard(&self, name: &str) -> Option<ShardIndexStatusWrapper> {
+        self.0.get_shard(name).map(|shard| shard.clone().into())
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "ShardIndexStatus", module = "solrstice.models", subclass)]
+pub struct ShardIndexStatusWrapper(ShardIndexStatus);
+
+impl From<ShardIndexStatus> for ShardIndexStatusWrapper {
+    fn from(value: ShardIndexStatus) -> Self {
+        ShardIndexStatusWrapper(value)
+    }
+}
+
+#[pymethods]
+impl ShardIndexStatusWrapper {
+    pub fn get_name(&self) -> &str {
+        self.0.get_name()
+    }
+
+    pub fn get_state(&self) -> ShardStateWrapper {
+        self.0.get_state().into()
+    }
+
+    pub fn get_range(&self) -> Option<&str> {
+        self.0.get_range()
+    }
+
+    pub fn get_replicas(&self) -> Option<ReplicaCountsWrapper> {
+        self.0.get_replicas().map(|replicas| (*replicas).into())
+    }
+
+    pub fn get_leader(&self) -> Option<LeaderIndexStatusWrapper> {
+        self.0.get_leader().map(|leader| leader.clone().into())
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "ReplicaCounts", module = "solrstice.models", subclass)]
+pub struct ReplicaCountsWrapper(ReplicaCounts);
+
+impl From<ReplicaCounts> for ReplicaCountsWrapper {
+    fn from(value: ReplicaCounts) -> Self {
+        ReplicaCountsWrapper(value)
+    }
+}
+
+#[pymethods]
+impl ReplicaCountsWrapper {
+    pub fn get_total(&self) -> usize {
+        self.0.get_total()
+    }
+
+    pub fn get_active(&self) -> usize {
+        self.0.get_active()
+    }
+
+    pub fn get_down(&self) -> usize {
+        self.0.get_down()
+    }
+
+    pub fn get_recovering(&self) -> usize {
+        self.0.get_recovering()
+    }
+
+    pub fn get_recovery_failed(&self) -> usize {
+        self.0.get_recovery_failed()
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "LeaderIndexStatus", module = "solrstice.models", subclass)]
+pub struct LeaderIndexStatusWrapper(LeaderIndexStatus);
+
+impl From<LeaderIndexStatus> for LeaderIndexStatusWrapper {
+    fn from(value: LeaderIndexStatus) -> Self {
+        LeaderIndexStatusWrapper(value)
+    }
+}
+
+#[pymethods]
+impl LeaderIndexStatusWrapper {
+    pub fn get_name(&self) -> Option<&str> {
+        self.0.get_name()
+    }
+
+    pub fn get_core(&self) -> &str {
+        self.0.get_core()
+    }
+
+    pub fn get_base_url(&self) -> Option<&str> {
+        self.0.get_base_url()
+    }
+
+    pub fn get_node_name(&self) -> &str {
+        self.0.get_node_name()
+    }
+
+    pub fn get_state(&self) -> ReplicaStateWrapper {
+        self.0.get_state().into()
+    }
+
+    pub fn get_type(&self) -> ReplicaTypeWrapper {
+        self.0.get_type().into()
+    }
+
+    pub fn get_segments_info(&self) -> Option<SegmentsInfoWrapper> {
+        self.0.get_segments_info().map(|info| info.clone().into())
+    }
+
+    pub fn get_error(&self) -> Option<&str> {
+        self.0.get_error()
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "SegmentsInfo", module = "solrstice.models", subclass)]
+pub struct SegmentsInfoWrapper(SegmentsInfo);
+
+impl From<SegmentsInfo> for SegmentsInfoWrapper {
+    fn from(value: SegmentsInfo) -> Self {
+        SegmentsInfoWrapper(value)
+    }
+}
+
+#[pymethods]
+impl SegmentsInfoWrapper {
+    pub fn get_num_segments(&self) -> usize {
+        self.0.get_num_segments()
+    }
+
+    pub fn get_total_max_doc(&self) -> u64 {
+        self.0.get_total_max_doc()
+    }
+
+    pub fn get_segments_file_name(&self) -> Option<&str> {
+        self.0.get_segments_file_name()
+    }
+
+    pub fn get_commit_lucene_version(&self) -> Option<&str> {
+        self.0.get_commit_lucene_version()
+    }
+
+    pub fn get_min_segment_lucene_version(&self) -> Option<&str> {
+        self.0.get_min_segment_lucene_version()
+    }
+
+    pub fn get_user_data(&self) -> HashMap<String, String> {
+        self.0.get_user_data().clone()
+    }
+
+    pub fn get_size_in_gb(&self) -> Option<f64> {
+        self.0.get_size_in_gb()
+    }
+
+    pub fn get_index_dir(&self) -> Option<&str> {
+        self.0.get_index_dir()
+    }
+
+    pub fn get_data_dir(&self) -> Option<&str> {
+        self.0.get_data_dir()
+    }
+
+    pub fn get_segments(&self) -> HashMap<String, SegmentInfoWrapper> {
+        self.0
+            .get_segments()
+            .iter()
+            .map(|(name, segment)| (name.clone(), segment.clone().into()))
+            .collect()
+    }
+
+    pub fn get_raw_size(&self) -> PyResult<Option<PyObject>> {
+        Python::with_gil(|py| -> PyResult<Option<PyObject>> {
+            match self.0.get_raw_size() {
+                Some(raw_size) => Ok(Some(pythonize(py, raw_size)?)),
+                None => Ok(None),
+            }
+        })
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "SegmentInfo", module = "solrstice.models", subclass)]
+pub struct SegmentInfoWrapper(SegmentInfo);
+
+impl From<SegmentInfo> for SegmentInfoWrapper {
+    fn from(value: SegmentInfo) -> Self {
+        SegmentInfoWrapper(value)
+    }
+}
+
+#[pymethods]
+impl SegmentInfoWrapper {
+    pub fn get_name(&self) -> &str {
+        self.0.get_name()
+    }
+
+    pub fn get_max_doc(&self) -> u64 {
+        self.0.get_max_doc()
+    }
+
+    pub fn get_del_count(&self) -> u64 {
+        self.0.get_del_count()
+    }
+
+    pub fn get_soft_del_count(&self) -> u64 {
+        self.0.get_soft_del_count()
+    }
+
+    pub fn has_field_updates(&self) -> bool {
+        self.0.has_field_updates()
+    }
+
+    pub fn get_size_in_bytes(&self) -> u64 {
+        self.0.get_size_in_bytes()
+    }
+
+    pub fn get_age(&self) -> Option<&str> {
+        self.0.get_age()
+    }
+
+    pub fn get_source(&self) -> Option<&str> {
+        self.0.get_source()
+    }
+
+    pub fn get_version(&self) -> Option<&str> {
+        self.0.get_version()
+    }
+
+    pub fn get_merge_candidate(&self) -> PyResult<Option<PyObject>> {
+        Python::with_gil(|py| -> PyResult<Option<PyObject>> {
+            match self.0.get_merge_candidate() {
+                Some(merge_candidate) => Ok(Some(pythonize(py, merge_candidate)?)),
+                None => Ok(None),
+            }
+        })
+    }
+
+    pub fn get_fields(&self) -> PyResult<PyObject> {
+        Python::with_gil(|py| -> PyResult<PyObject> { Ok(pythonize(py, self.0.get_fields())?) })
+    }
+}
@@ -22,6 +22,8 @@ impl SolrServerContextWrapper {
         host: SolrHostUnion,
         auth: Option<SolrAuthWrapper>,
         logging_policy: Option<LoggingPolicyWrapper>,
+        request_compression: Option<usize>,
+        response_compression: Option<bool>,
     ) -> Self {
         let host = match host {
             SolrHostUnion::SolrHostWrapperEnumValue(h) => h,
@@ -36,6 +38,10 @@ impl SolrServerContextWrapper {
             Some(logging_policy) => builder.with_logging_policy(logging_policy.into()),
             None => builder,
         };
+        builder = builder.with_request_compression(request_compression);
+        if let Some(response_compression) = response_compression {
+            builder = builder.with_response_compression(response_compression);
+        }
         SolrServerContextWrapper(builder.build())
     }
 }
@@ -0,0 +1,28 @@
+use pyo3::prelude::*;
+use solrstice::models::SolrExtractResponse;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+#[pyclass(name = "SolrExtractResponse", module = "solrstice.models", subclass)]
+pub struct SolrExtractResponseWrapper(SolrExtractResponse);
+
+impl From<SolrExtractResponse> for SolrExtractResponseWrapper {
+    fn from(value: SolrExtractResponse) -> Self {
+        SolrExtractResponseWrapper(value)
+    }
+}
+
+#[pymethods]
+impl SolrExtractResponseWrapper {
+    pub fn get_name(&self) -> String {
+        self.0.get_name().to_string()
+    }
+
+    pub fn get_content(&self) -> String {
+        self.0.get_content().to_string()
+    }
+
+    pub fn get_metadata(&self) -> HashMap<String, Vec<String>> {
+        self.0.get_metadata().clone()
+    }
+}
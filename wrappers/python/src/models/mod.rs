@@ -1,8 +1,16 @@
+pub mod admin_task;
+pub mod atomic_update;
 pub mod auth;
+pub mod backup;
+pub mod bulk_index;
+pub mod cluster_status;
+pub mod col_status;
 pub mod context;
 pub mod error;
+pub mod extract;
 pub mod facet_set;
 pub mod group;
 pub mod json_facet;
+pub mod reindex;
 pub mod response;
 pub mod stats;
@@ -0,0 +1,139 @@
+use pyo3::prelude::*;
+use solrstice::models::SolrReindexStatus;
+use solrstice::{ReindexProgress, ReindexSummary};
+
+#[derive(Clone)]
+#[pyclass(name = "ReindexSummary", module = "solrstice.models", subclass)]
+pub struct ReindexSummaryWrapper(ReindexSummary);
+
+impl From<ReindexSummary> for ReindexSummaryWrapper {
+    fn from(value: ReindexSummary) -> Self {
+        ReindexSummaryWrapper(value)
+    }
+}
+
+#[pymethods]
+impl ReindexSummaryWrapper {
+    pub fn get_pages(&self) -> usize {
+        self.0.get_pages()
+    }
+
+    pub fn get_docs_read(&self) -> usize {
+        self.0.get_docs_read()
+    }
+
+    pub fn get_docs_indexed(&self) -> usize {
+        self.0.get_docs_indexed()
+    }
+
+    pub fn get_docs_failed(&self) -> usize {
+        self.0.get_docs_failed()
+    }
+
+    pub fn get_docs_skipped(&self) -> usize {
+        self.0.get_docs_skipped()
+    }
+
+    pub fn get_cursor_mark(&self) -> &str {
+        self.0.get_cursor_mark()
+    }
+}
+
+/// Owned copy of [ReindexProgress], as it borrows the summary of the reindex.
+#[derive(Clone)]
+#[pyclass(name = "ReindexProgress", module = "solrstice.models", subclass)]
+pub struct ReindexProgressWrapper {
+    num_found: usize,
+    pages: usize,
+    docs_read: usize,
+    docs_indexed: usize,
+    docs_failed: usize,
+    docs_skipped: usize,
+    cursor_mark: String,
+}
+
+impl From<&ReindexProgress<'_>> for ReindexProgressWrapper {
+    fn from(value: &ReindexProgress) -> Self {
+        ReindexProgressWrapper {
+            num_found: value.get_num_found(),
+            pages: value.get_pages(),
+            docs_read: value.get_docs_read(),
+            docs_indexed: value.get_docs_indexed(),
+            docs_failed: value.get_docs_failed(),
+            docs_skipped: value.get_docs_skipped(),
+            cursor_mark: value.get_cursor_mark().to_string(),
+        }
+    }
+}
+
+#[pymethods]
+impl ReindexProgressWrapper {
+    pub fn get_num_found(&self) -> usize {
+        self.num_found
+    }
+
+    pub fn get_pages(&self) -> usize {
+        self.pages
+    }
+
+    pub fn get_docs_read(&self) -> usize {
+        self.docs_read
+    }
+
+    pub fn get_docs_indexed(&self) -> usize {
+        self.docs_indexed
+    }
+
+    pub fn get_docs_failed(&self) -> usize {
+        self.docs_failed
+    }
+
+    pub fn get_docs_skipped(&self) -> usize {
+        self.docs_skipped
+    }
+
+    pub fn get_cursor_mark(&self) -> &str {
+        &self.cursor_mark
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "SolrReindexStatus", module = "solrstice.models", subclass)]
+pub struct SolrReindexStatusWrapper(SolrReindexStatus);
+
+impl From<SolrReindexStatus> for SolrReindexStatusWrapper {
+    fn from(value: SolrReindexStatus) -> Self {
+        SolrReindexStatusWrapper(value)
+    }
+}
+
+#[pymethods]
+impl SolrReindexStatusWrapper {
+    pub fn get_state(&self) -> Option<&str> {
+        self.0.get_state()
+    }
+
+    pub fn get_phase(&self) -> Option<&str> {
+        self.0.get_phase()
+    }
+
+    pub fn get_input_docs(&self) -> Option<u64> {
+        self.0.get_input_docs()
+    }
+
+    pub fn get_processed_docs(&self) -> Option<u64> {
+        self.0.get_processed_docs()
+    }
+
+    pub fn get_actual_source_collection(&self) -> Option<&str> {
+        self.0.get_actual_source_collection()
+    }
+
+    pub fn get_actual_target_collection(&self) -> Option<&str> {
+        self.0.get_actual_target_collection()
+    }
+
+    pub fn get_checkpoint_collection(&self) -> Option<&str> {
+        self.0.get_checkpoint_collection()
+    }
+}
@@ -5,7 +5,7 @@ use crate::models::json_facet::SolrJsonFacetResponseWrapper;
 use crate::models::stats::SolrStatsResultWrapper;
 use pyo3::prelude::*;
 use pythonize::pythonize;
-use solrstice::models::{SolrDocsResponse, SolrResponse};
+use solrstice::models::{SolrDocsResponse, SolrResponse, SolrUpdateError, SolrUpdateErrorType};
 use std::collections::HashMap;
 
 #[derive(Clone)]
@@ -115,4 +115,64 @@ impl SolrResponseWrapper {
     pub fn get_json_facets(&self) -> Option<SolrJsonFacetResponseWrapper> {
         self.0.get_json_facets().map(|f| f.clone().into())
     }
+
+    pub fn get_response_header(&self) -> PyResult<Option<PyObject>> {
+        Python::with_gil(|py| {
+            self.0
+                .get_response_header()
+                .map(|header| pythonize(py, header).map_err(PyErrWrapper::from))
+                .transpose()
+        })
+        .map_err(|e| e.into())
+    }
+
+    pub fn get_partial_results(&self) -> bool {
+        self.0.get_partial_results()
+    }
+
+    pub fn get_added_versions(&self) -> Option<HashMap<String, i64>> {
+        self.0.get_added_versions()
+    }
+
+    pub fn get_deleted_versions(&self) -> Option<HashMap<String, i64>> {
+        self.0.get_deleted_versions()
+    }
+
+    pub fn get_update_errors(&self) -> Vec<SolrUpdateErrorWrapper> {
+        self.0
+            .get_update_errors()
+            .iter()
+            .cloned()
+            .map(SolrUpdateErrorWrapper::from)
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(name = "SolrUpdateError", module = "solrstice.models", subclass)]
+pub struct SolrUpdateErrorWrapper(SolrUpdateError);
+
+impl From<SolrUpdateError> for SolrUpdateErrorWrapper {
+    fn from(value: SolrUpdateError) -> Self {
+        SolrUpdateErrorWrapper(value)
+    }
+}
+
+#[pymethods]
+impl SolrUpdateErrorWrapper {
+    pub fn get_error_type(&self) -> &'static str {
+        match self.0.get_error_type() {
+            SolrUpdateErrorType::Add => "ADD",
+            SolrUpdateErrorType::DeleteById => "DELID",
+            SolrUpdateErrorType::DeleteByQuery => "DELQ",
+        }
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.0.get_id()
+    }
+
+    pub fn get_message(&self) -> &str {
+        self.0.get_message()
+    }
 }
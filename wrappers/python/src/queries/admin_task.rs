@@ -0,0 +1,139 @@
+use crate::models::admin_task::SolrRequestStatusWrapper;
+use crate::models::context::SolrServerContextWrapper;
+use crate::models::error::PyErrWrapper;
+use pyo3::prelude::*;
+use solrstice::{AsyncAdminTask, SolrServerContext};
+use std::time::Duration;
+
+#[derive(Clone)]
+#[pyclass(name = "AsyncAdminTask", module = "solrstice", subclass)]
+pub struct AsyncAdminTaskWrapper(AsyncAdminTask);
+
+#[pymethods]
+impl AsyncAdminTaskWrapper {
+    #[new]
+    pub fn new(request_id: String, poll_interval: Option<f64>, timeout: Option<f64>) -> Self {
+        let mut builder = AsyncAdminTask::new(request_id);
+        if let Some(poll_interval) = poll_interval {
+            builder = builder.poll_interval(Duration::from_secs_f64(poll_interval));
+        }
+        Self(builder.timeout(timeout.map(Duration::from_secs_f64)))
+    }
+
+    pub fn get_request_id(&self) -> &str {
+        self.0.get_request_id()
+    }
+
+    pub fn status<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, SolrRequestStatusWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = builder.status(&context).await.map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    pub fn status_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+    ) -> PyResult<SolrRequestStatusWrapper> {
+        let builder = self.0.clone();
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .status_blocking(&context)
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    pub fn wait<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, SolrRequestStatusWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = builder.wait(&context).await.map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    pub fn wait_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+    ) -> PyResult<SolrRequestStatusWrapper> {
+        let builder = self.0.clone();
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .wait_blocking(&context)
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    pub fn delete_status<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let context: SolrServerContext = context.into();
+            builder
+                .delete_status(&context)
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(())
+        })
+    }
+
+    pub fn delete_status_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+    ) -> PyResult<()> {
+        let builder = self.0.clone();
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            builder
+                .delete_status_blocking(&context)
+                .map_err(PyErrWrapper::from)?;
+            Ok(())
+        })
+    }
+
+    #[staticmethod]
+    pub fn delete_all_statuses(
+        py: Python,
+        context: SolrServerContextWrapper,
+    ) -> PyResult<Bound<PyAny>> {
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let context: SolrServerContext = context.into();
+            AsyncAdminTask::delete_all_statuses(&context)
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(())
+        })
+    }
+
+    #[staticmethod]
+    pub fn delete_all_statuses_blocking(
+        py: Python,
+        context: SolrServerContextWrapper,
+    ) -> PyResult<()> {
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            AsyncAdminTask::delete_all_statuses_blocking(&context).map_err(PyErrWrapper::from)?;
+            Ok(())
+        })
+    }
+}
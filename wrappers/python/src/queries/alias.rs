@@ -1,17 +1,20 @@
 use crate::models::context::SolrServerContextWrapper;
 use crate::models::error::PyErrWrapper;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde::{Deserialize, Serialize};
 use solrstice::queries::alias::{
-    alias_exists as alias_exists_rs, create_alias as create_alias_rs,
-    delete_alias as delete_alias_rs, get_aliases as get_aliases_rs,
+    alias_exists as alias_exists_rs, create_alias_with_options as create_alias_with_options_rs,
+    delete_alias_with_options as delete_alias_with_options_rs, get_aliases as get_aliases_rs,
+    CreateAliasOptions, DeleteAliasOptions,
 };
 use solrstice::queries::alias::{
     alias_exists_blocking as alias_exists_blocking_rs,
-    create_alias_blocking as create_alias_blocking_rs,
-    delete_alias_blocking as delete_alias_blocking_rs,
+    create_alias_with_options_blocking as create_alias_with_options_blocking_rs,
+    delete_alias_with_options_blocking as delete_alias_with_options_blocking_rs,
     get_aliases_blocking as get_aliases_blocking_rs,
 };
-use solrstice::SolrServerContext;
+use solrstice::{Error, SolrServerContext};
 use std::collections::HashMap;
 
 #[pymodule]
@@ -28,6 +31,76 @@ pub fn alias(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "CreateAliasOptions", module = "solrstice", subclass)]
+pub struct CreateAliasOptionsWrapper(CreateAliasOptions);
+
+#[pymethods]
+impl CreateAliasOptionsWrapper {
+    #[new]
+    pub fn new(async_id: Option<String>) -> Self {
+        Self(CreateAliasOptions::new().async_id::<String, _>(async_id))
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "DeleteAliasOptions", module = "solrstice", subclass)]
+pub struct DeleteAliasOptionsWrapper(DeleteAliasOptions);
+
+#[pymethods]
+impl DeleteAliasOptionsWrapper {
+    #[new]
+    pub fn new(async_id: Option<String>) -> Self {
+        Self(DeleteAliasOptions::new().async_id::<String, _>(async_id))
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
 #[pyfunction]
 pub fn get_aliases(py: Python, context: SolrServerContextWrapper) -> PyResult<Bound<PyAny>> {
     let context: SolrServerContext = context.into();
@@ -55,10 +128,12 @@ pub fn create_alias(
     context: SolrServerContextWrapper,
     name: String,
     collections: Vec<String>,
+    options: Option<CreateAliasOptionsWrapper>,
 ) -> PyResult<Bound<PyAny>> {
     pyo3_asyncio::tokio::future_into_py(py, async move {
         let context: SolrServerContext = context.into();
-        Ok(create_alias_rs(
+        let options = options.unwrap_or_default();
+        Ok(create_alias_with_options_rs(
             &context,
             name.as_str(),
             collections
@@ -66,6 +141,7 @@ pub fn create_alias(
                 .map(|x| x.as_str())
                 .collect::<Vec<_>>()
                 .as_slice(),
+            &options.0,
         )
         .await
         .map_err(PyErrWrapper::from)?)
@@ -78,10 +154,12 @@ pub fn create_alias_blocking(
     context: SolrServerContextWrapper,
     name: String,
     collections: Vec<String>,
+    options: Option<CreateAliasOptionsWrapper>,
 ) -> PyResult<()> {
     py.allow_threads(move || {
         let context: SolrServerContext = context.into();
-        Ok(create_alias_blocking_rs(
+        let options = options.unwrap_or_default();
+        Ok(create_alias_with_options_blocking_rs(
             &context,
             name.as_str(),
             collections
@@ -89,6 +167,7 @@ pub fn create_alias_blocking(
                 .map(|x| x.as_str())
                 .collect::<Vec<_>>()
                 .as_slice(),
+            &options.0,
         )
         .map_err(PyErrWrapper::from)?)
     })
@@ -128,10 +207,12 @@ pub fn delete_alias(
     py: Python,
     context: SolrServerContextWrapper,
     name: String,
+    options: Option<DeleteAliasOptionsWrapper>,
 ) -> PyResult<Bound<PyAny>> {
     pyo3_asyncio::tokio::future_into_py(py, async move {
         let context: SolrServerContext = context.into();
-        delete_alias_rs(&context, name.as_str())
+        let options = options.unwrap_or_default();
+        delete_alias_with_options_rs(&context, name.as_str(), &options.0)
             .await
             .map_err(PyErrWrapper::from)?;
         Ok(())
@@ -143,10 +224,13 @@ pub fn delete_alias_blocking(
     py: Python,
     context: SolrServerContextWrapper,
     name: String,
+    options: Option<DeleteAliasOptionsWrapper>,
 ) -> PyResult<()> {
     py.allow_threads(move || {
         let context: SolrServerContext = context.into();
-        delete_alias_blocking_rs(&context, name.as_str()).map_err(PyErrWrapper::from)?;
+        let options = options.unwrap_or_default();
+        delete_alias_with_options_blocking_rs(&context, name.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
         Ok(())
     })
 }
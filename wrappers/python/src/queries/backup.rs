@@ -0,0 +1,366 @@
+use crate::models::backup::{BackupPointWrapper, BackupResultWrapper, DeletedBackupWrapper};
+use crate::models::context::SolrServerContextWrapper;
+use crate::models::error::PyErrWrapper;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde::{Deserialize, Serialize};
+use solrstice::queries::backup::{
+    backup_collection as backup_collection_rs, delete_backup as delete_backup_rs,
+    list_backups as list_backups_rs, restore_collection as restore_collection_rs, BackupOptions,
+    DeleteBackupOptions, ListBackupsOptions, RestoreOptions,
+};
+use solrstice::queries::backup::{
+    backup_collection_blocking as backup_collection_blocking_rs,
+    delete_backup_blocking as delete_backup_blocking_rs,
+    list_backups_blocking as list_backups_blocking_rs,
+    restore_collection_blocking as restore_collection_blocking_rs,
+};
+use solrstice::{Error, SolrServerContext};
+
+#[pymodule]
+pub fn backup(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(backup_collection, m)?)?;
+    m.add_function(wrap_pyfunction!(restore_collection, m)?)?;
+    m.add_function(wrap_pyfunction!(list_backups, m)?)?;
+    m.add_function(wrap_pyfunction!(delete_backup, m)?)?;
+
+    m.add_function(wrap_pyfunction!(backup_collection_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(restore_collection_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(list_backups_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(delete_backup_blocking, m)?)?;
+    Ok(())
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "BackupOptions", module = "solrstice", subclass)]
+pub struct BackupOptionsWrapper(BackupOptions);
+
+#[pymethods]
+impl BackupOptionsWrapper {
+    #[new]
+    pub fn new(
+        location: Option<String>,
+        repository: Option<String>,
+        incremental: Option<bool>,
+        max_num_backup_points: Option<usize>,
+        async_id: Option<String>,
+    ) -> Self {
+        Self(
+            BackupOptions::new()
+                .location::<String, _>(location)
+                .repository::<String, _>(repository)
+                .incremental(incremental)
+                .max_num_backup_points(max_num_backup_points)
+                .async_id::<String, _>(async_id),
+        )
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "RestoreOptions", module = "solrstice", subclass)]
+pub struct RestoreOptionsWrapper(RestoreOptions);
+
+#[pymethods]
+impl RestoreOptionsWrapper {
+    #[new]
+    pub fn new(
+        location: Option<String>,
+        repository: Option<String>,
+        backup_id: Option<u64>,
+        config_name: Option<String>,
+        replication_factor: Option<usize>,
+        async_id: Option<String>,
+    ) -> Self {
+        Self(
+            RestoreOptions::new()
+                .location::<String, _>(location)
+                .repository::<String, _>(repository)
+                .backup_id(backup_id)
+                .config_name::<String, _>(config_name)
+                .replication_factor(replication_factor)
+                .async_id::<String, _>(async_id),
+        )
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "ListBackupsOptions", module = "solrstice", subclass)]
+pub struct ListBackupsOptionsWrapper(ListBackupsOptions);
+
+#[pymethods]
+impl ListBackupsOptionsWrapper {
+    #[new]
+    pub fn new(location: Option<String>, repository: Option<String>) -> Self {
+        Self(
+            ListBackupsOptions::new()
+                .location::<String, _>(location)
+                .repository::<String, _>(repository),
+        )
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "DeleteBackupOptions", module = "solrstice", subclass)]
+pub struct DeleteBackupOptionsWrapper(DeleteBackupOptions);
+
+#[pymethods]
+impl DeleteBackupOptionsWrapper {
+    #[new]
+    pub fn new(
+        location: Option<String>,
+        repository: Option<String>,
+        backup_id: Option<u64>,
+        max_num_backup_points: Option<usize>,
+        purge_unused: Option<bool>,
+        async_id: Option<String>,
+    ) -> Self {
+        Self(
+            DeleteBackupOptions::new()
+                .location::<String, _>(location)
+                .repository::<String, _>(repository)
+                .backup_id(backup_id)
+                .max_num_backup_points(max_num_backup_points)
+                .purge_unused(purge_unused)
+                .async_id::<String, _>(async_id),
+        )
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[pyfunction]
+pub fn backup_collection(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    name: String,
+    options: Option<BackupOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        let result = backup_collection_rs(&context, collection.as_str(), name.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(BackupResultWrapper::from(result))
+    })
+}
+
+#[pyfunction]
+pub fn backup_collection_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    name: String,
+    options: Option<BackupOptionsWrapper>,
+) -> PyResult<BackupResultWrapper> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        let result =
+            backup_collection_blocking_rs(&context, collection.as_str(), name.as_str(), &options.0)
+                .map_err(PyErrWrapper::from)?;
+        Ok(BackupResultWrapper::from(result))
+    })
+}
+
+#[pyfunction]
+pub fn restore_collection(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    name: String,
+    options: Option<RestoreOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        restore_collection_rs(&context, collection.as_str(), name.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn restore_collection_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    name: String,
+    options: Option<RestoreOptionsWrapper>,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        restore_collection_blocking_rs(&context, collection.as_str(), name.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn list_backups(
+    py: Python,
+    context: SolrServerContextWrapper,
+    name: String,
+    options: Option<ListBackupsOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        let result = list_backups_rs(&context, name.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(result
+            .into_iter()
+            .map(BackupPointWrapper::from)
+            .collect::<Vec<_>>())
+    })
+}
+
+#[pyfunction]
+pub fn list_backups_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    name: String,
+    options: Option<ListBackupsOptionsWrapper>,
+) -> PyResult<Vec<BackupPointWrapper>> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        let result = list_backups_blocking_rs(&context, name.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(result
+            .into_iter()
+            .map(BackupPointWrapper::from)
+            .collect::<Vec<_>>())
+    })
+}
+
+#[pyfunction]
+pub fn delete_backup(
+    py: Python,
+    context: SolrServerContextWrapper,
+    name: String,
+    options: DeleteBackupOptionsWrapper,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let result = delete_backup_rs(&context, name.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(result
+            .into_iter()
+            .map(DeletedBackupWrapper::from)
+            .collect::<Vec<_>>())
+    })
+}
+
+#[pyfunction]
+pub fn delete_backup_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    name: String,
+    options: DeleteBackupOptionsWrapper,
+) -> PyResult<Vec<DeletedBackupWrapper>> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let result = delete_backup_blocking_rs(&context, name.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(result
+            .into_iter()
+            .map(DeletedBackupWrapper::from)
+            .collect::<Vec<_>>())
+    })
+}
@@ -0,0 +1,158 @@
+use crate::models::atomic_update::doc_to_value;
+use crate::models::bulk_index::{BulkIndexProgressWrapper, BulkIndexSummaryWrapper};
+use crate::models::context::SolrServerContextWrapper;
+use crate::models::error::PyErrWrapper;
+use crate::queries::index::UpdateQueryWrapper;
+use pyo3::prelude::*;
+use pyo3::types::PyIterator;
+use serde_json::Value;
+use solrstice::{BulkIndexer, SolrServerContext, UpdateQuery};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Clone)]
+#[pyclass(name = "BulkIndexer", module = "solrstice", subclass)]
+pub struct BulkIndexerWrapper(BulkIndexer);
+
+#[pymethods]
+impl BulkIndexerWrapper {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        update_query: Option<UpdateQueryWrapper>,
+        batch_size: Option<usize>,
+        batch_bytes: Option<usize>,
+        concurrency: Option<usize>,
+        max_retries: Option<usize>,
+        initial_backoff: Option<f64>,
+        max_backoff: Option<f64>,
+        on_progress: Option<PyObject>,
+    ) -> Self {
+        let mut builder = BulkIndexer::new();
+        if let Some(update_query) = update_query {
+            builder = builder.update_query(UpdateQuery::from(update_query));
+        }
+        if let Some(batch_size) = batch_size {
+            builder = builder.batch_size(batch_size);
+        }
+        if let Some(batch_bytes) = batch_bytes {
+            builder = builder.batch_bytes(batch_bytes);
+        }
+        if let Some(concurrency) = concurrency {
+            builder = builder.concurrency(concurrency);
+        }
+        if let Some(max_retries) = max_retries {
+            builder = builder.max_retries(max_retries);
+        }
+        if let Some(initial_backoff) = initial_backoff {
+            builder = builder.initial_backoff(Duration::from_secs_f64(initial_backoff));
+        }
+        if let Some(max_backoff) = max_backoff {
+            builder = builder.max_backoff(Duration::from_secs_f64(max_backoff));
+        }
+        if let Some(on_progress) = on_progress {
+            builder = builder.on_progress(move |progress| {
+                Python::with_gil(|py| {
+                    let progress = BulkIndexProgressWrapper::from(progress);
+                    if let Err(e) = on_progress.call1(py, (progress,)) {
+                        e.print(py);
+                    }
+                })
+            });
+        }
+        Self(builder)
+    }
+
+    pub fn execute<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+        collection: String,
+        docs: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        let docs = PyDocIterator::new(docs)?;
+        let error = docs.error.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute_iter(&context, collection.as_str(), docs)
+                .await
+                .map_err(PyErrWrapper::from)?;
+            if let Some(e) = error.lock().unwrap().take() {
+                return Err(e);
+            }
+            Ok(Python::with_gil(|_| BulkIndexSummaryWrapper::from(result)))
+        })
+    }
+
+    pub fn execute_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+        docs: &Bound<'_, PyAny>,
+    ) -> PyResult<BulkIndexSummaryWrapper> {
+        let builder = self.0.clone();
+        let docs = PyDocIterator::new(docs)?;
+        let error = docs.error.clone();
+        let result = py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            builder
+                .execute_blocking(&context, collection.as_str(), docs)
+                .map_err(PyErrWrapper::from)
+        })?;
+        if let Some(e) = error.lock().unwrap().take() {
+            return Err(e);
+        }
+        Ok(result.into())
+    }
+}
+
+impl From<BulkIndexerWrapper> for BulkIndexer {
+    fn from(value: BulkIndexerWrapper) -> Self {
+        value.0
+    }
+}
+
+/// Reads documents from any Python iterable, such as a generator, one at a time.
+///
+/// Iteration stops at the first error, which is kept to be raised after the indexing.
+struct PyDocIterator {
+    iterator: Py<PyIterator>,
+    error: Arc<Mutex<Option<PyErr>>>,
+}
+
+impl PyDocIterator {
+    fn new(docs: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyDocIterator {
+            iterator: PyIterator::from_bound_object(docs)?.unbind(),
+            error: Arc::new(Mutex::new(None)),
+        })
+    }
+}
+
+impl Iterator for PyDocIterator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.lock().unwrap().is_some() {
+            return None;
+        }
+        Python::with_gil(|py| {
+            let doc = self
+                .iterator
+                .bind(py)
+                .clone()
+                .next()?
+                .and_then(|doc| doc_to_value(&doc));
+            match doc {
+                Ok(doc) => Some(doc),
+                Err(e) => {
+                    *self.error.lock().unwrap() = Some(e);
+                    None
+                }
+            }
+        })
+    }
+}
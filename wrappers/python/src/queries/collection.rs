@@ -1,29 +1,108 @@
+use crate::models::cluster_status::{ClusterStatusWrapper, ReplicaTypeWrapper};
+use crate::models::col_status::ColStatusWrapper;
 use crate::models::context::SolrServerContextWrapper;
 use crate::models::error::PyErrWrapper;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde::{Deserialize, Serialize};
+use solrstice::models::ReplicaType;
 use solrstice::queries::collection::{
+    add_replica as add_replica_rs, add_replica_property as add_replica_property_rs,
     collection_exists as collection_exists_rs, create_collection as create_collection_rs,
-    delete_collection as delete_collection_rs, get_collections as get_collections_rs,
+    create_collection_with_options as create_collection_with_options_rs,
+    create_shard as create_shard_rs,
+    delete_collection_with_options as delete_collection_with_options_rs,
+    delete_node as delete_node_rs, delete_replica as delete_replica_rs,
+    delete_replica_property as delete_replica_property_rs, delete_shard as delete_shard_rs,
+    get_cluster_status as get_cluster_status_rs, get_col_status as get_col_status_rs,
+    get_collections as get_collections_rs, modify_collection as modify_collection_rs,
+    move_replica as move_replica_rs, reload_collection as reload_collection_rs,
+    reload_collections_using_config as reload_collections_using_config_rs,
+    rename_collection as rename_collection_rs, replace_node as replace_node_rs,
+    split_shard as split_shard_rs, AddReplicaOptions, ColStatusOptions, CollectionRouter,
+    CreateCollectionOptions, CreateShardOptions, DeleteCollectionOptions, DeleteNodeOptions,
+    DeleteReplicaOptions, DeleteShardOptions, ModifyCollectionOptions, MoveReplicaOptions,
+    ReloadCollectionOptions, RenameCollectionOptions, ReplaceNodeOptions, ReplicaPropertyOptions,
+    SplitMethod, SplitShardOptions,
 };
 use solrstice::queries::collection::{
+    add_replica_blocking as add_replica_blocking_rs,
+    add_replica_property_blocking as add_replica_property_blocking_rs,
     collection_exists_blocking as collection_exists_blocking_rs,
     create_collection_blocking as create_collection_blocking_rs,
-    delete_collection_blocking as delete_collection_blocking_rs,
+    create_collection_with_options_blocking as create_collection_with_options_blocking_rs,
+    create_shard_blocking as create_shard_blocking_rs,
+    delete_collection_with_options_blocking as delete_collection_with_options_blocking_rs,
+    delete_node_blocking as delete_node_blocking_rs,
+    delete_replica_blocking as delete_replica_blocking_rs,
+    delete_replica_property_blocking as delete_replica_property_blocking_rs,
+    delete_shard_blocking as delete_shard_blocking_rs,
+    get_cluster_status_blocking as get_cluster_status_blocking_rs,
+    get_col_status_blocking as get_col_status_blocking_rs,
     get_collections_blocking as get_collections_blocking_rs,
+    modify_collection_blocking as modify_collection_blocking_rs,
+    move_replica_blocking as move_replica_blocking_rs,
+    reload_collection_blocking as reload_collection_blocking_rs,
+    reload_collections_using_config_blocking as reload_collections_using_config_blocking_rs,
+    rename_collection_blocking as rename_collection_blocking_rs,
+    replace_node_blocking as replace_node_blocking_rs,
+    split_shard_blocking as split_shard_blocking_rs,
 };
-use solrstice::SolrServerContext;
+use solrstice::{Error, SolrServerContext};
+use std::collections::HashMap;
+use std::time::Duration;
 
 #[pymodule]
 pub fn collection(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(create_collection, m)?)?;
+    m.add_function(wrap_pyfunction!(create_collection_with_options, m)?)?;
     m.add_function(wrap_pyfunction!(get_collections, m)?)?;
     m.add_function(wrap_pyfunction!(collection_exists, m)?)?;
+    m.add_function(wrap_pyfunction!(get_cluster_status, m)?)?;
+    m.add_function(wrap_pyfunction!(get_col_status, m)?)?;
     m.add_function(wrap_pyfunction!(delete_collection, m)?)?;
+    m.add_function(wrap_pyfunction!(reload_collection, m)?)?;
+    m.add_function(wrap_pyfunction!(reload_collections_using_config, m)?)?;
+    m.add_function(wrap_pyfunction!(modify_collection, m)?)?;
+    m.add_function(wrap_pyfunction!(rename_collection, m)?)?;
+    m.add_function(wrap_pyfunction!(split_shard, m)?)?;
+    m.add_function(wrap_pyfunction!(create_shard, m)?)?;
+    m.add_function(wrap_pyfunction!(delete_shard, m)?)?;
+    m.add_function(wrap_pyfunction!(add_replica, m)?)?;
+    m.add_function(wrap_pyfunction!(delete_replica, m)?)?;
+    m.add_function(wrap_pyfunction!(move_replica, m)?)?;
+    m.add_function(wrap_pyfunction!(delete_node, m)?)?;
+    m.add_function(wrap_pyfunction!(replace_node, m)?)?;
+    m.add_function(wrap_pyfunction!(add_replica_property, m)?)?;
+    m.add_function(wrap_pyfunction!(delete_replica_property, m)?)?;
 
     m.add_function(wrap_pyfunction!(create_collection_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        create_collection_with_options_blocking,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(get_collections_blocking, m)?)?;
     m.add_function(wrap_pyfunction!(collection_exists_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(get_cluster_status_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(get_col_status_blocking, m)?)?;
     m.add_function(wrap_pyfunction!(delete_collection_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(reload_collection_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        reload_collections_using_config_blocking,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(modify_collection_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(rename_collection_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(split_shard_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(create_shard_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(delete_shard_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(add_replica_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(delete_replica_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(move_replica_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(delete_node_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(replace_node_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(add_replica_property_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(delete_replica_property_blocking, m)?)?;
     Ok(())
 }
 
@@ -74,6 +153,779 @@ pub fn create_collection_blocking(
     })
 }
 
+#[pyclass(name = "CollectionRouter")]
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub enum CollectionRouterWrapper {
+    CompositeId,
+    Implicit,
+}
+
+impl From<CollectionRouter> for CollectionRouterWrapper {
+    fn from(value: CollectionRouter) -> Self {
+        match value {
+            CollectionRouter::CompositeId => CollectionRouterWrapper::CompositeId,
+            CollectionRouter::Implicit => CollectionRouterWrapper::Implicit,
+        }
+    }
+}
+
+impl From<CollectionRouterWrapper> for CollectionRouter {
+    fn from(value: CollectionRouterWrapper) -> Self {
+        match value {
+            CollectionRouterWrapper::CompositeId => CollectionRouter::CompositeId,
+            CollectionRouterWrapper::Implicit => CollectionRouter::Implicit,
+        }
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "CreateCollectionOptions", module = "solrstice", subclass)]
+pub struct CreateCollectionOptionsWrapper(CreateCollectionOptions);
+
+#[pymethods]
+impl CreateCollectionOptionsWrapper {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        config_name: Option<String>,
+        num_shards: Option<usize>,
+        replication_factor: Option<usize>,
+        router: Option<CollectionRouterWrapper>,
+        router_field: Option<String>,
+        shards: Option<Vec<String>>,
+        nrt_replicas: Option<usize>,
+        tlog_replicas: Option<usize>,
+        pull_replicas: Option<usize>,
+        create_node_set: Option<Vec<String>>,
+        per_replica_state: Option<bool>,
+        properties: Option<HashMap<String, String>>,
+        wait_for_final_state: Option<bool>,
+        async_id: Option<String>,
+    ) -> Self {
+        let mut builder = CreateCollectionOptions::new()
+            .config_name::<String, _>(config_name)
+            .num_shards(num_shards)
+            .replication_factor(replication_factor)
+            .router(router.map(CollectionRouter::from))
+            .router_field::<String, _>(router_field)
+            .shards::<String, Vec<String>, _>(shards)
+            .nrt_replicas(nrt_replicas)
+            .tlog_replicas(tlog_replicas)
+            .pull_replicas(pull_replicas)
+            .create_node_set::<String, Vec<String>, _>(create_node_set)
+            .per_replica_state(per_replica_state)
+            .wait_for_final_state(wait_for_final_state)
+            .async_id::<String, _>(async_id);
+        if let Some(properties) = properties {
+            builder = builder.properties(properties);
+        }
+        Self(builder)
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[pyclass(name = "SplitMethod")]
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub enum SplitMethodWrapper {
+    Rewrite,
+    Link,
+}
+
+impl From<SplitMethodWrapper> for SplitMethod {
+    fn from(value: SplitMethodWrapper) -> Self {
+        match value {
+            SplitMethodWrapper::Rewrite => SplitMethod::Rewrite,
+            SplitMethodWrapper::Link => SplitMethod::Link,
+        }
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "SplitShardOptions", module = "solrstice", subclass)]
+pub struct SplitShardOptionsWrapper(SplitShardOptions);
+
+#[pymethods]
+impl SplitShardOptionsWrapper {
+    #[new]
+    pub fn new(
+        shard: Option<String>,
+        split_key: Option<String>,
+        ranges: Option<Vec<String>>,
+        num_sub_shards: Option<usize>,
+        split_method: Option<SplitMethodWrapper>,
+        wait_for_final_state: Option<bool>,
+        async_id: Option<String>,
+    ) -> Self {
+        Self(
+            SplitShardOptions::new()
+                .shard::<String, _>(shard)
+                .split_key::<String, _>(split_key)
+                .ranges::<String, Vec<String>, _>(ranges)
+                .num_sub_shards(num_sub_shards)
+                .split_method(split_method.map(SplitMethod::from))
+                .wait_for_final_state(wait_for_final_state)
+                .async_id::<String, _>(async_id),
+        )
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "CreateShardOptions", module = "solrstice", subclass)]
+pub struct CreateShardOptionsWrapper(CreateShardOptions);
+
+#[pymethods]
+impl CreateShardOptionsWrapper {
+    #[new]
+    pub fn new(
+        nrt_replicas: Option<usize>,
+        tlog_replicas: Option<usize>,
+        pull_replicas: Option<usize>,
+        create_node_set: Option<Vec<String>>,
+        properties: Option<HashMap<String, String>>,
+        wait_for_final_state: Option<bool>,
+        async_id: Option<String>,
+    ) -> Self {
+        let mut builder = CreateShardOptions::new()
+            .nrt_replicas(nrt_replicas)
+            .tlog_replicas(tlog_replicas)
+            .pull_replicas(pull_replicas)
+            .create_node_set::<String, Vec<String>, _>(create_node_set)
+            .wait_for_final_state(wait_for_final_state)
+            .async_id::<String, _>(async_id);
+        if let Some(properties) = properties {
+            builder = builder.properties(properties);
+        }
+        Self(builder)
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "DeleteShardOptions", module = "solrstice", subclass)]
+pub struct DeleteShardOptionsWrapper(DeleteShardOptions);
+
+#[pymethods]
+impl DeleteShardOptionsWrapper {
+    #[new]
+    pub fn new(
+        delete_instance_dir: Option<bool>,
+        delete_data_dir: Option<bool>,
+        delete_index: Option<bool>,
+        async_id: Option<String>,
+    ) -> Self {
+        Self(
+            DeleteShardOptions::new()
+                .delete_instance_dir(delete_instance_dir)
+                .delete_data_dir(delete_data_dir)
+                .delete_index(delete_index)
+                .async_id::<String, _>(async_id),
+        )
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "AddReplicaOptions", module = "solrstice", subclass)]
+pub struct AddReplicaOptionsWrapper(AddReplicaOptions);
+
+#[pymethods]
+impl AddReplicaOptionsWrapper {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        replica_type: Option<ReplicaTypeWrapper>,
+        node: Option<String>,
+        create_node_set: Option<Vec<String>>,
+        nrt_replicas: Option<usize>,
+        tlog_replicas: Option<usize>,
+        pull_replicas: Option<usize>,
+        properties: Option<HashMap<String, String>>,
+        wait_for_final_state: Option<bool>,
+        async_id: Option<String>,
+    ) -> Self {
+        let mut builder = AddReplicaOptions::new()
+            .replica_type(replica_type.map(ReplicaType::from))
+            .node::<String, _>(node)
+            .create_node_set::<String, Vec<String>, _>(create_node_set)
+            .nrt_replicas(nrt_replicas)
+            .tlog_replicas(tlog_replicas)
+            .pull_replicas(pull_replicas)
+            .wait_for_final_state(wait_for_final_state)
+            .async_id::<String, _>(async_id);
+        if let Some(properties) = properties {
+            builder = builder.properties(properties);
+        }
+        Self(builder)
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "DeleteReplicaOptions", module = "solrstice", subclass)]
+pub struct DeleteReplicaOptionsWrapper(DeleteReplicaOptions);
+
+#[pymethods]
+impl DeleteReplicaOptionsWrapper {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        shard: Option<String>,
+        replica: Option<String>,
+        count: Option<usize>,
+        replica_type: Option<ReplicaTypeWrapper>,
+        node: Option<String>,
+        only_if_down: Option<bool>,
+        delete_instance_dir: Option<bool>,
+        delete_data_dir: Option<bool>,
+        delete_index: Option<bool>,
+        async_id: Option<String>,
+    ) -> Self {
+        Self(
+            DeleteReplicaOptions::new()
+                .shard::<String, _>(shard)
+                .replica::<String, _>(replica)
+                .count(count)
+                .replica_type(replica_type.map(ReplicaType::from))
+                .node::<String, _>(node)
+                .only_if_down(only_if_down)
+                .delete_instance_dir(delete_instance_dir)
+                .delete_data_dir(delete_data_dir)
+                .delete_index(delete_index)
+                .async_id::<String, _>(async_id),
+        )
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "MoveReplicaOptions", module = "solrstice", subclass)]
+pub struct MoveReplicaOptionsWrapper(MoveReplicaOptions);
+
+#[pymethods]
+impl MoveReplicaOptionsWrapper {
+    #[new]
+    pub fn new(
+        replica: Option<String>,
+        shard: Option<String>,
+        source_node: Option<String>,
+        in_place_move: Option<bool>,
+        timeout: Option<f64>,
+        async_id: Option<String>,
+    ) -> Self {
+        Self(
+            MoveReplicaOptions::new()
+                .replica::<String, _>(replica)
+                .shard::<String, _>(shard)
+                .source_node::<String, _>(source_node)
+                .in_place_move(in_place_move)
+                .timeout(timeout.map(Duration::from_secs_f64))
+                .async_id::<String, _>(async_id),
+        )
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "DeleteNodeOptions", module = "solrstice", subclass)]
+pub struct DeleteNodeOptionsWrapper(DeleteNodeOptions);
+
+#[pymethods]
+impl DeleteNodeOptionsWrapper {
+    #[new]
+    pub fn new(async_id: Option<String>) -> Self {
+        Self(DeleteNodeOptions::new().async_id::<String, _>(async_id))
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "ReplaceNodeOptions", module = "solrstice", subclass)]
+pub struct ReplaceNodeOptionsWrapper(ReplaceNodeOptions);
+
+#[pymethods]
+impl ReplaceNodeOptionsWrapper {
+    #[new]
+    pub fn new(
+        target_node: Option<String>,
+        timeout: Option<f64>,
+        async_id: Option<String>,
+    ) -> Self {
+        Self(
+            ReplaceNodeOptions::new()
+                .target_node::<String, _>(target_node)
+                .timeout(timeout.map(Duration::from_secs_f64))
+                .async_id::<String, _>(async_id),
+        )
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "ReloadCollectionOptions", module = "solrstice", subclass)]
+pub struct ReloadCollectionOptionsWrapper(ReloadCollectionOptions);
+
+#[pymethods]
+impl ReloadCollectionOptionsWrapper {
+    #[new]
+    pub fn new(async_id: Option<String>) -> Self {
+        Self(ReloadCollectionOptions::new().async_id::<String, _>(async_id))
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "ModifyCollectionOptions", module = "solrstice", subclass)]
+pub struct ModifyCollectionOptionsWrapper(ModifyCollectionOptions);
+
+#[pymethods]
+impl ModifyCollectionOptionsWrapper {
+    #[new]
+    pub fn new(
+        replication_factor: Option<usize>,
+        config_name: Option<String>,
+        read_only: Option<bool>,
+        properties: Option<HashMap<String, String>>,
+        async_id: Option<String>,
+    ) -> Self {
+        let mut builder = ModifyCollectionOptions::new()
+            .replication_factor(replication_factor)
+            .config_name::<String, _>(config_name)
+            .read_only(read_only)
+            .async_id::<String, _>(async_id);
+        if let Some(properties) = properties {
+            builder = builder.properties(properties);
+        }
+        Self(builder)
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "RenameCollectionOptions", module = "solrstice", subclass)]
+pub struct RenameCollectionOptionsWrapper(RenameCollectionOptions);
+
+#[pymethods]
+impl RenameCollectionOptionsWrapper {
+    #[new]
+    pub fn new(async_id: Option<String>) -> Self {
+        Self(RenameCollectionOptions::new().async_id::<String, _>(async_id))
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "ColStatusOptions", module = "solrstice", subclass)]
+pub struct ColStatusOptionsWrapper(ColStatusOptions);
+
+#[pymethods]
+impl ColStatusOptionsWrapper {
+    #[new]
+    pub fn new(
+        core_info: Option<bool>,
+        segments: Option<bool>,
+        field_info: Option<bool>,
+        size_info: Option<bool>,
+        raw_size: Option<bool>,
+        raw_size_sampling_percent: Option<f64>,
+    ) -> Self {
+        Self(
+            ColStatusOptions::new()
+                .core_info(core_info)
+                .segments(segments)
+                .field_info(field_info)
+                .size_info(size_info)
+                .raw_size(raw_size)
+                .raw_size_sampling_percent(raw_size_sampling_percent),
+        )
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "ReplicaPropertyOptions", module = "solrstice", subclass)]
+pub struct ReplicaPropertyOptionsWrapper(ReplicaPropertyOptions);
+
+#[pymethods]
+impl ReplicaPropertyOptionsWrapper {
+    #[new]
+    pub fn new(shard_unique: Option<bool>, async_id: Option<String>) -> Self {
+        Self(
+            ReplicaPropertyOptions::new()
+                .shard_unique(shard_unique)
+                .async_id::<String, _>(async_id),
+        )
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "DeleteCollectionOptions", module = "solrstice", subclass)]
+pub struct DeleteCollectionOptionsWrapper(DeleteCollectionOptions);
+
+#[pymethods]
+impl DeleteCollectionOptionsWrapper {
+    #[new]
+    pub fn new(async_id: Option<String>) -> Self {
+        Self(DeleteCollectionOptions::new().async_id::<String, _>(async_id))
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[pyfunction]
+pub fn create_collection_with_options(
+    py: Python,
+    context: SolrServerContextWrapper,
+    name: String,
+    options: CreateCollectionOptionsWrapper,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        create_collection_with_options_rs(&context, name.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn create_collection_with_options_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    name: String,
+    options: CreateCollectionOptionsWrapper,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        create_collection_with_options_blocking_rs(&context, name.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
 #[pyfunction]
 pub fn get_collections(py: Python, context: SolrServerContextWrapper) -> PyResult<Bound<PyAny>> {
     pyo3_asyncio::tokio::future_into_py(py, async move {
@@ -126,15 +978,85 @@ pub fn collection_exists_blocking(
     })
 }
 
+#[pyfunction]
+pub fn get_cluster_status(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: Option<String>,
+    shards: Option<Vec<String>>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let shards = shards.unwrap_or_default();
+        let shards: Vec<&str> = shards.iter().map(|s| s.as_str()).collect();
+        let result = get_cluster_status_rs(&context, collection.as_deref(), &shards)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(ClusterStatusWrapper::from(result))
+    })
+}
+
+#[pyfunction]
+pub fn get_cluster_status_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: Option<String>,
+    shards: Option<Vec<String>>,
+) -> PyResult<ClusterStatusWrapper> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let shards = shards.unwrap_or_default();
+        let shards: Vec<&str> = shards.iter().map(|s| s.as_str()).collect();
+        let result = get_cluster_status_blocking_rs(&context, collection.as_deref(), &shards)
+            .map_err(PyErrWrapper::from)?;
+        Ok(ClusterStatusWrapper::from(result))
+    })
+}
+
+#[pyfunction]
+pub fn get_col_status(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: Option<String>,
+    options: Option<ColStatusOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        let result = get_col_status_rs(&context, collection.as_deref(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(ColStatusWrapper::from(result))
+    })
+}
+
+#[pyfunction]
+pub fn get_col_status_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: Option<String>,
+    options: Option<ColStatusOptionsWrapper>,
+) -> PyResult<ColStatusWrapper> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        let result = get_col_status_blocking_rs(&context, collection.as_deref(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(ColStatusWrapper::from(result))
+    })
+}
+
 #[pyfunction]
 pub fn delete_collection(
     py: Python,
     context: SolrServerContextWrapper,
     name: String,
+    options: Option<DeleteCollectionOptionsWrapper>,
 ) -> PyResult<Bound<PyAny>> {
     pyo3_asyncio::tokio::future_into_py(py, async move {
         let context: SolrServerContext = context.into();
-        delete_collection_rs(&context, name.as_str())
+        let options = options.unwrap_or_default();
+        delete_collection_with_options_rs(&context, name.as_str(), &options.0)
             .await
             .map_err(PyErrWrapper::from)?;
         Ok(())
@@ -146,10 +1068,535 @@ pub fn delete_collection_blocking(
     py: Python,
     context: SolrServerContextWrapper,
     name: String,
+    options: Option<DeleteCollectionOptionsWrapper>,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        delete_collection_with_options_blocking_rs(&context, name.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn reload_collection(
+    py: Python,
+    context: SolrServerContextWrapper,
+    name: String,
+    options: Option<ReloadCollectionOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        reload_collection_rs(&context, name.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn reload_collection_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    name: String,
+    options: Option<ReloadCollectionOptionsWrapper>,
 ) -> PyResult<()> {
     py.allow_threads(move || {
         let context: SolrServerContext = context.into();
-        delete_collection_blocking_rs(&context, name.as_str()).map_err(PyErrWrapper::from)?;
+        let options = options.unwrap_or_default();
+        reload_collection_blocking_rs(&context, name.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn reload_collections_using_config(
+    py: Python,
+    context: SolrServerContextWrapper,
+    config: String,
+    options: Option<ReloadCollectionOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        let result = reload_collections_using_config_rs(&context, config.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(result)
+    })
+}
+
+#[pyfunction]
+pub fn reload_collections_using_config_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    config: String,
+    options: Option<ReloadCollectionOptionsWrapper>,
+) -> PyResult<Vec<String>> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        let result =
+            reload_collections_using_config_blocking_rs(&context, config.as_str(), &options.0)
+                .map_err(PyErrWrapper::from)?;
+        Ok(result)
+    })
+}
+
+#[pyfunction]
+pub fn modify_collection(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    options: ModifyCollectionOptionsWrapper,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        modify_collection_rs(&context, collection.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn modify_collection_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    options: ModifyCollectionOptionsWrapper,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        modify_collection_blocking_rs(&context, collection.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn rename_collection(
+    py: Python,
+    context: SolrServerContextWrapper,
+    name: String,
+    target: String,
+    options: Option<RenameCollectionOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        rename_collection_rs(&context, name.as_str(), target.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn rename_collection_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    name: String,
+    target: String,
+    options: Option<RenameCollectionOptionsWrapper>,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        rename_collection_blocking_rs(&context, name.as_str(), target.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn split_shard(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    options: Option<SplitShardOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        split_shard_rs(&context, collection.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn split_shard_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    options: Option<SplitShardOptionsWrapper>,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        split_shard_blocking_rs(&context, collection.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn create_shard(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    shard: String,
+    options: Option<CreateShardOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        create_shard_rs(&context, collection.as_str(), shard.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn create_shard_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    shard: String,
+    options: Option<CreateShardOptionsWrapper>,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        create_shard_blocking_rs(&context, collection.as_str(), shard.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn delete_shard(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    shard: String,
+    options: Option<DeleteShardOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        delete_shard_rs(&context, collection.as_str(), shard.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn delete_shard_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    shard: String,
+    options: Option<DeleteShardOptionsWrapper>,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        delete_shard_blocking_rs(&context, collection.as_str(), shard.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn add_replica(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    shard: String,
+    options: Option<AddReplicaOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        add_replica_rs(&context, collection.as_str(), shard.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn add_replica_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    shard: String,
+    options: Option<AddReplicaOptionsWrapper>,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        add_replica_blocking_rs(&context, collection.as_str(), shard.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn delete_replica(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    options: DeleteReplicaOptionsWrapper,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        delete_replica_rs(&context, collection.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn delete_replica_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    options: DeleteReplicaOptionsWrapper,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        delete_replica_blocking_rs(&context, collection.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn move_replica(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    target_node: String,
+    options: MoveReplicaOptionsWrapper,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        move_replica_rs(
+            &context,
+            collection.as_str(),
+            target_node.as_str(),
+            &options.0,
+        )
+        .await
+        .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn move_replica_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    target_node: String,
+    options: MoveReplicaOptionsWrapper,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        move_replica_blocking_rs(
+            &context,
+            collection.as_str(),
+            target_node.as_str(),
+            &options.0,
+        )
+        .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn delete_node(
+    py: Python,
+    context: SolrServerContextWrapper,
+    node: String,
+    options: Option<DeleteNodeOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        delete_node_rs(&context, node.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn delete_node_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    node: String,
+    options: Option<DeleteNodeOptionsWrapper>,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        delete_node_blocking_rs(&context, node.as_str(), &options.0).map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn replace_node(
+    py: Python,
+    context: SolrServerContextWrapper,
+    source_node: String,
+    options: Option<ReplaceNodeOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        replace_node_rs(&context, source_node.as_str(), &options.0)
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn replace_node_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    source_node: String,
+    options: Option<ReplaceNodeOptionsWrapper>,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        replace_node_blocking_rs(&context, source_node.as_str(), &options.0)
+            .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub fn add_replica_property(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    shard: String,
+    replica: String,
+    property: String,
+    value: String,
+    options: Option<ReplicaPropertyOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        add_replica_property_rs(
+            &context,
+            collection.as_str(),
+            shard.as_str(),
+            replica.as_str(),
+            property.as_str(),
+            value.as_str(),
+            &options.0,
+        )
+        .await
+        .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub fn add_replica_property_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    shard: String,
+    replica: String,
+    property: String,
+    value: String,
+    options: Option<ReplicaPropertyOptionsWrapper>,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        add_replica_property_blocking_rs(
+            &context,
+            collection.as_str(),
+            shard.as_str(),
+            replica.as_str(),
+            property.as_str(),
+            value.as_str(),
+            &options.0,
+        )
+        .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn delete_replica_property(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    shard: String,
+    replica: String,
+    property: String,
+    options: Option<ReplicaPropertyOptionsWrapper>,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        delete_replica_property_rs(
+            &context,
+            collection.as_str(),
+            shard.as_str(),
+            replica.as_str(),
+            property.as_str(),
+            &options.0,
+        )
+        .await
+        .map_err(PyErrWrapper::from)?;
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn delete_replica_property_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    collection: String,
+    shard: String,
+    replica: String,
+    property: String,
+    options: Option<ReplicaPropertyOptionsWrapper>,
+) -> PyResult<()> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let options = options.unwrap_or_default();
+        delete_replica_property_blocking_rs(
+            &context,
+            collection.as_str(),
+            shard.as_str(),
+            replica.as_str(),
+            property.as_str(),
+            &options.0,
+        )
+        .map_err(PyErrWrapper::from)?;
         Ok(())
     })
 }
@@ -0,0 +1,54 @@
+use pyo3::{pyclass, pymethods};
+use solrstice::ElevationComponent;
+
+#[derive(Clone, Debug, PartialEq)]
+#[pyclass(name = "ElevationComponent", module = "solrstice", subclass)]
+pub struct ElevationComponentWrapper(ElevationComponent);
+
+#[pymethods]
+impl ElevationComponentWrapper {
+    #[new]
+    pub fn new(
+        enable: Option<bool>,
+        force: Option<bool>,
+        elevate_ids: Option<Vec<String>>,
+        exclude_ids: Option<Vec<String>>,
+        mark_excludes: Option<bool>,
+        elevated_flag: Option<bool>,
+        excluded_flag: Option<bool>,
+    ) -> Self {
+        let component = ElevationComponent::new()
+            .enable(enable)
+            .force(force)
+            .elevate_ids::<String, Vec<String>, Option<Vec<String>>>(elevate_ids)
+            .exclude_ids::<String, Vec<String>, Option<Vec<String>>>(exclude_ids)
+            .mark_excludes(mark_excludes)
+            .elevated_flag(elevated_flag.unwrap_or(false))
+            .excluded_flag(excluded_flag.unwrap_or(false));
+        ElevationComponentWrapper(component)
+    }
+}
+
+impl From<ElevationComponentWrapper> for ElevationComponent {
+    fn from(wrapper: ElevationComponentWrapper) -> Self {
+        wrapper.0
+    }
+}
+
+impl From<&ElevationComponentWrapper> for ElevationComponent {
+    fn from(wrapper: &ElevationComponentWrapper) -> Self {
+        wrapper.0.clone()
+    }
+}
+
+impl From<ElevationComponent> for ElevationComponentWrapper {
+    fn from(component: ElevationComponent) -> Self {
+        ElevationComponentWrapper(component)
+    }
+}
+
+impl From<&ElevationComponent> for ElevationComponentWrapper {
+    fn from(component: &ElevationComponent) -> Self {
+        ElevationComponentWrapper(component.clone())
+    }
+}
@@ -1,3 +1,4 @@
+pub mod elevation;
 pub mod facet_set;
 pub mod grouping;
 pub mod json_facet;
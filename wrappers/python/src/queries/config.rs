@@ -4,12 +4,14 @@ use pyo3::prelude::*;
 use solrstice::queries::config::{
     config_exists as config_exists_rs, delete_config as delete_config_rs,
     get_configs as get_configs_rs, upload_config as upload_config_rs,
+    upload_elevation_config as upload_elevation_config_rs,
 };
 use solrstice::queries::config::{
     config_exists_blocking as config_exists_blocking_rs,
     delete_config_blocking as delete_config_blocking_rs,
     get_configs_blocking as get_configs_blocking_rs,
     upload_config_blocking as upload_config_blocking_rs,
+    upload_elevation_config_blocking as upload_elevation_config_blocking_rs,
 };
 use solrstice::SolrServerContext;
 use std::path::PathBuf;
@@ -20,11 +22,13 @@ pub fn config(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(get_configs, m)?)?;
     m.add_function(wrap_pyfunction!(config_exists, m)?)?;
     m.add_function(wrap_pyfunction!(delete_config, m)?)?;
+    m.add_function(wrap_pyfunction!(upload_elevation_config, m)?)?;
 
     m.add_function(wrap_pyfunction!(upload_config_blocking, m)?)?;
     m.add_function(wrap_pyfunction!(get_configs_blocking, m)?)?;
     m.add_function(wrap_pyfunction!(config_exists_blocking, m)?)?;
     m.add_function(wrap_pyfunction!(delete_config_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(upload_elevation_config_blocking, m)?)?;
     Ok(())
 }
 
@@ -136,3 +140,34 @@ pub fn delete_config_blocking(
         Ok(())
     })
 }
+
+#[pyfunction]
+pub fn upload_elevation_config(
+    py: Python,
+    context: SolrServerContextWrapper,
+    name: String,
+    path: PathBuf,
+) -> PyResult<Bound<PyAny>> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let context: SolrServerContext = context.into();
+        let result = upload_elevation_config_rs(&context, name.as_str(), path.as_path())
+            .await
+            .map_err(PyErrWrapper::from)?;
+        Ok(result)
+    })
+}
+
+#[pyfunction]
+pub fn upload_elevation_config_blocking(
+    py: Python,
+    context: SolrServerContextWrapper,
+    name: String,
+    path: PathBuf,
+) -> PyResult<Vec<String>> {
+    py.allow_threads(move || {
+        let context: SolrServerContext = context.into();
+        let result = upload_elevation_config_blocking_rs(&context, name.as_str(), path.as_path())
+            .map_err(PyErrWrapper::from)?;
+        Ok(result)
+    })
+}
@@ -0,0 +1,187 @@
+use crate::models::context::SolrServerContextWrapper;
+use crate::models::error::PyErrWrapper;
+use crate::models::extract::SolrExtractResponseWrapper;
+use crate::models::response::SolrResponseWrapper;
+use crate::queries::index::{to_commit_options, CommitTypeWrapper};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde::{Deserialize, Serialize};
+use solrstice::Error;
+use solrstice::SolrServerContext;
+use solrstice::{ExtractFormat, ExtractQuery};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[pyclass(name = "ExtractFormat")]
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub enum ExtractFormatWrapper {
+    Xml,
+    Text,
+}
+
+impl From<ExtractFormatWrapper> for ExtractFormat {
+    fn from(value: ExtractFormatWrapper) -> Self {
+        match value {
+            ExtractFormatWrapper::Xml => ExtractFormat::Xml,
+            ExtractFormatWrapper::Text => ExtractFormat::Text,
+        }
+    }
+}
+
+#[derive(FromPyObject)]
+pub enum LiteralUnion {
+    String(String),
+    List(Vec<String>),
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "ExtractQuery", module = "solrstice", subclass)]
+pub struct ExtractQueryWrapper(ExtractQuery);
+
+#[pymethods]
+impl ExtractQueryWrapper {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        handler: Option<String>,
+        commit_type: Option<CommitTypeWrapper>,
+        commit_within: Option<u64>,
+        overwrite: Option<bool>,
+        wait_searcher: Option<bool>,
+        open_searcher: Option<bool>,
+        expunge_deletes: Option<bool>,
+        literals: Option<HashMap<String, LiteralUnion>>,
+        fmap: Option<HashMap<String, String>>,
+        uprefix: Option<String>,
+        capture_attr: Option<bool>,
+        lowernames: Option<bool>,
+        extract_format: Option<ExtractFormatWrapper>,
+        resource_name: Option<String>,
+        content_type: Option<String>,
+    ) -> Self {
+        let mut builder = ExtractQuery::new().commit_options(to_commit_options(
+            commit_type,
+            commit_within,
+            overwrite,
+            wait_searcher,
+            open_searcher,
+            expunge_deletes,
+        ));
+        if let Some(handler) = handler {
+            builder = builder.handler(handler);
+        }
+        if let Some(literals) = literals {
+            builder = builder.literals(literals.into_iter().flat_map(|(field, value)| {
+                let values = match value {
+                    LiteralUnion::String(value) => vec![value],
+                    LiteralUnion::List(values) => values,
+                };
+                values.into_iter().map(move |value| (field.clone(), value))
+            }));
+        }
+        if let Some(fmap) = fmap {
+            builder = builder.fmap(fmap);
+        }
+        builder = builder
+            .uprefix::<String, _>(uprefix)
+            .capture_attr(capture_attr)
+            .lowernames(lowernames)
+            .extract_format(extract_format.map(ExtractFormat::from))
+            .resource_name::<String, _>(resource_name)
+            .content_type::<String, _>(content_type);
+        Self(builder)
+    }
+
+    pub fn execute_file<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, SolrResponseWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute_file(&context, collection.as_str(), path.as_path())
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    pub fn execute_file_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<SolrResponseWrapper> {
+        let builder = self.0.clone();
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute_file_blocking(&context, collection.as_str(), path.as_path())
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    pub fn extract_only_file<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, SolrExtractResponseWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .extract_only_file(&context, collection.as_str(), path.as_path())
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    pub fn extract_only_file_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<SolrExtractResponseWrapper> {
+        let builder = self.0.clone();
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .extract_only_file_blocking(&context, collection.as_str(), path.as_path())
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
@@ -0,0 +1,213 @@
+use crate::models::context::SolrServerContextWrapper;
+use crate::models::error::PyErrWrapper;
+use crate::models::response::SolrResponseWrapper;
+use crate::queries::index::{to_commit_options, CommitTypeWrapper};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde::{Deserialize, Serialize};
+use solrstice::Error;
+use solrstice::SolrServerContext;
+use solrstice::{CsvUpdateQuery, JsonDocsUpdateQuery};
+use std::path::PathBuf;
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "CsvUpdateQuery", module = "solrstice", subclass)]
+pub struct CsvUpdateQueryWrapper(CsvUpdateQuery);
+
+#[pymethods]
+impl CsvUpdateQueryWrapper {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        handler: Option<String>,
+        commit_type: Option<CommitTypeWrapper>,
+        commit_within: Option<u64>,
+        overwrite: Option<bool>,
+        wait_searcher: Option<bool>,
+        open_searcher: Option<bool>,
+        expunge_deletes: Option<bool>,
+        separator: Option<char>,
+        header: Option<bool>,
+        fieldnames: Option<Vec<String>>,
+        split: Option<Vec<String>>,
+        split_separator: Option<char>,
+        encapsulator: Option<char>,
+        skip: Option<Vec<String>>,
+        skip_lines: Option<usize>,
+    ) -> Self {
+        let mut builder = CsvUpdateQuery::new().commit_options(to_commit_options(
+            commit_type,
+            commit_within,
+            overwrite,
+            wait_searcher,
+            open_searcher,
+            expunge_deletes,
+        ));
+        if let Some(handler) = handler {
+            builder = builder.handler(handler);
+        }
+        builder = builder
+            .separator(separator)
+            .header(header)
+            .fieldnames::<String, Vec<String>, Option<Vec<String>>>(fieldnames)
+            .split::<String, Vec<String>, Option<Vec<String>>>(split)
+            .split_separator(split_separator)
+            .encapsulator(encapsulator)
+            .skip::<String, Vec<String>, Option<Vec<String>>>(skip)
+            .skip_lines(skip_lines);
+        Self(builder)
+    }
+
+    pub fn execute_file<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, SolrResponseWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute_file(&context, collection.as_str(), path.as_path())
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    pub fn execute_file_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<SolrResponseWrapper> {
+        let builder = self.0.clone();
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute_file_blocking(&context, collection.as_str(), path.as_path())
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "JsonDocsUpdateQuery", module = "solrstice", subclass)]
+pub struct JsonDocsUpdateQueryWrapper(JsonDocsUpdateQuery);
+
+#[pymethods]
+impl JsonDocsUpdateQueryWrapper {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        handler: Option<String>,
+        commit_type: Option<CommitTypeWrapper>,
+        commit_within: Option<u64>,
+        overwrite: Option<bool>,
+        wait_searcher: Option<bool>,
+        open_searcher: Option<bool>,
+        expunge_deletes: Option<bool>,
+        split: Option<String>,
+        f: Option<Vec<String>>,
+    ) -> Self {
+        let mut builder = JsonDocsUpdateQuery::new().commit_options(to_commit_options(
+            commit_type,
+            commit_within,
+            overwrite,
+            wait_searcher,
+            open_searcher,
+            expunge_deletes,
+        ));
+        if let Some(handler) = handler {
+            builder = builder.handler(handler);
+        }
+        builder = builder
+            .split::<String, Option<String>>(split)
+            .f::<String, Vec<String>, Option<Vec<String>>>(f);
+        Self(builder)
+    }
+
+    pub fn execute_file<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, SolrResponseWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute_file(&context, collection.as_str(), path.as_path())
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    pub fn execute_file_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+        path: PathBuf,
+    ) -> PyResult<SolrResponseWrapper> {
+        let builder = self.0.clone();
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute_file_blocking(&context, collection.as_str(), path.as_path())
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
@@ -1,37 +1,97 @@
+use crate::models::atomic_update::doc_to_value;
 use crate::models::context::SolrServerContextWrapper;
 use crate::models::error::PyErrWrapper;
 use crate::models::response::SolrResponseWrapper;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
-use pythonize::depythonize_bound;
 use serde::{Deserialize, Serialize};
 use solrstice::CommitType;
 use solrstice::Error;
 use solrstice::SolrServerContext;
-use solrstice::{DeleteQuery, UpdateQuery};
+use solrstice::{
+    CommitQuery, DeleteById, DeleteQuery, OptimizeQuery, RollbackQuery, UpdateCommitOptions,
+    UpdateQuery, VersionConstraint,
+};
 
 #[pyclass(name = "CommitType")]
 #[derive(Clone, Copy, Serialize, Deserialize)]
 pub enum CommitTypeWrapper {
     Hard,
     Soft,
+    NoCommit,
+}
+
+/// `commit_within` takes precedence, as the Python enum can not carry the milliseconds.
+pub fn to_commit_type(
+    commit_type: Option<CommitTypeWrapper>,
+    commit_within: Option<u64>,
+) -> Option<CommitType> {
+    match commit_within {
+        Some(ms) => Some(CommitType::CommitWithin(ms)),
+        None => commit_type.map(CommitType::from),
+    }
+}
+
+/// The commit keyword arguments shared by the update queries.
+pub fn to_commit_options(
+    commit_type: Option<CommitTypeWrapper>,
+    commit_within: Option<u64>,
+    overwrite: Option<bool>,
+    wait_searcher: Option<bool>,
+    open_searcher: Option<bool>,
+    expunge_deletes: Option<bool>,
+) -> UpdateCommitOptions {
+    UpdateCommitOptions::new()
+        .commit_type(to_commit_type(commit_type, commit_within).unwrap_or_default())
+        .overwrite(overwrite)
+        .wait_searcher(wait_searcher)
+        .open_searcher(open_searcher)
+        .expunge_deletes(expunge_deletes)
 }
 
 #[derive(Clone, Default, Serialize, Deserialize)]
 #[pyclass(name = "UpdateQuery", module = "solrstice", subclass)]
 pub struct UpdateQueryWrapper(UpdateQuery);
 
+impl From<UpdateQueryWrapper> for UpdateQuery {
+    fn from(value: UpdateQueryWrapper) -> Self {
+        value.0
+    }
+}
+
 #[pymethods]
 impl UpdateQueryWrapper {
     #[new]
-    pub fn new(handler: Option<String>, commit_type: Option<CommitTypeWrapper>) -> Self {
-        let mut builder = UpdateQuery::new();
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        handler: Option<String>,
+        commit_type: Option<CommitTypeWrapper>,
+        return_versions: Option<bool>,
+        commit_within: Option<u64>,
+        overwrite: Option<bool>,
+        wait_searcher: Option<bool>,
+        open_searcher: Option<bool>,
+        expunge_deletes: Option<bool>,
+        max_errors: Option<i64>,
+        update_chain: Option<String>,
+    ) -> Self {
+        let mut builder = UpdateQuery::new().commit_options(to_commit_options(
+            commit_type,
+            commit_within,
+            overwrite,
+            wait_searcher,
+            open_searcher,
+            expunge_deletes,
+        ));
         if let Some(handler) = handler {
             builder = builder.handler(handler);
         }
-        if let Some(commit_type) = commit_type {
-            builder = builder.commit_type(commit_type.into());
+        if let Some(return_versions) = return_versions {
+            builder = builder.return_versions(return_versions);
         }
+        builder = builder
+            .max_errors(max_errors)
+            .update_chain::<String, _>(update_chain);
         Self(builder)
     }
 
@@ -43,11 +103,10 @@ impl UpdateQueryWrapper {
         data: Vec<PyObject>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let builder = self.0.clone();
-        let data: Result<Vec<serde_json::Value>, PyErrWrapper> = data
+        let data = data
             .into_iter()
-            .map(|x| depythonize_bound(x.into_bound(py)).map_err(PyErrWrapper::from))
-            .collect();
-        let data = data?;
+            .map(|x| doc_to_value(x.bind(py)))
+            .collect::<PyResult<Vec<serde_json::Value>>>()?;
         pyo3_asyncio::tokio::future_into_py::<_, SolrResponseWrapper>(py, async move {
             let context: SolrServerContext = context.into();
             let result = builder
@@ -65,11 +124,10 @@ impl UpdateQueryWrapper {
         collection: String,
         data: Vec<PyObject>,
     ) -> PyResult<SolrResponseWrapper> {
-        let data: Result<Vec<serde_json::Value>, PyErrWrapper> = data
+        let data = data
             .into_iter()
-            .map(|x| depythonize_bound(x.into_bound(py)).map_err(PyErrWrapper::from))
-            .collect();
-        let data = data?;
+            .map(|x| doc_to_value(x.bind(py)))
+            .collect::<PyResult<Vec<serde_json::Value>>>()?;
         let builder = self.0.clone();
         py.allow_threads(move || {
             let context: SolrServerContext = context.into();
@@ -109,15 +167,73 @@ impl From<CommitTypeWrapper> for CommitType {
         match value {
             CommitTypeWrapper::Hard => CommitType::Hard,
             CommitTypeWrapper::Soft => CommitType::Soft,
+            CommitTypeWrapper::NoCommit => CommitType::NoCommit,
         }
     }
 }
 
-impl From<CommitType> for CommitTypeWrapper {
-    fn from(value: CommitType) -> Self {
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "DeleteById", module = "solrstice", subclass)]
+pub struct DeleteByIdWrapper(DeleteById);
+
+#[pymethods]
+impl DeleteByIdWrapper {
+    #[new]
+    pub fn new(id: String, route: Option<String>, version: Option<i64>) -> Self {
+        Self(
+            DeleteById::new(id)
+                .route::<String, _>(route)
+                .version(version.and_then(VersionConstraint::from_version)),
+        )
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.0.get_id()
+    }
+
+    pub fn get_route(&self) -> Option<&str> {
+        self.0.get_route()
+    }
+
+    pub fn get_version(&self) -> Option<i64> {
+        self.0.get_version().map(|v| v.as_i64())
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(FromPyObject)]
+pub enum DeleteByIdUnion {
+    DeleteById(DeleteByIdWrapper),
+    String(String),
+}
+
+impl From<DeleteByIdUnion> for DeleteById {
+    fn from(value: DeleteByIdUnion) -> Self {
         match value {
-            CommitType::Hard => CommitTypeWrapper::Hard,
-            CommitType::Soft => CommitTypeWrapper::Soft,
+            DeleteByIdUnion::DeleteById(d) => d.0,
+            DeleteByIdUnion::String(s) => DeleteById::new(s),
         }
     }
 }
@@ -129,26 +245,278 @@ pub struct DeleteQueryWrapper(DeleteQuery);
 #[pymethods]
 impl DeleteQueryWrapper {
     #[new]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         handler: Option<String>,
         commit_type: Option<CommitTypeWrapper>,
-        ids: Option<Vec<String>>,
+        ids: Option<Vec<DeleteByIdUnion>>,
         queries: Option<Vec<String>>,
+        return_versions: Option<bool>,
+        commit_within: Option<u64>,
+        wait_searcher: Option<bool>,
+        open_searcher: Option<bool>,
+        expunge_deletes: Option<bool>,
     ) -> Self {
-        let mut builder = DeleteQuery::new();
+        let mut builder = DeleteQuery::new().commit_options(to_commit_options(
+            commit_type,
+            commit_within,
+            None,
+            wait_searcher,
+            open_searcher,
+            expunge_deletes,
+        ));
         if let Some(handler) = handler {
             builder = builder.handler(handler);
         }
-        if let Some(commit_type) = commit_type {
-            let commit_type: CommitType = commit_type.into();
-            builder = builder.commit_type(commit_type);
-        }
         if let Some(ids) = ids {
-            builder = builder.ids(&ids);
+            builder = builder.ids(ids.into_iter().map(DeleteById::from));
         }
         if let Some(queries) = queries {
             builder = builder.queries(&queries);
         }
+        if let Some(return_versions) = return_versions {
+            builder = builder.return_versions(return_versions);
+        }
+        Self(builder)
+    }
+
+    pub fn execute<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, SolrResponseWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute(&context, collection.as_str())
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    pub fn execute_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<SolrResponseWrapper> {
+        let builder = self.0.clone();
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute_blocking(&context, collection.as_str())
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "CommitQuery", module = "solrstice", subclass)]
+pub struct CommitQueryWrapper(CommitQuery);
+
+#[pymethods]
+impl CommitQueryWrapper {
+    #[new]
+    pub fn new(
+        handler: Option<String>,
+        soft_commit: Option<bool>,
+        wait_searcher: Option<bool>,
+        open_searcher: Option<bool>,
+        expunge_deletes: Option<bool>,
+    ) -> Self {
+        let mut builder = CommitQuery::new();
+        if let Some(handler) = handler {
+            builder = builder.handler(handler);
+        }
+        if let Some(soft_commit) = soft_commit {
+            builder = builder.soft_commit(soft_commit);
+        }
+        Self(builder.commit_options(to_commit_options(
+            None,
+            None,
+            None,
+            wait_searcher,
+            open_searcher,
+            expunge_deletes,
+        )))
+    }
+
+    pub fn execute<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, SolrResponseWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute(&context, collection.as_str())
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    pub fn execute_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<SolrResponseWrapper> {
+        let builder = self.0.clone();
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute_blocking(&context, collection.as_str())
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "OptimizeQuery", module = "solrstice", subclass)]
+pub struct OptimizeQueryWrapper(OptimizeQuery);
+
+#[pymethods]
+impl OptimizeQueryWrapper {
+    #[new]
+    pub fn new(
+        handler: Option<String>,
+        max_segments: Option<usize>,
+        wait_searcher: Option<bool>,
+    ) -> Self {
+        let mut builder = OptimizeQuery::new();
+        if let Some(handler) = handler {
+            builder = builder.handler(handler);
+        }
+        Self(
+            builder
+                .max_segments(max_segments)
+                .wait_searcher(wait_searcher),
+        )
+    }
+
+    pub fn execute<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, SolrResponseWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute(&context, collection.as_str())
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    pub fn execute_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<SolrResponseWrapper> {
+        let builder = self.0.clone();
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute_blocking(&context, collection.as_str())
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "RollbackQuery", module = "solrstice", subclass)]
+pub struct RollbackQueryWrapper(RollbackQuery);
+
+#[pymethods]
+impl RollbackQueryWrapper {
+    #[new]
+    pub fn new(handler: Option<String>) -> Self {
+        let mut builder = RollbackQuery::new();
+        if let Some(handler) = handler {
+            builder = builder.handler(handler);
+        }
         Self(builder)
     }
 
@@ -208,3 +576,15 @@ impl DeleteQueryWrapper {
         .to_object(py))
     }
 }
+
+impl From<CommitQueryWrapper> for CommitQuery {
+    fn from(value: CommitQueryWrapper) -> Self {
+        value.0
+    }
+}
+
+impl From<OptimizeQueryWrapper> for OptimizeQuery {
+    fn from(value: OptimizeQueryWrapper) -> Self {
+        value.0
+    }
+}
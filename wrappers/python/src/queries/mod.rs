@@ -1,7 +1,14 @@
+pub mod admin_task;
 pub mod alias;
+pub mod backup;
+pub mod bulk_index;
 pub mod collection;
 pub mod components;
 pub mod config;
 pub mod def_type;
+pub mod extract;
+pub mod file_update;
 pub mod index;
+pub mod reindex;
 pub mod select;
+pub mod update_batch;
@@ -0,0 +1,285 @@
+use crate::models::atomic_update::doc_to_value;
+use crate::models::context::SolrServerContextWrapper;
+use crate::models::error::PyErrWrapper;
+use crate::models::reindex::{
+    ReindexProgressWrapper, ReindexSummaryWrapper, SolrReindexStatusWrapper,
+};
+use crate::queries::bulk_index::BulkIndexerWrapper;
+use crate::queries::select::SelectQueryWrapper;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pythonize::pythonize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solrstice::{Error, ReindexCollectionQuery, Reindexer, SolrServerContext};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone)]
+#[pyclass(name = "Reindexer", module = "solrstice", subclass)]
+pub struct ReindexerWrapper {
+    reindexer: Reindexer,
+    transform: Option<PyObject>,
+}
+
+#[pymethods]
+impl ReindexerWrapper {
+    #[new]
+    pub fn new(
+        query: Option<SelectQueryWrapper>,
+        cursor_mark: Option<String>,
+        indexer: Option<BulkIndexerWrapper>,
+        transform: Option<PyObject>,
+        on_progress: Option<PyObject>,
+    ) -> Self {
+        let mut builder = Reindexer::new();
+        if let Some(query) = query {
+            builder = builder.query(query);
+        }
+        if let Some(cursor_mark) = cursor_mark {
+            builder = builder.cursor_mark(cursor_mark);
+        }
+        if let Some(indexer) = indexer {
+            builder = builder.indexer(indexer);
+        }
+        if let Some(on_progress) = on_progress {
+            builder = builder.on_progress(move |progress| {
+                Python::with_gil(|py| {
+                    let progress = ReindexProgressWrapper::from(progress);
+                    if let Err(e) = on_progress.call1(py, (progress,)) {
+                        e.print(py);
+                    }
+                })
+            });
+        }
+        Self {
+            reindexer: builder,
+            transform,
+        }
+    }
+
+    pub fn execute<'py>(
+        &self,
+        py: Python<'py>,
+        source_context: SolrServerContextWrapper,
+        source: String,
+        target_context: SolrServerContextWrapper,
+        target: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let (builder, error) = self.with_transform();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let source_context: SolrServerContext = source_context.into();
+            let target_context: SolrServerContext = target_context.into();
+            let result = builder
+                .execute(&source_context, source, &target_context, target)
+                .await
+                .map_err(PyErrWrapper::from)?;
+            if let Some(e) = error.lock().unwrap().take() {
+                return Err(e);
+            }
+            Ok(Python::with_gil(|_| ReindexSummaryWrapper::from(result)))
+        })
+    }
+
+    pub fn execute_blocking(
+        &self,
+        py: Python,
+        source_context: SolrServerContextWrapper,
+        source: String,
+        target_context: SolrServerContextWrapper,
+        target: String,
+    ) -> PyResult<ReindexSummaryWrapper> {
+        let (builder, error) = self.with_transform();
+        let result = py.allow_threads(move || {
+            let source_context: SolrServerContext = source_context.into();
+            let target_context: SolrServerContext = target_context.into();
+            builder
+                .execute_blocking(&source_context, source, &target_context, target)
+                .map_err(PyErrWrapper::from)
+        })?;
+        if let Some(e) = error.lock().unwrap().take() {
+            return Err(e);
+        }
+        Ok(result.into())
+    }
+}
+
+impl ReindexerWrapper {
+    /// Wrap the Python transform for one run.
+    ///
+    /// A document is skipped when the transform returns `None`. After the first error every following document is skipped,
+    /// and the error is kept to be raised after the run.
+    fn with_transform(&self) -> (Reindexer, Arc<Mutex<Option<PyErr>>>) {
+        let error: Arc<Mutex<Option<PyErr>>> = Arc::new(Mutex::new(None));
+        let transform = match &self.transform {
+            Some(transform) => Python::with_gil(|py| transform.clone_ref(py)),
+            None => return (self.reindexer.clone(), error),
+        };
+        let transform_error = error.clone();
+        let builder = self.reindexer.clone().transform(move |doc: Value| {
+            if transform_error.lock().unwrap().is_some() {
+                return None;
+            }
+            Python::with_gil(|py| {
+                let result = pythonize(py, &doc)
+                    .map_err(PyErrWrapper::from)
+                    .map_err(PyErr::from)
+                    .and_then(|doc| transform.call1(py, (doc,)))
+                    .and_then(|doc| match doc.is_none(py) {
+                        true => Ok(None),
+                        false => doc_to_value(doc.bind(py)).map(Some),
+                    });
+                match result {
+                    Ok(doc) => doc,
+                    Err(e) => {
+                        *transform_error.lock().unwrap() = Some(e);
+                        None
+                    }
+                }
+            })
+        });
+        (builder, error)
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "ReindexCollectionQuery", module = "solrstice", subclass)]
+pub struct ReindexCollectionQueryWrapper(ReindexCollectionQuery);
+
+#[pymethods]
+impl ReindexCollectionQueryWrapper {
+    #[new]
+    pub fn new(
+        target: Option<String>,
+        q: Option<String>,
+        fl: Option<Vec<String>>,
+        rows: Option<usize>,
+        config_name: Option<String>,
+        remove_source: Option<bool>,
+        async_id: Option<String>,
+    ) -> Self {
+        Self(
+            ReindexCollectionQuery::new()
+                .target::<String, _>(target)
+                .q::<String, _>(q)
+                .fl::<String, Vec<String>, _>(fl)
+                .rows(rows)
+                .config_name::<String, _>(config_name)
+                .remove_source(remove_source)
+                .async_id::<String, _>(async_id),
+        )
+    }
+
+    pub fn execute<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, SolrReindexStatusWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute(&context, collection)
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    pub fn execute_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<SolrReindexStatusWrapper> {
+        let builder = self.0.clone();
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute_blocking(&context, collection)
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    #[staticmethod]
+    pub fn status(
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<Bound<PyAny>> {
+        pyo3_asyncio::tokio::future_into_py::<_, SolrReindexStatusWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = ReindexCollectionQuery::status(&context, collection)
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    #[staticmethod]
+    pub fn status_blocking(
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<SolrReindexStatusWrapper> {
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = ReindexCollectionQuery::status_blocking(&context, collection)
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    #[staticmethod]
+    pub fn abort(
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<Bound<PyAny>> {
+        pyo3_asyncio::tokio::future_into_py::<_, SolrReindexStatusWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = ReindexCollectionQuery::abort(&context, collection)
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    #[staticmethod]
+    pub fn abort_blocking(
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<SolrReindexStatusWrapper> {
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = ReindexCollectionQuery::abort_blocking(&context, collection)
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
@@ -1,6 +1,7 @@
 use crate::models::context::SolrServerContextWrapper;
 use crate::models::error::PyErrWrapper;
 use crate::models::response::SolrResponseWrapper;
+use crate::queries::components::elevation::ElevationComponentWrapper;
 use crate::queries::components::facet_set::FacetSetComponentWrapper;
 use crate::queries::components::grouping::GroupingComponentWrapper;
 use crate::queries::components::json_facet::JsonFacetComponentWrapper;
@@ -16,7 +17,7 @@ use solrstice::GroupingComponent;
 use solrstice::JsonFacetComponent;
 use solrstice::SelectQuery;
 use solrstice::SolrServerContext;
-use solrstice::{DefType, StatsComponent};
+use solrstice::{DefType, ElevationComponent, StatsComponent};
 use solrstice::{Error, SelectDestination};
 use std::collections::HashMap;
 
@@ -41,7 +42,13 @@ impl SelectQueryWrapper {
         facet_set: Option<FacetSetComponentWrapper>,
         json_facet: Option<JsonFacetComponentWrapper>,
         stats: Option<StatsComponentWrapper>,
+        elevation: Option<ElevationComponentWrapper>,
         additional_params: Option<HashMap<String, PyObject>>,
+        time_allowed: Option<usize>,
+        cpu_allowed: Option<usize>,
+        mem_allowed: Option<f64>,
+        omit_header: Option<bool>,
+        fail_on_partial_results: Option<bool>,
     ) -> PyResult<Self> {
         let mut builder = SelectQuery::new();
         if let Some(q) = q {
@@ -67,9 +74,18 @@ impl SelectQueryWrapper {
         builder = builder
             .facet_set::<FacetSetComponent, Option<FacetSetComponent>>(facet_set.map(|x| x.into()));
         builder = builder.stats::<StatsComponent, Option<StatsComponent>>(stats.map(|x| x.into()));
+        builder = builder.elevation::<ElevationComponent, Option<ElevationComponent>>(
+            elevation.map(|x| x.into()),
+        );
         builder = builder.json_facet::<JsonFacetComponent, Option<JsonFacetComponent>>(
             json_facet.map(|x| x.into()),
         );
+        builder = builder
+            .time_allowed(time_allowed)
+            .cpu_allowed(cpu_allowed)
+            .mem_allowed(mem_allowed)
+            .omit_header(omit_header)
+            .fail_on_partial_results(fail_on_partial_results.unwrap_or(false));
         if let Some(additional_params) = additional_params {
             // Import the json module and convert the PyObject to a string, then to a serde_json::Value
             let converted_params = Python::with_gil(|py| -> PyResult<HashMap<String, Value>> {
@@ -186,6 +202,59 @@ impl SelectQueryWrapper {
         Python::with_gil(|py| -> PyResult<PyObject> { Ok(pythonize(py, &value)?) })
     }
 
+    pub fn to_curl(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+        handler: Option<String>,
+    ) -> PyResult<String> {
+        let builder = self.0.clone();
+        let mut destination = SelectDestination::new(collection);
+        if let Some(handler) = handler {
+            destination = destination.handler(handler);
+        }
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .to_curl_blocking(&context, &destination)
+                .map_err(PyErrWrapper::from)?;
+            Ok(result)
+        })
+    }
+
+    pub fn to_url(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+        handler: Option<String>,
+    ) -> PyResult<String> {
+        let builder = self.0.clone();
+        let mut destination = SelectDestination::new(collection);
+        if let Some(handler) = handler {
+            destination = destination.handler(handler);
+        }
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .to_url_blocking(&context, &destination)
+                .map_err(PyErrWrapper::from)?;
+            Ok(result)
+        })
+    }
+
+    #[staticmethod]
+    pub fn from_query_string(query_string: String) -> PyResult<Self> {
+        Ok(Self(
+            SelectQuery::from_query_string(query_string).map_err(PyErrWrapper::from)?,
+        ))
+    }
+
+    pub fn to_solr_params(&self) -> PyResult<Vec<(String, String)>> {
+        Ok(self.0.to_solr_params().map_err(PyErrWrapper::from)?)
+    }
+
     pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
         match state.extract::<&PyBytes>(py) {
             Ok(s) => {
@@ -209,3 +278,9 @@ impl SelectQueryWrapper {
         .to_object(py))
     }
 }
+
+impl From<SelectQueryWrapper> for SelectQuery {
+    fn from(value: SelectQueryWrapper) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,135 @@
+use crate::models::atomic_update::doc_to_value;
+use crate::models::context::SolrServerContextWrapper;
+use crate::models::error::PyErrWrapper;
+use crate::models::response::SolrResponseWrapper;
+use crate::queries::index::{CommitQueryWrapper, DeleteByIdUnion, OptimizeQueryWrapper};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde::{Deserialize, Serialize};
+use solrstice::{AddCommand, DeleteById, DeleteCommand, Error, SolrServerContext, UpdateBatch};
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[pyclass(name = "UpdateBatch", module = "solrstice", subclass)]
+pub struct UpdateBatchWrapper(UpdateBatch);
+
+#[pymethods]
+impl UpdateBatchWrapper {
+    #[new]
+    pub fn new(handler: Option<String>) -> Self {
+        let mut builder = UpdateBatch::new();
+        if let Some(handler) = handler {
+            builder = builder.handler(handler);
+        }
+        Self(builder)
+    }
+
+    pub fn add_doc<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        doc: &Bound<'py, PyAny>,
+        overwrite: Option<bool>,
+        commit_within: Option<u64>,
+        boost: Option<f64>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let add = AddCommand::from(doc_to_value(doc)?)
+            .overwrite(overwrite)
+            .commit_within(commit_within)
+            .boost(boost);
+        slf.0 = std::mem::take(&mut slf.0).add_doc(add);
+        Ok(slf)
+    }
+
+    pub fn delete_by_id(
+        mut slf: PyRefMut<Self>,
+        id: DeleteByIdUnion,
+        commit_within: Option<u64>,
+    ) -> PyRefMut<Self> {
+        let delete = DeleteCommand::id(DeleteById::from(id)).commit_within(commit_within);
+        slf.0 = std::mem::take(&mut slf.0).delete(delete);
+        slf
+    }
+
+    pub fn delete_by_query(
+        mut slf: PyRefMut<Self>,
+        query: String,
+        commit_within: Option<u64>,
+    ) -> PyRefMut<Self> {
+        let delete = DeleteCommand::query(query).commit_within(commit_within);
+        slf.0 = std::mem::take(&mut slf.0).delete(delete);
+        slf
+    }
+
+    pub fn commit(mut slf: PyRefMut<Self>, commit: Option<CommitQueryWrapper>) -> PyRefMut<Self> {
+        let commit = commit.unwrap_or_default();
+        slf.0 = std::mem::take(&mut slf.0).commit(commit);
+        slf
+    }
+
+    pub fn optimize(
+        mut slf: PyRefMut<Self>,
+        optimize: Option<OptimizeQueryWrapper>,
+    ) -> PyRefMut<Self> {
+        let optimize = optimize.unwrap_or_default();
+        slf.0 = std::mem::take(&mut slf.0).optimize(optimize);
+        slf
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn execute<'py>(
+        &self,
+        py: Python<'py>,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builder = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, SolrResponseWrapper>(py, async move {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute(&context, collection.as_str())
+                .await
+                .map_err(PyErrWrapper::from)?;
+            Ok(Python::with_gil(|_| result.into()))
+        })
+    }
+
+    pub fn execute_blocking(
+        &self,
+        py: Python,
+        context: SolrServerContextWrapper,
+        collection: String,
+    ) -> PyResult<SolrResponseWrapper> {
+        let builder = self.0.clone();
+        py.allow_threads(move || {
+            let context: SolrServerContext = context.into();
+            let result = builder
+                .execute_blocking(&context, collection.as_str())
+                .map_err(PyErrWrapper::from)?;
+            Ok(result.into())
+        })
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                *self = serde_json::from_slice(s.as_bytes())
+                    .map_err(Error::from)
+                    .map_err(PyErrWrapper::from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new_bound(
+            py,
+            serde_json::to_string(&self)
+                .map_err(Error::from)
+                .map_err(PyErrWrapper::from)?
+                .as_bytes(),
+        )
+        .to_object(py))
+    }
+}
@@ -1,10 +1,11 @@
+import base64
 import json
 import os
 import time
 from dataclasses import dataclass
-from typing import Any, Dict, List, Optional
+from typing import Any, Dict, List, Optional, Tuple
 from urllib.error import HTTPError
-from urllib.request import urlopen
+from urllib.request import Request, urlopen
 
 from dotenv import load_dotenv
 
@@ -95,6 +96,20 @@ def wait_for_solr(host: str, max_time: int) -> None:
     raise RuntimeError(f"Solr did not respond within {max_time} seconds")
 
 
+def get_solr_version(config: Config) -> Tuple[int, int]:
+    """Get the (major, minor) version of the Solr server, for tests of features missing in older versions"""
+    request = Request(f"{config.solr_host}/solr/admin/info/system?wt=json")
+    if config.solr_username:
+        credentials = f"{config.solr_username}:{config.solr_password or ''}"
+        request.add_header(
+            "Authorization", f"Basic {base64.b64encode(credentials.encode()).decode()}"
+        )
+    with urlopen(request) as response:
+        version = json.loads(response.read())["lucene"]["solr-spec-version"]
+    major, minor = version.split(".")[:2]
+    return int(major), int(minor)
+
+
 @dataclass
 class ErrorTestsSetup:
     error_nginx_host: str
@@ -0,0 +1,105 @@
+from typing import Generator
+
+import pytest
+
+from solrstice import (
+    AsyncAdminTask,
+    CreateAliasOptions,
+    CreateCollectionOptions,
+    DeleteAliasOptions,
+    DeleteCollectionOptions,
+)
+from solrstice.alias import alias_exists_blocking, create_alias_blocking, delete_alias_blocking
+from solrstice.collection import (
+    collection_exists,
+    collection_exists_blocking,
+    create_collection_blocking,
+    create_collection_with_options,
+    delete_collection,
+    delete_collection_blocking,
+)
+from solrstice.config import delete_config, delete_config_blocking, upload_config, upload_config_blocking
+from solrstice.models import AdminTaskState
+
+from .helpers import Config, create_config, wait_for_solr
+
+
+@pytest.fixture()
+def config() -> Generator[Config, None, None]:
+    yield create_config()
+
+
+@pytest.mark.asyncio
+async def test_async_admin_task_waits_for_completion(config: Config) -> None:
+    name = "AsyncAdminTaskPython"
+    wait_for_solr(config.solr_host, 30)
+    task = AsyncAdminTask(name, poll_interval=0.2)
+
+    for cleanup in (delete_collection, delete_config):
+        try:
+            await cleanup(config.context, name)
+        except RuntimeError:
+            pass
+    try:
+        await task.delete_status(config.context)
+    except RuntimeError:
+        pass
+
+    try:
+        await upload_config(config.context, name, config.config_path)
+        await create_collection_with_options(
+            config.context, name, CreateCollectionOptions(config_name=name, num_shards=2, async_id=name)
+        )
+        status = await task.wait(config.context)
+        assert status.get_state() == AdminTaskState.Completed
+        assert await collection_exists(config.context, name)
+
+        await task.delete_status(config.context)
+        assert task.status_blocking(config.context).get_state() == AdminTaskState.NotFound
+    finally:
+        await delete_collection(config.context, name)
+        await delete_config(config.context, name)
+
+
+def test_async_admin_task_waits_for_alias_and_collection_deletion(config: Config) -> None:
+    name = "AsyncAdminTaskPythonAlias"
+    alias = "AsyncAdminTaskPythonAliasAlias"
+    wait_for_solr(config.solr_host, 30)
+    tasks = [AsyncAdminTask(f"{name}{step}", poll_interval=0.2) for step in ("Create", "Delete", "DeleteCollection")]
+
+    try:
+        delete_alias_blocking(config.context, alias)
+    except RuntimeError:
+        pass
+    try:
+        delete_collection_blocking(config.context, name)
+    except RuntimeError:
+        pass
+    for task in tasks:
+        try:
+            task.delete_status_blocking(config.context)
+        except RuntimeError:
+            pass
+
+    upload_config_blocking(config.context, name, config.config_path)
+    try:
+        create_collection_blocking(config.context, name, name)
+        create_alias_blocking(config.context, alias, [name], CreateAliasOptions(async_id=f"{name}Create"))
+        tasks[0].wait_blocking(config.context)
+        assert alias_exists_blocking(config.context, alias)
+
+        delete_alias_blocking(config.context, alias, DeleteAliasOptions(async_id=f"{name}Delete"))
+        tasks[1].wait_blocking(config.context)
+        assert not alias_exists_blocking(config.context, alias)
+
+        delete_collection_blocking(config.context, name, DeleteCollectionOptions(async_id=f"{name}DeleteCollection"))
+        tasks[2].wait_blocking(config.context)
+        assert not collection_exists_blocking(config.context, name)
+    finally:
+        delete_config_blocking(config.context, name)
+
+
+def test_async_admin_task_unknown_id_raises(config: Config) -> None:
+    wait_for_solr(config.solr_host, 30)
+    with pytest.raises(RuntimeError):
+        AsyncAdminTask("AsyncAdminTaskPythonUnknown").wait_blocking(config.context)
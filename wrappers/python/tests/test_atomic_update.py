@@ -0,0 +1,63 @@
+import pickle
+from typing import Generator
+
+import pytest
+
+from solrstice import AtomicUpdate, SelectQuery, UpdateQuery
+
+from .helpers import (
+    Config,
+    create_config,
+    setup_collection,
+    teardown_collection,
+    wait_for_solr,
+)
+
+
+@pytest.fixture()
+def config() -> Generator[Config, None, None]:
+    yield create_config()
+
+
+def test_atomic_update_to_dict() -> None:
+    update = AtomicUpdate(
+        "parent",
+        set={"city_name": "Alta", "removed": None},
+        add={"population": [AtomicUpdate("child", fields={"age": 3})]},
+        add_distinct={"interests": "Cars"},
+        remove_regex={"interests": "^tmp_.*"},
+        inc={"count": 2},
+    )
+    assert update.to_dict() == {
+        "id": "parent",
+        "city_name": {"set": "Alta"},
+        "removed": {"set": None},
+        "population": {"add": [{"id": "child", "age": 3}]},
+        "interests": {"add-distinct": "Cars", "removeregex": "^tmp_.*"},
+        "count": {"inc": 2},
+    }
+    assert pickle.loads(pickle.dumps(update)).to_dict() == update.to_dict()
+
+
+@pytest.mark.asyncio
+async def test_atomic_update_updates_fields(config: Config) -> None:
+    name = "AtomicUpdate"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        await UpdateQuery().execute(
+            config.context, name, [{"id": "atomic_1", "city_name": "Old", "count": 1}]
+        )
+        update = AtomicUpdate("atomic_1", set={"city_name": "New"}, inc={"count": 2})
+        await UpdateQuery().execute(config.context, name, [update])
+
+        response = await SelectQuery(fq=["id:atomic_1"], fl=["id", "city_name", "count"]).execute(
+            config.context, name
+        )
+        docs_response = response.get_docs_response()
+        assert docs_response is not None
+        assert docs_response.get_docs() == [{"id": "atomic_1", "city_name": "New", "count": 3}]
+    finally:
+        await teardown_collection(config.context, name)
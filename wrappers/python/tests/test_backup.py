@@ -0,0 +1,102 @@
+from typing import Generator
+
+import pytest
+
+from solrstice import (
+    BackupOptions,
+    DeleteBackupOptions,
+    ListBackupsOptions,
+    RestoreOptions,
+    SelectQuery,
+    UpdateQuery,
+)
+from solrstice.backup import (
+    backup_collection,
+    delete_backup,
+    delete_backup_blocking,
+    list_backups,
+    list_backups_blocking,
+    restore_collection,
+)
+from solrstice.collection import create_collection, delete_collection
+from solrstice.config import delete_config, upload_config
+
+from .helpers import Config, create_config, get_solr_version, wait_for_solr
+
+BACKUP_LOCATION = "/var/solr/backups"
+
+
+@pytest.fixture()
+def config() -> Generator[Config, None, None]:
+    yield create_config()
+
+
+@pytest.mark.asyncio
+async def test_backup_list_restore_and_delete_backup(config: Config) -> None:
+    name = "PyBackupCollection"
+    restored_name = "PyBackupRestoredCollection"
+    backup_name = "PyBackupCollectionBackup"
+    wait_for_solr(config.solr_host, 30)
+    if get_solr_version(config) < (8, 9):
+        pytest.skip("Incremental backups, LISTBACKUP and DELETEBACKUP require Solr 8.9")
+
+    for collection in [restored_name, name]:
+        try:
+            await delete_collection(config.context, collection)
+        except RuntimeError:
+            pass
+    try:
+        await delete_config(config.context, name)
+    except RuntimeError:
+        pass
+    list_options = ListBackupsOptions(location=BACKUP_LOCATION)
+    try:
+        for backup in list_backups_blocking(config.context, backup_name, list_options):
+            delete_backup_blocking(
+                config.context,
+                backup_name,
+                DeleteBackupOptions(location=BACKUP_LOCATION, backup_id=backup.get_backup_id()),
+            )
+    except RuntimeError:
+        pass
+    await upload_config(config.context, name, config.config_path)
+    await create_collection(config.context, name, name, 2, 1)
+    await UpdateQuery().execute(config.context, name, [{"id": "1"}, {"id": "2"}])
+
+    options = BackupOptions(location=BACKUP_LOCATION, incremental=True)
+    first = await backup_collection(config.context, name, backup_name, options)
+    assert first.get_collection() == name
+    assert len(first.get_shards()) == 2
+    for shard in first.get_shards():
+        file_count = shard.get_index_file_count()
+        assert file_count is not None and file_count > 0
+    first_id = first.get_backup_id()
+    assert first_id is not None
+    second = await backup_collection(config.context, name, backup_name, options)
+    assert second.get_backup_id() == first_id + 1
+
+    backups = await list_backups(config.context, backup_name, list_options)
+    assert [backup.get_backup_id() for backup in backups] == [first_id, first_id + 1]
+
+    await restore_collection(
+        config.context,
+        restored_name,
+        backup_name,
+        RestoreOptions(location=BACKUP_LOCATION, backup_id=first_id),
+    )
+    response = await SelectQuery().execute(config.context, restored_name)
+    docs = response.get_docs_response()
+    assert docs is not None
+    assert docs.get_num_found() == 2
+
+    deleted = await delete_backup(
+        config.context,
+        backup_name,
+        DeleteBackupOptions(location=BACKUP_LOCATION, max_num_backup_points=1),
+    )
+    assert [backup.get_backup_id() for backup in deleted] == [first_id]
+    await delete_backup(config.context, backup_name, DeleteBackupOptions(location=BACKUP_LOCATION, purge_unused=True))
+
+    await delete_collection(config.context, restored_name)
+    await delete_collection(config.context, name)
+    await delete_config(config.context, name)
@@ -0,0 +1,90 @@
+from typing import Any, Dict, Generator, Iterator, List
+
+import pytest
+
+from solrstice import BulkIndexer, SelectQuery, UpdateQuery
+from solrstice.models import BulkIndexProgress
+
+from .helpers import (
+    Config,
+    create_config,
+    setup_collection,
+    teardown_collection,
+    wait_for_solr,
+)
+
+
+@pytest.fixture()
+def config() -> Generator[Config, None, None]:
+    yield create_config()
+
+
+def generate_docs(count: int) -> Iterator[Dict[str, Any]]:
+    for i in range(count):
+        yield {"id": f"bulk_{i}", "city_name": "Bulk"}
+
+
+@pytest.mark.asyncio
+async def test_bulk_index_indexes_generator(config: Config) -> None:
+    name = "BulkIndexGenerator"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        progress: List[BulkIndexProgress] = []
+        indexer = BulkIndexer(batch_size=100, concurrency=3, on_progress=progress.append)
+        summary = await indexer.execute(config.context, name, generate_docs(1050))
+        assert summary.get_batches() == 11
+        assert summary.get_docs_indexed() == 1050
+        assert summary.get_failed_batches() == 0
+        assert len(progress) == 11
+        assert all(p.get_error() is None for p in progress)
+
+        response = await SelectQuery(fq=["city_name:Bulk"], rows=0).execute(config.context, name)
+        docs_response = response.get_docs_response()
+        assert docs_response is not None
+        assert docs_response.get_num_found() == 1050
+    finally:
+        await teardown_collection(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_bulk_index_blocking_raises_generator_error(config: Config) -> None:
+    name = "BulkIndexGeneratorError"
+    wait_for_solr(config.solr_host, 30)
+
+    def failing_docs() -> Iterator[Dict[str, Any]]:
+        yield {"id": "bulk_ok"}
+        raise ValueError("Generator failed")
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        with pytest.raises(ValueError):
+            BulkIndexer().execute_blocking(config.context, name, failing_docs())
+    finally:
+        await teardown_collection(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_bulk_index_reports_update_errors_of_tolerant_chain(config: Config) -> None:
+    name = "BulkIndexTolerant"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        progress: List[BulkIndexProgress] = []
+        indexer = BulkIndexer(
+            update_query=UpdateQuery(update_chain="tolerant", max_errors=-1),
+            on_progress=progress.append,
+        )
+        docs = [{"id": "bulk_tolerant_1", "age": 20}, {"id": "bulk_tolerant_2", "age": "not a number"}]
+        summary = await indexer.execute(config.context, name, docs)
+        assert summary.get_failed_batches() == 0
+        assert summary.get_docs_indexed() == 1
+        assert summary.get_docs_failed() == 1
+        assert [e.get_id() for p in progress for e in p.get_update_errors()] == ["bulk_tolerant_2"]
+    finally:
+        await teardown_collection(config.context, name)
@@ -2,13 +2,28 @@ from typing import Generator
 
 import pytest
 
+from solrstice import (
+    AsyncAdminTask,
+    ColStatusOptions,
+    CollectionRouter,
+    CreateCollectionOptions,
+    ModifyCollectionOptions,
+    ReloadCollectionOptions,
+)
 from solrstice.collection import (
     collection_exists,
     collection_exists_blocking,
     create_collection,
     create_collection_blocking,
+    create_collection_with_options,
     delete_collection,
     delete_collection_blocking,
+    get_cluster_status,
+    get_cluster_status_blocking,
+    get_col_status,
+    modify_collection,
+    reload_collection,
+    reload_collections_using_config_blocking,
 )
 from solrstice.config import (
     config_exists,
@@ -19,7 +34,9 @@ from solrstice.config import (
     upload_config_blocking,
 )
 
-from .helpers import Config, create_config
+from solrstice.models import ReplicaType, ShardState
+
+from .helpers import Config, create_config, get_solr_version
 
 
 @pytest.fixture()
@@ -74,3 +91,111 @@ def test_collection_all_blocking_functions_exported(config: Config) -> None:
     assert collection_exists_blocking(config.context, name)
     delete_collection_blocking(config.context, name)
     delete_config_blocking(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_create_collection_with_options(config: Config) -> None:
+    name = "CollectionWithOptions"
+
+    try:
+        await delete_collection(config.context, name)
+    except RuntimeError:
+        pass
+    try:
+        await delete_config(config.context, name)
+    except RuntimeError:
+        pass
+    await upload_config(config.context, name, config.config_path)
+    options = CreateCollectionOptions(
+        config_name=name,
+        router=CollectionRouter.Implicit,
+        shards=["shard_a", "shard_b"],
+        nrt_replicas=1,
+        properties={"solr.autoSoftCommit.maxTime": "1000"},
+        wait_for_final_state=True,
+    )
+    await create_collection_with_options(config.context, name, options)
+    assert await collection_exists(config.context, name)
+    await delete_collection(config.context, name)
+    await delete_config(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_get_cluster_status(config: Config) -> None:
+    name = "ClusterStatusCollection"
+
+    try:
+        await delete_collection(config.context, name)
+    except RuntimeError:
+        pass
+    try:
+        await delete_config(config.context, name)
+    except RuntimeError:
+        pass
+    await upload_config(config.context, name, config.config_path)
+    options = CreateCollectionOptions(config_name=name, num_shards=2, nrt_replicas=1, wait_for_final_state=True)
+    await create_collection_with_options(config.context, name, options)
+
+    status = await get_cluster_status(config.context, name)
+    assert len(status.get_live_nodes()) > 0
+    collection = status.get_collection(name)
+    assert collection is not None
+    assert collection.get_config_name() == name
+    assert collection.get_router() == CollectionRouter.CompositeId
+    assert len(collection.get_shards()) == 2
+    for shard in collection.get_shards().values():
+        assert shard.get_state() == ShardState.Active
+        leader = shard.get_leader()
+        assert leader is not None
+        assert leader.get_type() == ReplicaType.Nrt
+        assert leader.get_node_name() in status.get_live_nodes()
+
+    status = get_cluster_status_blocking(config.context, name, ["shard1"])
+    collection = status.get_collection(name)
+    assert collection is not None
+    assert list(collection.get_shards().keys()) == ["shard1"]
+    await delete_collection(config.context, name)
+    await delete_config(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_modify_reload_and_get_col_status(config: Config) -> None:
+    name = "ModifyCollectionCollection"
+
+    try:
+        await delete_collection(config.context, name)
+    except RuntimeError:
+        pass
+    try:
+        await delete_config(config.context, name)
+    except RuntimeError:
+        pass
+    await upload_config(config.context, name, config.config_path)
+    await create_collection(config.context, name, name, 1, 1)
+
+    await modify_collection(config.context, name, ModifyCollectionOptions(properties={"team": "search"}))
+    task = AsyncAdminTask(f"{name}Reload", poll_interval=0.2)
+    try:
+        await task.delete_status(config.context)
+    except RuntimeError:
+        pass
+    await reload_collection(config.context, name, ReloadCollectionOptions(async_id=task.get_request_id()))
+    await task.wait(config.context)
+    await task.delete_status(config.context)
+    assert reload_collections_using_config_blocking(config.context, name) == [name]
+
+    # COLSTATUS requires Solr 8.1
+    if get_solr_version(config) >= (8, 1):
+        status = await get_col_status(config.context, name, ColStatusOptions(core_info=True))
+        collection = status.get_collection(name)
+        assert collection is not None
+        assert collection.get_active_shards() == 1
+        shard = collection.get_shard("shard1")
+        assert shard is not None
+        leader = shard.get_leader()
+        assert leader is not None
+        segments_info = leader.get_segments_info()
+        assert segments_info is not None
+        assert segments_info.get_size_in_gb() is not None
+    await delete_collection(config.context, name)
+    await delete_config(config.context, name)
@@ -0,0 +1,49 @@
+from typing import Generator
+
+import pytest
+
+from solrstice import (
+    AsyncSolrCloudClient,
+    OffLoggingPolicy,
+    SelectQuery,
+    SolrServerContext,
+    UpdateQuery,
+)
+
+from .helpers import (
+    Config,
+    create_config,
+    setup_collection,
+    teardown_collection,
+    wait_for_solr,
+)
+
+
+@pytest.fixture()
+def config() -> Generator[Config, None, None]:
+    yield create_config()
+
+
+@pytest.mark.asyncio
+async def test_compression_indexes_and_selects_documents(config: Config) -> None:
+    name = "CompressionIndexSelect"
+    wait_for_solr(config.solr_host, 30)
+
+    context = SolrServerContext(
+        config.solr_host,
+        config.solr_auth,
+        OffLoggingPolicy(),
+        request_compression=0,
+        response_compression=True,
+    )
+    client = AsyncSolrCloudClient(context)
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        await client.index(UpdateQuery(), name, [{"id": "compressed"}])
+        response = await client.select(SelectQuery(fq=["id:compressed"]), name)
+        docs = response.get_docs_response()
+        assert docs is not None
+        assert docs.get_num_found() == 1
+    finally:
+        await teardown_collection(config.context, name)
@@ -0,0 +1,83 @@
+import os
+import tempfile
+from typing import Generator
+
+import pytest
+
+from .helpers import (
+    Config,
+    create_config,
+    get_solr_version,
+    wait_for_solr,
+    setup_collection,
+    index_test_data,
+    teardown_collection,
+)
+from solrstice import SelectQuery, ElevationComponent
+from solrstice.config import upload_elevation_config
+
+
+@pytest.fixture()
+def config() -> Generator[Config, None, None]:
+    yield create_config()
+
+
+@pytest.mark.asyncio
+async def test_elevation_elevates_and_excludes_ids(config: Config) -> None:
+    name = "ElevationIds"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+        await index_test_data(config.context, name)
+
+        select_builder = SelectQuery(
+            q="city_name:Alta",
+            elevation=ElevationComponent(
+                elevate_ids=["city_Tromsø"], exclude_ids=["city_Alta"], elevated_flag=True
+            ),
+        )
+        response = await config.async_client.select(select_builder, name)
+        docs_response = response.get_docs_response()
+        assert docs_response is not None
+        docs = docs_response.get_docs()
+        assert docs[0]["id"] == "city_Tromsø"
+        assert docs[0]["[elevated]"] is True
+        assert all(doc["id"] != "city_Alta" for doc in docs)
+    finally:
+        await teardown_collection(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_upload_elevation_config_reloads_collections(config: Config) -> None:
+    if get_solr_version(config) < (8, 7):
+        pytest.skip("Uploading a single file into a config set requires Solr 8.7")
+    name = "ElevationUpload"
+    wait_for_solr(config.solr_host, 30)
+
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "elevate.xml")
+        with open(path, "w", encoding="utf-8") as f:
+            f.write(
+                '<?xml version="1.0" encoding="UTF-8" ?>\n'
+                "<elevate>\n"
+                '    <query text="city_name:Alta">\n'
+                '        <doc id="city_Tromsø"/>\n'
+                "    </query>\n"
+                "</elevate>"
+            )
+        try:
+            await setup_collection(config.context, name, config.config_path)
+            await index_test_data(config.context, name)
+
+            reloaded = await upload_elevation_config(config.context, name, path)
+            assert reloaded == [name]
+
+            response = await config.async_client.select(
+                SelectQuery(q="city_name:Alta"), name
+            )
+            docs_response = response.get_docs_response()
+            assert docs_response is not None
+            assert docs_response.get_docs()[0]["id"] == "city_Tromsø"
+        finally:
+            await teardown_collection(config.context, name)
@@ -0,0 +1,64 @@
+from pathlib import Path
+from typing import Generator
+
+import pytest
+
+from solrstice import ExtractFormat, ExtractQuery, SelectQuery
+
+from .helpers import (
+    Config,
+    create_config,
+    setup_collection,
+    teardown_collection,
+    wait_for_solr,
+)
+
+
+@pytest.fixture()
+def config() -> Generator[Config, None, None]:
+    yield create_config()
+
+
+@pytest.mark.asyncio
+async def test_extract_indexes_file_with_literals(config: Config, tmp_path: Path) -> None:
+    name = "ExtractIndexesFile"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        path = tmp_path / "report.txt"
+        path.write_text("A report about Tromsø")
+        await ExtractQuery(
+            literals={"id": "extract_1", "city_name": "Tromsø"}, uprefix="ignored_"
+        ).execute_file(config.context, name, path)
+
+        response = await SelectQuery(fl=["id", "city_name"]).execute(config.context, name)
+        docs_response = response.get_docs_response()
+        assert docs_response is not None
+        assert docs_response.get_docs() == [{"id": "extract_1", "city_name": "Tromsø"}]
+    finally:
+        await teardown_collection(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_extract_only_returns_text_and_metadata(config: Config, tmp_path: Path) -> None:
+    name = "ExtractOnly"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        path = tmp_path / "report.txt"
+        path.write_text("A report about Tromsø")
+        response = await ExtractQuery(extract_format=ExtractFormat.Text).extract_only_file(
+            config.context, name, path
+        )
+        assert "A report about Tromsø" in response.get_content()
+        assert response.get_name() == "report.txt"
+        assert any(
+            content_type.startswith("text/plain")
+            for content_type in response.get_metadata()["Content-Type"]
+        )
+    finally:
+        await teardown_collection(config.context, name)
@@ -1,8 +1,21 @@
+from pathlib import Path
 from typing import Generator
 
 import pytest
 
-from solrstice import CommitType, UpdateQuery
+from solrstice import (
+    AtomicUpdate,
+    CommitQuery,
+    CommitType,
+    CsvUpdateQuery,
+    DeleteById,
+    DeleteQuery,
+    JsonDocsUpdateQuery,
+    OptimizeQuery,
+    SelectQuery,
+    UpdateBatch,
+    UpdateQuery,
+)
 
 from .helpers import (
     Config,
@@ -31,3 +44,174 @@ async def test_index_indexes_documents(config: Config) -> None:
         )
     finally:
         await teardown_collection(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_index_enforces_versions(config: Config) -> None:
+    name = "IndexEnforcesVersions"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        response = await UpdateQuery(return_versions=True).execute(
+            config.context, name, [{"id": "versioned_1", "_version_": -1}]
+        )
+        versions = response.get_added_versions()
+        assert versions is not None
+        version = versions["versioned_1"]
+
+        with pytest.raises(RuntimeError, match="version conflict"):
+            await UpdateQuery().execute(
+                config.context,
+                name,
+                [AtomicUpdate("versioned_1", version=version + 1, set={"city_name": "Alta"})],
+            )
+
+        response = await DeleteQuery(
+            ids=[DeleteById("versioned_1", version=version)], return_versions=True
+        ).execute(config.context, name)
+        deleted = response.get_deleted_versions()
+        assert deleted is not None
+        assert "versioned_1" in deleted
+    finally:
+        await teardown_collection(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_explicit_commit_makes_documents_visible(config: Config) -> None:
+    name = "IndexExplicitCommit"
+    wait_for_solr(config.solr_host, 30)
+
+    async def num_found() -> int:
+        response = await SelectQuery(fq=["id:uncommitted_1"]).execute(config.context, name)
+        docs_response = response.get_docs_response()
+        assert docs_response is not None
+        return docs_response.get_num_found()
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        await UpdateQuery(commit_type=CommitType.NoCommit, overwrite=False).execute(
+            config.context, name, [{"id": "uncommitted_1"}]
+        )
+        assert await num_found() == 0
+
+        await CommitQuery(wait_searcher=True).execute(config.context, name)
+        assert await num_found() == 1
+
+        await OptimizeQuery(max_segments=1).execute(config.context, name)
+    finally:
+        await teardown_collection(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_delete_escapes_ids_and_queries(config: Config) -> None:
+    name = "IndexDeleteEscapes"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        ids = ["a&b", "<id>c</id>", "d"]
+        await UpdateQuery().execute(config.context, name, [{"id": id} for id in ids])
+        await DeleteQuery(
+            ids=["a&b", DeleteById("<id>c</id>", version=1)], queries=['id:"d"']
+        ).execute(config.context, name)
+
+        response = await SelectQuery().execute(config.context, name)
+        docs_response = response.get_docs_response()
+        assert docs_response is not None
+        assert docs_response.get_num_found() == 0
+    finally:
+        await teardown_collection(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_update_batch_applies_commands_in_order(config: Config) -> None:
+    name = "IndexUpdateBatch"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        batch = (
+            UpdateBatch()
+            .add_doc({"id": "batch_1"})
+            .add_doc({"id": "batch_2"}, overwrite=False)
+            .add_doc({"id": "batch_3"}, commit_within=1000)
+            .delete_by_id("batch_1")
+            .delete_by_query("id:batch_3")
+            .commit(CommitQuery())
+        )
+        assert len(batch) == 6
+        await batch.execute(config.context, name)
+
+        response = await SelectQuery(fl=["id"]).execute(config.context, name)
+        docs_response = response.get_docs_response()
+        assert docs_response is not None
+        assert docs_response.get_docs() == [{"id": "batch_2"}]
+    finally:
+        await teardown_collection(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_csv_and_json_docs_files_are_streamed(config: Config, tmp_path: Path) -> None:
+    name = "IndexFileUpdate"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        csv_path = tmp_path / "export.csv"
+        csv_path.write_text("id;city_name;interests\ncsv_1;Alta;fishing|hiking\n")
+        await CsvUpdateQuery(
+            separator=";", split=["interests"], split_separator="|"
+        ).execute_file(config.context, name, csv_path)
+
+        ndjson_path = tmp_path / "export.ndjson"
+        ndjson_path.write_text('{"id": "json_1", "city": {"name": "Oslo"}}\n')
+        await JsonDocsUpdateQuery(
+            split="/", f=["id:/id", "city_name:/city/name"]
+        ).execute_file(config.context, name, str(ndjson_path))
+
+        response = await SelectQuery(
+            fl=["id", "city_name", "interests"], sort=["id asc"]
+        ).execute(config.context, name)
+        docs_response = response.get_docs_response()
+        assert docs_response is not None
+        assert docs_response.get_docs() == [
+            {"id": "csv_1", "city_name": "Alta", "interests": ["fishing", "hiking"]},
+            {"id": "json_1", "city_name": "Oslo"},
+        ]
+    finally:
+        await teardown_collection(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_tolerant_update_reports_failed_documents(config: Config) -> None:
+    name = "IndexTolerantUpdate"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        response = await UpdateQuery(update_chain="tolerant", max_errors=-1).execute(
+            config.context,
+            name,
+            [
+                {"id": "tolerant_1", "age": 20},
+                {"id": "tolerant_2", "age": "not a number"},
+            ],
+        )
+        errors = response.get_update_errors()
+        assert len(errors) == 1
+        assert errors[0].get_id() == "tolerant_2"
+        assert errors[0].get_error_type() == "ADD"
+
+        select_response = await SelectQuery().execute(config.context, name)
+        docs_response = select_response.get_docs_response()
+        assert docs_response is not None
+        assert docs_response.get_num_found() == 1
+    finally:
+        await teardown_collection(config.context, name)
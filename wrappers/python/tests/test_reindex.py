@@ -0,0 +1,84 @@
+from typing import Any, Dict, Generator, List, Optional
+
+import pytest
+
+from solrstice import BulkIndexer, Reindexer, SelectQuery
+from solrstice.models import ReindexProgress
+
+from .helpers import (
+    Config,
+    create_config,
+    setup_collection,
+    teardown_collection,
+    wait_for_solr,
+)
+
+
+@pytest.fixture()
+def config() -> Generator[Config, None, None]:
+    yield create_config()
+
+
+def reindex_query(rows: int) -> SelectQuery:
+    return SelectQuery(fq=["city_name:Reindex"], sort=["id asc"], rows=rows)
+
+
+@pytest.mark.asyncio
+async def test_reindex_copies_and_transforms_documents(config: Config) -> None:
+    source = "ReindexSource"
+    target = "ReindexTarget"
+    wait_for_solr(config.solr_host, 30)
+
+    def transform(doc: Dict[str, Any]) -> Optional[Dict[str, Any]]:
+        if doc["id"] == "reindex_0":
+            return None
+        doc["age"] = 10
+        return doc
+
+    try:
+        await setup_collection(config.context, source, config.config_path)
+        await setup_collection(config.context, target, config.config_path)
+        await BulkIndexer().execute(
+            config.context, source, ({"id": f"reindex_{i}", "city_name": "Reindex"} for i in range(25))
+        )
+
+        progress: List[ReindexProgress] = []
+        reindexer = Reindexer(query=reindex_query(10), transform=transform, on_progress=progress.append)
+        summary = await reindexer.execute(config.context, source, config.context, target)
+        assert summary.get_pages() == 3
+        assert summary.get_docs_read() == 25
+        assert summary.get_docs_skipped() == 1
+        assert summary.get_docs_indexed() == 24
+        assert [p.get_docs_read() for p in progress] == [10, 20, 25]
+        assert progress[-1].get_cursor_mark() == summary.get_cursor_mark()
+
+        response = await SelectQuery(fq=["age:10"], rows=0).execute(config.context, target)
+        docs_response = response.get_docs_response()
+        assert docs_response is not None
+        assert docs_response.get_num_found() == 24
+    finally:
+        await teardown_collection(config.context, source)
+        await teardown_collection(config.context, target)
+
+
+@pytest.mark.asyncio
+async def test_reindex_blocking_raises_transform_error(config: Config) -> None:
+    source = "ReindexTransformErrorSource"
+    target = "ReindexTransformErrorTarget"
+    wait_for_solr(config.solr_host, 30)
+
+    def transform(doc: Dict[str, Any]) -> Optional[Dict[str, Any]]:
+        raise ValueError("Transform failed")
+
+    try:
+        await setup_collection(config.context, source, config.config_path)
+        await setup_collection(config.context, target, config.config_path)
+        await BulkIndexer().execute(config.context, source, [{"id": "reindex_0", "city_name": "Reindex"}])
+
+        with pytest.raises(ValueError):
+            Reindexer(query=reindex_query(10), transform=transform).execute_blocking(
+                config.context, source, config.context, target
+            )
+    finally:
+        await teardown_collection(config.context, source)
+        await teardown_collection(config.context, target)
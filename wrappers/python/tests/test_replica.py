@@ -0,0 +1,85 @@
+from typing import Generator, List
+
+import pytest
+
+from solrstice import AddReplicaOptions, CreateCollectionOptions, DeleteReplicaOptions, ReplicaPropertyOptions
+from solrstice.collection import (
+    add_replica,
+    add_replica_property,
+    create_collection_with_options,
+    delete_collection,
+    delete_replica,
+    delete_replica_blocking,
+    delete_replica_property,
+    get_cluster_status,
+)
+from solrstice.config import delete_config, upload_config
+from solrstice.models import ReplicaType, ShardStatus
+
+from .helpers import Config, create_config, wait_for_solr
+
+
+@pytest.fixture()
+def config() -> Generator[Config, None, None]:
+    yield create_config()
+
+
+def replicas_of(shard: ShardStatus, replica_type: ReplicaType) -> List[str]:
+    return [name for name, replica in shard.get_replicas().items() if replica.get_type() == replica_type]
+
+
+@pytest.mark.asyncio
+async def test_add_and_delete_replicas(config: Config) -> None:
+    name = "ReplicaCollection"
+    wait_for_solr(config.solr_host, 30)
+    try:
+        await delete_collection(config.context, name)
+    except RuntimeError:
+        pass
+    try:
+        await delete_config(config.context, name)
+    except RuntimeError:
+        pass
+
+    try:
+        await upload_config(config.context, name, config.config_path)
+        await create_collection_with_options(
+            config.context,
+            name,
+            CreateCollectionOptions(config_name=name, num_shards=1, nrt_replicas=1, wait_for_final_state=True),
+        )
+        await add_replica(
+            config.context,
+            name,
+            "shard1",
+            AddReplicaOptions(replica_type=ReplicaType.Tlog, wait_for_final_state=True),
+        )
+        await add_replica(config.context, name, "shard1", AddReplicaOptions(pull_replicas=2, wait_for_final_state=True))
+
+        status = await get_cluster_status(config.context, name)
+        collection = status.get_collection(name)
+        assert collection is not None
+        shard = collection.get_shards()["shard1"]
+        assert len(replicas_of(shard, ReplicaType.Nrt)) == 1
+        assert len(replicas_of(shard, ReplicaType.Tlog)) == 1
+        assert len(replicas_of(shard, ReplicaType.Pull)) == 2
+        tlog_replica = replicas_of(shard, ReplicaType.Tlog)[0]
+
+        options = ReplicaPropertyOptions(shard_unique=True)
+        await add_replica_property(config.context, name, "shard1", tlog_replica, "preferredLeader", "true", options)
+        await delete_replica_property(config.context, name, "shard1", tlog_replica, "preferredLeader")
+
+        await delete_replica(config.context, name, DeleteReplicaOptions(shard="shard1", replica=tlog_replica))
+        delete_replica_blocking(
+            config.context, name, DeleteReplicaOptions(shard="shard1", count=1, replica_type=ReplicaType.Pull)
+        )
+
+        status = await get_cluster_status(config.context, name)
+        collection = status.get_collection(name)
+        assert collection is not None
+        shard = collection.get_shards()["shard1"]
+        assert len(shard.get_replicas()) == 2
+        assert tlog_replica not in shard.get_replicas()
+    finally:
+        await delete_collection(config.context, name)
+        await delete_config(config.context, name)
@@ -2,7 +2,7 @@ from typing import Generator
 
 import pytest
 
-from solrstice import SelectQuery
+from solrstice import SelectQuery, SolrBasicAuth, SolrServerContext, SolrSingleServerHost
 
 from .helpers import (
     Config,
@@ -192,3 +192,54 @@ async def test_select_works_with_additional_params(config: Config) -> None:
         assert len(child_result["docs"]) == 1
     finally:
         await teardown_collection(config.context, name)
+
+
+def test_to_solr_params_repeats_multivalued_params() -> None:
+    builder = SelectQuery(q="age:[* TO *]", fq=["id:1", "id:2"], sort=["age desc", "id asc"])
+    params = builder.to_solr_params()
+    assert ("q", "age:[* TO *]") in params
+    assert ("fq", "id:1") in params
+    assert ("fq", "id:2") in params
+    assert ("sort", "age desc,id asc") in params
+
+
+def test_from_query_string_round_trips() -> None:
+    builder = SelectQuery(q="age:[* TO *]", fq=["id:1", "id:2"], sort=["age desc", "id asc"])
+    query_string = "&".join(f"{key}={value}" for key, value in builder.to_solr_params())
+    parsed = SelectQuery.from_query_string(query_string)
+    assert parsed.to_solr_params() == builder.to_solr_params()
+
+
+def test_to_curl_masks_auth() -> None:
+    context = SolrServerContext(SolrSingleServerHost("http://localhost:8983"), SolrBasicAuth("solr", "SolrRocks"))
+    builder = SelectQuery(fq=["age:[* TO *]"])
+    curl = builder.to_curl(context, "collection_name")
+    assert curl.startswith("curl -X POST 'http://localhost:8983/solr/collection_name/select?wt=json'")
+    assert "Basic ****" in curl
+    assert "SolrRocks" not in curl
+    url = builder.to_url(context, "collection_name")
+    assert url == "http://localhost:8983/solr/collection_name/select?fq=age%3A%5B*+TO+*%5D&q=*%3A*&wt=json"
+
+
+@pytest.mark.asyncio
+async def test_select_response_header(config: Config) -> None:
+    name = "SelectResponseHeader"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await setup_collection(config.context, name, config.config_path)
+
+        builder = SelectQuery(
+            time_allowed=10000, fail_on_partial_results=True, additional_params={"echoParams": "explicit"}
+        )
+        solr_response = await builder.execute(config.context, name)
+        header = solr_response.get_response_header()
+        assert header is not None
+        assert header["status"] == 0
+        assert header["params"]["timeAllowed"] == "10000"
+        assert not solr_response.get_partial_results()
+
+        solr_response = await SelectQuery(omit_header=True).execute(config.context, name)
+        assert solr_response.get_response_header() is None
+    finally:
+        await teardown_collection(config.context, name)
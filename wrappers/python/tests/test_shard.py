@@ -0,0 +1,109 @@
+from typing import Generator
+
+import pytest
+
+from solrstice import (
+    AsyncAdminTask,
+    CollectionRouter,
+    CreateCollectionOptions,
+    CreateShardOptions,
+    DeleteShardOptions,
+    SplitMethod,
+    SplitShardOptions,
+)
+from solrstice.collection import (
+    create_collection_with_options,
+    create_shard,
+    delete_collection,
+    delete_shard,
+    delete_shard_blocking,
+    get_cluster_status,
+    split_shard,
+)
+from solrstice.config import delete_config, upload_config
+from solrstice.models import ShardState
+
+from .helpers import Config, create_config, wait_for_solr
+
+
+@pytest.fixture()
+def config() -> Generator[Config, None, None]:
+    yield create_config()
+
+
+async def recreate_collection(config: Config, name: str, options: CreateCollectionOptions) -> None:
+    try:
+        await delete_collection(config.context, name)
+    except RuntimeError:
+        pass
+    try:
+        await delete_config(config.context, name)
+    except RuntimeError:
+        pass
+    await upload_config(config.context, name, config.config_path)
+    await create_collection_with_options(config.context, name, options)
+
+
+@pytest.mark.asyncio
+async def test_split_shard_creates_sub_shards(config: Config) -> None:
+    name = "SplitShardCollection"
+    wait_for_solr(config.solr_host, 30)
+    task = AsyncAdminTask(name, poll_interval=0.2)
+    try:
+        await task.delete_status(config.context)
+    except RuntimeError:
+        pass
+
+    try:
+        await recreate_collection(
+            config, name, CreateCollectionOptions(config_name=name, num_shards=1, wait_for_final_state=True)
+        )
+        options = SplitShardOptions(shard="shard1", split_method=SplitMethod.Rewrite, async_id=name)
+        await split_shard(config.context, name, options)
+        await task.wait(config.context)
+        await task.delete_status(config.context)
+
+        status = await get_cluster_status(config.context, name)
+        collection = status.get_collection(name)
+        assert collection is not None
+        assert collection.get_shards()["shard1"].get_state() == ShardState.Inactive
+        assert collection.get_shards()["shard1_0"].get_state() == ShardState.Active
+        assert collection.get_shards()["shard1_1"].get_state() == ShardState.Active
+
+        delete_shard_blocking(config.context, name, "shard1", DeleteShardOptions(delete_instance_dir=True))
+        status = await get_cluster_status(config.context, name)
+        collection = status.get_collection(name)
+        assert collection is not None
+        assert collection.get_shard("shard1") is None
+    finally:
+        await delete_collection(config.context, name)
+        await delete_config(config.context, name)
+
+
+@pytest.mark.asyncio
+async def test_create_shard_adds_shard(config: Config) -> None:
+    name = "CreateShardCollection"
+    wait_for_solr(config.solr_host, 30)
+
+    try:
+        await recreate_collection(
+            config,
+            name,
+            CreateCollectionOptions(
+                config_name=name, router=CollectionRouter.Implicit, shards=["2024"], wait_for_final_state=True
+            ),
+        )
+        await create_shard(config.context, name, "2025", CreateShardOptions(nrt_replicas=1, wait_for_final_state=True))
+        status = await get_cluster_status(config.context, name)
+        collection = status.get_collection(name)
+        assert collection is not None
+        assert sorted(collection.get_shards().keys()) == ["2024", "2025"]
+
+        await delete_shard(config.context, name, "2024")
+        status = await get_cluster_status(config.context, name)
+        collection = status.get_collection(name)
+        assert collection is not None
+        assert list(collection.get_shards().keys()) == ["2025"]
+    finally:
+        await delete_collection(config.context, name)
+        await delete_config(config.context, name)